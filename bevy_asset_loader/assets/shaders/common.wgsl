@@ -0,0 +1,3 @@
+fn brighten(color: vec4<f32>) -> vec4<f32> {
+    return vec4<f32>(min(color.rgb * 1.5, vec3<f32>(1.0)), color.a);
+}
@@ -0,0 +1,6 @@
+#import "shaders/common.wgsl"
+
+@fragment
+fn fragment(@location(0) color: vec4<f32>) -> @location(0) vec4<f32> {
+    return brighten(color);
+}
@@ -0,0 +1,113 @@
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+/// This example shows how to order two loading states that are active at the same time
+///
+/// The boot loading state loads a font, while the menu assets already load in the background.
+/// The loading screen of the menu shows its text in the boot font. Its systems are ordered after
+/// the boot loading state, so they see the `BootAssets` in the same frame that boot finished.
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_state::<BootState>()
+        .add_state::<MenuState>()
+        .add_loading_state(LoadingState::new(BootState::Booting).continue_to_state(BootState::Done))
+        .add_collection_to_loading_state::<_, BootAssets>(BootState::Booting)
+        .add_loading_state(
+            LoadingState::new(MenuState::Loading)
+                .continue_to_state(MenuState::Ready)
+                .with_loading_system(show_loading_text),
+        )
+        .add_collection_to_loading_state::<_, MenuAssets>(MenuState::Loading)
+        .configure_set(
+            Update,
+            LoadingStateSet(BootState::Booting).before(LoadingStateSet(MenuState::Loading)),
+        )
+        .configure_set(
+            Update,
+            LoadingSystemSet(MenuState::Loading).after(LoadingStateSet(BootState::Booting)),
+        )
+        .add_systems(Startup, spawn_camera)
+        .add_systems(OnEnter(MenuState::Ready), show_menu)
+        .run();
+}
+
+#[derive(AssetCollection, Resource)]
+struct BootAssets {
+    #[asset(path = "fonts/FiraSans-Bold.ttf")]
+    font: Handle<Font>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct MenuAssets {
+    #[asset(path = "images/background.png")]
+    background: Handle<Image>,
+    #[asset(path = "audio/background.ogg")]
+    music: Handle<AudioSource>,
+}
+
+#[derive(Component)]
+struct LoadingText;
+
+fn spawn_camera(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+}
+
+fn show_loading_text(
+    mut commands: Commands,
+    boot_assets: Option<Res<BootAssets>>,
+    loading_text: Query<(), With<LoadingText>>,
+) {
+    let Some(boot_assets) = boot_assets else {
+        return;
+    };
+    if !loading_text.is_empty() {
+        return;
+    }
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section(
+                "Loading menu...",
+                TextStyle {
+                    font: boot_assets.font.clone(),
+                    font_size: 40.,
+                    color: Color::WHITE,
+                },
+            ),
+            ..default()
+        },
+        LoadingText,
+    ));
+}
+
+fn show_menu(
+    mut commands: Commands,
+    menu_assets: Res<MenuAssets>,
+    loading_text: Query<Entity, With<LoadingText>>,
+) {
+    for entity in &loading_text {
+        commands.entity(entity).despawn();
+    }
+    commands.spawn(SpriteBundle {
+        texture: menu_assets.background.clone(),
+        ..default()
+    });
+    commands.spawn(AudioBundle {
+        source: menu_assets.music.clone(),
+        settings: PlaybackSettings::LOOP,
+    });
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum BootState {
+    #[default]
+    Booting,
+    Done,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MenuState {
+    #[default]
+    Loading,
+    Ready,
+}
@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+/// This example shows the built-in failure screen
+///
+/// One asset of the collection does not exist. The failure screen lists it
+/// and offers a button to retry loading the collection.
+///
+/// Requires the feature 'failure_screen'
+fn main() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins(DefaultPlugins)
+        .add_loading_state(
+            LoadingState::new(MyStates::AssetLoading)
+                .continue_to_state(MyStates::Next)
+                .on_failure_continue_to_state(MyStates::ErrorScreen),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::AssetLoading)
+        .add_plugins(AssetFailureScreenPlugin {
+            title: "Some assets could not be loaded".to_owned(),
+            ..AssetFailureScreenPlugin::new(MyStates::AssetLoading, MyStates::ErrorScreen)
+        })
+        .add_systems(Startup, spawn_camera)
+        .run();
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/plop.ogg")]
+    _plop: Handle<AudioSource>,
+    #[asset(path = "non-existing-file.ogg")]
+    _non_existing_file: Handle<AudioSource>,
+}
+
+fn spawn_camera(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    AssetLoading,
+    Next,
+    ErrorScreen,
+}
@@ -26,6 +26,9 @@ struct MyAssets {
     // Any file that can be loaded and turned into a standard material
     #[asset(path = "images/player.png", standard_material)]
     standard_material: Handle<StandardMaterial>,
+    // Any file that can be loaded and turned into a color material
+    #[asset(path = "images/player.png", color_material)]
+    color_material: Handle<ColorMaterial>,
     // Any file that can be loaded and turned into a texture atlas
     #[asset(texture_atlas(tile_size_x = 96., tile_size_y = 99., columns = 8, rows = 1))]
     #[asset(path = "images/female_adventurer_sheet.png")]
@@ -69,6 +72,7 @@ fn expectations(
     assets: Res<MyAssets>,
     asset_server: Res<AssetServer>,
     standard_materials: Res<Assets<StandardMaterial>>,
+    color_materials: Res<Assets<ColorMaterial>>,
     texture_atlases: Res<Assets<TextureAtlas>>,
     mut quit: EventWriter<AppExit>,
 ) {
@@ -90,6 +94,18 @@ fn expectations(
         ),
         LoadState::Loaded
     );
+    let material = color_materials
+        .get(&assets.color_material)
+        .expect("Color material should be added to its assets resource.");
+    assert_eq!(
+        asset_server.get_load_state(
+            material
+                .texture
+                .clone()
+                .expect("Material should have image as texture")
+        ),
+        LoadState::Loaded
+    );
     let atlas = texture_atlases
         .get(&assets.texture_atlas)
         .expect("Texture atlas should be added to its assets resource.");
@@ -34,6 +34,10 @@ struct MyAssets {
     // Type in `assets/my.assets`: `StandardMaterial`
     #[asset(key = "standard_material")]
     standard_material: Handle<StandardMaterial>,
+    // This file will be converted to a color material
+    // Type in `assets/my.assets`: `ColorMaterial`
+    #[asset(key = "color_material")]
+    color_material: Handle<ColorMaterial>,
     // This file will be converted to a texture atlas
     // The configuration for that is part of the `.assets` file
     // Type in `assets/my.assets`: `TextureAtlas`
@@ -42,7 +46,7 @@ struct MyAssets {
     // Optional asset
     // The key `optional_file` is not defined in `assets/my.assets`, so the value of this field
     // will be `None`
-    // Type in `assets/my.assets`: `File`, `StandardMaterial`, or `TextureAtlas`
+    // Type in `assets/my.assets`: `File`, `StandardMaterial`, `ColorMaterial`, or `TextureAtlas`
     #[asset(key = "optional_file", optional)]
     optional_file: Option<Handle<AudioSource>>,
 
@@ -88,6 +92,7 @@ fn expectations(
     assets: Res<MyAssets>,
     asset_server: Res<AssetServer>,
     standard_materials: Res<Assets<StandardMaterial>>,
+    color_materials: Res<Assets<ColorMaterial>>,
     texture_atlases: Res<Assets<TextureAtlas>>,
     mut quit: EventWriter<AppExit>,
 ) {
@@ -109,6 +114,18 @@ fn expectations(
         ),
         LoadState::Loaded
     );
+    let material = color_materials
+        .get(&assets.color_material)
+        .expect("Color material should be added to its assets resource.");
+    assert_eq!(
+        asset_server.get_load_state(
+            material
+                .texture
+                .clone()
+                .expect("Material should have image as texture")
+        ),
+        LoadState::Loaded
+    );
     let atlas = texture_atlases
         .get(&assets.texture_atlas)
         .expect("Texture atlas should be added to its assets resource.");
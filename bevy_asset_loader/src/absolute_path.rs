@@ -0,0 +1,45 @@
+use bevy::log::error;
+use std::path::Path;
+
+/// Resolve the path of a field with the `absolute` attribute
+///
+/// Absolute paths are returned unchanged. Relative paths are resolved against the directory of
+/// the running executable instead of the assets folder. The asset server joins the resulting
+/// absolute path with its root, which keeps the absolute path.
+///
+/// The web has neither a file system nor an executable directory, so the path is returned
+/// unchanged and will be loaded relative to the assets folder.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(AssetCollection, Resource)]
+/// struct ToolAssets {
+///     #[asset(path = "/usr/share/my_tool/logo.png", absolute)]
+///     logo: Handle<Image>,
+///     #[asset(path = "data/palette.png", absolute)]
+///     palette: Handle<Image>,
+/// }
+/// ```
+pub fn resolve_absolute_path(path: &str) -> String {
+    if Path::new(path).is_absolute() {
+        return path.to_owned();
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        error!("Paths relative to the executable are not supported on the web, loading '{path}' from the assets folder");
+        path.to_owned()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    match std::env::current_exe() {
+        Ok(executable) => executable
+            .parent()
+            .unwrap_or_else(|| Path::new(""))
+            .join(path)
+            .to_string_lossy()
+            .into_owned(),
+        Err(error) => {
+            error!("Failed to find the directory of the executable to load '{path}' from: {error}");
+            path.to_owned()
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use bevy::animation::AnimationClip;
+use bevy::asset::{AssetServer, Assets, Handle};
+use bevy::ecs::world::World;
+use bevy::gltf::Gltf;
+use bevy::utils::HashMap;
+
+/// The named animations of a loaded glTF file
+///
+/// Fields with the `animations` attribute are created from this map. The keys are the animation
+/// names in the glTF file, which are more stable than the `#AnimationN` labels of the clips.
+///
+/// Returns `None` if the file is not loaded as [`Gltf`].
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy::utils::HashMap;
+/// #[derive(AssetCollection, Resource)]
+/// struct CharacterAssets {
+///     #[asset(path = "models/character.gltf", animations)]
+///     animations: HashMap<String, Handle<AnimationClip>>,
+///     #[asset(path = "models/character.gltf#Animation0")]
+///     idle: Handle<AnimationClip>,
+/// }
+/// ```
+#[doc(hidden)]
+pub fn named_animations(
+    world: &World,
+    gltf_path: &str,
+) -> Option<HashMap<String, Handle<AnimationClip>>> {
+    let asset_server = world
+        .get_resource::<AssetServer>()
+        .expect("Cannot get AssetServer");
+    let gltfs = world
+        .get_resource::<Assets<Gltf>>()
+        .expect("Cannot get Assets<Gltf>");
+    let handle: Handle<Gltf> = asset_server.get_handle(gltf_path);
+    gltfs.get(&handle).map(|gltf| gltf.named_animations.clone())
+}
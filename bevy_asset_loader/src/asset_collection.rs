@@ -1,8 +1,20 @@
-use crate::dynamic_asset::DynamicAssets;
-use bevy::app::App;
-use bevy::asset::HandleUntyped;
+use crate::dynamic_asset::{DynamicAsset, DynamicAssetType, DynamicAssets};
+use crate::lazy_collection::{load_lazy_collection, LazyCollection};
+use bevy::app::{App, PreUpdate};
+use bevy::asset::{Asset, AssetServer, Handle, HandleId, HandleUntyped, LoadState};
+use bevy::ecs::reflect::{AppTypeRegistry, ReflectResource};
 use bevy::ecs::system::Resource;
-use bevy::ecs::world::World;
+use bevy::ecs::world::{FromWorld, World};
+use bevy::log::warn;
+use bevy::reflect::{FromType, GetTypeRegistration, Reflect, TypeRegistryInternal};
+use bevy::utils::{HashMap, HashSet};
+use path_slash::PathExt;
+use std::any::type_name;
+use std::any::TypeId;
+use std::ffi::OsStr;
+use std::marker::PhantomData;
+use std::path::Path;
+use std::time::{Duration, Instant};
 
 pub use bevy_asset_loader_derive::AssetCollection;
 
@@ -20,11 +32,662 @@ pub use bevy_asset_loader_derive::AssetCollection;
 ///     tree: Handle<Image>
 /// }
 /// ```
-pub trait AssetCollection: Resource {
+pub trait AssetCollection: CollectionResource {
     /// Create a new asset collection from the [`AssetServer`](::bevy::asset::AssetServer)
     fn create(world: &mut World) -> Self;
     /// Start loading all the assets in the collection
     fn load(world: &mut World) -> Vec<HandleUntyped>;
+    /// Split loading the collection into requests that can be issued individually
+    ///
+    /// Loading states use these requests to spread the loading of a collection over multiple frames
+    /// if a [batch size](crate::loading_state::LoadingState::batch_size) is configured.
+    /// The default implementation loads the whole collection in a single request.
+    fn load_requests() -> Vec<AssetLoadRequest> {
+        vec![Box::new(Self::load)]
+    }
+    /// The [load requests](AssetCollection::load_requests) with the name of the asset type they load
+    ///
+    /// Loading states use the types to cap the number of concurrent loads per asset type
+    /// (see [`max_concurrent_loads_for`](crate::loading_state::LoadingState::max_concurrent_loads_for)).
+    /// Requests without a type are never throttled. The default implementation returns the
+    /// load requests without types.
+    fn typed_load_requests() -> Vec<(Option<&'static str>, AssetLoadRequest)> {
+        Self::load_requests()
+            .into_iter()
+            .map(|request| (None, request))
+            .collect()
+    }
+    /// Start loading assets whose paths are only known once the other assets of the collection loaded
+    ///
+    /// Loading states call this once, after all handles returned by [`load`](AssetCollection::load)
+    /// finished loading, and wait for the returned handles before creating the collection.
+    /// The derive macro uses it for fields with the `path_list` attribute.
+    /// The default implementation returns no handles.
+    fn load_deferred(_world: &mut World) -> Vec<HandleUntyped> {
+        vec![]
+    }
+    /// Whether work started by [`load_deferred`](AssetCollection::load_deferred) finished
+    ///
+    /// Loading states and lazy collections wait for this before creating the collection.
+    /// The derive macro uses it for the content hashes of fields with the `hash` attribute.
+    /// The default implementation is always ready.
+    fn ready_to_create(_world: &World) -> bool {
+        true
+    }
+    /// Names of the asset types loaded by the collection
+    ///
+    /// The derive macro lists the asset types of all typed handles in the collection. Untyped
+    /// handles and fields initialised with [`FromWorld`](::bevy::ecs::world::FromWorld) do not
+    /// contribute a type. The default implementation returns an empty list.
+    fn asset_types() -> Vec<&'static str> {
+        vec![]
+    }
+    /// Add systems reporting modifications of the assets of the collection
+    ///
+    /// Loading states call this for collections configured with
+    /// [`rebuild_on_modify`](crate::loading_state::LoadingState::rebuild_on_modify).
+    /// The derive macro calls [`watch_modified_assets`](crate::loading_state::watch_modified_assets)
+    /// for the asset types of all typed handles in the collection. The default implementation
+    /// does not watch any asset type.
+    fn add_modification_watchers(_app: &mut App) {}
+    /// Register the collection and its reflected types in the given registry
+    ///
+    /// Loading states and [`AssetCollectionApp::init_collection`] call this with the
+    /// [`AppTypeRegistry`] when a collection is added to an app. The derive macro registers the
+    /// collection if the struct has the attribute `#[asset_collection(reflect)]`, which requires
+    /// the collection to derive [`Reflect`](bevy::reflect::Reflect). The default implementation
+    /// registers nothing.
+    fn register_types(_registry: &mut TypeRegistryInternal) {}
+    /// Keys of the dynamic assets used by the collection
+    ///
+    /// The derive macro lists the keys of all fields with a `key` or `key_enum` attribute.
+    /// The default implementation returns an empty list.
+    fn dynamic_asset_keys() -> Vec<String> {
+        vec![]
+    }
+    /// Key value pairs describing the collection for tooling, like a category
+    ///
+    /// The derive macro returns the pairs of the struct attribute
+    /// `#[asset_collection(meta(category = "critical"))]` in their declared order.
+    /// The default implementation returns no pairs.
+    fn meta() -> &'static [(&'static str, &'static str)] {
+        &[]
+    }
+    /// Whether to keep strong handles to everything loaded for the collection in [`PinnedHandles`]
+    ///
+    /// The derive macro returns true for the struct attribute `#[asset_collection(pin_strong)]`.
+    /// The default implementation returns false.
+    fn pin_strong() -> bool {
+        false
+    }
+    /// How loading states merge a newly created collection into an existing resource
+    ///
+    /// If this returns a function and the collection already exists as a resource, loading
+    /// states merge the new collection into it instead of replacing it. The derive macro returns
+    /// [`MergeableCollection::merge`] for the struct attribute `#[asset_collection(merge)]`.
+    /// The default implementation returns `None`.
+    fn merge_with() -> Option<fn(&mut Self, Self)> {
+        None
+    }
+    /// Whether loading states insert the collection before its assets finished loading
+    ///
+    /// The derive macro returns true for collections with fields of type [`LoadingField`](crate::loading_field::LoadingField).
+    /// The default implementation returns false.
+    fn has_loading_fields() -> bool {
+        false
+    }
+    /// Mark the [`LoadingField`](crate::loading_field::LoadingField)s of the collection whose assets finished loading as ready
+    ///
+    /// Returns true if any field changed. The default implementation does nothing.
+    fn update_loading_fields(&mut self, _asset_server: &AssetServer) -> bool {
+        false
+    }
+}
+
+/// Asset collections that add the assets of another instance to themselves
+///
+/// This allows loading the same collection type in multiple loading states, e.g. base cards
+/// first and expansion cards later, without the second collection replacing the first.
+/// The derived implementation for `#[asset_collection(merge)]` extends fields holding
+/// multiple handles, like folders, and replaces all other fields with the newer value.
+/// Skipped fields keep their current value.
+///
+/// Loading states only merge collections whose [`AssetCollection::merge_with`] returns
+/// a function. Manual implementations should override it to return `Some(Self::merge)`.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy::utils::HashMap;
+/// #[derive(AssetCollection, Resource)]
+/// #[asset_collection(merge)]
+/// struct CardAssets {
+///     #[asset(key = "cards", collection(typed, mapped))]
+///     cards: HashMap<String, Handle<Image>>,
+/// }
+/// ```
+pub trait MergeableCollection: AssetCollection {
+    /// Add the assets of `other` to this collection
+    fn merge(&mut self, other: Self);
+}
+
+/// Strong handles to all assets that were loaded for the collection `A`
+///
+/// Fields of a collection only hold the handles they expose. Assets that were loaded to create a
+/// field, like the image of a texture atlas, the files of a folder or the list of a `path_list`
+/// field, are unloaded once nothing else holds a strong handle to them. Collections with the
+/// attribute `#[asset_collection(pin_strong)]` keep all their loaded handles in this resource,
+/// which is inserted into the main world next to the collection. Remove it together with the
+/// collection to allow the assets to unload.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(AssetCollection, Resource)]
+/// #[asset_collection(pin_strong)]
+/// struct LevelAssets {
+///     #[asset(path = "levels", collection(typed))]
+///     levels: Vec<Handle<Image>>,
+/// }
+///
+/// fn unload_level(mut commands: Commands) {
+///     commands.remove_resource::<LevelAssets>();
+///     commands.remove_resource::<PinnedHandles<LevelAssets>>();
+/// }
+/// ```
+#[derive(Resource)]
+pub struct PinnedHandles<A: AssetCollection> {
+    handles: Vec<HandleUntyped>,
+    marker: PhantomData<A>,
+}
+
+impl<A: AssetCollection> PinnedHandles<A> {
+    /// The strong handles kept alive for the collection
+    pub fn handles(&self) -> &[HandleUntyped] {
+        &self.handles
+    }
+}
+
+/// Keep strong handles for collections with the `pin_strong` attribute
+pub(crate) fn pin_handles<A: AssetCollection>(world: &mut World, handles: &[HandleUntyped]) {
+    if !A::pin_strong() {
+        return;
+    }
+    world.insert_resource(PinnedHandles::<A> {
+        handles: handles.to_vec(),
+        marker: PhantomData,
+    });
+}
+
+/// An [`AssetCollection`] that can be created on the [`AsyncComputeTaskPool`](bevy::tasks::AsyncComputeTaskPool)
+///
+/// Creating a collection with [`AssetCollection::create`] blocks the main thread. For collections
+/// that need heavy post-processing of their loaded assets, a loading state configured with
+/// [`async_create`](crate::loading_state::LoadingState::async_create) calls
+/// [`prepare`](AsyncAssetCollection::prepare) on the main thread and moves the expensive work in
+/// [`create_async`](AsyncAssetCollection::create_async) to a background task.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(AssetCollection, Resource)]
+/// struct LevelAssets {
+///     #[asset(path = "images/player.png")]
+///     player: Handle<Image>,
+///     #[asset(skip)]
+///     outline: Vec<Vec2>,
+/// }
+///
+/// impl AsyncAssetCollection for LevelAssets {
+///     type Input = (Self, Image);
+///
+///     fn prepare(world: &mut World) -> Self::Input {
+///         let collection = LevelAssets::create(world);
+///         let image = world.resource::<Assets<Image>>().get(&collection.player).unwrap().clone();
+///         (collection, image)
+///     }
+///
+///     fn create_async((mut collection, image): Self::Input) -> Self {
+///         collection.outline = trace_outline(&image);
+///         collection
+///     }
+/// }
+/// # fn trace_outline(_image: &Image) -> Vec<Vec2> { vec![] }
+/// ```
+pub trait AsyncAssetCollection: AssetCollection {
+    /// Everything [`create_async`](AsyncAssetCollection::create_async) needs from the world
+    type Input: Send + 'static;
+
+    /// Take the input for creating the collection from the world
+    ///
+    /// This runs on the main thread after all assets of the collection finished loading.
+    /// Keep it cheap, e.g. by only cloning handles or moving data out of
+    /// [`Assets`](bevy::asset::Assets) with `remove`.
+    fn prepare(world: &mut World) -> Self::Input;
+
+    /// Create the collection from the prepared input
+    ///
+    /// This runs in a task on the [`AsyncComputeTaskPool`](bevy::tasks::AsyncComputeTaskPool).
+    fn create_async(input: Self::Input) -> Self;
+}
+
+/// A deferred request to start loading one or more assets of an [`AssetCollection`]
+pub type AssetLoadRequest = Box<dyn FnOnce(&mut World) -> Vec<HandleUntyped> + Send + Sync>;
+
+/// Resource transforming the paths of all asset collections before they are loaded
+///
+/// The transform is applied to every path configured with derive attributes, both when loading
+/// a collection and when creating it. Dynamic assets transform their paths in
+/// [`DynamicAsset::load_with_transform`](crate::dynamic_asset::DynamicAsset::load_with_transform);
+/// [`FileAsset`](crate::dynamic_asset::FileAsset)s and standard dynamic assets loaded from
+/// `.assets.ron` files apply it to all of their paths.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// # fn main() {
+///     App::new()
+///         .insert_resource(AssetPathTransform::new(|path| format!("{path}?v=1234")));
+/// # }
+/// ```
+#[derive(Resource)]
+pub struct AssetPathTransform(Box<dyn Fn(&str) -> String + Send + Sync>);
+
+impl AssetPathTransform {
+    /// Create a transform from the given closure
+    pub fn new(transform: impl Fn(&str) -> String + Send + Sync + 'static) -> Self {
+        AssetPathTransform(Box::new(transform))
+    }
+
+    /// Apply the transform to the given path
+    pub fn apply(&self, path: &str) -> String {
+        (self.0)(path)
+    }
+}
+
+/// Replace a path by its alias from [`DynamicAssets`] and apply the [`AssetPathTransform`] if the resources exist
+#[doc(hidden)]
+pub fn transform_asset_path(
+    dynamic_assets: Option<&DynamicAssets>,
+    transform: Option<&AssetPathTransform>,
+    path: &str,
+) -> String {
+    let path = dynamic_assets
+        .and_then(|dynamic_assets| dynamic_assets.alias(path))
+        .unwrap_or(path);
+    match transform {
+        Some(transform) => transform.apply(path),
+        None => path.to_owned(),
+    }
+}
+
+/// Get the entry of a folder map by the file name of the asset without its extension
+#[doc(hidden)]
+pub fn folder_map_entry<'a, T>(folder_map: &'a HashMap<String, T>, name: &str) -> Option<&'a T> {
+    folder_map
+        .iter()
+        .find(|(path, _)| Path::new(path).file_stem().and_then(OsStr::to_str) == Some(name))
+        .map(|(_, handle)| handle)
+}
+
+/// Return type of a `map_key` function: either `Option<K>` or `Result<K, E>`
+#[doc(hidden)]
+pub trait MapKeyResult<K> {
+    fn into_key(self) -> Option<K>;
+}
+
+impl<K> MapKeyResult<K> for Option<K> {
+    fn into_key(self) -> Option<K> {
+        self
+    }
+}
+
+impl<K, E> MapKeyResult<K> for Result<K, E> {
+    fn into_key(self) -> Option<K> {
+        self.ok()
+    }
+}
+
+/// Parse the file stem of an asset into the key of a folder map, warning if it cannot be parsed
+#[doc(hidden)]
+pub fn parse_map_key<A: 'static, K, R: MapKeyResult<K>>(
+    field: &'static str,
+    path: &Path,
+    map_key: impl FnOnce(&str) -> R,
+) -> Option<K> {
+    let key = path
+        .file_stem()
+        .and_then(OsStr::to_str)
+        .and_then(|stem| map_key(stem).into_key());
+    if key.is_none() {
+        warn!(
+            collection = type_name::<A>(),
+            field,
+            path = %path.display(),
+            "Skipping asset, its file name cannot be parsed into a map key"
+        );
+    }
+    key
+}
+
+/// Errors collected while creating asset collections
+///
+/// Loading states configured with [`collect_errors`](crate::loading_state::LoadingState::collect_errors)
+/// do not panic when a dynamic asset key is missing or a dynamic asset cannot be built.
+/// Instead, the affected fields are filled with a placeholder and the errors are listed here.
+/// Optional fields are set to `None`, other fields to their default value. Fields using
+/// [`DynamicAssetField`](crate::dynamic_asset::DynamicAssetField) without being optional
+/// cannot be filled and still panic.
+///
+/// The errors are cleared when a loading state collecting errors is entered.
+#[derive(Resource, Default, Debug)]
+pub struct CollectionErrors {
+    errors: Vec<CollectionError>,
+    pub(crate) collecting: bool,
+}
+
+impl CollectionErrors {
+    /// All errors collected since the last loading state collecting errors was entered
+    pub fn errors(&self) -> &[CollectionError] {
+        &self.errors
+    }
+
+    /// Are there no collected errors?
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.errors.clear();
+    }
+}
+
+/// A field of an asset collection that could not be created
+#[derive(Debug, Clone)]
+pub struct CollectionError {
+    /// Type name of the asset collection
+    pub collection: &'static str,
+    /// Name of the field
+    pub field: &'static str,
+    /// Description of the error
+    pub message: String,
+}
+
+/// Start collecting errors if the [`CollectionErrors`] resource is currently collecting
+#[doc(hidden)]
+pub fn collecting_errors(world: &World) -> Option<Vec<CollectionError>> {
+    world
+        .get_resource::<CollectionErrors>()
+        .filter(|collection_errors| collection_errors.collecting)
+        .map(|_| vec![])
+}
+
+/// Record an error for the given field and return a placeholder, or panic if errors are not collected
+#[doc(hidden)]
+pub fn collection_error<A: 'static, T: Default>(
+    errors: &mut Option<Vec<CollectionError>>,
+    field: &'static str,
+    message: String,
+) -> T {
+    match errors {
+        Some(errors) => {
+            errors.push(CollectionError {
+                collection: type_name::<A>(),
+                field,
+                message,
+            });
+            T::default()
+        }
+        None => panic!("{}", message),
+    }
+}
+
+/// Build a dynamic asset and record an error if building fails
+#[doc(hidden)]
+pub fn build_dynamic_asset<A: 'static>(
+    asset: &dyn DynamicAsset,
+    field: &'static str,
+    key: &str,
+    world: &mut World,
+    errors: &mut Option<Vec<CollectionError>>,
+) -> Option<DynamicAssetType> {
+    match asset.build(world) {
+        Ok(built) => Some(built),
+        Err(error) => collection_error::<A, _>(
+            errors,
+            field,
+            format!("Error building the dynamic asset {asset:?} with the key {key}: {error}"),
+        ),
+    }
+}
+
+/// Convert the handle of a dynamic asset to the asset type of its field
+///
+/// Records an error if the key was registered with [`DynamicAssets::register_typed`] for
+/// another asset type.
+#[doc(hidden)]
+pub fn typed_dynamic_handle<A: 'static, T: Asset>(
+    dynamic_assets: &DynamicAssets,
+    key: &str,
+    handle: HandleUntyped,
+    field: &'static str,
+    errors: &mut Option<Vec<CollectionError>>,
+) -> Handle<T> {
+    match dynamic_assets.expected_type(key) {
+        Some((type_id, expected)) if type_id != TypeId::of::<T>() => collection_error::<A, _>(
+            errors,
+            field,
+            format!(
+                "The dynamic asset '{key}' was registered as `{expected}`, but the field expects `{}`",
+                type_name::<T>()
+            ),
+        ),
+        _ => handle.typed(),
+    }
+}
+
+/// Vector of handles of a collection field with `sort = "path"`
+#[doc(hidden)]
+pub trait SortByPath {
+    /// Sort the handles by their asset paths, using forward slashes on all platforms
+    fn sort_by_path(&mut self, world: &World);
+}
+
+fn sort_key(asset_server: &AssetServer, id: HandleId) -> String {
+    let Some(asset_path) = asset_server.get_handle_path(id) else {
+        return String::new();
+    };
+    let path = asset_path
+        .path()
+        .to_slash()
+        .map(|path| path.into_owned())
+        .unwrap_or_else(|| asset_path.path().to_string_lossy().into_owned());
+    match asset_path.label() {
+        Some(label) => format!("{path}#{label}"),
+        None => path,
+    }
+}
+
+impl<T: Asset> SortByPath for Vec<Handle<T>> {
+    fn sort_by_path(&mut self, world: &World) {
+        let asset_server = world
+            .get_resource::<AssetServer>()
+            .expect("Cannot get AssetServer");
+        self.sort_by_cached_key(|handle| sort_key(asset_server, handle.id()));
+    }
+}
+
+impl SortByPath for Vec<HandleUntyped> {
+    fn sort_by_path(&mut self, world: &World) {
+        let asset_server = world
+            .get_resource::<AssetServer>()
+            .expect("Cannot get AssetServer");
+        self.sort_by_cached_key(|handle| sort_key(asset_server, handle.id()));
+    }
+}
+
+impl<V: SortByPath> SortByPath for Option<V> {
+    fn sort_by_path(&mut self, world: &World) {
+        if let Some(handles) = self {
+            handles.sort_by_path(world);
+        }
+    }
+}
+
+/// Add errors collected while creating an asset collection to the [`CollectionErrors`] resource
+#[doc(hidden)]
+pub fn report_collection_errors(world: &mut World, errors: Option<Vec<CollectionError>>) {
+    if let Some(errors) = errors {
+        if let Some(mut collection_errors) = world.get_resource_mut::<CollectionErrors>() {
+            collection_errors.errors.extend(errors);
+        }
+    }
+}
+
+/// Handles of the fields of the collection `A` that have an `on_fail` fallback
+///
+/// A failed handle in this resource does not fail the loading of the collection. Instead, the
+/// field is filled by its fallback function when the collection is created.
+#[derive(Resource)]
+pub(crate) struct RecoverableHandles<A> {
+    fields: HashMap<&'static str, Vec<HandleId>>,
+    marker: PhantomData<A>,
+}
+
+impl<A> RecoverableHandles<A> {
+    /// Ids of all handles that can fail without failing the collection
+    pub(crate) fn handle_ids(&self) -> HashSet<HandleId> {
+        self.fields.values().flatten().copied().collect()
+    }
+}
+
+/// Replace the recoverable handles of the collection `A` after loading all of it
+#[doc(hidden)]
+pub fn insert_recoverable_handles<A: AssetCollection>(
+    world: &mut World,
+    fields: Vec<(&'static str, Vec<HandleId>)>,
+) {
+    world.remove_resource::<RecoverableHandles<A>>();
+    add_recoverable_handles::<A>(world, fields);
+}
+
+/// Add recoverable handles of the collection `A` loaded by a single load request
+#[doc(hidden)]
+pub fn add_recoverable_handles<A: AssetCollection>(
+    world: &mut World,
+    fields: Vec<(&'static str, Vec<HandleId>)>,
+) {
+    if fields.is_empty() {
+        return;
+    }
+    let mut recoverable = world.get_resource_or_insert_with(|| RecoverableHandles::<A> {
+        fields: HashMap::default(),
+        marker: PhantomData,
+    });
+    for (field, handles) in fields {
+        recoverable.fields.entry(field).or_default().extend(handles);
+    }
+}
+
+/// Progress weights of the handles of the collection `A` loaded for fields with a `weight`
+///
+/// Handles without an entry have a weight of 1.
+#[derive(Resource)]
+pub(crate) struct HandleWeights<A> {
+    weights: HashMap<HandleId, f32>,
+    marker: PhantomData<A>,
+}
+
+impl<A> HandleWeights<A> {
+    pub(crate) fn weights(&self) -> &HashMap<HandleId, f32> {
+        &self.weights
+    }
+}
+
+/// Replace the handle weights of the collection `A` after loading all of it
+#[doc(hidden)]
+pub fn insert_handle_weights<A: AssetCollection>(
+    world: &mut World,
+    fields: Vec<(f32, Vec<HandleId>)>,
+) {
+    world.remove_resource::<HandleWeights<A>>();
+    add_handle_weights::<A>(world, fields);
+}
+
+/// Add handle weights of the collection `A` loaded by a single load request
+#[doc(hidden)]
+pub fn add_handle_weights<A: AssetCollection>(
+    world: &mut World,
+    fields: Vec<(f32, Vec<HandleId>)>,
+) {
+    if fields.is_empty() {
+        return;
+    }
+    let mut handle_weights = world.get_resource_or_insert_with(|| HandleWeights::<A> {
+        weights: HashMap::default(),
+        marker: PhantomData,
+    });
+    for (weight, handles) in fields {
+        for handle in handles {
+            handle_weights.weights.insert(handle, weight);
+        }
+    }
+}
+
+/// Did loading an asset of the given field fail?
+#[doc(hidden)]
+pub fn field_failed<A: AssetCollection>(world: &World, field: &str) -> bool {
+    let Some(recoverable) = world.get_resource::<RecoverableHandles<A>>() else {
+        return false;
+    };
+    let asset_server = world.resource::<AssetServer>();
+    recoverable.fields.get(field).is_some_and(|handles| {
+        handles
+            .iter()
+            .any(|handle| asset_server.get_load_state(*handle) == LoadState::Failed)
+    })
+}
+
+/// Implemented for all [`Resource`]s
+///
+/// Asset collections are inserted as resources. This supertrait of [`AssetCollection`] explains
+/// a missing `#[derive(Resource)]` on a collection in the compiler error.
+/// ```compile_fail,E0277
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// // Missing `#[derive(Resource)]`
+/// #[derive(AssetCollection)]
+/// struct MyAssets {
+///     #[asset(path = "audio/plop.ogg")]
+///     plop: Handle<AudioSource>,
+/// }
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is an asset collection, but does not implement `Resource`",
+    label = "asset collections are inserted as resources",
+    note = "add `#[derive(Resource)]` to `{Self}`"
+)]
+pub trait CollectionResource: Resource {}
+
+impl<T: Resource> CollectionResource for T {}
+
+/// Register a reflectable collection together with its [`ReflectResource`] type data
+///
+/// This is called by derived collections with the attribute `#[asset_collection(reflect)]`.
+/// The derive implements [`FromWorld`] for those collections by creating them with
+/// [`AssetCollection::create`]. Reflected values are applied on top of such a new instance, so
+/// fields marked with `#[reflect(ignore)]` keep the handles loaded for the collection.
+pub fn register_reflect_collection<A>(registry: &mut TypeRegistryInternal)
+where
+    A: AssetCollection + Reflect + GetTypeRegistration + FromWorld,
+{
+    registry.register::<A>();
+    if let Some(registration) = registry.get_mut(TypeId::of::<A>()) {
+        registration.insert(<ReflectResource as FromType<A>>::from_type());
+    }
+}
+
+/// Register the types of a collection in the [`AppTypeRegistry`] if the world has one
+pub(crate) fn register_collection_types<A: AssetCollection>(world: &World) {
+    if let Some(registry) = world.get_resource::<AppTypeRegistry>() {
+        A::register_types(&mut registry.write());
+    }
 }
 
 /// Extension trait for [`App`](::bevy::app::App) enabling initialisation of [asset collections](crate::asset_collection::AssetCollection)
@@ -35,6 +698,9 @@ pub trait AssetCollectionApp {
     /// If you want to use a loading state, you do not need this function! Instead use an [`LoadingState`](crate::loading_state::LoadingState)
     /// and add collections to it to be prepared during the loading state.
     fn init_collection<A: AssetCollection>(&mut self) -> &mut Self;
+
+    /// Add a [`LazyCollection`](crate::lazy_collection::LazyCollection) that loads the collection `A` the first time it is accessed
+    fn init_lazy_collection<A: AssetCollection>(&mut self) -> &mut Self;
 }
 
 impl AssetCollectionApp for App {
@@ -42,15 +708,27 @@ impl AssetCollectionApp for App {
     where
         Collection: AssetCollection,
     {
+        register_collection_types::<Collection>(&self.world);
         if !self.world.contains_resource::<Collection>() {
             // This resource is required for loading a collection
             // Since bevy_asset_loader does not have a "real" Plugin,
             // we need to make sure the resource exists here
             self.init_resource::<DynamicAssets>();
             // make sure the assets start to load
-            let _ = Collection::load(&mut self.world);
+            let handles = Collection::load(&mut self.world);
             let resource = Collection::create(&mut self.world);
             self.insert_resource(resource);
+            pin_handles::<Collection>(&mut self.world, &handles);
+        }
+        self
+    }
+
+    fn init_lazy_collection<A: AssetCollection>(&mut self) -> &mut Self {
+        register_collection_types::<A>(&self.world);
+        if !self.world.contains_resource::<LazyCollection<A>>() {
+            self.init_resource::<DynamicAssets>()
+                .init_resource::<LazyCollection<A>>()
+                .add_systems(PreUpdate, load_lazy_collection::<A>);
         }
         self
     }
@@ -67,15 +745,113 @@ pub trait AssetCollectionWorld {
 
 impl AssetCollectionWorld for World {
     fn init_collection<A: AssetCollection>(&mut self) {
+        register_collection_types::<A>(self);
         if self.get_resource::<A>().is_none() {
             // This resource is required for loading a collection
             // Since bevy_asset_loader does not have a "real" Plugin,
             // we need to make sure the resource exists here
             self.init_resource::<DynamicAssets>();
             // make sure the assets start to load
-            let _ = A::load(self);
+            let handles = A::load(self);
             let collection = A::create(self);
             self.insert_resource(collection);
+            pin_handles::<A>(self, &handles);
+        }
+    }
+}
+
+/// Load all assets of a collection and report the ones that failed to load
+///
+/// This is meant as a helper for tests, e.g. to make sure that all configured paths point to
+/// existing files. The app is updated until every handle of the collection finished or failed
+/// loading, or until [`VALIDATION_TIMEOUT`] passed. Assets that are still loading at that point,
+/// e.g. because their loader never completes, are reported with the suffix ` (timed out)`.
+/// Use [`validate_collection_within`] for a different time limit. The collection itself is not
+/// created or inserted as a resource.
+///
+/// The app needs the [`AssetPlugin`](::bevy::asset::AssetPlugin) and the plugins registering loaders
+/// for all asset types of the collection. Keys of dynamic assets need to be registered in the
+/// [`DynamicAssets`] resource beforehand.
+///
+/// # Panics
+/// Loading a folder that does not exist panics.
+/// ```edition2021,no_run
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy_asset_loader::asset_collection::validate_collection;
+/// # use bevy::prelude::*;
+/// # use bevy::asset::AssetPlugin;
+/// # use bevy::audio::AudioPlugin;
+/// # fn main() {
+///     let mut app = App::new();
+///     app.add_plugins((MinimalPlugins, AssetPlugin::default(), AudioPlugin::default()));
+///
+///     assert_eq!(validate_collection::<AudioAssets>(&mut app), Ok(()));
+/// # }
+/// #[derive(AssetCollection, Resource)]
+/// struct AudioAssets {
+///     #[asset(path = "audio/background.ogg")]
+///     background: Handle<AudioSource>,
+/// }
+/// ```
+pub fn validate_collection<A: AssetCollection>(app: &mut App) -> Result<(), Vec<String>> {
+    validate_collection_within::<A>(app, VALIDATION_TIMEOUT)
+}
+
+/// Time after which [`validate_collection`] reports assets that are still loading
+pub const VALIDATION_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Like [`validate_collection`], but with a custom time limit for loading the collection
+pub fn validate_collection_within<A: AssetCollection>(
+    app: &mut App,
+    timeout: Duration,
+) -> Result<(), Vec<String>> {
+    app.init_resource::<DynamicAssets>();
+    let started = Instant::now();
+    let mut handles = A::load(&mut app.world);
+    let mut deferred_issued = false;
+    loop {
+        app.update();
+        let asset_server = app.world.resource::<AssetServer>();
+        let load_states: Vec<_> = handles
+            .iter()
+            .map(|handle| asset_server.get_load_state(handle.id()))
+            .collect();
+        let timed_out = started.elapsed() >= timeout;
+        if timed_out
+            || load_states
+                .iter()
+                .all(|state| matches!(state, LoadState::Loaded | LoadState::Failed))
+        {
+            if !deferred_issued && !timed_out {
+                deferred_issued = true;
+                let deferred = A::load_deferred(&mut app.world);
+                if !deferred.is_empty() {
+                    handles.extend(deferred);
+                    continue;
+                }
+            }
+            let asset_server = app.world.resource::<AssetServer>();
+            let failed: Vec<_> = handles
+                .iter()
+                .zip(load_states)
+                .filter(|(_, state)| *state != LoadState::Loaded)
+                .map(|(handle, state)| {
+                    let path = match asset_server.get_handle_path(handle) {
+                        Some(asset_path) => asset_path.path().display().to_string(),
+                        None => format!("{:?}", handle.id()),
+                    };
+                    if state == LoadState::Failed {
+                        path
+                    } else {
+                        format!("{path} (timed out)")
+                    }
+                })
+                .collect();
+            return if failed.is_empty() {
+                Ok(())
+            } else {
+                Err(failed)
+            };
         }
     }
 }
@@ -0,0 +1,40 @@
+/// An asset path referenced by a field of an asset collection
+///
+/// With the feature `asset_manifest`, the derive macro registers one entry for every path given
+/// in the attributes of a collection. Paths of dynamic assets and of `paths_const` fields are
+/// only known at run time and are not part of the manifest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AssetRef {
+    /// Path of the asset collection type, like `my_game::assets::ImageAssets`
+    pub collection: &'static str,
+    /// Name of the field referencing the asset
+    pub field: &'static str,
+    /// The path as given in the attribute, before any [`AssetPathTransform`](crate::asset_collection::AssetPathTransform)
+    pub path: &'static str,
+}
+
+::inventory::collect!(AssetRef);
+
+/// All asset paths referenced by the asset collections linked into the binary
+///
+/// The entries are sorted by collection, field and path. A build tool or test can compare them
+/// with the shipped asset folder to find missing or unused files.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// fn missing_assets() -> Vec<AssetRef> {
+///     collect_asset_manifest()
+///         .into_iter()
+///         .filter(|asset| !std::path::Path::new("assets").join(asset.path).exists())
+///         .collect()
+/// }
+/// ```
+pub fn collect_asset_manifest() -> Vec<AssetRef> {
+    let mut manifest: Vec<AssetRef> = ::inventory::iter::<AssetRef>.into_iter().copied().collect();
+    manifest.sort_unstable();
+    manifest
+}
+
+// re-export for usage in macros
+#[doc(hidden)]
+pub use ::inventory;
@@ -0,0 +1,37 @@
+use bevy::asset::{Asset, Assets, Handle, HandleId};
+use bevy::ecs::world::World;
+use bevy::utils::Uuid;
+
+/// The id of the asset that a field with the `uuid` attribute resolves to
+///
+/// Assets addressed by UUID are not loaded by the [`AssetServer`](bevy::asset::AssetServer).
+/// Add them to their [`Assets`] under this id before the collection is created, for example
+/// with [`Assets::set_untracked`]. The loading state does not wait for them and they do not
+/// count towards the loading progress. If an asset is missing when the collection is created,
+/// an error is recorded for the field.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy_asset_loader::asset_uuid::uuid_handle_id;
+/// # use bevy::prelude::*;
+/// # use bevy::utils::Uuid;
+/// #[derive(AssetCollection, Resource)]
+/// struct GeneratedAssets {
+///     #[asset(uuid = "6c8e2f3a-9b1d-4e7f-8a2c-5d3b1f0e9a47")]
+///     noise: Handle<Image>,
+/// }
+///
+/// fn add_noise(mut images: ResMut<Assets<Image>>) {
+///     let id = uuid_handle_id(Uuid::from_u128(0x6c8e2f3a_9b1d_4e7f_8a2c_5d3b1f0e9a47));
+///     images.set_untracked(id, Image::default());
+/// }
+/// ```
+pub fn uuid_handle_id(uuid: Uuid) -> HandleId {
+    HandleId::Id(uuid, 0)
+}
+
+#[doc(hidden)]
+pub fn get_uuid_handle<T: Asset>(world: &World, uuid: Uuid) -> Option<Handle<T>> {
+    let assets = world.get_resource::<Assets<T>>()?;
+    let handle = Handle::weak(uuid_handle_id(uuid));
+    assets.contains(&handle).then(|| assets.get_handle(&handle))
+}
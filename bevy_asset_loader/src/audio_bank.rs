@@ -0,0 +1,114 @@
+use bevy::app::{App, Plugin};
+use bevy::asset::{AssetServer, Assets, Handle};
+use bevy::audio::AudioSource;
+use bevy::ecs::world::World;
+use bevy::reflect::{TypePath, TypeUuid};
+use bevy::utils::HashMap;
+use bevy_common_assets::json::JsonAssetPlugin;
+
+/// Manifest of named clips in a single audio file
+///
+/// The manifest is a JSON object mapping clip names to their start and end time in seconds:
+/// ```json
+/// {
+///     "jump": [0.0, 0.35],
+///     "coin": [0.5, 0.8]
+/// }
+/// ```
+/// Manifests are loaded by the [`AudioBankPlugin`].
+#[derive(serde::Deserialize, TypeUuid, TypePath, Debug, Clone)]
+#[uuid = "8c5f9f4e-5a3b-4e5e-9d52-0f6d2a8b3c71"]
+pub struct AudioBankManifest(pub HashMap<String, (f32, f32)>);
+
+/// A single audio file split into named clips
+///
+/// Fields of this type are loaded with the `audio_bank` attribute, which takes the path of
+/// an [`AudioBankManifest`] next to the path of the audio file.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(AssetCollection, Resource)]
+/// struct SoundEffects {
+///     #[asset(path = "audio/sfx.ogg", audio_bank = "audio/sfx.bank.json")]
+///     sfx: AudioBank,
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AudioBank {
+    /// Handle to the audio file shared by all clips
+    pub source: Handle<AudioSource>,
+    /// Start and end time in seconds of all clips by name
+    pub clips: HashMap<String, (f32, f32)>,
+}
+
+impl AudioBank {
+    /// Start and end time in seconds of the clip with the given name
+    pub fn clip(&self, name: &str) -> Option<(f32, f32)> {
+        self.clips.get(name).copied()
+    }
+}
+
+/// Plugin loading [`AudioBankManifest`]s
+///
+/// By default, manifests are expected to end on `.bank.json`, so other JSON files are left to
+/// their own loaders. Use [`AudioBankPlugin::new`] to configure different file endings.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy::asset::AssetPlugin;
+/// # fn main() {
+///     App::new()
+///         .add_plugins((
+///             MinimalPlugins,
+///             AssetPlugin::default(),
+///             AudioBankPlugin::default(),
+///         ))
+/// #       .set_runner(|mut app| app.update())
+///         .run();
+/// # }
+/// ```
+pub struct AudioBankPlugin {
+    file_endings: Vec<&'static str>,
+}
+
+impl AudioBankPlugin {
+    /// Load audio bank manifests from files with the given endings
+    pub fn new(file_endings: &[&'static str]) -> Self {
+        AudioBankPlugin {
+            file_endings: file_endings.to_vec(),
+        }
+    }
+}
+
+impl Default for AudioBankPlugin {
+    fn default() -> Self {
+        AudioBankPlugin::new(&["bank.json"])
+    }
+}
+
+impl Plugin for AudioBankPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(JsonAssetPlugin::<AudioBankManifest>::new(
+            &self.file_endings,
+        ));
+    }
+}
+
+/// Create an [`AudioBank`] from its loaded audio file and manifest
+#[doc(hidden)]
+pub fn create_audio_bank(world: &World, path: &str, manifest_path: &str) -> AudioBank {
+    let asset_server = world
+        .get_resource::<AssetServer>()
+        .expect("Cannot get AssetServer");
+    let manifest: Handle<AudioBankManifest> = asset_server.get_handle(manifest_path);
+    let manifests = world
+        .get_resource::<Assets<AudioBankManifest>>()
+        .expect("Cannot get Assets<AudioBankManifest>. Did you add the AudioBankPlugin?");
+    let manifest = manifests
+        .get(&manifest)
+        .unwrap_or_else(|| panic!("The audio bank manifest '{manifest_path}' is not loaded"));
+    AudioBank {
+        source: asset_server.get_handle(path),
+        clips: manifest.0.clone(),
+    }
+}
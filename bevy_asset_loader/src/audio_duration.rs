@@ -0,0 +1,48 @@
+use bevy::asset::{AssetServer, Assets, Handle};
+use bevy::audio::{AudioSource, Decodable, Source};
+use bevy::ecs::world::World;
+use bevy::log::warn;
+use std::time::Duration;
+
+/// The duration of a loaded audio source, if its decoder knows it
+///
+/// Only the header of the audio data is inspected, so this does not decode the whole file.
+/// Whether the duration is known depends on the format and the decoder of the Bevy audio backend:
+/// WAV and FLAC files report their duration, while the decoders for Ogg Vorbis and MP3 do not
+/// and return `None`.
+pub fn audio_duration(source: &AudioSource) -> Option<Duration> {
+    source.decoder().total_duration()
+}
+
+/// Create the value of a field with the `duration` attribute
+///
+/// Fields are left at their default with a warning if the duration of the audio file is unknown.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// # use std::time::Duration;
+/// #[derive(AssetCollection, Resource)]
+/// struct MusicAssets {
+///     #[asset(path = "music/song.wav")]
+///     song: Handle<AudioSource>,
+///     #[asset(path = "music/song.wav", duration)]
+///     song_duration: Duration,
+/// }
+/// ```
+#[doc(hidden)]
+pub fn create_audio_duration(world: &World, path: &str) -> Duration {
+    let asset_server = world
+        .get_resource::<AssetServer>()
+        .expect("Cannot get AssetServer");
+    let sources = world
+        .get_resource::<Assets<AudioSource>>()
+        .expect("Cannot get Assets<AudioSource>");
+    let handle: Handle<AudioSource> = asset_server.get_handle(path);
+    match sources.get(&handle).and_then(audio_duration) {
+        Some(duration) => duration,
+        None => {
+            warn!("The duration of '{path}' is unknown to the audio decoder, using the default");
+            Duration::default()
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use bevy::asset::{Asset, AssetPath, AssetServer, Handle};
+use bevy::ecs::system::Resource;
+use bevy::ecs::world::World;
+use bevy::tasks::IoTaskPool;
+use bevy::utils::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// A handle together with the content hash of the file it was loaded from
+///
+/// Fields of this type are loaded with the `hash` attribute. Once the asset is loaded, its file
+/// is read again through the `AssetIo` of the [`AssetServer`] on the [`IoTaskPool`] and hashed
+/// with [`hash_bytes`]. Loading states and lazy collections wait for the hash before creating
+/// the collection. The hash is stable across runs and platforms, so it can be stored to detect
+/// changed assets later, or compared between a server and its clients.
+///
+/// Collections initialised with [`init_collection`](crate::asset_collection::AssetCollectionApp::init_collection)
+/// do not wait for their assets and cannot contain hashed fields.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(AssetCollection, Resource)]
+/// struct LevelAssets {
+///     #[asset(path = "images/background.png", hash)]
+///     background: HashedHandle<Image>,
+/// }
+/// ```
+pub struct HashedHandle<T: Asset> {
+    /// Handle to the loaded asset
+    pub handle: Handle<T>,
+    /// Hash of the bytes of the asset file
+    pub hash: [u8; 32],
+}
+
+impl<T: Asset> Clone for HashedHandle<T> {
+    fn clone(&self) -> Self {
+        HashedHandle {
+            handle: self.handle.clone(),
+            hash: self.hash,
+        }
+    }
+}
+
+impl<T: Asset> Default for HashedHandle<T> {
+    fn default() -> Self {
+        HashedHandle {
+            handle: Handle::default(),
+            hash: [0; 32],
+        }
+    }
+}
+
+/// Hash bytes the same way [`HashedHandle`]s are hashed
+///
+/// This is the BLAKE3 hash of the bytes.
+pub fn hash_bytes(bytes: &[u8]) -> [u8; 32] {
+    *blake3::hash(bytes).as_bytes()
+}
+
+/// Hash of a file, filled in by the task reading the file
+pub(crate) type FileHashSlot = Arc<Mutex<Option<Result<[u8; 32], String>>>>;
+
+/// Hashes of asset files that are read in tasks
+#[derive(Resource, Default)]
+struct FileHashes(HashMap<String, FileHashSlot>);
+
+/// Start reading and hashing the file of the given asset path
+///
+/// Labels of the asset path are ignored, so all labeled assets of a file share its hash.
+/// A previous hash of the file is discarded.
+#[doc(hidden)]
+pub fn request_file_hash(world: &mut World, path: &str) {
+    let slot = spawn_file_hash(
+        world
+            .get_resource::<AssetServer>()
+            .expect("Cannot get AssetServer"),
+        path,
+    );
+    world
+        .get_resource_or_insert_with(FileHashes::default)
+        .0
+        .insert(path.to_owned(), slot);
+}
+
+/// Whether the requested hash of the given asset path is still being computed
+#[doc(hidden)]
+pub fn file_hash_pending(world: &World, path: &str) -> bool {
+    world
+        .get_resource::<FileHashes>()
+        .and_then(|hashes| hashes.0.get(path))
+        .is_some_and(|slot| slot.lock().unwrap().is_none())
+}
+
+/// The hash of the file of the given asset path
+#[doc(hidden)]
+pub fn file_hash(world: &World, path: &str) -> Result<[u8; 32], String> {
+    let Some(slot) = world
+        .get_resource::<FileHashes>()
+        .and_then(|hashes| hashes.0.get(path))
+    else {
+        return Err(
+            "the file was not hashed, only loading states and lazy collections hash files"
+                .to_owned(),
+        );
+    };
+    let hash = slot.lock().unwrap().clone();
+    hash.unwrap_or_else(|| Err("hashing the file did not finish".to_owned()))
+}
+
+/// Read and hash the file of the given asset path on the [`IoTaskPool`]
+pub(crate) fn spawn_file_hash(asset_server: &AssetServer, path: &str) -> FileHashSlot {
+    let asset_server = asset_server.clone();
+    let file = AssetPath::from(path).path().to_path_buf();
+    let slot = FileHashSlot::default();
+    let result = slot.clone();
+    // Single threaded task pools (e.g. on wasm) do not return the output of tasks
+    IoTaskPool::get()
+        .spawn(async move {
+            let hash = asset_server
+                .asset_io()
+                .load_path(&file)
+                .await
+                .map(|bytes| hash_bytes(&bytes))
+                .map_err(|error| error.to_string());
+            *result.lock().unwrap() = Some(hash);
+        })
+        .detach();
+    slot
+}
+
+/// Lowercase hexadecimal representation of a hash
+pub(crate) fn hex(hash: &[u8; 32]) -> String {
+    hash.iter().map(|byte| format!("{byte:02x}")).collect()
+}
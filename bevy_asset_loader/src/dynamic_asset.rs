@@ -1,12 +1,17 @@
 use bevy::utils::HashMap;
-use std::any::TypeId;
+use std::any::{type_name, TypeId};
 use std::fmt::Debug;
 
 use bevy::asset::{Asset, AssetServer, HandleUntyped};
 use bevy::ecs::schedule::States;
 use bevy::ecs::system::Resource;
 use bevy::ecs::world::World;
+use bevy::log::debug;
 use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+
+use crate::asset_collection::{transform_asset_path, AssetPathTransform};
+use crate::in_memory_asset::{InMemoryAsset, InMemoryAssets, IN_MEMORY_ASSET_DIRECTORY};
 
 /// Different typed that can generate the asset field value of a dynamic asset
 pub enum DynamicAssetType {
@@ -22,8 +27,109 @@ pub trait DynamicAsset: Debug + Send + Sync {
     /// Return handles to all required asset paths
     fn load(&self, asset_server: &AssetServer) -> Vec<HandleUntyped>;
 
+    /// Return handles to all required asset paths after applying the [`AssetPathTransform`]
+    ///
+    /// Asset collections load their dynamic assets through this method. The default
+    /// implementation ignores the transform and calls [`DynamicAsset::load`]. Assets that
+    /// transform their paths here should do the same in [`DynamicAsset::build`].
+    fn load_with_transform(
+        &self,
+        asset_server: &AssetServer,
+        _transform: Option<&AssetPathTransform>,
+    ) -> Vec<HandleUntyped> {
+        self.load(asset_server)
+    }
+
     /// Return the handle(s) defining this asset
     fn build(&self, world: &mut World) -> Result<DynamicAssetType, anyhow::Error>;
+
+    /// Describe this asset as a [`StandardDynamicAsset`](crate::standard_dynamic_asset::StandardDynamicAsset)
+    ///
+    /// This is used to save the asset in a [`CollectionManifest`](crate::standard_dynamic_asset::CollectionManifest).
+    /// The default implementation returns `None`, leaving the asset out of manifests.
+    #[cfg(feature = "standard_dynamic_assets")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "standard_dynamic_assets")))]
+    fn as_standard(&self) -> Option<crate::standard_dynamic_asset::StandardDynamicAsset> {
+        None
+    }
+}
+
+/// Field type of asset collections that is built from the handles of a dynamic asset
+///
+/// Fields with the `dynamic_field` attribute are created through this trait instead of
+/// expecting a single typed handle. This allows fields like enums, where the dynamic asset
+/// registered for the key decides which variant is built.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// # use anyhow::anyhow;
+/// enum Sprite {
+///     Image(Handle<Image>),
+///     Atlas(Handle<TextureAtlas>),
+/// }
+///
+/// impl DynamicAssetField for Sprite {
+///     fn from_dynamic_asset(asset: DynamicAssetType, world: &World) -> Result<Self, anyhow::Error> {
+///         match asset {
+///             DynamicAssetType::Single(handle)
+///                 if world.resource::<Assets<TextureAtlas>>().contains(&handle) =>
+///             {
+///                 Ok(Sprite::Atlas(handle.typed()))
+///             }
+///             DynamicAssetType::Single(handle) => Ok(Sprite::Image(handle.typed())),
+///             DynamicAssetType::Collection(_) => Err(anyhow!("Expected a single handle")),
+///         }
+///     }
+/// }
+///
+/// #[derive(AssetCollection, Resource)]
+/// struct CharacterAssets {
+///     #[asset(key = "character", dynamic_field)]
+///     character: Sprite,
+///     #[asset(key = "pet", dynamic_field, optional)]
+///     pet: Option<Sprite>,
+/// }
+/// ```
+pub trait DynamicAssetField: Sized {
+    /// Build the field value from the handle(s) of the dynamic asset
+    ///
+    /// All assets of the dynamic asset finished loading before this is called.
+    fn from_dynamic_asset(asset: DynamicAssetType, world: &World) -> Result<Self, anyhow::Error>;
+}
+
+/// Typed key for dynamic assets
+///
+/// Typed keys, like enum variants, catch typos at compile time. Internally, every typed key
+/// maps to a string key. Fields of asset collections can use typed keys with the
+/// `key_enum` attribute.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// enum MyKeys {
+///     Character,
+///     Tree,
+/// }
+///
+/// impl AssetKey for MyKeys {
+///     fn as_str(&self) -> &str {
+///         match self {
+///             MyKeys::Character => "character",
+///             MyKeys::Tree => "tree",
+///         }
+///     }
+/// }
+///
+/// #[derive(AssetCollection, Resource)]
+/// struct ImageAssets {
+///     #[asset(key_enum(MyKeys::Character))]
+///     character: Handle<Image>,
+///     #[asset(key_enum(MyKeys::Tree), optional)]
+///     tree: Option<Handle<Image>>,
+/// }
+/// ```
+pub trait AssetKey {
+    /// The string key this typed key maps to
+    fn as_str(&self) -> &str;
 }
 
 /// Resource to dynamically resolve keys to assets.
@@ -35,6 +141,75 @@ pub trait DynamicAsset: Debug + Send + Sync {
 #[derive(Resource, Default)]
 pub struct DynamicAssets {
     key_asset_map: HashMap<String, Box<dyn DynamicAsset>>,
+    /// Descriptions of the registered assets that can be described as standard dynamic assets
+    #[cfg(feature = "standard_dynamic_assets")]
+    standard_assets: HashMap<String, crate::standard_dynamic_asset::StandardDynamicAsset>,
+    path_aliases: HashMap<String, String>,
+    in_memory_assets: InMemoryAssets,
+    /// Asset types of the keys registered with [`DynamicAssets::register_typed`]
+    expected_types: HashMap<String, (TypeId, &'static str)>,
+    /// Registrations replaced in the active scopes, innermost last
+    ///
+    /// See [`DynamicAssets::scoped`]
+    scopes: Vec<ReplacedRegistrations>,
+}
+
+/// Registrations of the keys and aliases that a scope changed, from before their first change
+#[derive(Default)]
+struct ReplacedRegistrations {
+    assets: HashMap<String, Option<Box<dyn DynamicAsset>>>,
+    #[cfg(feature = "standard_dynamic_assets")]
+    standard_assets: HashMap<String, Option<crate::standard_dynamic_asset::StandardDynamicAsset>>,
+    expected_types: HashMap<String, Option<(TypeId, &'static str)>>,
+    path_aliases: HashMap<String, Option<String>>,
+}
+
+/// Put back the values a scope replaced and remove the keys it added
+fn restore<V>(map: &mut HashMap<String, V>, replaced: HashMap<String, Option<V>>) {
+    for (key, value) in replaced {
+        match value {
+            Some(value) => {
+                map.insert(key, value);
+            }
+            None => {
+                map.remove(&key);
+            }
+        }
+    }
+}
+
+/// Ends the innermost scope of the [`DynamicAssets`] when dropped
+struct DynamicAssetsScope<'a>(&'a mut DynamicAssets);
+
+impl Deref for DynamicAssetsScope<'_> {
+    type Target = DynamicAssets;
+
+    fn deref(&self) -> &Self::Target {
+        self.0
+    }
+}
+
+impl DerefMut for DynamicAssetsScope<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.0
+    }
+}
+
+impl Drop for DynamicAssetsScope<'_> {
+    fn drop(&mut self) {
+        self.0.end_scope();
+    }
+}
+
+/// Ends the innermost scope of the [`DynamicAssets`] resource of the world when dropped
+struct WorldScope<'a>(&'a mut World);
+
+impl Drop for WorldScope<'_> {
+    fn drop(&mut self) {
+        if let Some(mut dynamic_assets) = self.0.get_resource_mut::<DynamicAssets>() {
+            dynamic_assets.end_scope();
+        }
+    }
 }
 
 impl DynamicAssets {
@@ -47,7 +222,323 @@ impl DynamicAssets {
     ///
     /// In case the key is already known, its value will be overwritten.
     pub fn register_asset<K: Into<String>>(&mut self, key: K, asset: Box<dyn DynamicAsset>) {
-        self.key_asset_map.insert(key.into(), asset);
+        let key = key.into();
+        #[cfg(feature = "standard_dynamic_assets")]
+        let previous_standard_asset = match asset.as_standard() {
+            Some(standard_asset) => self.standard_assets.insert(key.clone(), standard_asset),
+            None => self.standard_assets.remove(&key),
+        };
+        let previous_expected_type = self.expected_types.remove(&key);
+        let previous_asset = self.key_asset_map.insert(key.clone(), asset);
+        if let Some(scope) = self.scopes.last_mut() {
+            #[cfg(feature = "standard_dynamic_assets")]
+            scope
+                .standard_assets
+                .entry(key.clone())
+                .or_insert(previous_standard_asset);
+            scope
+                .expected_types
+                .entry(key.clone())
+                .or_insert(previous_expected_type);
+            scope.assets.entry(key).or_insert(previous_asset);
+        }
+    }
+
+    /// Register the file at the given path for the key and record the expected asset type
+    ///
+    /// The file is loaded like a [`FileAsset`]. When a collection is created, fields using the key
+    /// are checked against the recorded type. If a field expects another asset type, creating the
+    /// collection fails with an error naming both types, instead of silently building a handle of
+    /// the wrong type. Registering an untyped asset for the key later removes the expected type.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// fn register_player(mut dynamic_assets: ResMut<DynamicAssets>) {
+    ///     dynamic_assets.register_typed::<Image, _>("player", "images/player.png");
+    /// }
+    /// ```
+    pub fn register_typed<T: Asset, K: Into<String>>(&mut self, key: K, path: impl Into<String>) {
+        let key = key.into();
+        self.register_asset(key.clone(), Box::new(FileAsset { path: path.into() }));
+        self.expected_types
+            .insert(key, (TypeId::of::<T>(), type_name::<T>()));
+    }
+
+    /// Get the type id and name of the asset type registered for the given key
+    ///
+    /// See [`DynamicAssets::register_typed`]
+    pub(crate) fn expected_type(&self, key: &str) -> Option<(TypeId, &'static str)> {
+        self.expected_types.get(key).copied()
+    }
+
+    /// Get the [`StandardDynamicAsset`](crate::standard_dynamic_asset::StandardDynamicAsset) registered for the given key
+    ///
+    /// The description is retained when the asset is registered, so it shows what a key
+    /// resolved to, like the loaded path or the parameters of a texture atlas. This is useful for
+    /// debugging or to display which of several variants was chosen for a key. Assets that
+    /// cannot be described as a standard dynamic asset (see [`DynamicAsset::as_standard`])
+    /// return `None`.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy_asset_loader::standard_dynamic_asset::StandardDynamicAsset;
+    /// # use bevy::prelude::*;
+    /// fn log_level_path(dynamic_assets: Res<DynamicAssets>) {
+    ///     if let Some(StandardDynamicAsset::File { path }) = dynamic_assets.resolved_info("level") {
+    ///         info!("The level was loaded from {path}");
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "standard_dynamic_assets")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "standard_dynamic_assets")))]
+    pub fn resolved_info(
+        &self,
+        key: &str,
+    ) -> Option<&crate::standard_dynamic_asset::StandardDynamicAsset> {
+        self.standard_assets.get(key)
+    }
+
+    /// Set the corresponding dynamic assets for all given keys.
+    ///
+    /// This is useful to register assets from a configuration that was deserialized by hand.
+    /// In case a key is already known, its value will be overwritten.
+    pub fn register_all<K: Into<String>>(
+        &mut self,
+        assets: impl IntoIterator<Item = (K, Box<dyn DynamicAsset>)>,
+    ) {
+        for (key, asset) in assets {
+            self.register_asset(key, asset);
+        }
+    }
+
+    /// Get the asset corresponding to the given typed key.
+    pub fn get<K: AssetKey>(&self, key: &K) -> Option<&dyn DynamicAsset> {
+        self.get_asset(key.as_str())
+    }
+
+    /// Set the corresponding dynamic asset for the given typed key.
+    ///
+    /// In case the key is already known, its value will be overwritten.
+    pub fn register<K: AssetKey>(&mut self, key: K, asset: Box<dyn DynamicAsset>) {
+        self.register_asset(key.as_str(), asset);
+    }
+
+    /// Register bytes as the asset for the given key.
+    ///
+    /// The bytes are loaded by the asset loader registered for the given file extension
+    /// (e.g. `"png"`). This requires the [`InMemoryAssetsPlugin`](crate::in_memory_asset::InMemoryAssetsPlugin)
+    /// to be added to your app before Bevy's `AssetPlugin`.
+    ///
+    /// The asset server will not load the same key again while handles to the previous
+    /// asset are alive. Register new bytes under a new key, or drop all handles before
+    /// reusing a key.
+    pub fn register_bytes<K: Into<String>>(&mut self, key: K, bytes: Vec<u8>, extension: &str) {
+        let key = key.into();
+        let path = format!("{IN_MEMORY_ASSET_DIRECTORY}/{key}.{extension}");
+        self.in_memory_assets.insert(path.clone(), bytes);
+        self.register_asset(key, Box::new(InMemoryAsset { path }));
+    }
+
+    /// Load `new` wherever an asset collection configures the path `old`
+    ///
+    /// Aliases redirect the paths given in derive attributes, like `path`, `paths` or the
+    /// folder of a collection, before any [`AssetPathTransform`](crate::asset_collection::AssetPathTransform)
+    /// is applied. This allows renaming asset files without touching every attribute during a
+    /// migration. Aliases are not followed recursively.
+    ///
+    /// Fields with a dynamic `key` are resolved through their registered dynamic asset and
+    /// ignore aliases, so a dynamic key always takes precedence. Register the alias before the
+    /// collection starts loading; in case the path already has an alias, it will be overwritten.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// fn renamed_assets(mut dynamic_assets: ResMut<DynamicAssets>) {
+    ///     dynamic_assets.register_alias("images/player.png", "images/characters/player.png");
+    /// }
+    /// ```
+    pub fn register_alias<O: Into<String>, N: Into<String>>(&mut self, old: O, new: N) {
+        let old = old.into();
+        let previous = self.path_aliases.insert(old.clone(), new.into());
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.path_aliases.entry(old).or_insert(previous);
+        }
+    }
+
+    /// Get the path registered as alias for the given path
+    pub fn alias(&self, path: &str) -> Option<&str> {
+        self.path_aliases.get(path).map(String::as_str)
+    }
+
+    /// Change the registered assets only for the duration of the closure
+    ///
+    /// Assets, typed keys and aliases that the closure registers are restored to their previous
+    /// registrations afterwards, and keys that did not exist before are removed again. This
+    /// allows previewing alternative assets without changing the registrations for the rest of
+    /// the app. Scopes can be nested; each one restores the state from when it started, even if
+    /// the closure panics.
+    ///
+    /// Restoring does not cancel anything that was already requested. Loads started inside the
+    /// scope keep loading the scoped files and their handles stay valid. Bytes registered with
+    /// [`DynamicAssets::register_bytes`] stay available for such loads. Keys are resolved again
+    /// when a collection is created, though, so a collection that started loading inside the
+    /// scope but is created after it builds its dynamic fields from the restored registrations.
+    /// Load and create a preview collection inside the scope, for example with
+    /// [`DynamicAssets::scoped_in_world`] and [`init_collection`](crate::asset_collection::AssetCollectionWorld::init_collection).
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// fn preview_player(mut dynamic_assets: ResMut<DynamicAssets>) {
+    ///     dynamic_assets.scoped(|dynamic_assets| {
+    ///         dynamic_assets.register_asset(
+    ///             "player",
+    ///             Box::new(FileAsset { path: "images/player_alternative.png".to_owned() }),
+    ///         );
+    ///         assert!(dynamic_assets.get_asset("player").is_some());
+    ///     });
+    /// }
+    /// ```
+    pub fn scoped<R>(&mut self, scope: impl FnOnce(&mut DynamicAssets) -> R) -> R {
+        self.scopes.push(ReplacedRegistrations::default());
+        let mut guard = DynamicAssetsScope(self);
+        scope(&mut guard)
+    }
+
+    /// Run the closure with a scope on the [`DynamicAssets`] resource of the world
+    ///
+    /// Works like [`DynamicAssets::scoped`], but the closure gets the world, so it can register
+    /// assets through the resource and load and create collections with them. The resource is
+    /// initialized if it does not exist.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// fn preview(world: &mut World) {
+    ///     DynamicAssets::scoped_in_world(world, |world| {
+    ///         world.resource_mut::<DynamicAssets>().register_asset(
+    ///             "player",
+    ///             Box::new(FileAsset { path: "images/player_alternative.png".to_owned() }),
+    ///         );
+    ///         world.init_collection::<PreviewAssets>();
+    ///     });
+    /// }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # struct PreviewAssets {
+    /// #     #[asset(key = "player")]
+    /// #     player: Handle<Image>,
+    /// # }
+    /// ```
+    pub fn scoped_in_world<R>(world: &mut World, scope: impl FnOnce(&mut World) -> R) -> R {
+        world
+            .get_resource_or_insert_with(DynamicAssets::default)
+            .scopes
+            .push(ReplacedRegistrations::default());
+        let guard = WorldScope(world);
+        scope(&mut *guard.0)
+    }
+
+    fn end_scope(&mut self) {
+        let Some(replaced) = self.scopes.pop() else {
+            return;
+        };
+        #[cfg(feature = "standard_dynamic_assets")]
+        restore(&mut self.standard_assets, replaced.standard_assets);
+        restore(&mut self.expected_types, replaced.expected_types);
+        restore(&mut self.key_asset_map, replaced.assets);
+        restore(&mut self.path_aliases, replaced.path_aliases);
+    }
+
+    /// Get the storage of all bytes registered through [`DynamicAssets::register_bytes`]
+    pub fn in_memory_assets(&self) -> InMemoryAssets {
+        self.in_memory_assets.clone()
+    }
+
+    /// Register the values of command line flags as [`FileAsset`]s for the mapped keys
+    ///
+    /// The mapping pairs a flag with the key its value is registered for. Flags can be given as
+    /// `--level path/to/level.png` or `--level=path/to/level.png`. Keys of flags that were not
+    /// passed to the binary stay untouched, so they keep the assets of dynamic asset files or
+    /// any other configuration.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// fn level_from_args(mut dynamic_assets: ResMut<DynamicAssets>) {
+    ///     dynamic_assets.register_from_args(&[("--level", "level")]);
+    /// }
+    /// ```
+    pub fn register_from_args(&mut self, mapping: &[(impl AsRef<str>, impl AsRef<str>)]) {
+        self.register_from_arg_list(mapping, std::env::args().skip(1));
+    }
+
+    /// Register the values of flags in the given arguments as [`FileAsset`]s for the mapped keys
+    ///
+    /// Works like [`DynamicAssets::register_from_args`] with arguments from another source than
+    /// [`std::env::args`]. If a flag is given multiple times, the last value wins.
+    pub fn register_from_arg_list<A: Into<String>>(
+        &mut self,
+        mapping: &[(impl AsRef<str>, impl AsRef<str>)],
+        args: impl IntoIterator<Item = A>,
+    ) {
+        let mut args = args.into_iter().map(Into::into);
+        while let Some(arg) = args.next() {
+            for (flag, key) in mapping {
+                let flag = flag.as_ref();
+                let value = if arg == flag {
+                    args.next()
+                } else {
+                    arg.strip_prefix(flag)
+                        .and_then(|value| value.strip_prefix('='))
+                        .map(str::to_owned)
+                };
+                if let Some(path) = value {
+                    debug!(
+                        flag,
+                        key = key.as_ref(),
+                        path,
+                        "Registering dynamic asset from command line"
+                    );
+                    self.register_asset(key.as_ref(), Box::new(FileAsset { path }));
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// Dynamic asset loading a single file
+///
+/// See [`DynamicAssets::register_from_args`]
+#[derive(Debug, Clone)]
+pub struct FileAsset {
+    /// Path of the file relative to the asset folder
+    pub path: String,
+}
+
+impl DynamicAsset for FileAsset {
+    fn load(&self, asset_server: &AssetServer) -> Vec<HandleUntyped> {
+        self.load_with_transform(asset_server, None)
+    }
+
+    fn load_with_transform(
+        &self,
+        asset_server: &AssetServer,
+        transform: Option<&AssetPathTransform>,
+    ) -> Vec<HandleUntyped> {
+        vec![asset_server.load_untyped(transform_asset_path(None, transform, &self.path).as_str())]
+    }
+
+    fn build(&self, world: &mut World) -> Result<DynamicAssetType, anyhow::Error> {
+        let asset_server = world
+            .get_resource::<AssetServer>()
+            .expect("Cannot get AssetServer");
+        let path = transform_asset_path(None, world.get_resource(), &self.path);
+        Ok(DynamicAssetType::Single(
+            asset_server.get_handle_untyped(path.as_str()),
+        ))
+    }
+
+    #[cfg(feature = "standard_dynamic_assets")]
+    fn as_standard(&self) -> Option<crate::standard_dynamic_asset::StandardDynamicAsset> {
+        Some(crate::standard_dynamic_asset::StandardDynamicAsset::File {
+            path: self.path.clone(),
+        })
     }
 }
 
@@ -69,11 +560,7 @@ impl<State: States> DynamicAssetCollections<State> {
     /// Register a file containing dynamic asset definitions to be loaded and applied to the given loading state
     ///
     /// The file will be read every time the loading state is entered
-    pub fn register_file<C: DynamicAssetCollection + Asset>(
-        &mut self,
-        loading_state: State,
-        file: &str,
-    ) -> bool {
+    pub fn register_file<C: Asset>(&mut self, loading_state: State, file: &str) -> bool {
         let mut dynamic_collections_for_state =
             self.files.remove(&loading_state).unwrap_or_default();
         let initialize_dynamic_assets =
@@ -90,10 +577,7 @@ impl<State: States> DynamicAssetCollections<State> {
     }
 
     /// Get all currently registered files to be loaded for the given loading state and dynamic asset collection type.
-    pub fn get_files<C: DynamicAssetCollection + Asset>(
-        &self,
-        loading_state: &State,
-    ) -> Option<&Vec<String>> {
+    pub fn get_files<C: Asset>(&self, loading_state: &State) -> Option<&Vec<String>> {
         let files = self
             .files
             .get(loading_state)
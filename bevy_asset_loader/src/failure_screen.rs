@@ -0,0 +1,190 @@
+use crate::loading_state::FailedAssets;
+use bevy::app::{App, Plugin, Update};
+use bevy::ecs::component::Component;
+use bevy::ecs::entity::Entity;
+use bevy::ecs::query::{Changed, With};
+use bevy::ecs::schedule::{
+    common_conditions::in_state, IntoSystemConfigs, NextState, OnEnter, OnExit, States,
+};
+use bevy::ecs::system::{Commands, Query, Res, ResMut, Resource};
+use bevy::hierarchy::{BuildChildren, DespawnRecursiveExt};
+use bevy::render::color::Color;
+use bevy::text::TextStyle;
+use bevy::ui::node_bundles::{ButtonBundle, NodeBundle, TextBundle};
+use bevy::ui::{AlignItems, FlexDirection, Interaction, JustifyContent, Style, UiRect, Val};
+use bevy::utils::default;
+
+/// Plugin showing an error screen with all assets that failed to load
+///
+/// When the failure state is entered, the plugin spawns UI listing the paths from the
+/// [`FailedAssets`] resource and a button to retry loading. Pressing the button
+/// re-enters the loading state. The UI is removed again when leaving the failure state.
+///
+/// The failure state has to be configured with
+/// [`LoadingState::on_failure_continue_to_state`](crate::loading_state::LoadingState::on_failure_continue_to_state).
+/// A camera is required to render the UI.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy_asset_loader::failure_screen::AssetFailureScreenPlugin;
+/// # use bevy::prelude::*;
+/// # use bevy::asset::AssetPlugin;
+/// # fn main() {
+///     App::new()
+/// #       .add_state::<GameState>()
+/// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+/// #       .init_resource::<iyes_progress::ProgressCounter>()
+///         .add_loading_state(
+///           LoadingState::new(GameState::Loading)
+///             .continue_to_state(GameState::Menu)
+///             .on_failure_continue_to_state(GameState::Error)
+///         )
+///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Loading)
+///         .add_plugins(AssetFailureScreenPlugin {
+///             title: "Oh no!".to_owned(),
+///             ..AssetFailureScreenPlugin::new(GameState::Loading, GameState::Error)
+///         })
+/// #       .set_runner(|mut app| app.update())
+/// #       .run();
+/// # }
+/// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+/// # enum GameState {
+/// #     #[default]
+/// #     Loading,
+/// #     Menu,
+/// #     Error
+/// # }
+/// # #[derive(AssetCollection, Resource)]
+/// # pub struct MyAssets {
+/// #     #[asset(path = "audio/background.ogg")]
+/// #     pub background: Handle<AudioSource>,
+/// # }
+/// ```
+#[derive(Resource, Clone)]
+pub struct AssetFailureScreenPlugin<S: States> {
+    /// The loading state that is re-entered when retrying
+    pub loading_state: S,
+    /// The failure state of the loading state
+    pub failure_state: S,
+    /// Heading shown above the list of failed assets
+    pub title: String,
+    /// Text of the retry button
+    pub retry_text: String,
+    /// Color of the text
+    pub text_color: Color,
+    /// Background color of the screen
+    pub background_color: Color,
+    /// Background color of the retry button
+    pub button_color: Color,
+}
+
+impl<S: States> AssetFailureScreenPlugin<S> {
+    /// Create the plugin with default texts and colors
+    pub fn new(loading_state: S, failure_state: S) -> Self {
+        AssetFailureScreenPlugin {
+            loading_state,
+            failure_state,
+            title: "Failed to load assets".to_owned(),
+            retry_text: "Retry".to_owned(),
+            text_color: Color::WHITE,
+            background_color: Color::rgb(0.15, 0.15, 0.15),
+            button_color: Color::rgb(0.35, 0.35, 0.35),
+        }
+    }
+}
+
+impl<S: States> Plugin for AssetFailureScreenPlugin<S> {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.clone())
+            .add_systems(
+                OnEnter(self.failure_state.clone()),
+                spawn_failure_screen::<S>,
+            )
+            .add_systems(OnExit(self.failure_state.clone()), despawn_failure_screen)
+            .add_systems(
+                Update,
+                retry::<S>.run_if(in_state(self.failure_state.clone())),
+            );
+    }
+}
+
+#[derive(Component)]
+struct FailureScreen;
+
+#[derive(Component)]
+struct RetryButton;
+
+fn spawn_failure_screen<S: States>(
+    mut commands: Commands,
+    config: Res<AssetFailureScreenPlugin<S>>,
+    failed_assets: Res<FailedAssets>,
+) {
+    let text_style = |font_size: f32| TextStyle {
+        font_size,
+        color: config.text_color,
+        ..default()
+    };
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.),
+                    height: Val::Percent(100.),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    row_gap: Val::Px(8.),
+                    ..default()
+                },
+                background_color: config.background_color.into(),
+                ..default()
+            },
+            FailureScreen,
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                config.title.clone(),
+                text_style(40.),
+            ));
+            for path in &failed_assets.paths {
+                parent.spawn(TextBundle::from_section(path.clone(), text_style(20.)));
+            }
+            parent
+                .spawn((
+                    ButtonBundle {
+                        style: Style {
+                            padding: UiRect::all(Val::Px(10.)),
+                            margin: UiRect::top(Val::Px(20.)),
+                            ..default()
+                        },
+                        background_color: config.button_color.into(),
+                        ..default()
+                    },
+                    RetryButton,
+                ))
+                .with_children(|button| {
+                    button.spawn(TextBundle::from_section(
+                        config.retry_text.clone(),
+                        text_style(30.),
+                    ));
+                });
+        });
+}
+
+fn despawn_failure_screen(mut commands: Commands, screens: Query<Entity, With<FailureScreen>>) {
+    for screen in &screens {
+        commands.entity(screen).despawn_recursive();
+    }
+}
+
+fn retry<S: States>(
+    buttons: Query<&Interaction, (Changed<Interaction>, With<RetryButton>)>,
+    config: Res<AssetFailureScreenPlugin<S>>,
+    mut next_state: ResMut<NextState<S>>,
+) {
+    if buttons
+        .iter()
+        .any(|interaction| *interaction == Interaction::Pressed)
+    {
+        next_state.set(config.loading_state.clone());
+    }
+}
@@ -0,0 +1,37 @@
+use bevy::asset::{AssetServer, Assets, Handle};
+use bevy::ecs::world::World;
+use bevy::gltf::Gltf;
+use bevy::pbr::StandardMaterial;
+
+/// The materials of a loaded glTF file
+///
+/// Fields with the `gltf_materials` attribute are created from this vector. The materials are
+/// ordered by their index in the glTF file, which is the index of the `#MaterialN` labels.
+/// Unnamed materials are included at their index like all others; the names of named
+/// materials are available in [`Gltf::named_materials`]. The default material that Bevy uses
+/// for primitives without a material is not part of the file and not included.
+///
+/// Returns `None` if the file is not loaded as [`Gltf`].
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy::gltf::Gltf;
+/// #[derive(AssetCollection, Resource)]
+/// struct CharacterAssets {
+///     #[asset(path = "models/character.gltf")]
+///     gltf: Handle<Gltf>,
+///     #[asset(path = "models/character.gltf", gltf_materials)]
+///     materials: Vec<Handle<StandardMaterial>>,
+/// }
+/// ```
+#[doc(hidden)]
+pub fn gltf_materials(world: &World, gltf_path: &str) -> Option<Vec<Handle<StandardMaterial>>> {
+    let asset_server = world
+        .get_resource::<AssetServer>()
+        .expect("Cannot get AssetServer");
+    let gltfs = world
+        .get_resource::<Assets<Gltf>>()
+        .expect("Cannot get Assets<Gltf>");
+    let handle: Handle<Gltf> = asset_server.get_handle(gltf_path);
+    gltfs.get(&handle).map(|gltf| gltf.materials.clone())
+}
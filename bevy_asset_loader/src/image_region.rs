@@ -0,0 +1,76 @@
+use bevy::asset::{AssetServer, Assets, Handle};
+use bevy::ecs::world::World;
+use bevy::render::render_resource::{Extent3d, TextureDimension};
+use bevy::render::texture::Image;
+
+/// Copy a rectangular region of a loaded image into a new image
+///
+/// The new image keeps the texture format and sampler of the source image. Only the first mip
+/// level of the source is copied. Any uncompressed format is supported, since pixels are copied
+/// byte by byte.
+///
+/// Panics if the source image is not loaded, uses a compressed format, is not a single 2d
+/// image, or if the region is empty or does not fit into the source image.
+#[doc(hidden)]
+pub fn extract_region(
+    world: &mut World,
+    source: &Handle<Image>,
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+) -> Handle<Image> {
+    let source_path = world
+        .get_resource::<AssetServer>()
+        .and_then(|asset_server| asset_server.get_handle_path(source))
+        .map(|path| path.path().to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let mut images = world
+        .get_resource_mut::<Assets<Image>>()
+        .expect("Cannot get Assets<Image>");
+    let image = images.get(source).unwrap_or_else(|| {
+        panic!("Cannot extract a region from the image '{source_path}', because it is not loaded")
+    });
+    let descriptor = &image.texture_descriptor;
+    let format = descriptor.format;
+    let pixel_size = match (format.block_dimensions(), format.block_size(None)) {
+        ((1, 1), Some(pixel_size)) => pixel_size as usize,
+        _ => panic!(
+            "Cannot extract a region from the image '{source_path}' with the compressed format {format:?}"
+        ),
+    };
+    if descriptor.dimension != TextureDimension::D2 || descriptor.size.depth_or_array_layers != 1 {
+        panic!("Cannot extract a region from the image '{source_path}', because it is not a single 2d image");
+    }
+    let size = descriptor.size;
+    if width == 0
+        || height == 0
+        || x.saturating_add(width) > size.width
+        || y.saturating_add(height) > size.height
+    {
+        panic!(
+            "The region (x = {x}, y = {y}, w = {width}, h = {height}) is outside of the image '{source_path}' with the size {}x{}",
+            size.width, size.height
+        );
+    }
+
+    let row_length = width as usize * pixel_size;
+    let mut data = Vec::with_capacity(row_length * height as usize);
+    for row in y..y + height {
+        let start = (row as usize * size.width as usize + x as usize) * pixel_size;
+        data.extend_from_slice(&image.data[start..start + row_length]);
+    }
+    let mut region = Image::new(
+        Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        format,
+    );
+    region.sampler_descriptor = image.sampler_descriptor.clone();
+
+    images.add(region)
+}
@@ -0,0 +1,168 @@
+use crate::dynamic_asset::{DynamicAsset, DynamicAssetType, DynamicAssets};
+use bevy::app::{App, Plugin};
+use bevy::asset::{
+    AssetIo, AssetIoError, AssetPlugin, AssetServer, ChangeWatcher, FileType, HandleUntyped,
+    Metadata,
+};
+use bevy::ecs::world::World;
+use bevy::log::warn;
+use bevy::utils::{BoxedFuture, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+/// Directory under which all in memory assets are served by the [`InMemoryAssetIo`]
+pub const IN_MEMORY_ASSET_DIRECTORY: &str = "bevy_asset_loader_in_memory";
+
+/// Storage for asset bytes that were registered at run time
+///
+/// The storage is shared between the [`DynamicAssets`] resource and the [`InMemoryAssetIo`].
+#[derive(Clone, Default)]
+pub struct InMemoryAssets {
+    files: Arc<RwLock<HashMap<PathBuf, Arc<[u8]>>>>,
+}
+
+impl InMemoryAssets {
+    /// Store the given bytes under the given path
+    ///
+    /// Bytes that were stored under the same path before are replaced.
+    pub fn insert<P: Into<PathBuf>>(&self, path: P, bytes: Vec<u8>) {
+        self.files
+            .write()
+            .expect("In memory asset storage is poisoned")
+            .insert(path.into(), bytes.into());
+    }
+
+    /// Get the bytes stored under the given path
+    pub fn get(&self, path: &Path) -> Option<Arc<[u8]>> {
+        self.files
+            .read()
+            .expect("In memory asset storage is poisoned")
+            .get(path)
+            .cloned()
+    }
+
+    /// Check if any bytes are stored under the given path
+    pub fn contains(&self, path: &Path) -> bool {
+        self.files
+            .read()
+            .expect("In memory asset storage is poisoned")
+            .contains_key(path)
+    }
+}
+
+/// [`AssetIo`] that serves [`InMemoryAssets`] and forwards everything else to another [`AssetIo`]
+pub struct InMemoryAssetIo {
+    assets: InMemoryAssets,
+    fallback: Box<dyn AssetIo>,
+}
+
+impl InMemoryAssetIo {
+    /// Create a new [`InMemoryAssetIo`] serving the given assets
+    ///
+    /// All paths that are not part of the in memory assets are loaded through `fallback`.
+    pub fn new(assets: InMemoryAssets, fallback: Box<dyn AssetIo>) -> Self {
+        InMemoryAssetIo { assets, fallback }
+    }
+}
+
+impl AssetIo for InMemoryAssetIo {
+    fn load_path<'a>(&'a self, path: &'a Path) -> BoxedFuture<'a, Result<Vec<u8>, AssetIoError>> {
+        match self.assets.get(path) {
+            Some(bytes) => Box::pin(async move { Ok(bytes.to_vec()) }),
+            None => self.fallback.load_path(path),
+        }
+    }
+
+    fn read_directory(
+        &self,
+        path: &Path,
+    ) -> Result<Box<dyn Iterator<Item = PathBuf>>, AssetIoError> {
+        self.fallback.read_directory(path)
+    }
+
+    fn get_metadata(&self, path: &Path) -> Result<Metadata, AssetIoError> {
+        if self.assets.contains(path) {
+            return Ok(Metadata::new(FileType::File));
+        }
+        self.fallback.get_metadata(path)
+    }
+
+    fn watch_path_for_changes(
+        &self,
+        to_watch: &Path,
+        to_reload: Option<PathBuf>,
+    ) -> Result<(), AssetIoError> {
+        if self.assets.contains(to_watch) {
+            return Ok(());
+        }
+        self.fallback.watch_path_for_changes(to_watch, to_reload)
+    }
+
+    fn watch_for_changes(&self, configuration: &ChangeWatcher) -> Result<(), AssetIoError> {
+        self.fallback.watch_for_changes(configuration)
+    }
+}
+
+/// Plugin to load assets from bytes registered at run time
+///
+/// Bytes can be registered with [`DynamicAssets::register_bytes`]. The plugin replaces the
+/// [`AssetIo`] of your platform with an [`InMemoryAssetIo`] and needs to be added before Bevy's
+/// [`AssetPlugin`].
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy::asset::AssetPlugin;
+/// # fn main() {
+///     App::new()
+///         .add_plugins((
+///             MinimalPlugins,
+///             InMemoryAssetsPlugin::default(),
+///             AssetPlugin::default(),
+///         ))
+/// #       .set_runner(|mut app| app.update())
+///         .run();
+/// # }
+/// ```
+#[derive(Default)]
+pub struct InMemoryAssetsPlugin {
+    /// Configuration of the [`AssetIo`] used for all assets that are not in memory
+    pub asset_plugin: AssetPlugin,
+}
+
+impl Plugin for InMemoryAssetsPlugin {
+    fn build(&self, app: &mut App) {
+        if app.world.contains_resource::<AssetServer>() {
+            warn!("The InMemoryAssetsPlugin needs to be added before the AssetPlugin. In memory assets will fail to load.");
+            return;
+        }
+        app.init_resource::<DynamicAssets>();
+        let assets = app.world.resource::<DynamicAssets>().in_memory_assets();
+        let asset_io =
+            InMemoryAssetIo::new(assets, self.asset_plugin.create_platform_default_asset_io());
+        app.insert_resource(AssetServer::new(asset_io));
+    }
+}
+
+/// Dynamic asset loaded from bytes that were registered at run time
+///
+/// See [`DynamicAssets::register_bytes`]
+#[derive(Debug, Clone)]
+pub struct InMemoryAsset {
+    /// Virtual path of the asset in the [`InMemoryAssetIo`]
+    pub path: String,
+}
+
+impl DynamicAsset for InMemoryAsset {
+    fn load(&self, asset_server: &AssetServer) -> Vec<HandleUntyped> {
+        vec![asset_server.load_untyped(&self.path)]
+    }
+
+    fn build(&self, world: &mut World) -> Result<DynamicAssetType, anyhow::Error> {
+        let asset_server = world
+            .get_resource::<AssetServer>()
+            .expect("Cannot get AssetServer");
+        Ok(DynamicAssetType::Single(
+            asset_server.get_handle_untyped(&self.path),
+        ))
+    }
+}
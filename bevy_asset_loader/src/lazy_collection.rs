@@ -0,0 +1,177 @@
+use crate::asset_collection::{pin_handles, AssetCollection, RecoverableHandles};
+use crate::loading_state::describe_handle;
+use bevy::asset::{AssetServer, HandleUntyped, LoadState};
+use bevy::ecs::system::Resource;
+use bevy::ecs::world::World;
+use bevy::log::{debug, warn};
+use std::any::type_name;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Resource loading the asset collection `A` the first time it is accessed
+///
+/// Add it with [`AssetCollectionApp::init_lazy_collection`](crate::asset_collection::AssetCollectionApp::init_lazy_collection).
+/// Nothing is loaded until a system calls [`LazyCollection::get`]. That call returns `None` and
+/// requests the collection. In the next `PreUpdate`, the assets start loading, and once all of
+/// them are loaded, the collection is created and `get` returns it. This happens independent of
+/// any loading state, which makes it a good fit for collections of rarely visited menus.
+///
+/// If an asset fails to load, a warning is logged and the next access requests the collection again.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// fn show_credits(
+///     credits: Res<LazyCollection<CreditsAssets>>,
+///     mut screens: Query<&mut Handle<Image>, With<CreditsScreen>>,
+/// ) {
+///     // Until the collection is loaded, the screen keeps showing a placeholder
+///     let Some(credits) = credits.get() else {
+///         return;
+///     };
+///     for mut image in &mut screens {
+///         *image = credits.background.clone();
+///     }
+/// }
+///
+/// #[derive(Component)]
+/// struct CreditsScreen;
+///
+/// #[derive(AssetCollection, Resource)]
+/// struct CreditsAssets {
+///     #[asset(path = "images/credits.png")]
+///     background: Handle<Image>,
+/// }
+/// ```
+#[derive(Resource)]
+pub struct LazyCollection<A: AssetCollection> {
+    requested: AtomicBool,
+    state: LazyState<A>,
+}
+
+enum LazyState<A> {
+    Unloaded,
+    Loading {
+        handles: Vec<HandleUntyped>,
+        /// Whether the [deferred assets](AssetCollection::load_deferred) started loading
+        deferred_issued: bool,
+    },
+    Loaded(A),
+}
+
+impl<A: AssetCollection> LazyCollection<A> {
+    /// The collection, if it is loaded
+    ///
+    /// Requests loading the collection if it is not loaded yet.
+    pub fn get(&self) -> Option<&A> {
+        match &self.state {
+            LazyState::Loaded(collection) => Some(collection),
+            _ => {
+                self.requested.store(true, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+
+    /// Whether the collection is loaded
+    ///
+    /// In contrast to [`LazyCollection::get`], this does not request the collection.
+    pub fn is_loaded(&self) -> bool {
+        matches!(self.state, LazyState::Loaded(_))
+    }
+
+    /// Drop the collection and its handles
+    ///
+    /// The next access loads the collection again. Handles kept for collections with
+    /// `#[asset_collection(pin_strong)]` stay in [`PinnedHandles`](crate::asset_collection::PinnedHandles).
+    pub fn unload(&mut self) {
+        self.state = LazyState::Unloaded;
+        *self.requested.get_mut() = false;
+    }
+}
+
+impl<A: AssetCollection> Default for LazyCollection<A> {
+    fn default() -> Self {
+        LazyCollection {
+            requested: AtomicBool::new(false),
+            state: LazyState::Unloaded,
+        }
+    }
+}
+
+/// Start loading requested lazy collections and create them once all their assets are loaded
+pub(crate) fn load_lazy_collection<A: AssetCollection>(world: &mut World) {
+    let Some(lazy_collection) = world.get_resource::<LazyCollection<A>>() else {
+        return;
+    };
+    match &lazy_collection.state {
+        LazyState::Unloaded => {
+            if !lazy_collection.requested.load(Ordering::Relaxed) {
+                return;
+            }
+            debug!(collection = type_name::<A>(), "Loading lazy collection");
+            let handles = A::load(world);
+            world.resource_mut::<LazyCollection<A>>().state = LazyState::Loading {
+                handles,
+                deferred_issued: false,
+            };
+        }
+        LazyState::Loading {
+            handles,
+            deferred_issued,
+        } => {
+            let asset_server = world.resource::<AssetServer>();
+            let recoverable = world
+                .get_resource::<RecoverableHandles<A>>()
+                .map(RecoverableHandles::handle_ids)
+                .unwrap_or_default();
+            let load_states: Vec<_> = handles
+                .iter()
+                .map(|handle| match asset_server.get_load_state(handle.id()) {
+                    // Fields with a fallback are replaced on creation
+                    LoadState::Failed if recoverable.contains(&handle.id()) => LoadState::Loaded,
+                    load_state => load_state,
+                })
+                .collect();
+            if load_states.contains(&LoadState::Failed) {
+                for (handle, load_state) in handles.iter().zip(load_states) {
+                    if load_state == LoadState::Failed {
+                        warn!(
+                            collection = type_name::<A>(),
+                            path = %describe_handle(asset_server, handle.id()),
+                            "Failed to load asset"
+                        );
+                    }
+                }
+                world.resource_mut::<LazyCollection<A>>().unload();
+            } else if load_states
+                .iter()
+                .all(|load_state| *load_state == LoadState::Loaded)
+            {
+                let mut handles = handles.clone();
+                if !*deferred_issued {
+                    // Deferred assets only start loading once all other assets are loaded
+                    let deferred = A::load_deferred(world);
+                    if !deferred.is_empty() {
+                        handles.extend(deferred);
+                        world.resource_mut::<LazyCollection<A>>().state = LazyState::Loading {
+                            handles,
+                            deferred_issued: true,
+                        };
+                        return;
+                    }
+                }
+                if !A::ready_to_create(world) {
+                    world.resource_mut::<LazyCollection<A>>().state = LazyState::Loading {
+                        handles,
+                        deferred_issued: true,
+                    };
+                    return;
+                }
+                let collection = A::create(world);
+                world.resource_mut::<LazyCollection<A>>().state = LazyState::Loaded(collection);
+                pin_handles::<A>(world, &handles);
+                debug!(collection = type_name::<A>(), "Loaded lazy collection");
+            }
+        }
+        LazyState::Loaded(_) => {}
+    }
+}
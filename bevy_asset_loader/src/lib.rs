@@ -65,34 +65,140 @@
 #![warn(unused_imports, missing_docs)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+/// Assets loaded from absolute paths or paths relative to the executable
+pub mod absolute_path;
+/// Maps of the named animations in glTF files
+#[cfg_attr(docsrs, doc(cfg(feature = "animation")))]
+#[cfg(feature = "animation")]
+pub mod animation;
 /// Trait definition for types that represent a collection of assets
 ///
 /// And extension traits to insert said collections into your Bevy app or world
 pub mod asset_collection;
+/// Manifest of the asset paths referenced by all asset collections of the binary
+#[cfg_attr(docsrs, doc(cfg(feature = "asset_manifest")))]
+#[cfg(feature = "asset_manifest")]
+pub mod asset_manifest;
+/// Assets that are addressed by UUID instead of a path
+pub mod asset_uuid;
+/// Single audio files split into named clips
+#[cfg_attr(docsrs, doc(cfg(feature = "audio_bank")))]
+#[cfg(feature = "audio_bank")]
+pub mod audio_bank;
+/// Durations of loaded audio files
+#[cfg_attr(docsrs, doc(cfg(feature = "audio_duration")))]
+#[cfg(feature = "audio_duration")]
+pub mod audio_duration;
+/// Hashes of the files of loaded assets
+#[cfg_attr(docsrs, doc(cfg(feature = "content_hash")))]
+#[cfg(feature = "content_hash")]
+pub mod content_hash;
 /// Types and infrastructure to load and use dynamic assets
 pub mod dynamic_asset;
+/// A built-in error screen for assets that failed to load
+#[cfg_attr(docsrs, doc(cfg(feature = "failure_screen")))]
+#[cfg(feature = "failure_screen")]
+pub mod failure_screen;
+/// Materials of glTF files
+#[cfg_attr(docsrs, doc(cfg(feature = "gltf_materials")))]
+#[cfg(feature = "gltf_materials")]
+pub mod gltf_materials;
+/// Copy regions of images into their own images
+#[cfg_attr(docsrs, doc(cfg(any(feature = "2d", feature = "3d"))))]
+#[cfg(any(feature = "2d", feature = "3d"))]
+pub mod image_region;
+/// Load assets from bytes that are only known at run time
+pub mod in_memory_asset;
+/// Asset collections that load the first time they are accessed
+pub mod lazy_collection;
+/// Record and replay the order in which assets finish loading
+#[cfg_attr(docsrs, doc(cfg(feature = "load_replay")))]
+#[cfg(feature = "load_replay")]
+pub mod load_replay;
+/// Snapshot the handles of active loading states for debugging
+pub mod loading_dump;
+/// Fields of collections that can be used while their assets are still loading
+pub mod loading_field;
+/// Await asset collections in async code
+pub mod loading_future;
 /// A game state responsible for loading assets
 pub mod loading_state;
+/// Generate mipmaps for images of asset collections
+#[cfg_attr(docsrs, doc(cfg(any(feature = "2d", feature = "3d"))))]
+#[cfg(any(feature = "2d", feature = "3d"))]
+pub mod mipmaps;
+/// Asset paths listed in text files
+pub mod path_list;
+/// Reload asset collections without a loading state
+pub mod reload_collection;
+/// Upload data of asset collections in the render world
+#[cfg_attr(docsrs, doc(cfg(any(feature = "2d", feature = "3d"))))]
+#[cfg(any(feature = "2d", feature = "3d"))]
+pub mod render_upload;
+/// Wait for the imports of shaders in asset collections
+#[cfg_attr(docsrs, doc(cfg(any(feature = "2d", feature = "3d"))))]
+#[cfg(any(feature = "2d", feature = "3d"))]
+pub mod shader;
 /// Dynamic assets for common Bevy asset types
 #[cfg_attr(docsrs, doc(cfg(feature = "standard_dynamic_assets")))]
 #[cfg(feature = "standard_dynamic_assets")]
 pub mod standard_dynamic_asset;
+/// Helpers for testing loading states
+#[cfg_attr(docsrs, doc(cfg(feature = "test-utils")))]
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
 
 /// Most commonly used types
 pub mod prelude {
+    #[doc(hidden)]
+    #[cfg(feature = "asset_manifest")]
+    pub use crate::asset_manifest::{collect_asset_manifest, AssetRef};
+    #[doc(hidden)]
+    #[cfg(feature = "audio_bank")]
+    pub use crate::audio_bank::{AudioBank, AudioBankManifest, AudioBankPlugin};
+    #[doc(hidden)]
+    #[cfg(feature = "content_hash")]
+    pub use crate::content_hash::HashedHandle;
+    #[doc(hidden)]
+    #[cfg(feature = "failure_screen")]
+    pub use crate::failure_screen::AssetFailureScreenPlugin;
+    #[doc(hidden)]
+    #[cfg(feature = "load_replay")]
+    pub use crate::load_replay::{LoadRecorder, LoadReplayer, RecordedLoad};
+    #[doc(hidden)]
+    #[cfg(any(feature = "2d", feature = "3d"))]
+    pub use crate::render_upload::RenderUploads;
     #[doc(hidden)]
     #[cfg(feature = "standard_dynamic_assets")]
     pub use crate::standard_dynamic_asset::{
-        RegisterStandardDynamicAsset, StandardDynamicAsset, StandardDynamicAssetCollection,
+        CollectionManifest, DynamicAssetsSchema, RegisterStandardDynamicAsset, SchemaDiscrepancies,
+        SchemaDiscrepancy, StandardDynamicAsset, StandardDynamicAssetCollection,
+        StandardDynamicAssetKind,
     };
     #[doc(hidden)]
     pub use crate::{
-        asset_collection::{AssetCollection, AssetCollectionApp, AssetCollectionWorld},
+        asset_collection::{
+            AssetCollection, AssetCollectionApp, AssetCollectionWorld, AssetPathTransform,
+            AsyncAssetCollection, CollectionErrors, MergeableCollection, PinnedHandles,
+        },
         dynamic_asset::{
-            DynamicAsset, DynamicAssetCollection, DynamicAssetCollections, DynamicAssetType,
-            DynamicAssets,
+            AssetKey, DynamicAsset, DynamicAssetCollection, DynamicAssetCollections,
+            DynamicAssetField, DynamicAssetType, DynamicAssets, FileAsset,
+        },
+        in_memory_asset::InMemoryAssetsPlugin,
+        lazy_collection::LazyCollection,
+        loading_dump::{LoadingDump, LoadingDumpWorld},
+        loading_field::LoadingField,
+        loading_future::LoadingStateFuture,
+        loading_state::{
+            all_loaded, collection_loading, loading_state, AssetLoadedWithin, CollectionTimings,
+            FailedAssets, IssuedLoadsSet, LoadingCancelled, LoadingComplete, LoadingProgress,
+            LoadingProgressChanged, LoadingScreenAssets, LoadingState, LoadingStateAppExt,
+            LoadingStateSet, LoadingStateTime, LoadingSystemSet, OnCancelLoadingStateSet,
+            OnEnterLoadingStateSet, OnExitLoadingStateSet, SequenceProgress,
         },
-        loading_state::{LoadingState, LoadingStateAppExt},
+        path_list::{PathList, PathListPlugin},
+        reload_collection::ReloadCollection,
     };
 }
 
@@ -0,0 +1,126 @@
+use crate::loading_state::describe_handle;
+use bevy::asset::{AssetServer, HandleId, LoadState};
+use bevy::ecs::system::Resource;
+use bevy::utils::{Duration, HashMap};
+
+/// An asset that finished loading in a loading state
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedLoad {
+    /// The [`FrameCount`](bevy::core::FrameCount) of the frame the loading state saw the asset loaded in
+    pub frame: u32,
+    /// Time since the collection started loading
+    pub elapsed: Duration,
+    /// Type name of the asset collection the asset belongs to
+    pub collection: &'static str,
+    /// Path of the loaded asset
+    pub path: String,
+}
+
+/// Resource recording the order and frame in which the assets of loading states finish loading
+///
+/// While the resource exists, loading states add an entry for every asset of a collection in the
+/// first frame they see it loaded. Frames are taken from the [`FrameCount`](bevy::core::FrameCount) resource of the
+/// `FrameCountPlugin`, which is part of the `MinimalPlugins` and the `DefaultPlugins`.
+/// A recording can be replayed with [`LoadRecorder::replayer`].
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// fn print_recording(recorder: Res<LoadRecorder>) {
+///     for load in recorder.loads() {
+///         info!("{} loaded in frame {}", load.path, load.frame);
+///     }
+/// }
+/// ```
+#[derive(Resource, Debug, Default, Clone)]
+pub struct LoadRecorder {
+    loads: Vec<RecordedLoad>,
+}
+
+impl LoadRecorder {
+    /// All recorded loads in the order they finished
+    pub fn loads(&self) -> &[RecordedLoad] {
+        &self.loads
+    }
+
+    /// Create a replayer finishing every recorded asset in the frame it was recorded in
+    pub fn replayer(&self) -> LoadReplayer {
+        LoadReplayer::new(
+            self.loads
+                .iter()
+                .map(|load| (load.path.clone(), load.frame)),
+        )
+    }
+
+    pub(crate) fn record(&mut self, load: RecordedLoad) {
+        self.loads.push(load);
+    }
+}
+
+/// Resource deciding when the assets of loading states count as loaded
+///
+/// The script maps asset paths to the [`FrameCount`](bevy::core::FrameCount) from which on the asset is loaded.
+/// Loading states ignore the actual load state of these assets, so a loading screen goes through
+/// the same frames on every run, no matter how fast the files are read. Assets that are not
+/// part of the script are checked as usual.
+///
+/// The loading state only looks at the load state of the handles. Collections that need the data
+/// of their assets during creation, like texture atlases, still need the real assets.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy::asset::AssetPlugin;
+/// # fn main() {
+///     App::new()
+/// #       .add_state::<GameState>()
+/// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+///         .insert_resource(LoadReplayer::new([
+///             ("images/player.png", 10),
+///             ("images/tree.png", 25),
+///         ]))
+///         .add_loading_state(
+///           LoadingState::new(GameState::Loading)
+///             .continue_to_state(GameState::Menu)
+///         )
+/// #       .set_runner(|mut app| app.update())
+/// #       .run();
+/// # }
+/// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+/// # enum GameState {
+/// #     #[default]
+/// #     Loading,
+/// #     Menu
+/// # }
+/// ```
+#[derive(Resource, Debug, Default, Clone)]
+pub struct LoadReplayer {
+    script: HashMap<String, u32>,
+}
+
+impl LoadReplayer {
+    /// Create a replayer from pairs of asset paths and the frames they finish loading in
+    pub fn new<P: Into<String>>(script: impl IntoIterator<Item = (P, u32)>) -> Self {
+        LoadReplayer {
+            script: script
+                .into_iter()
+                .map(|(path, frame)| (path.into(), frame))
+                .collect(),
+        }
+    }
+
+    /// The scripted load state of the given handle in the given frame
+    ///
+    /// Returns `None` for assets that are not part of the script.
+    pub fn load_state(
+        &self,
+        asset_server: &AssetServer,
+        handle_id: HandleId,
+        frame: u32,
+    ) -> Option<LoadState> {
+        let loaded_in = self.script.get(&describe_handle(asset_server, handle_id))?;
+        Some(if frame >= *loaded_in {
+            LoadState::Loaded
+        } else {
+            LoadState::NotLoaded
+        })
+    }
+}
@@ -0,0 +1,138 @@
+use bevy::asset::LoadState;
+use bevy::ecs::system::Resource;
+use bevy::ecs::world::World;
+use std::any::TypeId;
+use std::fmt;
+
+/// A handle tracked by a loading state
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DumpedHandle {
+    /// Asset path of the handle, or its id if it has no path
+    pub path: String,
+    /// Load state of the handle at the time of the dump
+    pub load_state: LoadState,
+}
+
+/// The tracked handles of a single collection
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CollectionDump {
+    /// Type name of the asset collection, or of the dynamic asset collection for dynamic asset files
+    pub collection: &'static str,
+    /// Handles that were issued for the collection
+    pub handles: Vec<DumpedHandle>,
+    /// Number of load requests that were not issued yet
+    ///
+    /// See [`LoadingState::batch_size`](crate::loading_state::LoadingState::batch_size) and
+    /// [`LoadingState::max_concurrent_loads_for`](crate::loading_state::LoadingState::max_concurrent_loads_for)
+    pub queued: usize,
+}
+
+/// Snapshot of all handles that loading states are currently waiting for
+///
+/// Created with [`LoadingDumpWorld::debug_dump_loading`].
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// fn watchdog(world: &World) {
+///     let dump = world.debug_dump_loading();
+///     for handle in dump.not_loaded() {
+///         warn!("Still waiting for {} ({:?})", handle.path, handle.load_state);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LoadingDump {
+    /// Tracked handles grouped by their collection
+    pub collections: Vec<CollectionDump>,
+}
+
+impl LoadingDump {
+    /// All tracked handles, independent of their collection
+    pub fn handles(&self) -> impl Iterator<Item = &DumpedHandle> {
+        self.collections
+            .iter()
+            .flat_map(|collection| collection.handles.iter())
+    }
+
+    /// Tracked handles that did not finish loading yet
+    pub fn not_loaded(&self) -> impl Iterator<Item = &DumpedHandle> {
+        self.handles()
+            .filter(|handle| handle.load_state != LoadState::Loaded)
+    }
+
+    /// Whether no loading state is tracking any handles
+    pub fn is_empty(&self) -> bool {
+        self.collections.is_empty()
+    }
+}
+
+impl fmt::Display for LoadingDump {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.collections.is_empty() {
+            return write!(f, "No collections are loading");
+        }
+        for (index, collection) in self.collections.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", collection.collection)?;
+            if collection.queued > 0 {
+                write!(f, " ({} queued)", collection.queued)?;
+            }
+            for handle in &collection.handles {
+                write!(f, "\n  {} {:?}", handle.path, handle.load_state)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Extension trait for [`World`] to snapshot the handles of all active loading states
+pub trait LoadingDumpWorld {
+    /// Collect every handle that loading states are currently tracking with its path and [`LoadState`]
+    ///
+    /// Covers the collections and dynamic asset files of all loading states that are active,
+    /// as well as collections that [finish in the background](crate::loading_state::LoadingState::finish_in_background).
+    /// The dump can be printed with its [`Display`](fmt::Display) implementation.
+    fn debug_dump_loading(&self) -> LoadingDump;
+}
+
+impl LoadingDumpWorld for World {
+    fn debug_dump_loading(&self) -> LoadingDump {
+        let Some(registry) = self.get_resource::<LoadingHandleRegistry>() else {
+            return LoadingDump::default();
+        };
+        LoadingDump {
+            collections: registry
+                .dumps
+                .iter()
+                .filter_map(|(_, dump)| dump(self))
+                .collect(),
+        }
+    }
+}
+
+/// Function dumping the tracked handles of one collection if it is currently loading
+pub(crate) type DumpHandles = fn(&World) -> Option<CollectionDump>;
+
+/// All collection types whose loading handles can be dumped
+#[derive(Resource, Default)]
+pub(crate) struct LoadingHandleRegistry {
+    dumps: Vec<(TypeId, DumpHandles)>,
+}
+
+impl LoadingHandleRegistry {
+    /// Register the dump of a type, returns whether the type was not registered before
+    pub(crate) fn register<T: 'static>(&mut self, dump: DumpHandles) -> bool {
+        let type_id = TypeId::of::<T>();
+        if self
+            .dumps
+            .iter()
+            .any(|(registered, _)| *registered == type_id)
+        {
+            return false;
+        }
+        self.dumps.push((type_id, dump));
+        true
+    }
+}
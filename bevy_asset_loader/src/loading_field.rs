@@ -0,0 +1,123 @@
+use bevy::asset::{Asset, AssetServer, Handle, LoadState};
+use bevy::ecs::world::World;
+use std::fmt;
+
+/// Field of an asset collection that can be used before its asset finished loading
+///
+/// Loading states insert collections with fields of this type as soon as they start loading
+/// them. Every field starts out [`Pending`](LoadingField::Pending) and turns
+/// [`Ready`](LoadingField::Ready) once its asset is loaded. When the whole collection finished
+/// loading, it is inserted again with all fields ready. This allows using parts of a large
+/// collection early, for example to play music while the rest is still loading.
+///
+/// All other fields of such a collection need to be loading fields as well, or use one of the
+/// attributes `skip`, `from_resource` or `uuid`. Loading fields support a single `path` or `key`.
+///
+/// If the loading state is cancelled, the collection stays inserted with the fields it got so far.
+/// With a [`target_world`](crate::loading_state::LoadingState::target_world), the collection is
+/// inserted into and updated in that world.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(AssetCollection, Resource)]
+/// struct LevelAssets {
+///     #[asset(path = "audio/music.ogg")]
+///     music: LoadingField<AudioSource>,
+///     #[asset(path = "audio/ambience.ogg")]
+///     ambience: LoadingField<AudioSource>,
+/// }
+///
+/// fn play_music(
+///     mut commands: Commands,
+///     assets: Option<Res<LevelAssets>>,
+///     mut playing: Local<bool>,
+/// ) {
+///     if *playing {
+///         return;
+///     }
+///     if let Some(music) = assets.and_then(|assets| assets.music.get().cloned()) {
+///         commands.spawn(AudioBundle {
+///             source: music,
+///             ..default()
+///         });
+///         *playing = true;
+///     }
+/// }
+/// ```
+pub enum LoadingField<T: Asset> {
+    /// The asset is still loading
+    Pending(Handle<T>),
+    /// The asset finished loading
+    Ready(Handle<T>),
+}
+
+impl<T: Asset> LoadingField<T> {
+    /// Whether the asset finished loading
+    pub fn is_ready(&self) -> bool {
+        matches!(self, LoadingField::Ready(_))
+    }
+
+    /// The handle of the asset if it finished loading
+    pub fn get(&self) -> Option<&Handle<T>> {
+        match self {
+            LoadingField::Pending(_) => None,
+            LoadingField::Ready(handle) => Some(handle),
+        }
+    }
+
+    /// The handle of the asset, independent of whether it finished loading
+    pub fn handle(&self) -> &Handle<T> {
+        match self {
+            LoadingField::Pending(handle) | LoadingField::Ready(handle) => handle,
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn from_handle(handle: Handle<T>, world: &World) -> Self {
+        let asset_server = world
+            .get_resource::<AssetServer>()
+            .expect("Cannot get AssetServer");
+        if asset_server.get_load_state(&handle) == LoadState::Loaded {
+            LoadingField::Ready(handle)
+        } else {
+            LoadingField::Pending(handle)
+        }
+    }
+
+    /// Turn the field ready if its asset finished loading. Returns true if the field changed.
+    #[doc(hidden)]
+    pub fn update(&mut self, asset_server: &AssetServer) -> bool {
+        let LoadingField::Pending(handle) = self else {
+            return false;
+        };
+        if asset_server.get_load_state(&*handle) != LoadState::Loaded {
+            return false;
+        }
+        *self = LoadingField::Ready(handle.clone());
+        true
+    }
+}
+
+impl<T: Asset> Default for LoadingField<T> {
+    fn default() -> Self {
+        LoadingField::Pending(Handle::default())
+    }
+}
+
+impl<T: Asset> Clone for LoadingField<T> {
+    fn clone(&self) -> Self {
+        match self {
+            LoadingField::Pending(handle) => LoadingField::Pending(handle.clone()),
+            LoadingField::Ready(handle) => LoadingField::Ready(handle.clone()),
+        }
+    }
+}
+
+impl<T: Asset> fmt::Debug for LoadingField<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadingField::Pending(handle) => f.debug_tuple("Pending").field(handle).finish(),
+            LoadingField::Ready(handle) => f.debug_tuple("Ready").field(handle).finish(),
+        }
+    }
+}
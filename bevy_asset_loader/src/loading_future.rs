@@ -0,0 +1,103 @@
+use crate::asset_collection::AssetCollection;
+use bevy::app::App;
+use bevy::ecs::world::World;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Future resolving once the given asset collections are inserted into the app
+///
+/// The ECS does not make progress on its own, so every poll updates the app once and then checks
+/// if all collections are available as resources. The collections are loaded the usual way, for
+/// example in a [`LoadingState`](crate::loading_state::LoadingState) or with
+/// [`AssetCollectionApp::init_collection`](crate::asset_collection::AssetCollectionApp::init_collection).
+///
+/// While the collections are missing, the future wakes itself up again, so an executor polls it
+/// in a loop. Code that is not async can call [`LoadingStateFuture::poll_loaded`] instead.
+/// If a collection fails to load, the future never resolves.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy::asset::AssetPlugin;
+/// # use futures_lite::future::block_on;
+/// fn main() {
+///     let mut app = App::new();
+///     app
+/// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+///         .add_state::<GameState>()
+///         .add_loading_state(
+///             LoadingState::new(GameState::Loading).continue_to_state(GameState::Menu),
+///         )
+///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Loading);
+///
+///     block_on(async {
+/// #       return;
+///         LoadingStateFuture::new(&mut app)
+///             .with_collection::<MyAssets>()
+///             .await;
+///         // all collections are loaded
+///     });
+/// }
+///
+/// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+/// # enum GameState {
+/// #     #[default]
+/// #     Loading,
+/// #     Menu
+/// # }
+/// #[derive(AssetCollection, Resource)]
+/// struct MyAssets {
+///     #[asset(path = "images/player.png")]
+///     player: Handle<Image>,
+/// }
+/// ```
+pub struct LoadingStateFuture<'a> {
+    app: &'a mut App,
+    collections: Vec<fn(&World) -> bool>,
+}
+
+impl<'a> LoadingStateFuture<'a> {
+    /// Create a future updating the given app until all added collections are loaded
+    pub fn new(app: &'a mut App) -> Self {
+        LoadingStateFuture {
+            app,
+            collections: vec![],
+        }
+    }
+
+    /// Wait for the collection `A` to be inserted as a resource
+    #[must_use = "The future does nothing unless it is awaited or polled"]
+    pub fn with_collection<A: AssetCollection>(mut self) -> Self {
+        self.collections
+            .push(|world| world.contains_resource::<A>());
+        self
+    }
+
+    /// Update the app once and check if all collections are loaded
+    ///
+    /// Returns [`Poll::Ready`] as soon as all collections are resources of the main world.
+    pub fn poll_loaded(&mut self) -> Poll<()> {
+        self.app.update();
+        if self
+            .collections
+            .iter()
+            .all(|is_loaded| is_loaded(&self.app.world))
+        {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl Future for LoadingStateFuture<'_> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let poll = self.get_mut().poll_loaded();
+        if poll.is_pending() {
+            cx.waker().wake_by_ref();
+        }
+        poll
+    }
+}
@@ -2,43 +2,79 @@ mod dynamic_asset_systems;
 mod systems;
 
 use bevy::app::{App, Plugin};
-use bevy::asset::{Asset, HandleUntyped};
+use bevy::asset::{Asset, AssetServer, Handle, HandleId, HandleUntyped, LoadState};
 use bevy::ecs::{
+    event::Event,
     schedule::{
-        common_conditions::in_state, BoxedScheduleLabel, IntoSystemConfigs, IntoSystemSetConfig,
-        NextState, OnEnter, ScheduleLabel, State, States, SystemSet,
+        common_conditions::{in_state, resource_exists},
+        BoxedScheduleLabel, Condition, IntoSystemConfigs, IntoSystemSetConfig, NextState, OnEnter,
+        OnExit, ScheduleLabel, State, States, SystemConfigs, SystemSet,
     },
-    system::Resource,
-    world::FromWorld,
+    system::{IntoSystem, Res, Resource},
+    world::{FromWorld, World},
 };
-use bevy::prelude::{StateTransition, Update};
-use bevy::utils::{default, HashMap, HashSet};
-use std::any::TypeId;
+#[cfg(not(feature = "progress_tracking"))]
+use bevy::log::warn;
+use bevy::prelude::{First, Last, PostUpdate, StateTransition, Update};
+use bevy::utils::{default, HashMap, HashSet, Instant};
+use std::any::{type_name, TypeId};
 use std::array::IntoIter;
+use std::collections::VecDeque;
 use std::marker::PhantomData;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
-use crate::asset_collection::AssetCollection;
+pub use bevy_asset_loader_derive::loading_state;
+
+use crate::asset_collection::{
+    register_collection_types, AssetCollection, AssetLoadRequest, AsyncAssetCollection,
+    CollectionErrors,
+};
 use crate::dynamic_asset::{DynamicAssetCollection, DynamicAssetCollections};
+use crate::loading_dump::{CollectionDump, DumpedHandle, LoadingHandleRegistry};
 
 use systems::{
-    check_loading_collection, finish_loading_state, init_resource, initialize_loading_state,
-    reset_loading_state, resume_to_finalize, start_loading_collection,
+    cancel_loading_collection, check_async_collections, check_loading_collection,
+    check_tracked_handles, count_pending_collection, exit_loading_state,
+    finish_collection_in_background, finish_loading_state, init_resource, initialize_loading_state,
+    move_collection_to_background, preload_collection, preload_collection_now,
+    rebuild_modified_collection, recreate_reloaded_collection, reset_loading_state,
+    resume_to_finalize, send_progress_changed, spawn_create_task, start_loading_collection,
+    start_tracking_handles, update_loading_fields, update_sequence_progress, wait_condition_met,
+    watch_modified_handles,
 };
 
+pub(crate) use systems::describe_handle;
+
 use dynamic_asset_systems::{
-    check_dynamic_asset_collections, load_dynamic_asset_collections,
-    resume_to_loading_asset_collections,
+    cancel_loading_dynamic_asset_collections, check_dynamic_asset_collections, check_manifests,
+    load_dynamic_asset_collections, resume_to_loading_asset_collections,
+    CheckDynamicAssetCollectionsState,
 };
 
 #[cfg(feature = "standard_dynamic_assets")]
 use bevy_common_assets::ron::RonAssetPlugin;
 
 #[cfg(feature = "standard_dynamic_assets")]
-use crate::standard_dynamic_asset::{StandardDynamicAsset, StandardDynamicAssetCollection};
+use crate::standard_dynamic_asset::{
+    load_schemas, validate_dynamic_assets, DynamicAssetsSchema, StandardDynamicAsset,
+    StandardDynamicAssetCollection,
+};
 
 #[cfg(feature = "progress_tracking")]
 use iyes_progress::TrackedProgressSet;
 
+#[cfg(feature = "content_hash")]
+use crate::content_hash::FileHashSlot;
+#[cfg(any(feature = "2d", feature = "3d"))]
+use crate::render_upload::{upload_pending, RenderUploads};
+#[cfg(any(feature = "2d", feature = "3d"))]
+use bevy::render::{ExtractSchedule, RenderApp};
+#[cfg(feature = "content_hash")]
+use systems::{file_hash_completed, request_condition_file_hash};
+#[cfg(any(feature = "2d", feature = "3d"))]
+use systems::{render_uploads_completed, request_render_uploads};
+
 use crate::dynamic_asset::{DynamicAsset, DynamicAssets};
 use crate::loading_state::systems::{apply_internal_state_transition, run_loading_state};
 
@@ -95,11 +131,47 @@ use crate::loading_state::systems::{apply_internal_state_transition, run_loading
 pub struct LoadingState<State> {
     next_state: Option<State>,
     failure_state: Option<State>,
+    #[cfg(feature = "content_hash")]
+    hash_condition: Option<HashCondition<State>>,
+    stay_in_state: bool,
+    finish_in_background: bool,
+    no_auto_transition: bool,
     loading_state: State,
     dynamic_assets: HashMap<String, Box<dyn DynamicAsset>>,
+    verbose_logging: Option<bool>,
+    batch_size: Option<usize>,
+    max_concurrent_loads: HashMap<&'static str, usize>,
+    non_blocking_types: HashSet<&'static str>,
+    settle_frames: Option<usize>,
+    delay_start: Option<Duration>,
+    dynamic_assets_file_weight: Option<f32>,
+    progress_event_threshold: Option<f32>,
+    wait_condition: Option<WaitCondition>,
+    handle_observers: Vec<HandleObserver>,
+    completion_checks: HashMap<TypeId, CompletionCheck>,
+    expect_hot_reload: bool,
+    collect_errors: bool,
+    tracked_handles: Vec<HandleUntyped>,
+    preload_states: Vec<State>,
+    preload_now: Vec<fn(&mut World)>,
+    target_world: Option<TargetWorld>,
+    async_collections: HashMap<TypeId, SpawnCreateTask>,
+    rebuild_on_modify: HashMap<TypeId, fn(&mut App)>,
+    on_enter_systems: Vec<SystemConfigs>,
+    loads_issued_systems: Vec<SystemConfigs>,
+    loading_systems: Vec<SystemConfigs>,
+    on_cancel_systems: Vec<SystemConfigs>,
+    loading_screen_assets: Vec<(String, String)>,
+    cli_overrides: Vec<(String, String)>,
 
+    #[cfg(any(feature = "2d", feature = "3d"))]
+    render_uploads: Vec<(TypeId, SystemConfigs)>,
     #[cfg(feature = "standard_dynamic_assets")]
     standard_dynamic_asset_collection_file_endings: Vec<&'static str>,
+    #[cfg(feature = "standard_dynamic_assets")]
+    dynamic_assets_files: Vec<String>,
+    #[cfg(feature = "standard_dynamic_assets")]
+    dynamic_assets_schemas: Vec<String>,
 }
 
 impl<S> LoadingState<S>
@@ -152,10 +224,46 @@ where
         Self {
             next_state: None,
             failure_state: None,
+            #[cfg(feature = "content_hash")]
+            hash_condition: None,
+            stay_in_state: false,
+            finish_in_background: false,
+            no_auto_transition: false,
             loading_state: load,
             dynamic_assets: HashMap::default(),
+            verbose_logging: None,
+            batch_size: None,
+            max_concurrent_loads: default(),
+            non_blocking_types: default(),
+            settle_frames: None,
+            delay_start: None,
+            dynamic_assets_file_weight: None,
+            progress_event_threshold: None,
+            wait_condition: None,
+            handle_observers: vec![],
+            completion_checks: default(),
+            expect_hot_reload: false,
+            collect_errors: false,
+            tracked_handles: vec![],
+            preload_states: vec![],
+            preload_now: vec![],
+            target_world: None,
+            async_collections: default(),
+            rebuild_on_modify: default(),
+            on_enter_systems: vec![],
+            loads_issued_systems: vec![],
+            loading_systems: vec![],
+            on_cancel_systems: vec![],
+            loading_screen_assets: vec![],
+            cli_overrides: vec![],
+            #[cfg(any(feature = "2d", feature = "3d"))]
+            render_uploads: vec![],
             #[cfg(feature = "standard_dynamic_assets")]
             standard_dynamic_asset_collection_file_endings: vec!["assets.ron"],
+            #[cfg(feature = "standard_dynamic_assets")]
+            dynamic_assets_files: vec![],
+            #[cfg(feature = "standard_dynamic_assets")]
+            dynamic_assets_schemas: vec![],
         }
     }
 
@@ -244,41 +352,119 @@ where
         self
     }
 
-    /// Insert a map of asset keys with corresponding standard dynamic assets
+    /// Only continue to the [next state](LoadingState::continue_to_state) if the file at the given
+    /// asset path has the expected content hash
+    ///
+    /// When all collections are loaded, the file is read on the
+    /// [`IoTaskPool`](bevy::tasks::IoTaskPool) and hashed with
+    /// [`hash_bytes`](crate::content_hash::hash_bytes). The loading state waits for the hash.
+    /// If it differs from `expected`, or the file cannot be read, the loading state continues
+    /// to `else_state` instead. The file is usually a manifest that is part of one of the
+    /// collections, so it is loaded by then. This way, a self-updating client can verify its
+    /// assets before entering gameplay.
+    ///
+    /// Requires the `content_hash` feature.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Game)
+    ///             .continue_if_hash_matches("manifest.ron", MANIFEST_HASH, GameState::Update)
+    ///         )
+    ///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # const MANIFEST_HASH: [u8; 32] = [0; 32];
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Update,
+    /// #     Game
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct MyAssets {
+    /// #     #[asset(path = "audio/background.ogg")]
+    /// #     pub background: Handle<AudioSource>,
+    /// # }
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "content_hash")))]
+    #[cfg(feature = "content_hash")]
     #[must_use]
-    #[cfg(feature = "standard_dynamic_assets")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "standard_dynamic_assets")))]
-    pub fn add_standard_dynamic_assets(
+    pub fn continue_if_hash_matches(
         mut self,
-        mut dynamic_assets: HashMap<String, StandardDynamicAsset>,
+        path: impl Into<String>,
+        expected: [u8; 32],
+        else_state: S,
     ) -> Self {
-        dynamic_assets.drain().for_each(|(key, value)| {
-            self.dynamic_assets.insert(key, Box::new(value));
+        self.hash_condition = Some(HashCondition {
+            path: path.into(),
+            expected,
+            else_state,
         });
 
         self
     }
 
-    /// Set all file endings that should be loaded as [`StandardDynamicAssetCollection`].
+    /// Stay in the loading state after all asset collections are loaded
     ///
-    /// The default file ending is `.assets`
+    /// Without a [next state](LoadingState::continue_to_state) or a
+    /// [failure state](LoadingState::on_failure_continue_to_state), nothing happens once loading
+    /// finished. Since this is usually a configuration mistake, entering such a loading state for
+    /// the first time logs a warning. Use this method if your own systems change the state after loading, to silence
+    /// the warning.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .stay_in_state()
+    ///         )
+    ///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct MyAssets {
+    /// #     #[asset(path = "audio/background.ogg")]
+    /// #     pub background: Handle<AudioSource>,
+    /// # }
+    /// ```
     #[must_use]
-    #[cfg_attr(docsrs, doc(cfg(feature = "standard_dynamic_assets")))]
-    #[cfg(feature = "standard_dynamic_assets")]
-    pub fn set_standard_dynamic_asset_collection_file_endings(
-        mut self,
-        endings: Vec<&'static str>,
-    ) -> Self {
-        self.standard_dynamic_asset_collection_file_endings = endings;
+    pub fn stay_in_state(mut self) -> Self {
+        self.stay_in_state = true;
 
         self
     }
 
-    /// Finish configuring the [`LoadingState`]
+    /// Do not continue to the next state after all asset collections are loaded
     ///
-    /// Calling this function is required to set up the asset loading.
-    /// Most of the time you do not want to call this method directly though, but complete the setup
-    /// using [`LoadingStateAppExt::add_loading_state`].
+    /// The loading state still creates all collections and sends a [`LoadingComplete`] event
+    /// when it is done, but it does not set the [next state](LoadingState::continue_to_state).
+    /// Changing the state is left to your own systems, for example an orchestrator that waits
+    /// for multiple loading states before advancing. The configured next state is part of the
+    /// event. Continuing to the [failure state](LoadingState::on_failure_continue_to_state) is
+    /// not affected.
     /// ```edition2021
     /// # use bevy_asset_loader::prelude::*;
     /// # use bevy::prelude::*;
@@ -291,12 +477,24 @@ where
     ///         .add_loading_state(
     ///           LoadingState::new(GameState::Loading)
     ///             .continue_to_state(GameState::Menu)
+    ///             .no_auto_transition()
     ///         )
-    ///         .add_collection_to_loading_state::<_, AudioAssets>(GameState::Loading)
-    ///         .add_collection_to_loading_state::<_, ImageAssets>(GameState::Loading)
+    ///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Loading)
+    ///         .add_systems(Update, advance)
     /// #       .set_runner(|mut app| app.update())
     /// #       .run();
     /// # }
+    ///
+    /// fn advance(
+    ///     mut complete: EventReader<LoadingComplete<GameState>>,
+    ///     mut next_state: ResMut<NextState<GameState>>,
+    /// ) {
+    ///     for event in complete.iter() {
+    ///         if let Some(next) = &event.next {
+    ///             next_state.set(next.clone());
+    ///         }
+    ///     }
+    /// }
     /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
     /// # enum GameState {
     /// #     #[default]
@@ -304,89 +502,1892 @@ where
     /// #     Menu
     /// # }
     /// # #[derive(AssetCollection, Resource)]
-    /// # pub struct AudioAssets {
+    /// # pub struct MyAssets {
     /// #     #[asset(path = "audio/background.ogg")]
     /// #     pub background: Handle<AudioSource>,
     /// # }
+    /// ```
+    #[must_use]
+    pub fn no_auto_transition(mut self) -> Self {
+        self.no_auto_transition = true;
+
+        self
+    }
+
+    /// Log every asset of this loading state when it starts and when it finishes loading
+    ///
+    /// The messages are logged on info level and include the time each asset took to load.
+    /// This can help finding the assets that dominate the loading time of a state.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .verbose_logging(true)
+    ///         )
+    ///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
     /// # #[derive(AssetCollection, Resource)]
-    /// # pub struct ImageAssets {
-    /// #     #[asset(path = "images/player.png")]
-    /// #     pub player: Handle<Image>,
-    /// #     #[asset(path = "images/tree.png")]
-    /// #     pub tree: Handle<Image>,
+    /// # pub struct MyAssets {
+    /// #     #[asset(path = "audio/background.ogg")]
+    /// #     pub background: Handle<AudioSource>,
     /// # }
     /// ```
-    #[allow(unused_mut)]
-    pub fn build(mut self, app: &mut App) {
-        app.init_resource::<AssetLoaderConfiguration<S>>();
-        {
-            let mut asset_loader_configuration = app
-                .world
-                .get_resource_mut::<AssetLoaderConfiguration<S>>()
-                .unwrap();
-            let mut loading_config = asset_loader_configuration
-                .state_configurations
-                .remove(&self.loading_state)
-                .unwrap_or_default();
-            if self.next_state.is_some() {
-                loading_config.next = self.next_state;
-            }
-            if self.failure_state.is_some() {
-                loading_config.failure = self.failure_state;
-            }
-            asset_loader_configuration
-                .state_configurations
-                .insert(self.loading_state.clone(), loading_config);
-        }
-        app.init_resource::<State<InternalLoadingState<S>>>();
-        app.init_resource::<NextState<InternalLoadingState<S>>>();
+    #[must_use]
+    pub fn verbose_logging(mut self, verbose: bool) -> Self {
+        self.verbose_logging = Some(verbose);
 
-        app.init_resource::<DynamicAssetCollections<S>>();
-        #[cfg(feature = "standard_dynamic_assets")]
-        if !app.is_plugin_added::<RonAssetPlugin<StandardDynamicAssetCollection>>() {
-            app.add_plugins(RonAssetPlugin::<StandardDynamicAssetCollection>::new(
-                &self.standard_dynamic_asset_collection_file_endings,
-            ));
-        }
+        self
+    }
 
-        if !app.is_plugin_added::<InternalAssetLoaderPlugin<S>>() {
-            app.add_plugins(InternalAssetLoaderPlugin::<S>::new());
-        }
+    /// Start loading at most `batch_size` assets of a collection per frame
+    ///
+    /// By default, all assets of a collection start loading in the same frame. For collections
+    /// with a lot of assets that can cause a noticeable hitch. With a batch size, the remaining
+    /// loads are queued and issued in the following frames. The loading state only finishes
+    /// after all queued loads were issued and finished loading.
+    ///
+    /// The batch size applies per collection. It counts the paths of a collection individually,
+    /// while folders and dynamic assets are counted as one request each.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .batch_size(100)
+    ///         )
+    ///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct MyAssets {
+    /// #     #[asset(paths("images/player.png", "images/tree.png"), collection(typed))]
+    /// #     pub images: Vec<Handle<Image>>,
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `batch_size` is zero.
+    #[must_use]
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        assert!(
+            batch_size > 0,
+            "The batch size of a loading state must not be zero"
+        );
+        self.batch_size = Some(batch_size);
 
-        app.init_resource::<LoadingStateSchedules<S>>();
+        self
+    }
 
-        let loading_state_schedule = LoadingStateSchedule(self.loading_state.clone());
-        let configure_loading_state = app.get_schedule(loading_state_schedule.clone()).is_none();
-        app.init_schedule(loading_state_schedule.clone())
-            .init_schedule(OnEnterInternalLoadingState(
-                self.loading_state.clone(),
-                InternalLoadingState::LoadingAssets,
-            ))
-            .init_schedule(OnEnterInternalLoadingState(
-                self.loading_state.clone(),
-                InternalLoadingState::LoadingDynamicAssetCollections,
-            ))
-            .init_schedule(OnEnterInternalLoadingState(
-                self.loading_state.clone(),
-                InternalLoadingState::Finalize,
-            ));
+    /// Limit the number of assets of type `A` that load at the same time
+    ///
+    /// Decoding a lot of assets of the same type at once can saturate the CPU, e.g. images
+    /// starving audio decoding. With a limit, loads of the asset type are queued and issued
+    /// as soon as earlier loads of the type finished, while other asset types load independently.
+    /// The limit applies to all collections of the loading state together and can be combined
+    /// with a [`batch_size`](Self::batch_size).
+    ///
+    /// Loads are assigned to the asset type of their field. Fields loading images, like
+    /// standard materials or texture atlases, count as images. Folders and untyped fields are
+    /// never throttled. A folder counts as a single load, even though it loads multiple assets.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .max_concurrent_loads_for::<Image>(4)
+    ///         )
+    ///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct MyAssets {
+    /// #     #[asset(paths("images/player.png", "images/tree.png"), collection(typed))]
+    /// #     pub images: Vec<Handle<Image>>,
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `max` is zero.
+    #[must_use]
+    pub fn max_concurrent_loads_for<A: Asset>(mut self, max: usize) -> Self {
+        assert!(
+            max > 0,
+            "The number of concurrent loads of an asset type must not be zero"
+        );
+        self.max_concurrent_loads.insert(type_name::<A>(), max);
 
-        if configure_loading_state {
-            app.add_systems(
-                loading_state_schedule.clone(),
-                (
-                    resume_to_loading_asset_collections::<S>
-                        .in_set(InternalLoadingStateSet::ResumeDynamicAssetCollections),
-                    initialize_loading_state::<S>.in_set(InternalLoadingStateSet::Initialize),
-                    resume_to_finalize::<S>.in_set(InternalLoadingStateSet::CheckAssets),
-                    finish_loading_state::<S>.in_set(InternalLoadingStateSet::Finalize),
-                ),
-            )
+        self
+    }
+
+    /// Do not wait for assets of the given type before leaving the loading state
+    ///
+    /// Handles of the asset type are still loaded and part of the collections, but the loading
+    /// state does not wait for them to finish. Collections are created as soon as their other
+    /// assets are loaded, while e.g. music keeps loading in the background. Non-blocking assets
+    /// that fail to load do not fail the loading state. [`LoadingProgress`] still reports them
+    /// until the collections are created.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .non_blocking_type::<AudioSource>()
+    ///         )
+    ///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct MyAssets {
+    /// #     #[asset(path = "images/player.png")]
+    /// #     pub player: Handle<Image>,
+    /// #     #[asset(path = "audio/background.ogg")]
+    /// #     pub music: Handle<AudioSource>,
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn non_blocking_type<A: Asset>(mut self) -> Self {
+        self.non_blocking_types.insert(type_name::<A>());
+
+        self
+    }
+
+    /// Wait `frames` additional frames after all assets finished loading before leaving the loading state
+    ///
+    /// Some assets, like large textures, are not ready to be rendered in the frame their load
+    /// state changes to loaded, because they still need to be uploaded to the GPU. Waiting a few
+    /// frames gives the render world time to catch up and reduces pop-in after the transition.
+    /// The collections are already inserted as resources while the loading state settles.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .settle_frames(3)
+    ///         )
+    ///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct MyAssets {
+    /// #     #[asset(path = "images/player.png")]
+    /// #     pub player: Handle<Image>,
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn settle_frames(mut self, frames: usize) -> Self {
+        self.settle_frames = Some(frames);
+
+        self
+    }
+
+    /// Set the weight of every dynamic asset collection file in the [`LoadingProgress`]
+    ///
+    /// Dynamic asset collection files, like `.assets.ron` files, load before any collection of
+    /// the loading state starts loading. While they load, they count towards the progress like
+    /// assets with the given weight. Collections that did not start loading yet count as a single
+    /// asset with a weight of 1 each, so the progress does not reach 100% before the collections
+    /// are known. The default weight of a file is 1.
+    ///
+    /// Use a higher weight for large files, which take a noticeable part of the loading time.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .dynamic_assets_file_weight(10.)
+    ///         )
+    ///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct MyAssets {
+    /// #     #[asset(path = "images/player.png")]
+    /// #     pub player: Handle<Image>,
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `weight` is negative or not finite.
+    #[must_use]
+    pub fn dynamic_assets_file_weight(mut self, weight: f32) -> Self {
+        assert!(
+            weight.is_finite() && weight >= 0.,
+            "The weight of dynamic asset collection files must be finite and not negative"
+        );
+        self.dynamic_assets_file_weight = Some(weight);
+
+        self
+    }
+
+    /// Set how much the [`LoadingProgress`] has to change before a [`LoadingProgressChanged`]
+    /// event is sent
+    ///
+    /// An event is sent as soon as the fraction differs from the last sent fraction by more than
+    /// the threshold. Reaching a fraction of 1 always sends an event. The default threshold is
+    /// `0.01`, so at most about one hundred events are sent per run of the loading state.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .progress_event_threshold(0.05)
+    ///         )
+    ///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct MyAssets {
+    /// #     #[asset(path = "images/player.png")]
+    /// #     pub player: Handle<Image>,
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn progress_event_threshold(mut self, threshold: f32) -> Self {
+        self.progress_event_threshold = Some(threshold);
+
+        self
+    }
+
+    /// Wait the given time after entering the loading state before any asset starts loading
+    ///
+    /// This keeps a splash screen free of IO contention for its first moments. In contrast to
+    /// [`settle_frames`](Self::settle_frames), which delays leaving the state after everything
+    /// loaded, this delays the start of loading. Collections that were preloaded with
+    /// [`preload_during`](Self::preload_during) already started loading and are not delayed.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # use std::time::Duration;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Splash)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .delay_start(Duration::from_millis(500))
+    ///         )
+    ///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Splash)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Splash,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct MyAssets {
+    /// #     #[asset(path = "images/player.png")]
+    /// #     pub player: Handle<Image>,
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn delay_start(mut self, delay: Duration) -> Self {
+        self.delay_start = Some(delay);
+
+        self
+    }
+
+    /// Stay in the loading state after all assets finished loading until `condition` returns true
+    ///
+    /// This is meant for screens like "Press Start" that show a prompt once everything is loaded
+    /// and only continue on user input. The condition is checked every frame after all
+    /// collections were inserted as resources and the configured [`settle_frames`](Self::settle_frames)
+    /// passed. Until it returns true, the loading progress reports all assets as loaded, but
+    /// the loading state does not continue to the next state.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    /// #       .init_resource::<Input<KeyCode>>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .wait_for_input_with(|world| {
+    ///                 world.resource::<Input<KeyCode>>().just_pressed(KeyCode::Return)
+    ///             })
+    ///         )
+    ///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct MyAssets {
+    /// #     #[asset(path = "images/player.png")]
+    /// #     pub player: Handle<Image>,
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn wait_for_input_with(
+        mut self,
+        condition: impl Fn(&World) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.wait_condition = Some(Arc::new(condition));
+
+        self
+    }
+
+    /// Observe the load state of every handle of the collections in this loading state
+    ///
+    /// While a collection is loading, the closure is called once per check for each of its issued
+    /// handles with the asset path and the current [`LoadState`]. Handles that already finished
+    /// loading are reported again on every check until the collection is created. Loads that were
+    /// not issued yet (see [`LoadingState::batch_size`]) are not reported.
+    ///
+    /// The check runs every frame, so the closure is called `frames * handles` times for a
+    /// collection. Keep it cheap and hand the data off to your own tracking, for example through
+    /// a channel. Calling this method multiple times registers multiple observers.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::{AssetPlugin, LoadState};
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .on_each_handle(|path, state| {
+    ///                 if state == LoadState::Failed {
+    ///                     eprintln!("telemetry: {path} failed");
+    ///                 }
+    ///             })
+    ///         )
+    ///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct MyAssets {
+    /// #     #[asset(path = "images/player.png")]
+    /// #     pub player: Handle<Image>,
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn on_each_handle(
+        mut self,
+        observer: impl Fn(&str, LoadState) + Send + Sync + 'static,
+    ) -> Self {
+        self.handle_observers.push(Arc::new(observer));
+
+        self
+    }
+
+    /// Decide when a handle of the collection `A` finished loading with a custom predicate
+    ///
+    /// By default, a handle is done once its [`LoadState`] is [`LoadState::Loaded`]. Some assets
+    /// are not usable at that point yet, for example because they are still processed by other
+    /// systems. With a completion check, the handles of `A` count as done only while the
+    /// predicate returns `true` for them. The predicate replaces the default check, so it usually
+    /// checks the load state itself first. It is called once per check for every issued handle
+    /// of the collection. Handles that fail to load still fail the loading state, independent of
+    /// the predicate. Calling this method again for the same collection replaces its check.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::{AssetPlugin, LoadState};
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .with_completion_check::<MyAssets>(|world, handle| {
+    ///                 world.resource::<AssetServer>().get_load_state(handle) == LoadState::Loaded
+    ///                     && world.resource::<ProcessedAssets>().0.contains(&handle.id())
+    ///             })
+    ///         )
+    ///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Loading)
+    /// #       .init_resource::<ProcessedAssets>()
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Resource, Default)]
+    /// # struct ProcessedAssets(Vec<bevy::asset::HandleId>);
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct MyAssets {
+    /// #     #[asset(path = "images/player.png")]
+    /// #     pub player: Handle<Image>,
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_completion_check<A: AssetCollection>(
+        mut self,
+        check: impl Fn(&World, &HandleUntyped) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.completion_checks
+            .insert(TypeId::of::<A>(), Arc::new(check));
+
+        self
+    }
+
+    /// Keep watching the collections of this loading state for hot reloaded assets
+    ///
+    /// By default, the handles of a collection are dropped after it was inserted as a resource.
+    /// With this option, the collections stay tracked after the loading state finished. Whenever
+    /// reloaded assets of a collection finished loading again, the collection is recreated and
+    /// inserted as a resource again. This way, derived assets like texture atlases or materials
+    /// are rebuilt from the reloaded assets.
+    ///
+    /// Bevy only reloads assets if watching for changes is enabled on the `AssetPlugin`.
+    /// Reloads are detected by the handles of a collection leaving the loaded state and finishing
+    /// loading again.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::{AssetPlugin, ChangeWatcher};
+    /// # use std::time::Duration;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    ///         .add_plugins((
+    ///             MinimalPlugins,
+    ///             AssetPlugin {
+    ///                 watch_for_changes: ChangeWatcher::with_delay(Duration::from_millis(200)),
+    ///                 ..default()
+    ///             },
+    ///         ))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Playing)
+    ///             .expect_hot_reload()
+    ///         )
+    ///         .add_collection_to_loading_state::<_, LevelAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Playing
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct LevelAssets {
+    /// #     #[asset(path = "images/player.png")]
+    /// #     pub player: Handle<Image>,
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn expect_hot_reload(mut self) -> Self {
+        self.expect_hot_reload = true;
+
+        self
+    }
+
+    /// Collect errors of dynamic assets instead of panicking while creating collections
+    ///
+    /// By default, creating a collection panics on the first dynamic asset key that is missing or
+    /// cannot be built. With this option, all such errors of the loading state are listed in the
+    /// [`CollectionErrors`] resource and the affected fields get a placeholder value. This is
+    /// useful to show all problems of user provided dynamic asset files at once, e.g. for mods.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .collect_errors()
+    ///         )
+    ///         .add_collection_to_loading_state::<_, ModAssets>(GameState::Loading)
+    ///         .add_systems(OnEnter(GameState::Menu), list_errors)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// fn list_errors(errors: Res<CollectionErrors>) {
+    ///     for error in errors.errors() {
+    ///         println!("{}.{}: {}", error.collection, error.field, error.message);
+    ///     }
+    /// }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct ModAssets {
+    /// #     #[asset(key = "player")]
+    /// #     pub player: Handle<Image>,
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn collect_errors(mut self) -> Self {
+        self.collect_errors = true;
+
+        self
+    }
+
+    /// Register the values of command line flags as dynamic assets for the mapped keys
+    ///
+    /// Every time the loading state is entered, the flags are read from [`std::env::args`]
+    /// with [`DynamicAssets::register_from_args`]. This happens after all dynamic asset
+    /// files of the loading state were registered, so a flag overrides the key from a file.
+    /// Keys of flags that were not passed keep their configured assets.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Editor)
+    ///             // `editor --level levels/forest.png` loads the forest level
+    ///             .with_cli_overrides(&[("--level", "level")])
+    ///         )
+    ///         .add_collection_to_loading_state::<_, LevelAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Editor
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct LevelAssets {
+    /// #     #[asset(key = "level", optional)]
+    /// #     pub level: Option<Handle<Image>>,
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_cli_overrides(mut self, mapping: &[(&str, &str)]) -> Self {
+        self.cli_overrides.extend(
+            mapping
+                .iter()
+                .map(|(flag, key)| (flag.to_string(), key.to_string())),
+        );
+
+        self
+    }
+
+    /// Load assets for the loading screen before any collection starts loading
+    ///
+    /// The loading screen itself often needs a few assets, like a logo or a font. The given
+    /// `(key, path)` pairs start loading when entering the loading state and the collections
+    /// only start loading once they finished. The handles are available under their keys in the
+    /// [`LoadingScreenAssets`] resource while the app is in the loading state.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .with_loading_screen_assets(&[("logo", "images/player.png")])
+    ///         )
+    ///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Loading)
+    ///         .add_systems(Update, show_logo.run_if(in_state(GameState::Loading)))
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct MyAssets {
+    /// #     #[asset(path = "images/tree.png")]
+    /// #     pub tree: Handle<Image>,
+    /// # }
+    /// fn show_logo(loading_screen_assets: Res<LoadingScreenAssets>) {
+    ///     let logo: Option<Handle<Image>> = loading_screen_assets.get("logo");
+    ///     // spawn the logo
+    /// }
+    /// ```
+    #[must_use]
+    pub fn with_loading_screen_assets(mut self, assets: &[(&str, &str)]) -> Self {
+        self.loading_screen_assets.extend(
+            assets
+                .iter()
+                .map(|(key, path)| (key.to_string(), path.to_string())),
+        );
+
+        self
+    }
+
+    /// Wait for the given handle to finish loading before leaving the loading state
+    ///
+    /// This is useful for single assets that do not need a whole [`AssetCollection`]. The handle
+    /// is tracked like the handles of a collection. If it fails to load, the loading state fails.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     let mut app = App::new();
+    /// #   app.add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>();
+    ///     let music: Handle<AudioSource> = app
+    ///         .world
+    ///         .resource::<AssetServer>()
+    ///         .load("audio/background.ogg");
+    ///     app.insert_resource(Music(music.clone()))
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .track_handle(music)
+    ///         )
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// #[derive(Resource)]
+    /// struct Music(Handle<AudioSource>);
+    /// ```
+    #[must_use]
+    pub fn track_handle(mut self, handle: impl Into<HandleUntyped>) -> Self {
+        self.tracked_handles.push(handle.into());
+
+        self
+    }
+
+    /// Start loading the collections of this loading state when entering an earlier state
+    ///
+    /// The collections start loading as soon as `earlier_state` is entered, but only the
+    /// transition out of this loading state waits for them. This way, loading can overlap with
+    /// e.g. a splash screen to reduce the time spent in the loading state.
+    ///
+    /// Dynamic asset collection files of this loading state are only loaded in the loading state
+    /// itself. The keys of all dynamic assets used by the collections need to be registered
+    /// before `earlier_state` is entered.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .preload_during(GameState::Splash)
+    ///         )
+    ///         .add_collection_to_loading_state::<_, MenuAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Splash,
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct MenuAssets {
+    /// #     #[asset(path = "audio/background.ogg")]
+    /// #     pub background: Handle<AudioSource>,
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn preload_during(mut self, earlier_state: S) -> Self {
+        self.preload_states.push(earlier_state);
+
+        self
+    }
+
+    /// Start loading a collection while the loading state is built
+    ///
+    /// The assets of the collection are requested from the [`AssetServer`](bevy::asset::AssetServer)
+    /// as soon as the loading state is added to the app, so loading overlaps with the rest of the
+    /// app setup. When the loading state is entered, it only waits for the collection to finish,
+    /// which might already be the case.
+    ///
+    /// The collection still needs to be added to the loading state with
+    /// [`LoadingStateAppExt::add_collection_to_loading_state`]. The `AssetPlugin` has to be added
+    /// before the loading state and all dynamic asset keys used by the collection need to be
+    /// registered already. Otherwise, a warning is logged and the collection starts loading when
+    /// the loading state is entered.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .preload_now::<AudioAssets>()
+    ///         )
+    ///         .add_collection_to_loading_state::<_, AudioAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct AudioAssets {
+    /// #     #[asset(path = "audio/plop.ogg")]
+    /// #     pub plop: Handle<AudioSource>,
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn preload_now<A: AssetCollection>(mut self) -> Self {
+        self.preload_now.push(preload_collection_now::<A>);
+
+        self
+    }
+
+    /// Insert the collections of this loading state into another world
+    ///
+    /// By default, finished collections are inserted as resources into the main world. The given
+    /// function is called with the main world and returns the world to insert them into instead.
+    /// It runs in an exclusive system of the loading state, so the target world has to be
+    /// reachable from the main world, e.g. by being stored in a resource. The worlds of sub apps
+    /// cannot be targeted while the main world is updated.
+    ///
+    /// Only the collection resources move to the target world. Their assets are still stored in
+    /// the main world and the handles stay bound to its [`AssetServer`](bevy::asset::AssetServer).
+    /// Resources initialised with [`LoadingStateAppExt::init_resource_after_loading_state`] are
+    /// created from the main world and cannot access collections in the target world.
+    /// Collections with [`LoadingField`](crate::loading_field::LoadingField)s are inserted into
+    /// the target world early and their fields are updated there.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .init_resource::<SceneWorld>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .target_world(|world| &mut world.resource_mut::<SceneWorld>().into_inner().0)
+    ///         )
+    ///         .add_collection_to_loading_state::<_, SceneAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// #[derive(Resource, Default)]
+    /// struct SceneWorld(World);
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct SceneAssets {
+    /// #     #[asset(path = "audio/background.ogg")]
+    /// #     pub background: Handle<AudioSource>,
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn target_world(
+        mut self,
+        target_world: impl Fn(&mut World) -> &mut World + Send + Sync + 'static,
+    ) -> Self {
+        self.target_world = Some(Arc::new(target_world));
+
+        self
+    }
+
+    /// Create the collection `A` in a background task instead of on the main thread
+    ///
+    /// Once all assets of the collection finished loading, [`AsyncAssetCollection::prepare`]
+    /// runs on the main thread and [`AsyncAssetCollection::create_async`] in a task on the
+    /// [`AsyncComputeTaskPool`](bevy::tasks::AsyncComputeTaskPool). The loading state keeps
+    /// counting the collection as loading until the task completed and the collection was
+    /// inserted as a resource. Only then can the loading state continue to the next state.
+    ///
+    /// The collection still needs to be added to the loading state with
+    /// [`LoadingStateAppExt::add_collection_to_loading_state`]. Collections recreated because
+    /// of [hot reloading](LoadingState::expect_hot_reload) use [`AssetCollection::create`].
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .async_create::<LevelAssets>()
+    ///         )
+    ///         .add_collection_to_loading_state::<_, LevelAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct LevelAssets {
+    /// #     #[asset(path = "images/player.png")]
+    /// #     pub player: Handle<Image>,
+    /// # }
+    /// # impl AsyncAssetCollection for LevelAssets {
+    /// #     type Input = Self;
+    /// #     fn prepare(world: &mut World) -> Self { LevelAssets::create(world) }
+    /// #     fn create_async(input: Self) -> Self { input }
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn async_create<A: AsyncAssetCollection>(mut self) -> Self {
+        self.async_collections
+            .insert(TypeId::of::<A>(), spawn_create_task::<A>);
+
+        self
+    }
+
+    /// Recreate the given collection whenever one of its assets is modified
+    ///
+    /// Derived assets like texture atlases or materials are built when creating a collection.
+    /// If their source assets change, e.g. because an image is hot reloaded, they become stale.
+    /// With this option, [`AssetEvent::Modified`](bevy::asset::AssetEvent::Modified) events for
+    /// the handles of a loaded collection re-run [`AssetCollection::create`] and replace the
+    /// collection resource. No loads are issued again.
+    ///
+    /// To guard against rebuild loops, modifications in the frame of a rebuild are ignored,
+    /// since they are usually caused by creating the collection or by systems reacting to it.
+    /// A collection that keeps getting rebuilt in (almost) consecutive frames stops being rebuilt
+    /// with a warning.
+    ///
+    /// The collection still needs to be added to the loading state with
+    /// [`LoadingStateAppExt::add_collection_to_loading_state`]. Modifications are detected for
+    /// the asset types watched by [`AssetCollection::add_modification_watchers`].
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Playing)
+    ///             .rebuild_on_modify::<LevelAssets>()
+    ///         )
+    ///         .add_collection_to_loading_state::<_, LevelAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Playing
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct LevelAssets {
+    /// #     #[asset(path = "images/player.png")]
+    /// #     pub player: Handle<Image>,
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn rebuild_on_modify<A: AssetCollection>(mut self) -> Self {
+        self.rebuild_on_modify
+            .insert(TypeId::of::<A>(), add_rebuild_systems::<A>);
+
+        self
+    }
+
+    /// Upload data of a collection in the render world before leaving the loading state
+    ///
+    /// The systems are added to the [`ExtractSchedule`](bevy::render::ExtractSchedule) of the
+    /// render app. Once all collections of the loading state are inserted, the upload of `A` is
+    /// requested and the systems run every frame until one of them calls
+    /// [`RenderUploads::complete::<A>`](crate::render_upload::RenderUploads::complete). Only
+    /// then does the loading state continue to the next state. The systems can read the main
+    /// world with [`Extract`](bevy::render::Extract) parameters and create GPU resources, like
+    /// buffers, with the [`RenderDevice`](bevy::render::renderer::RenderDevice) of the render
+    /// world. Storing the created resources is up to them.
+    ///
+    /// If the app has no render app, e.g. in tests without a renderer, the systems are not
+    /// added and the upload has to be completed manually from the [`RenderUploads`](crate::render_upload::RenderUploads)
+    /// resource in the main world.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # use bevy::render::Extract;
+    /// # use bevy::render::render_resource::{Buffer, BufferInitDescriptor, BufferUsages};
+    /// # use bevy::render::renderer::RenderDevice;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Playing)
+    ///             .upload_in_render_world::<TerrainAssets, _>(upload_heights)
+    ///         )
+    ///         .add_collection_to_loading_state::<_, TerrainAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Playing
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct TerrainAssets {
+    /// #     #[asset(path = "images/player.png")]
+    /// #     pub heights: Handle<Image>,
+    /// # }
+    /// #[derive(Resource)]
+    /// struct HeightBuffer(Buffer);
+    ///
+    /// fn upload_heights(
+    ///     mut commands: Commands,
+    ///     terrain: Extract<Option<Res<TerrainAssets>>>,
+    ///     images: Extract<Res<Assets<Image>>>,
+    ///     device: Res<RenderDevice>,
+    ///     uploads: Res<RenderUploads>,
+    /// ) {
+    ///     let Some(image) = terrain.as_ref().and_then(|terrain| images.get(&terrain.heights)) else {
+    ///         return;
+    ///     };
+    ///     let buffer = device.create_buffer_with_data(&BufferInitDescriptor {
+    ///         label: Some("heights"),
+    ///         contents: &image.data,
+    ///         usage: BufferUsages::STORAGE,
+    ///     });
+    ///     commands.insert_resource(HeightBuffer(buffer));
+    ///     uploads.complete::<TerrainAssets>();
+    /// }
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "2d", feature = "3d"))))]
+    #[cfg(any(feature = "2d", feature = "3d"))]
+    #[must_use]
+    pub fn upload_in_render_world<A: AssetCollection, M>(
+        mut self,
+        systems: impl IntoSystemConfigs<M>,
+    ) -> Self {
+        self.render_uploads
+            .push((TypeId::of::<A>(), systems.run_if(upload_pending::<A>)));
+
+        self
+    }
+
+    /// Add systems that run when the loading state is entered
+    ///
+    /// The systems run in [`OnEnter`] of the loading state after the internal loading state
+    /// was reset. They are part of the [`OnEnterLoadingStateSet`] of the loading state, which
+    /// can be used to order other systems relative to them.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .on_enter(show_loading_tip)
+    ///         )
+    ///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct MyAssets {
+    /// #     #[asset(path = "audio/background.ogg")]
+    /// #     pub background: Handle<AudioSource>,
+    /// # }
+    /// fn show_loading_tip() {
+    ///     info!("Tip: you can pet the dog");
+    /// }
+    /// ```
+    #[must_use]
+    pub fn on_enter<M>(mut self, systems: impl IntoSystemConfigs<M>) -> Self {
+        self.on_enter_systems.push(systems.into_configs());
+
+        self
+    }
+
+    /// Add systems that run right after the loading state issued the loads of its collections
+    ///
+    /// The systems run once, in the same frame in which all collections of the loading state
+    /// started loading and before any of them is checked. They are part of the [`IssuedLoadsSet`]
+    /// of the loading state, which can be used to order other systems relative to them.
+    ///
+    /// Loads that are held back by [`LoadingState::batch_size`] or
+    /// [`LoadingState::max_concurrent_loads_for`] are issued later while checking the collections.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .on_loads_issued(log_pending_loads)
+    ///         )
+    ///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct MyAssets {
+    /// #     #[asset(path = "audio/background.ogg")]
+    /// #     pub background: Handle<AudioSource>,
+    /// # }
+    /// fn log_pending_loads(asset_server: Res<AssetServer>) {
+    ///     let state = asset_server.get_load_state("audio/background.ogg");
+    ///     info!("Background music is {state:?}");
+    /// }
+    /// ```
+    #[must_use]
+    pub fn on_loads_issued<M>(mut self, systems: impl IntoSystemConfigs<M>) -> Self {
+        self.loads_issued_systems.push(systems.into_configs());
+
+        self
+    }
+
+    /// Add systems that run every frame while the loading state is active
+    ///
+    /// The systems run in [`Update`] after the loading state checked its assets in that frame.
+    /// They are part of the [`LoadingSystemSet`] of the loading state, which can be used to order
+    /// other systems relative to them. This is useful to animate loading screens.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .with_loading_system(rotate_spinner)
+    ///         )
+    ///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct MyAssets {
+    /// #     #[asset(path = "audio/background.ogg")]
+    /// #     pub background: Handle<AudioSource>,
+    /// # }
+    /// #[derive(Component)]
+    /// struct Spinner;
+    ///
+    /// fn rotate_spinner(mut spinners: Query<&mut Transform, With<Spinner>>, time: Res<Time>) {
+    ///     for mut transform in &mut spinners {
+    ///         transform.rotate_z(time.delta_seconds());
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    pub fn with_loading_system<M>(mut self, systems: impl IntoSystemConfigs<M>) -> Self {
+        self.loading_systems.push(systems.into_configs());
+
+        self
+    }
+
+    /// Add systems that run if the loading state is left before it finished loading
+    ///
+    /// A loading state is cancelled when the app changes to another state while assets are
+    /// still loading, e.g. because the player backed out of a loading screen. The handles of
+    /// all unfinished collections are dropped, the progress is reset and a [`LoadingCancelled`]
+    /// event is sent. Afterwards, the systems run in [`OnExit`] of the loading state as part
+    /// of the [`OnCancelLoadingStateSet`]. This is the place to clean up loading screen UI.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Level)
+    ///             .on_cancel(despawn_loading_screen)
+    ///         )
+    ///         .add_collection_to_loading_state::<_, LevelAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Level
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct LevelAssets {
+    /// #     #[asset(path = "images/player.png")]
+    /// #     pub player: Handle<Image>,
+    /// # }
+    /// #[derive(Component)]
+    /// struct LoadingScreen;
+    ///
+    /// fn despawn_loading_screen(mut commands: Commands, screens: Query<Entity, With<LoadingScreen>>) {
+    ///     for screen in &screens {
+    ///         commands.entity(screen).despawn();
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    pub fn on_cancel<M>(mut self, systems: impl IntoSystemConfigs<M>) -> Self {
+        self.on_cancel_systems.push(systems.into_configs());
+
+        self
+    }
+
+    /// Keep loading collections in the background if the loading state is left before it finished
+    ///
+    /// By default, the handles of unfinished collections are dropped when the loading state is
+    /// cancelled. With this option, collections that already started loading keep their handles.
+    /// A system running every frame in [`Update`], independent of the current state, creates each
+    /// of them once all of its assets are loaded and inserts it as a resource.
+    ///
+    /// Leaving the loading state still counts as cancelling it: the progress is reset, a
+    /// [`LoadingCancelled`] event is sent and the [`on_cancel`](LoadingState::on_cancel) systems
+    /// run. The loading state does not change the state anymore, so neither its next state nor
+    /// its failure state are entered. Collections with an asset that fails to load are dropped
+    /// with a warning. Async collections are created on the main thread in the background, and
+    /// hot reloading is not set up for them. If the loading state is entered again before a
+    /// collection finished, the loading state takes over the collection again.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Level)
+    ///             .finish_in_background()
+    ///         )
+    ///         .add_collection_to_loading_state::<_, LevelAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Level
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct LevelAssets {
+    /// #     #[asset(path = "images/player.png")]
+    /// #     pub player: Handle<Image>,
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn finish_in_background(mut self) -> Self {
+        self.finish_in_background = true;
+
+        self
+    }
+
+    /// Insert a map of asset keys with corresponding standard dynamic assets
+    #[must_use]
+    #[cfg(feature = "standard_dynamic_assets")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "standard_dynamic_assets")))]
+    pub fn add_standard_dynamic_assets(
+        mut self,
+        mut dynamic_assets: HashMap<String, StandardDynamicAsset>,
+    ) -> Self {
+        dynamic_assets.drain().for_each(|(key, value)| {
+            self.dynamic_assets.insert(key, Box::new(value));
+        });
+
+        self
+    }
+
+    /// Load the given files as [`StandardDynamicAssetCollection`]s with later files overriding earlier ones
+    ///
+    /// All files are loaded when entering the loading state. Once every file finished loading,
+    /// their dynamic assets are registered in the given order, so a key defined in multiple files
+    /// resolves to the asset of the last file. This allows shipping a base file that mods
+    /// or configurations extend by adding or replacing keys.
+    /// The files are merged before any asset collection of the loading state starts loading.
+    ///
+    /// Files registered through [`LoadingStateAppExt::add_dynamic_collection_to_loading_state`]
+    /// for the same loading state are applied after the files given here.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .with_dynamic_assets_files(&["base.assets.ron", "mods/override.assets.ron"])
+    ///         )
+    ///         .add_collection_to_loading_state::<_, AudioAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct AudioAssets {
+    /// #     #[asset(key = "sounds.background")]
+    /// #     pub background: Handle<AudioSource>,
+    /// # }
+    /// ```
+    #[must_use]
+    #[cfg_attr(docsrs, doc(cfg(feature = "standard_dynamic_assets")))]
+    #[cfg(feature = "standard_dynamic_assets")]
+    pub fn with_dynamic_assets_files(mut self, files: &[&str]) -> Self {
+        self.dynamic_assets_files
+            .extend(files.iter().map(|file| file.to_string()));
+
+        self
+    }
+
+    /// Check the dynamic assets of this loading state against a [`DynamicAssetsSchema`] file
+    ///
+    /// The schema file is loaded together with the dynamic asset collection files. Once all
+    /// dynamic assets are registered, every key required by the schema is checked for presence
+    /// and the expected [`StandardDynamicAsset`] variant. Discrepancies are logged as warnings
+    /// and collected in the [`SchemaDiscrepancies`](crate::standard_dynamic_asset::SchemaDiscrepancies) resource. They do not fail the loading state.
+    ///
+    /// Schema files need the file ending `.schema.ron`.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .with_dynamic_assets_files(&["game.assets.ron"])
+    ///             .validate_dynamic_assets_against("game.schema.ron")
+    ///         )
+    ///         .add_collection_to_loading_state::<_, AudioAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct AudioAssets {
+    /// #     #[asset(key = "sounds.background")]
+    /// #     pub background: Handle<AudioSource>,
+    /// # }
+    /// ```
+    #[must_use]
+    #[cfg_attr(docsrs, doc(cfg(feature = "standard_dynamic_assets")))]
+    #[cfg(feature = "standard_dynamic_assets")]
+    pub fn validate_dynamic_assets_against(mut self, schema_path: &str) -> Self {
+        self.dynamic_assets_schemas.push(schema_path.to_owned());
+
+        self
+    }
+
+    /// Set all file endings that should be loaded as [`StandardDynamicAssetCollection`].
+    ///
+    /// The default file ending is `.assets`
+    #[must_use]
+    #[cfg_attr(docsrs, doc(cfg(feature = "standard_dynamic_assets")))]
+    #[cfg(feature = "standard_dynamic_assets")]
+    pub fn set_standard_dynamic_asset_collection_file_endings(
+        mut self,
+        endings: Vec<&'static str>,
+    ) -> Self {
+        self.standard_dynamic_asset_collection_file_endings = endings;
+
+        self
+    }
+
+    /// Finish configuring the [`LoadingState`]
+    ///
+    /// Calling this function is required to set up the asset loading.
+    /// Most of the time you do not want to call this method directly though, but complete the setup
+    /// using [`LoadingStateAppExt::add_loading_state`].
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///         )
+    ///         .add_collection_to_loading_state::<_, AudioAssets>(GameState::Loading)
+    ///         .add_collection_to_loading_state::<_, ImageAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct AudioAssets {
+    /// #     #[asset(path = "audio/background.ogg")]
+    /// #     pub background: Handle<AudioSource>,
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct ImageAssets {
+    /// #     #[asset(path = "images/player.png")]
+    /// #     pub player: Handle<Image>,
+    /// #     #[asset(path = "images/tree.png")]
+    /// #     pub tree: Handle<Image>,
+    /// # }
+    /// ```
+    pub fn build(self, app: &mut App) {
+        let loading_state = self.loading_state.clone();
+        self.build_in_schedules(
+            app,
+            OnEnter(loading_state.clone()),
+            Update,
+            OnExit(loading_state),
+            None,
+        );
+    }
+
+    /// Finish configuring the [`LoadingState`] with its systems in the given schedules
+    ///
+    /// [`build`](LoadingState::build) uses [`OnEnter`] and [`OnExit`] of the loading state and
+    /// [`Update`]. Tools that run schedules manually, like a headless asset baker, can place the
+    /// systems in their own schedules instead:
+    /// - `enter_schedule` resets the loading state and has to run once before loading starts.
+    ///   Systems added with [`on_enter`](LoadingState::on_enter) are added to it.
+    /// - `update_schedule` checks the assets and has to run until loading finished. Systems added
+    ///   with [`with_loading_system`](LoadingState::with_loading_system) are added to it. Collections
+    ///   that [finish in the background](LoadingState::finish_in_background), are recreated after
+    ///   hot reloads or have [`LoadingField`](crate::loading_field::LoadingField)s are handled in
+    ///   it as well, if they are added to this loading state first.
+    /// - `exit_schedule` stops collecting errors and should run when leaving the loading state.
+    ///   Systems added with [`on_cancel`](LoadingState::on_cancel) are added to it.
+    ///
+    /// The internal steps of the loading state are applied at the end of `update_schedule`, so the
+    /// [`StateTransition`] schedule does not need to run. The systems still only run while the
+    /// [`State`] resource of `S` is the loading state. Once done, the loading state sets
+    /// [`NextState`] like always; applying it is up to your app.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # use bevy::ecs::schedule::ScheduleLabel;
+    /// #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    /// struct BakeStart;
+    /// #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    /// struct BakeStep;
+    /// #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    /// struct BakeEnd;
+    ///
+    /// # fn main() {
+    ///     let mut app = App::new();
+    /// #   app.add_plugins((MinimalPlugins, AssetPlugin::default()));
+    /// #   app.init_resource::<iyes_progress::ProgressCounter>();
+    ///     app.init_resource::<State<BakeState>>()
+    ///         .init_resource::<NextState<BakeState>>();
+    ///     LoadingState::new(BakeState::Loading)
+    ///         .continue_to_state(BakeState::Baking)
+    ///         .build_in(&mut app, BakeStart, BakeStep, BakeEnd);
+    ///     app.add_collection_to_loading_state::<_, BakeAssets>(BakeState::Loading);
+    ///
+    ///     app.world.run_schedule(BakeStart);
+    ///     app.world.run_schedule(BakeStep);
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum BakeState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Baking
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct BakeAssets {
+    /// #     #[asset(path = "images/player.png")]
+    /// #     pub player: Handle<Image>,
+    /// # }
+    /// ```
+    pub fn build_in(
+        self,
+        app: &mut App,
+        enter_schedule: impl ScheduleLabel + Clone,
+        update_schedule: impl ScheduleLabel + Clone,
+        exit_schedule: impl ScheduleLabel + Clone,
+    ) {
+        let loading_state = self.loading_state.clone();
+        let configure_loading_state = self.build_in_schedules(
+            app,
+            enter_schedule,
+            update_schedule.clone(),
+            exit_schedule,
+            Some(Box::new(update_schedule.clone())),
+        );
+        // Building the same loading state again must not apply its transitions twice per update
+        if configure_loading_state {
+            app.add_systems(
+                update_schedule,
+                apply_internal_state_transition::<S>.after(LoadingStateSet(loading_state)),
+            );
+        }
+    }
+
+    /// Returns whether the loading state was configured for the first time
+    #[allow(unused_mut)]
+    fn build_in_schedules(
+        mut self,
+        app: &mut App,
+        enter_schedule: impl ScheduleLabel + Clone,
+        update_schedule: impl ScheduleLabel + Clone,
+        exit_schedule: impl ScheduleLabel + Clone,
+        collection_schedule: Option<BoxedScheduleLabel>,
+    ) -> bool {
+        app.init_resource::<AssetLoaderConfiguration<S>>();
+        app.init_resource::<FailedAssets>();
+        app.init_resource::<LoadingProgress<S>>();
+        app.init_resource::<CollectionTimings>();
+        app.init_resource::<CollectionErrors>();
+        app.add_event::<AssetLoadedWithin>();
+        app.add_event::<LoadingProgressChanged<S>>();
+        app.add_event::<LoadingComplete<S>>();
+        app.add_event::<LoadingCancelled<S>>();
+        {
+            let mut asset_loader_configuration = app
+                .world
+                .get_resource_mut::<AssetLoaderConfiguration<S>>()
+                .unwrap();
+            let mut loading_config = asset_loader_configuration
+                .state_configurations
+                .remove(&self.loading_state)
+                .unwrap_or_default();
+            if self.next_state.is_some() {
+                loading_config.next = self.next_state;
+            }
+            if self.failure_state.is_some() {
+                loading_config.failure = self.failure_state;
+            }
+            #[cfg(feature = "content_hash")]
+            if self.hash_condition.is_some() {
+                loading_config.hash_condition = self.hash_condition;
+            }
+            if self.stay_in_state {
+                loading_config.stay_in_state = true;
+            }
+            if self.finish_in_background {
+                loading_config.finish_in_background = true;
+            }
+            if self.no_auto_transition {
+                loading_config.no_auto_transition = true;
+            }
+            if let Some(verbose_logging) = self.verbose_logging {
+                loading_config.verbose_logging = verbose_logging;
+            }
+            if self.batch_size.is_some() {
+                loading_config.batch_size = self.batch_size;
+            }
+            loading_config
+                .max_concurrent_loads
+                .extend(self.max_concurrent_loads);
+            loading_config
+                .non_blocking_types
+                .extend(self.non_blocking_types);
+            if let Some(settle_frames) = self.settle_frames {
+                loading_config.settle_frames = settle_frames;
+            }
+            if let Some(weight) = self.dynamic_assets_file_weight {
+                loading_config.dynamic_assets_file_weight = weight;
+            }
+            if let Some(threshold) = self.progress_event_threshold {
+                loading_config.progress_event_threshold = threshold;
+            }
+            if let Some(delay_start) = self.delay_start {
+                loading_config.delay_start = delay_start;
+            }
+            if self.wait_condition.is_some() {
+                loading_config.wait_condition = self.wait_condition;
+            }
+            loading_config
+                .handle_observers
+                .extend(self.handle_observers);
+            loading_config
+                .completion_checks
+                .extend(self.completion_checks);
+            if self.expect_hot_reload {
+                loading_config.expect_hot_reload = true;
+            }
+            loading_config.cli_overrides.extend(self.cli_overrides);
+            #[cfg(any(feature = "2d", feature = "3d"))]
+            loading_config.render_uploads.extend(
+                self.render_uploads
+                    .iter()
+                    .map(|(collection, _)| *collection),
+            );
+            loading_config
+                .loading_screen_assets
+                .extend(self.loading_screen_assets);
+            if self.collect_errors {
+                loading_config.collect_errors = true;
+            }
+            loading_config.tracked_handles.extend(self.tracked_handles);
+            if self.target_world.is_some() {
+                loading_config.target_world = self.target_world;
+            }
+            loading_config
+                .async_collections
+                .extend(self.async_collections);
+            loading_config
+                .rebuild_on_modify
+                .extend(self.rebuild_on_modify.keys());
+            if collection_schedule.is_some() {
+                loading_config.collection_schedule = collection_schedule;
+            }
+            asset_loader_configuration
+                .state_configurations
+                .insert(self.loading_state.clone(), loading_config);
+        }
+        app.init_resource::<State<InternalLoadingState<S>>>();
+        app.init_resource::<NextState<InternalLoadingState<S>>>();
+        for add_rebuild_systems in self.rebuild_on_modify.values() {
+            add_rebuild_systems(app);
+        }
+
+        app.init_resource::<DynamicAssetCollections<S>>();
+        #[cfg(feature = "standard_dynamic_assets")]
+        if !app.is_plugin_added::<RonAssetPlugin<StandardDynamicAssetCollection>>() {
+            app.add_plugins(RonAssetPlugin::<StandardDynamicAssetCollection>::new(
+                &self.standard_dynamic_asset_collection_file_endings,
+            ));
+        }
+
+        if !app.is_plugin_added::<InternalAssetLoaderPlugin<S>>() {
+            app.add_plugins(InternalAssetLoaderPlugin::<S>::new());
+        }
+
+        app.init_resource::<LoadingStateSchedules<S>>();
+
+        let loading_state_schedule = LoadingStateSchedule(self.loading_state.clone());
+        let configure_loading_state = app.get_schedule(loading_state_schedule.clone()).is_none();
+        app.init_schedule(loading_state_schedule.clone())
+            .init_schedule(OnEnterInternalLoadingState(
+                self.loading_state.clone(),
+                InternalLoadingState::LoadingAssets,
+            ))
+            .init_schedule(OnEnterInternalLoadingState(
+                self.loading_state.clone(),
+                InternalLoadingState::LoadingDynamicAssetCollections,
+            ))
+            .init_schedule(OnEnterInternalLoadingState(
+                self.loading_state.clone(),
+                InternalLoadingState::Finalize,
+            ));
+
+        let finish_loading_state = finish_loading_state::<S>.run_if(wait_condition_met::<S>);
+        #[cfg(any(feature = "2d", feature = "3d"))]
+        let finish_loading_state = finish_loading_state.run_if(render_uploads_completed::<S>);
+        #[cfg(feature = "content_hash")]
+        let finish_loading_state = finish_loading_state.run_if(file_hash_completed::<S>);
+        #[cfg(feature = "content_hash")]
+        if configure_loading_state {
+            app.add_systems(
+                OnEnterInternalLoadingState(
+                    self.loading_state.clone(),
+                    InternalLoadingState::Finalize,
+                ),
+                request_condition_file_hash::<S>,
+            );
+        }
+        #[cfg(any(feature = "2d", feature = "3d"))]
+        if configure_loading_state {
+            app.add_systems(
+                OnEnterInternalLoadingState(
+                    self.loading_state.clone(),
+                    InternalLoadingState::Finalize,
+                ),
+                request_render_uploads::<S>,
+            );
+        }
+        if configure_loading_state {
+            app.add_systems(
+                loading_state_schedule.clone(),
+                (
+                    resume_to_loading_asset_collections::<S>
+                        .in_set(InternalLoadingStateSet::ResumeDynamicAssetCollections),
+                    initialize_loading_state::<S>.in_set(InternalLoadingStateSet::Initialize),
+                    resume_to_finalize::<S>.in_set(InternalLoadingStateSet::CheckAssets),
+                    finish_loading_state.in_set(InternalLoadingStateSet::Finalize),
+                    check_tracked_handles::<S>.in_set(InternalLoadingStateSet::CheckAssets),
+                    check_async_collections::<S>.in_set(InternalLoadingStateSet::CheckAssets),
+                ),
+            )
+            .add_systems(
+                OnEnterInternalLoadingState(
+                    self.loading_state.clone(),
+                    InternalLoadingState::LoadingAssets,
+                ),
+                start_tracking_handles::<S>.in_set(InternalLoadingStateSet::StartLoading),
+            )
+            .configure_set(
+                OnEnterInternalLoadingState(
+                    self.loading_state.clone(),
+                    InternalLoadingState::LoadingAssets,
+                ),
+                IssuedLoadsSet(self.loading_state.clone())
+                    .after(InternalLoadingStateSet::StartLoading),
+            )
             .add_systems(
-                OnEnter(self.loading_state.clone()),
-                reset_loading_state::<S>,
+                enter_schedule.clone(),
+                reset_loading_state::<S>.run_if(in_state(self.loading_state.clone())),
+            )
+            .add_systems(exit_schedule.clone(), {
+                let loading_state = self.loading_state.clone();
+                (move |world: &mut World| exit_loading_state::<S>(world, &loading_state))
+                    .in_set(OnExitLoadingStateSet(self.loading_state.clone()))
+            })
+            .configure_set(
+                exit_schedule.clone(),
+                OnCancelLoadingStateSet(self.loading_state.clone())
+                    .after(OnExitLoadingStateSet(self.loading_state.clone()))
+                    .run_if(loading_cancelled(self.loading_state.clone())),
+            )
+            .configure_set(
+                enter_schedule.clone(),
+                OnEnterLoadingStateSet(self.loading_state.clone())
+                    .after(reset_loading_state::<S>)
+                    .run_if(in_state(self.loading_state.clone())),
             )
-            .configure_set(Update, LoadingStateSet(self.loading_state.clone()));
+            .configure_set(
+                update_schedule.clone(),
+                LoadingStateSet(self.loading_state.clone()),
+            )
+            .configure_set(
+                update_schedule.clone(),
+                LoadingSystemSet(self.loading_state.clone())
+                    .after(LoadingStateSet(self.loading_state.clone()))
+                    .run_if(in_state(self.loading_state.clone())),
+            )
+            .add_systems(
+                update_schedule.clone(),
+                send_progress_changed::<S>
+                    .after(LoadingStateSet(self.loading_state.clone()))
+                    .run_if(in_state(self.loading_state.clone())),
+            );
             let mut loading_state_schedule = app.get_schedule_mut(loading_state_schedule).unwrap();
             loading_state_schedule
                 .configure_set(
@@ -414,101 +2415,997 @@ where
                         .run_if(in_state(InternalLoadingState::<S>::Finalize)),
                 );
 
-            #[cfg(feature = "progress_tracking")]
-            app.add_systems(
-                Update,
-                run_loading_state::<S>
-                    .in_set(TrackedProgressSet)
-                    .in_set(LoadingStateSet(self.loading_state.clone()))
-                    .run_if(in_state(self.loading_state)),
-            );
-            #[cfg(not(feature = "progress_tracking"))]
-            app.add_systems(
-                Update,
-                run_loading_state::<S>
-                    .in_set(LoadingStateSet(self.loading_state.clone()))
-                    .run_if(in_state(self.loading_state)),
-            );
-        }
+            #[cfg(feature = "progress_tracking")]
+            app.add_systems(
+                update_schedule.clone(),
+                run_loading_state::<S>
+                    .in_set(TrackedProgressSet)
+                    .in_set(LoadingStateSet(self.loading_state.clone()))
+                    .run_if(in_state(self.loading_state.clone())),
+            );
+            #[cfg(not(feature = "progress_tracking"))]
+            app.add_systems(
+                update_schedule.clone(),
+                run_loading_state::<S>
+                    .in_set(LoadingStateSet(self.loading_state.clone()))
+                    .run_if(in_state(self.loading_state.clone())),
+            );
+        }
+
+        let preload_schedule = PreloadLoadingState(self.loading_state.clone());
+        if !self.preload_states.is_empty() && app.get_schedule(preload_schedule.clone()).is_none() {
+            app.init_schedule(preload_schedule.clone());
+        }
+        for preload_state in self.preload_states {
+            let preload_schedule = preload_schedule.clone();
+            app.add_systems(OnEnter(preload_state), move |world: &mut World| {
+                world.run_schedule(preload_schedule.clone())
+            });
+        }
+
+        for systems in self.on_enter_systems {
+            app.add_systems(
+                enter_schedule.clone(),
+                systems.in_set(OnEnterLoadingStateSet(self.loading_state.clone())),
+            );
+        }
+        for systems in self.loading_systems {
+            app.add_systems(
+                update_schedule.clone(),
+                systems.in_set(LoadingSystemSet(self.loading_state.clone())),
+            );
+        }
+        for systems in self.loads_issued_systems {
+            app.add_systems(
+                OnEnterInternalLoadingState(
+                    self.loading_state.clone(),
+                    InternalLoadingState::LoadingAssets,
+                ),
+                systems.in_set(IssuedLoadsSet(self.loading_state.clone())),
+            );
+        }
+        for systems in self.on_cancel_systems {
+            app.add_systems(
+                exit_schedule.clone(),
+                systems.in_set(OnCancelLoadingStateSet(self.loading_state.clone())),
+            );
+        }
+
+        app.init_resource::<DynamicAssets>();
+        let mut dynamic_assets = app.world.get_resource_mut::<DynamicAssets>().unwrap();
+        for (key, asset) in self.dynamic_assets {
+            dynamic_assets.register_asset(key, asset);
+        }
+        for preload_now in &self.preload_now {
+            preload_now(&mut app.world);
+        }
+
+        #[cfg(any(feature = "2d", feature = "3d"))]
+        if !self.render_uploads.is_empty() {
+            app.init_resource::<RenderUploads>();
+            let uploads = app.world.resource::<RenderUploads>().clone();
+            match app.get_sub_app_mut(RenderApp) {
+                Ok(render_app) => {
+                    render_app.insert_resource(uploads);
+                    for (_, systems) in self.render_uploads {
+                        render_app.add_systems(ExtractSchedule, systems);
+                    }
+                }
+                Err(_) => warn!(
+                    state = ?self.loading_state,
+                    "There is no render app to upload collections in. Complete the uploads with the RenderUploads resource of the main world."
+                ),
+            }
+        }
+
+        #[cfg(feature = "standard_dynamic_assets")]
+        for file in &self.dynamic_assets_files {
+            app.add_dynamic_collection_to_loading_state::<S, StandardDynamicAssetCollection>(
+                self.loading_state.clone(),
+                file,
+            );
+        }
+        #[cfg(feature = "standard_dynamic_assets")]
+        if !self.dynamic_assets_schemas.is_empty() {
+            if !app.is_plugin_added::<RonAssetPlugin<DynamicAssetsSchema>>() {
+                app.add_plugins(RonAssetPlugin::<DynamicAssetsSchema>::new(&["schema.ron"]));
+            }
+            for schema in &self.dynamic_assets_schemas {
+                app.add_dynamic_collection_to_loading_state::<S, DynamicAssetsSchema>(
+                    self.loading_state.clone(),
+                    schema,
+                );
+            }
+            let schemas = self.dynamic_assets_schemas;
+            app.add_systems(
+                OnEnterInternalLoadingState(
+                    self.loading_state.clone(),
+                    InternalLoadingState::LoadingDynamicAssetCollections,
+                ),
+                move |world: &mut World| load_schemas::<S>(world, &schemas),
+            )
+            .add_systems(
+                OnEnterInternalLoadingState(
+                    self.loading_state.clone(),
+                    InternalLoadingState::LoadingAssets,
+                ),
+                validate_dynamic_assets::<S>,
+            );
+        }
+
+        configure_loading_state
+    }
+}
+
+/// The systems checking the assets of a loading state are part of this set
+///
+/// The set runs in [`Update`] while the loading state is active. Finished collections are
+/// inserted and the next state is set from this set, so it can be used to order loading states of
+/// different state types that are active at the same time.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// # fn main() {
+///     App::new()
+/// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+///         .add_state::<BootState>()
+///         .add_state::<MenuState>()
+///         .add_loading_state(LoadingState::new(BootState::Loading))
+///         .add_loading_state(LoadingState::new(MenuState::Loading))
+///         .configure_set(
+///             Update,
+///             LoadingStateSet(BootState::Loading).before(LoadingStateSet(MenuState::Loading)),
+///         );
+/// # }
+/// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+/// # enum BootState {
+/// #     #[default]
+/// #     Loading,
+/// #     Done
+/// # }
+/// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+/// # enum MenuState {
+/// #     #[default]
+/// #     Loading,
+/// #     Ready
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemSet)]
+pub struct LoadingStateSet<S: States>(pub S);
+
+/// Systems added with [`LoadingState::on_enter`] are part of this set
+///
+/// The set runs in [`OnEnter`] of the loading state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemSet)]
+pub struct OnEnterLoadingStateSet<S: States>(pub S);
+
+/// Systems added with [`LoadingState::with_loading_system`] are part of this set
+///
+/// The set runs in [`Update`] while the loading state is active.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemSet)]
+pub struct LoadingSystemSet<S: States>(pub S);
+
+/// The system cleaning up after a loading state is part of this set
+///
+/// The set runs in [`OnExit`] of the loading state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemSet)]
+pub struct OnExitLoadingStateSet<S: States>(pub S);
+
+/// Systems added with [`LoadingState::on_loads_issued`] are part of this set
+///
+/// The set runs once when a loading state starts loading its collections, after all of them
+/// issued their loads and before any of them is checked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemSet)]
+pub struct IssuedLoadsSet<S: States>(pub S);
+
+/// Systems added with [`LoadingState::on_cancel`] are part of this set
+///
+/// The set runs in [`OnExit`] of the loading state after the [`OnExitLoadingStateSet`], but only
+/// if the loading state was left before it finished.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemSet)]
+pub struct OnCancelLoadingStateSet<S: States>(pub S);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemSet)]
+pub(crate) enum InternalLoadingStateSet {
+    Initialize,
+    StartLoading,
+    CheckDynamicAssetCollections,
+    ResumeDynamicAssetCollections,
+    CheckAssets,
+    Finalize,
+}
+
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct OnEnterInternalLoadingState<S: States>(pub S, pub InternalLoadingState<S>);
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct LoadingStateSchedule<S: States>(pub S);
+/// Runs when entering a state configured with [`LoadingState::preload_during`]
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct PreloadLoadingState<S: States>(pub S);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub(crate) enum InternalLoadingState<S: States> {
+    /// Starting point. Here it will be decided whether or not dynamic asset collections need to be loaded.
+    #[default]
+    Initialize,
+    /// Load dynamic asset collections and configure their key <-> asset mapping
+    LoadingDynamicAssetCollections,
+    /// Load the actual asset collections and check their status every frame.
+    LoadingAssets,
+    /// All collections are loaded and inserted. Time to e.g. run custom [insert_resource](bevy_asset_loader::AssetLoader::insert_resource).
+    Finalize,
+    /// A 'parking' state in case no next state is defined
+    Done(PhantomData<S>),
+}
+
+impl<S: States> States for InternalLoadingState<S> {
+    type Iter = IntoIter<Self, 5>;
+
+    fn variants() -> Self::Iter {
+        [
+            Self::Initialize,
+            Self::LoadingDynamicAssetCollections,
+            Self::LoadingAssets,
+            Self::Finalize,
+            Self::Done(PhantomData::default()),
+        ]
+        .into_iter()
+    }
+}
+
+/// This resource is used for handles from asset collections and loading dynamic asset collection files.
+/// The generic will be the [`AssetCollection`] type for the first and the [`DynamicAssetCollection`] for the second.
+#[derive(Resource)]
+pub(crate) struct LoadingAssetHandles<T> {
+    handles: Vec<HandleUntyped>,
+    /// Handles that did not finish loading yet with the time their loading was started
+    pending: HashMap<HandleId, Instant>,
+    /// Described asset paths of the issued handles
+    paths: HashMap<HandleId, String>,
+    /// Load requests that were not issued yet with the asset type they load
+    queued: VecDeque<(Option<&'static str>, AssetLoadRequest)>,
+    /// Issued handles of [non-blocking asset types](LoadingState::non_blocking_type)
+    non_blocking: HashSet<HandleId>,
+    /// Whether the deferred loads of the collection were issued
+    ///
+    /// See [`AssetCollection::load_deferred`]
+    deferred_issued: bool,
+    /// When the collection started loading
+    started: Instant,
+    marker: PhantomData<T>,
+}
+
+/// Handles of a collection that started loading before its loading state
+///
+/// See [`LoadingState::preload_during`]
+#[derive(Resource)]
+pub(crate) struct PreloadedAssetHandles<T> {
+    /// Strong handles keeping the preloaded assets alive until the collection is loaded
+    #[allow(dead_code)]
+    handles: Vec<HandleUntyped>,
+    marker: PhantomData<T>,
+}
+
+/// Handles of a loaded collection that is recreated when its assets are hot reloaded
+///
+/// See [`LoadingState::expect_hot_reload`]
+#[derive(Resource)]
+pub(crate) struct ReloadingAssetHandles<T> {
+    handles: Vec<HandleUntyped>,
+    /// Handles that left the loaded state since the collection was last created
+    reloading: HashSet<HandleId>,
+    target_world: Option<TargetWorld>,
+    marker: PhantomData<T>,
+}
+
+/// Handles of a loaded collection that is recreated when its assets are modified
+///
+/// See [`LoadingState::rebuild_on_modify`]
+#[derive(Resource)]
+pub(crate) struct RebuildingAssetHandles<T> {
+    handles: HashSet<HandleId>,
+    /// Whether one of the handles was modified since the collection was last created
+    modified: bool,
+    /// Counts the rebuilds, so watchers can ignore modifications caused by a rebuild
+    generation: usize,
+    frames_since_rebuild: usize,
+    /// Rebuilds in a row with only a few frames in between
+    rapid_rebuilds: usize,
+    target_world: Option<TargetWorld>,
+    marker: PhantomData<T>,
+}
+
+/// Function returning the world to insert collections into
+///
+/// See [`LoadingState::target_world`]
+pub(crate) type TargetWorld = Arc<dyn Fn(&mut World) -> &mut World + Send + Sync>;
+
+/// Condition that has to be true before a loading state continues
+///
+/// See [`LoadingState::wait_for_input_with`]
+pub(crate) type WaitCondition = Arc<dyn Fn(&World) -> bool + Send + Sync>;
+
+/// File that has to match a content hash to continue to the next state
+///
+/// See [`LoadingState::continue_if_hash_matches`]
+#[cfg(feature = "content_hash")]
+pub(crate) struct HashCondition<State> {
+    pub(crate) path: String,
+    pub(crate) expected: [u8; 32],
+    pub(crate) else_state: State,
+}
+
+/// Called with the path and load state of every handle of a loading collection
+///
+/// See [`LoadingState::on_each_handle`]
+pub(crate) type HandleObserver = Arc<dyn Fn(&str, LoadState) + Send + Sync>;
+
+/// Predicate deciding whether a handle of a collection finished loading
+///
+/// See [`LoadingState::with_completion_check`]
+pub(crate) type CompletionCheck = Arc<dyn Fn(&World, &HandleUntyped) -> bool + Send + Sync>;
+
+/// Inserts a collection that was created in a background task
+///
+/// See [`LoadingState::async_create`]
+pub(crate) type InsertCollection = Box<dyn FnOnce(&mut World, Option<&TargetWorld>) + Send>;
+
+/// Receives the collection once its creation task completed
+pub(crate) type CreatedCollection = Arc<Mutex<Option<InsertCollection>>>;
+
+/// Prepares a collection and spawns the task creating it
+pub(crate) type SpawnCreateTask = fn(&mut World) -> CreatedCollection;
+
+/// Drops the loading progress of a single collection when its loading state is cancelled
+pub(crate) type CancelLoading = fn(&mut World);
+
+/// Keeps a single collection loading after its loading state was cancelled
+///
+/// See [`LoadingState::finish_in_background`]
+pub(crate) type FinishInBackground = fn(&mut World, Option<TargetWorld>);
+
+/// World that a collection with [`LoadingField`](crate::loading_field::LoadingField)s was inserted into
+///
+/// See [`LoadingState::target_world`]
+#[derive(Resource)]
+pub(crate) struct LoadingFieldsTarget<T> {
+    target_world: Option<TargetWorld>,
+    marker: PhantomData<T>,
+}
+
+/// Marks a collection that keeps loading after its loading state was cancelled
+///
+/// See [`LoadingState::finish_in_background`]
+#[derive(Resource)]
+pub(crate) struct BackgroundCollection<T> {
+    target_world: Option<TargetWorld>,
+    marker: PhantomData<T>,
+}
+
+impl<T> Default for LoadingAssetHandles<T> {
+    fn default() -> Self {
+        LoadingAssetHandles {
+            handles: Default::default(),
+            pending: Default::default(),
+            paths: Default::default(),
+            queued: Default::default(),
+            non_blocking: Default::default(),
+            deferred_issued: false,
+            started: Instant::now(),
+            marker: Default::default(),
+        }
+    }
+}
+
+/// Dump the tracked handles of `T` if it is currently loading
+///
+/// See [`LoadingDumpWorld::debug_dump_loading`](crate::loading_dump::LoadingDumpWorld::debug_dump_loading)
+pub(crate) fn dump_loading_handles<T: Send + Sync + 'static>(
+    world: &World,
+    collection: &'static str,
+) -> Option<CollectionDump> {
+    let loading_asset_handles = world.get_resource::<LoadingAssetHandles<T>>()?;
+    let asset_server = world.get_resource::<AssetServer>()?;
+    Some(CollectionDump {
+        collection,
+        handles: loading_asset_handles
+            .handles
+            .iter()
+            .map(|handle| DumpedHandle {
+                path: describe_handle(asset_server, handle.id()),
+                load_state: asset_server.get_load_state(handle.id()),
+            })
+            .collect(),
+        queued: loading_asset_handles.queued.len(),
+    })
+}
+
+/// Run condition that is true while the given [`AssetCollection`] is loading
+///
+/// The condition becomes true when the collection starts loading in a loading state and is false
+/// again as soon as the collection is inserted as a resource or its loading state failed.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy::asset::AssetPlugin;
+/// # fn main() {
+///     App::new()
+/// #       .add_state::<GameState>()
+/// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+/// #       .init_resource::<iyes_progress::ProgressCounter>()
+///         .add_loading_state(
+///           LoadingState::new(GameState::Loading)
+///             .continue_to_state(GameState::Menu)
+///         )
+///         .add_collection_to_loading_state::<_, AudioAssets>(GameState::Loading)
+///         .add_systems(Update, spin_audio_icon.run_if(collection_loading::<AudioAssets>()))
+/// #       .set_runner(|mut app| app.update())
+/// #       .run();
+/// # }
+/// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+/// # enum GameState {
+/// #     #[default]
+/// #     Loading,
+/// #     Menu
+/// # }
+/// # #[derive(AssetCollection, Resource)]
+/// # pub struct AudioAssets {
+/// #     #[asset(path = "audio/background.ogg")]
+/// #     pub background: Handle<AudioSource>,
+/// # }
+/// fn spin_audio_icon() {
+///     // rotate a loading icon
+/// }
+/// ```
+pub fn collection_loading<A: AssetCollection>() -> impl Condition<()> {
+    IntoSystem::into_system(|handles: Option<Res<LoadingAssetHandles<A>>>| handles.is_some())
+}
+
+fn loading_cancelled<S: States>(loading_state: S) -> impl Condition<()> {
+    IntoSystem::into_system(move |config: Res<AssetLoaderConfiguration<S>>| {
+        config
+            .state_configurations
+            .get(&loading_state)
+            .is_some_and(|config| config.cancelled)
+    })
+}
+
+/// Count the loaded assets of the given handles
+///
+/// Returns the number of loaded assets and the total number of handles. Loading states count the
+/// handles of their collections with the same function, so it can gate your own transitions on
+/// handles that were loaded outside of asset collections the same way. For collections, a
+/// [completion check](LoadingState::with_completion_check) or a replayed load state replaces the
+/// load state of the asset server. Assets that failed to load are not counted as loaded; use
+/// [`AssetServer::get_load_state`] to find them.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Resource)]
+/// struct LevelHandles(Vec<HandleUntyped>);
+///
+/// fn level_ready(asset_server: Res<AssetServer>, level: Res<LevelHandles>) -> bool {
+///     let (loaded, total) = all_loaded(&asset_server, &level.0);
+///     loaded == total
+/// }
+/// ```
+pub fn all_loaded(asset_server: &AssetServer, handles: &[HandleUntyped]) -> (usize, usize) {
+    count_loaded_by(handles, |handle_id| {
+        asset_server.get_load_state(handle_id) == LoadState::Loaded
+    })
+}
+
+/// Count the given handles that `is_loaded` accepts, together with the total number of handles
+pub(crate) fn count_loaded_by(
+    handles: &[HandleUntyped],
+    is_loaded: impl Fn(HandleId) -> bool,
+) -> (usize, usize) {
+    let loaded = handles
+        .iter()
+        .filter(|handle| is_loaded(handle.id()))
+        .count();
+    (loaded, handles.len())
+}
+
+/// Report modifications of assets of type `T` to collections of type `A`
+///
+/// Collections configured with [`LoadingState::rebuild_on_modify`] are recreated after a
+/// modification was reported. The derived [`AssetCollection::add_modification_watchers`] calls
+/// this function for all asset types of a collection. Implement it manually to watch other types.
+pub fn watch_modified_assets<A: AssetCollection, T: Asset>(app: &mut App) {
+    app.add_systems(Last, watch_modified_handles::<A, T>);
+}
+
+/// Add the systems of a collection type that run independent of its loading states
+///
+/// They are added once per collection type. Without a schedule from [`LoadingState::build_in`],
+/// they run in [`First`] and [`Update`].
+fn add_collection_systems<A: AssetCollection>(
+    app: &mut App,
+    collection_schedule: Option<BoxedScheduleLabel>,
+) {
+    let (first, update): (BoxedScheduleLabel, BoxedScheduleLabel) = match collection_schedule {
+        Some(schedule) => (schedule.clone(), schedule),
+        None => (Box::new(First), Box::new(Update)),
+    };
+    app.add_systems(first, recreate_reloaded_collection::<A>)
+        .add_systems(
+            update.clone(),
+            finish_collection_in_background::<A>
+                .run_if(resource_exists::<BackgroundCollection<A>>()),
+        );
+    if A::has_loading_fields() {
+        app.add_systems(update, update_loading_fields::<A>);
+    }
+}
+
+fn add_rebuild_systems<A: AssetCollection>(app: &mut App) {
+    A::add_modification_watchers(app);
+    app.add_systems(First, rebuild_modified_collection::<A>);
+}
+
+/// Resource listing the assets that failed to load in a loading state
+///
+/// The list is cleared whenever a loading state is entered. Together with
+/// [`LoadingState::on_failure_continue_to_state`], it can be used to inform the user about
+/// missing or broken assets.
+#[derive(Resource, Debug, Default)]
+pub struct FailedAssets {
+    /// Paths of the failed assets
+    pub paths: Vec<String>,
+}
+
+/// Resource with the assets for the loading screen of the current loading state
+///
+/// The assets are configured with [`LoadingState::with_loading_screen_assets`]. The resource is
+/// inserted when entering the loading state and removed when leaving it. Collections of the
+/// loading state only start loading after all loading screen assets finished loading.
+#[derive(Resource, Debug, Default)]
+pub struct LoadingScreenAssets {
+    handles: HashMap<String, HandleUntyped>,
+}
+
+impl LoadingScreenAssets {
+    /// The handle of the loading screen asset with the given key
+    pub fn get<T: Asset>(&self, key: &str) -> Option<Handle<T>> {
+        self.handles.get(key).map(|handle| handle.clone().typed())
+    }
+
+    /// The untyped handle of the loading screen asset with the given key
+    pub fn get_untyped(&self, key: &str) -> Option<&HandleUntyped> {
+        self.handles.get(key)
+    }
+
+    /// The handles of all loading screen assets
+    pub fn handles(&self) -> impl Iterator<Item = &HandleUntyped> {
+        self.handles.values()
+    }
+}
+
+/// Resource with the time the current loading state was entered
+///
+/// The resource is inserted when a loading state is entered and removed when it is left. It is
+/// also available while a loading state [stays in its state](LoadingState::stay_in_state) after
+/// loading finished. Use it to rotate tips on a loading screen or to report loading times.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// const TIPS: [&str; 2] = ["Jump with space", "Crouch with shift"];
+///
+/// fn rotate_tips(time: Option<Res<LoadingStateTime>>) {
+///     if let Some(time) = time {
+///         let tip = TIPS[time.elapsed().as_secs() as usize / 5 % TIPS.len()];
+///         info!("Tip: {tip}");
+///     }
+/// }
+/// ```
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct LoadingStateTime {
+    entered: Instant,
+}
+
+impl LoadingStateTime {
+    /// The moment the current loading state was entered
+    pub fn entered(&self) -> Instant {
+        self.entered
+    }
+
+    /// Time since the current loading state was entered
+    pub fn elapsed(&self) -> Duration {
+        self.entered.elapsed()
+    }
+}
 
-        app.init_resource::<DynamicAssets>();
-        let mut dynamic_assets = app.world.get_resource_mut::<DynamicAssets>().unwrap();
-        for (key, asset) in self.dynamic_assets {
-            dynamic_assets.register_asset(key, asset);
-        }
+/// Resource with the time each asset collection took to load
+///
+/// The time is measured from the moment a loading state starts loading the collection until all
+/// of its assets are loaded, before the collection is created. Loading a collection again, for
+/// example when re-entering its loading state, replaces its timing.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// fn log_timings(timings: Res<CollectionTimings>) {
+///     for (collection, duration) in timings.iter() {
+///         info!("{collection} took {duration:?} to load");
+///     }
+/// }
+/// ```
+#[derive(Resource, Debug, Default)]
+pub struct CollectionTimings {
+    timings: HashMap<&'static str, Duration>,
+}
+
+impl CollectionTimings {
+    /// Time the given collection took to load
+    pub fn get<A: AssetCollection>(&self) -> Option<Duration> {
+        self.get_by_name(type_name::<A>())
+    }
+
+    /// Time the collection with the given type name took to load
+    pub fn get_by_name(&self, collection: &str) -> Option<Duration> {
+        self.timings.get(collection).copied()
+    }
+
+    /// Iterate over the type names of all loaded collections and their loading times
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, Duration)> + '_ {
+        self.timings
+            .iter()
+            .map(|(collection, duration)| (*collection, *duration))
+    }
+
+    pub(crate) fn insert<A: AssetCollection>(&mut self, duration: Duration) {
+        self.timings.insert(type_name::<A>(), duration);
     }
 }
 
-///  Systems in this set check the loading state of assets and will change the [`InternalLoadingState`] accordingly.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemSet)]
-pub(crate) struct LoadingStateSet<S: States>(S);
+/// Event sent when a single asset of a collection finished loading
+///
+/// Loading states send the event once per asset, in the first frame the asset is loaded. This can
+/// be used to tick off the entries of a checklist while loading.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// fn tick_off_assets(mut loaded: EventReader<AssetLoadedWithin>) {
+///     for event in loaded.iter() {
+///         info!("Loaded '{}' for {}", event.path, event.collection);
+///     }
+/// }
+/// ```
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct AssetLoadedWithin {
+    /// Type name of the asset collection the asset belongs to
+    pub collection: &'static str,
+    /// Path of the loaded asset
+    pub path: String,
+}
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemSet)]
-pub(crate) enum InternalLoadingStateSet {
-    Initialize,
-    CheckDynamicAssetCollections,
-    ResumeDynamicAssetCollections,
-    CheckAssets,
-    Finalize,
+/// Event sent when the fraction of the [`LoadingProgress`] changed noticeably
+///
+/// Loading states send the event when the fraction moved by more than the
+/// [threshold](LoadingState::progress_event_threshold) since the last event, and once it reaches 1.
+/// Systems following the progress, like a splash video seeking to the current fraction, can
+/// react to the event instead of polling the progress every frame.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// fn seek_splash_video(mut progress: EventReader<LoadingProgressChanged<GameState>>) {
+///     if let Some(event) = progress.iter().last() {
+///         info!("Seeking to {:.0}% of the splash video", event.fraction * 100.);
+///     }
+/// }
+/// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+/// # enum GameState {
+/// #     #[default]
+/// #     Loading,
+/// #     Menu
+/// # }
+/// ```
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct LoadingProgressChanged<S: States> {
+    /// The loading state whose progress changed
+    pub state: S,
+    /// The new [fraction](LoadingProgress::fraction) of the loading state
+    pub fraction: f32,
 }
 
-#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
-pub(crate) struct OnEnterInternalLoadingState<S: States>(pub S, pub InternalLoadingState<S>);
-#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
-pub(crate) struct LoadingStateSchedule<S: States>(pub S);
+/// Event sent when a loading state finished loading all of its asset collections
+///
+/// The event is sent right before the loading state continues to its next state. With
+/// [`LoadingState::no_auto_transition`], the loading state does not change the state itself and
+/// this event signals your own systems that it is safe to do so.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// fn log_completed(mut complete: EventReader<LoadingComplete<GameState>>) {
+///     for event in complete.iter() {
+///         info!("{:?} is done, next up: {:?}", event.state, event.next);
+///     }
+/// }
+/// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+/// # enum GameState {
+/// #     #[default]
+/// #     Loading,
+/// #     Menu
+/// # }
+/// ```
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct LoadingComplete<S: States> {
+    /// The loading state that finished
+    pub state: S,
+    /// The configured [next state](LoadingState::continue_to_state) of the loading state
+    pub next: Option<S>,
+}
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
-pub(crate) enum InternalLoadingState<S: States> {
-    /// Starting point. Here it will be decided whether or not dynamic asset collections need to be loaded.
-    #[default]
-    Initialize,
-    /// Load dynamic asset collections and configure their key <-> asset mapping
-    LoadingDynamicAssetCollections,
-    /// Load the actual asset collections and check their status every frame.
-    LoadingAssets,
-    /// All collections are loaded and inserted. Time to e.g. run custom [insert_resource](bevy_asset_loader::AssetLoader::insert_resource).
-    Finalize,
-    /// A 'parking' state in case no next state is defined
-    Done(PhantomData<S>),
+/// Event sent when a loading state is left before it finished loading
+///
+/// All handles of unfinished collections were dropped and the loading state will start
+/// from scratch the next time it is entered. See [`LoadingState::on_cancel`]
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct LoadingCancelled<S: States> {
+    /// The loading state that was cancelled
+    pub state: S,
 }
 
-impl<S: States> States for InternalLoadingState<S> {
-    type Iter = IntoIter<Self, 5>;
+/// Resource with the combined loading progress of the current loading state of the type `S`
+///
+/// Every [`States`] type has its own progress, so loading states of different types can run at
+/// the same time. The progress is reset whenever a loading state of the type is entered. With
+/// [`LoadingState::batch_size`], not all assets start loading right away. Until a load request
+/// is issued, it counts as a single asset in `total`.
+///
+/// Dynamic asset collection files count as assets while they load and every collection that did
+/// not start loading yet as a single asset. See [`LoadingState::dynamic_assets_file_weight`].
+///
+/// Every asset has a weight of 1, unless its field sets a different one with
+/// `#[asset(weight = 5.0)]`. The [`fraction`](LoadingProgress::fraction) is the weight of the
+/// loaded assets divided by the weight of all assets. Giving gameplay-critical assets a high
+/// weight lets the progress reach most of the way once the game is playable.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// fn show_progress(progress: Res<LoadingProgress<GameState>>) {
+///     if progress.issued < progress.total {
+///         info!("Requesting assets...");
+///     } else {
+///         info!("Loading assets... {:.0}%", progress.fraction() * 100.);
+///     }
+/// }
+/// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+/// # enum GameState {
+/// #     #[default]
+/// #     Loading,
+/// #     Menu
+/// # }
+/// ```
+#[derive(Resource, Debug, Default)]
+pub struct LoadingProgress<S: States> {
+    /// Number of assets the loading state waits for
+    pub total: usize,
+    /// Number of assets that started loading
+    pub issued: usize,
+    /// Number of assets that finished loading
+    pub loaded: usize,
+    /// Combined weight of all assets the loading state waits for
+    pub total_weight: f32,
+    /// Combined weight of the assets that finished loading
+    pub loaded_weight: f32,
+    collections: HashMap<TypeId, CollectionProgress>,
+    marker: PhantomData<S>,
+}
 
-    fn variants() -> Self::Iter {
-        [
-            Self::Initialize,
-            Self::LoadingDynamicAssetCollections,
-            Self::LoadingAssets,
-            Self::Finalize,
-            Self::Done(PhantomData::default()),
-        ]
-        .into_iter()
+/// Loading progress of a single collection
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct CollectionProgress {
+    pub(crate) total: usize,
+    pub(crate) issued: usize,
+    pub(crate) loaded: usize,
+    pub(crate) total_weight: f32,
+    pub(crate) loaded_weight: f32,
+}
+
+impl<S: States> LoadingProgress<S> {
+    /// Weighted fraction of loaded assets between 0 and 1
+    ///
+    /// Returns 0 as long as the total number of assets is not known. If all assets have a
+    /// weight of 0, every asset counts the same.
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            return 0.;
+        }
+        if self.total_weight <= 0. {
+            return self.loaded as f32 / self.total as f32;
+        }
+        self.loaded_weight / self.total_weight
+    }
+
+    pub(crate) fn update<T: 'static>(&mut self, progress: CollectionProgress) {
+        self.collections.insert(TypeId::of::<T>(), progress);
+        let combined = self.collections.values().fold(
+            CollectionProgress::default(),
+            |combined, collection| CollectionProgress {
+                total: combined.total + collection.total,
+                issued: combined.issued + collection.issued,
+                loaded: combined.loaded + collection.loaded,
+                total_weight: combined.total_weight + collection.total_weight,
+                loaded_weight: combined.loaded_weight + collection.loaded_weight,
+            },
+        );
+        self.total = combined.total;
+        self.issued = combined.issued;
+        self.loaded = combined.loaded;
+        self.total_weight = combined.total_weight;
+        self.loaded_weight = combined.loaded_weight;
+    }
+
+    pub(crate) fn reset(&mut self) {
+        *self = LoadingProgress::default();
     }
 }
 
-/// This resource is used for handles from asset collections and loading dynamic asset collection files.
-/// The generic will be the [`AssetCollection`] type for the first and the [`DynamicAssetCollection`] for the second.
-#[derive(Resource)]
-pub(crate) struct LoadingAssetHandles<T> {
-    handles: Vec<HandleUntyped>,
-    marker: PhantomData<T>,
+/// Resource with the combined loading progress of a sequence of loading states of the type `S`
+///
+/// Added by [`LoadingStateAppExt::add_loading_sequence`]. Every loading state of the sequence
+/// is weighted by the number of collections added to it, with a minimum weight of 1. A
+/// finished loading state contributes its full weight, the current loading state the weight
+/// times its [`LoadingProgress::fraction`] and later loading states nothing. The weights are
+/// recomputed every frame in [`PostUpdate`], so collections added while the sequence runs are
+/// included.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// fn show_startup_progress(progress: Res<SequenceProgress<GameState>>) {
+///     info!(
+///         "Starting up... {:.0}% ({}/{} loading states)",
+///         progress.fraction() * 100.,
+///         progress.finished,
+///         progress.states
+///     );
+/// }
+/// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+/// # enum GameState {
+/// #     #[default]
+/// #     Boot,
+/// #     Menu
+/// # }
+/// ```
+#[derive(Resource, Debug, Default)]
+pub struct SequenceProgress<S: States> {
+    /// Number of loading states in the sequence
+    pub states: usize,
+    /// Number of loading states of the sequence that finished
+    pub finished: usize,
+    /// Combined weight of all loading states in the sequence
+    pub total_weight: f32,
+    /// Weight of the finished loading states plus the loaded part of the current one
+    pub loaded_weight: f32,
+    marker: PhantomData<S>,
 }
 
-impl<T> Default for LoadingAssetHandles<T> {
-    fn default() -> Self {
-        LoadingAssetHandles {
-            handles: Default::default(),
-            marker: Default::default(),
+impl<S: States> SequenceProgress<S> {
+    /// Weighted fraction of the sequence that finished loading between 0 and 1
+    pub fn fraction(&self) -> f32 {
+        if self.total_weight <= 0. {
+            return 0.;
         }
+        self.loaded_weight / self.total_weight
     }
 }
 
+/// The loading states of a [`SequenceProgress`] and whether they finished
+#[derive(Resource)]
+pub(crate) struct LoadingSequence<S: States> {
+    pub(crate) states: Vec<S>,
+    pub(crate) finished: Vec<bool>,
+}
+
+/// Resource holding the configuration of all loading states of the same [`States`] type
+///
+/// The configuration is built from the [`LoadingState`]s added to the app and can be read
+/// to inspect how the loading states were set up.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy_asset_loader::loading_state::AssetLoaderConfiguration;
+/// # use bevy::prelude::*;
+/// # use bevy::asset::AssetPlugin;
+/// # fn main() {
+///     let mut app = App::new();
+///     app
+/// #       .add_state::<GameState>()
+/// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+/// #       .init_resource::<iyes_progress::ProgressCounter>()
+///         .add_loading_state(
+///           LoadingState::new(GameState::Loading)
+///             .continue_to_state(GameState::Menu)
+///             .on_failure_continue_to_state(GameState::Error)
+///         );
+///
+///     let configuration = app.world.resource::<AssetLoaderConfiguration<GameState>>();
+///     assert_eq!(configuration.next_state_for(&GameState::Loading), Some(&GameState::Menu));
+///     assert_eq!(configuration.failure_state_for(&GameState::Loading), Some(&GameState::Error));
+///     assert_eq!(configuration.failure_state_for(&GameState::Menu), None);
+/// # }
+/// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+/// # enum GameState {
+/// #     #[default]
+/// #     Loading,
+/// #     Menu,
+/// #     Error
+/// # }
+/// ```
 #[derive(Resource)]
-pub(crate) struct AssetLoaderConfiguration<State: States> {
+pub struct AssetLoaderConfiguration<State: States> {
     state_configurations: HashMap<State, LoadingConfiguration<State>>,
 }
 
+impl<State: States> AssetLoaderConfiguration<State> {
+    /// The state the given loading state continues to after all collections are loaded
+    ///
+    /// See [`LoadingState::continue_to_state`]
+    pub fn next_state_for(&self, loading_state: &State) -> Option<&State> {
+        self.state_configurations
+            .get(loading_state)
+            .and_then(|config| config.next.as_ref())
+    }
+
+    /// The state the given loading state continues to if an asset fails to load
+    ///
+    /// See [`LoadingState::on_failure_continue_to_state`]
+    pub fn failure_state_for(&self, loading_state: &State) -> Option<&State> {
+        self.state_configurations
+            .get(loading_state)
+            .and_then(|config| config.failure.as_ref())
+    }
+
+    /// Type names of the asset collections added to the given loading state
+    ///
+    /// The names are in the order the collections were added with
+    /// [`LoadingStateAppExt::add_collection_to_loading_state`]. This helps to check which
+    /// collections plugins contributed to a loading state, e.g. in a diagnostics panel.
+    pub fn collections_for(&self, loading_state: &State) -> &[&'static str] {
+        self.state_configurations
+            .get(loading_state)
+            .map(|config| config.collections.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Names of the asset types loaded by the collections of the given loading state
+    ///
+    /// The names are sorted and include the types of all collections added to the loading state
+    /// so far. See [`AssetCollection::asset_types`]
+    pub fn asset_types_for(&self, loading_state: &State) -> Vec<&'static str> {
+        let mut asset_types: Vec<_> = self
+            .state_configurations
+            .get(loading_state)
+            .map(|config| config.asset_types.iter().copied().collect())
+            .unwrap_or_default();
+        asset_types.sort_unstable();
+        asset_types
+    }
+
+    /// Meta data of the collections of the given loading state by collection type name
+    ///
+    /// The collections are sorted by their type name. Collections without meta data are included
+    /// with an empty list. See [`AssetCollection::meta`]
+    pub fn collection_meta_for(
+        &self,
+        loading_state: &State,
+    ) -> Vec<(&'static str, &'static [(&'static str, &'static str)])> {
+        let mut meta: Vec<_> = self
+            .state_configurations
+            .get(loading_state)
+            .map(|config| {
+                config
+                    .collection_meta
+                    .iter()
+                    .map(|(collection, meta)| (*collection, *meta))
+                    .collect()
+            })
+            .unwrap_or_default();
+        meta.sort_unstable_by_key(|(collection, _)| *collection);
+        meta
+    }
+}
+
 impl<State: States> Default for AssetLoaderConfiguration<State> {
     fn default() -> Self {
         AssetLoaderConfiguration {
@@ -520,9 +3417,73 @@ impl<State: States> Default for AssetLoaderConfiguration<State> {
 struct LoadingConfiguration<State: States> {
     next: Option<State>,
     failure: Option<State>,
+    #[cfg(feature = "content_hash")]
+    hash_condition: Option<HashCondition<State>>,
+    stay_in_state: bool,
+    finish_in_background: bool,
+    no_auto_transition: bool,
+    verbose_logging: bool,
+    batch_size: Option<usize>,
+    max_concurrent_loads: HashMap<&'static str, usize>,
+    /// Asset types the loading state does not wait for
+    non_blocking_types: HashSet<&'static str>,
+    settle_frames: usize,
+    /// Frames left to wait after all assets of the current run finished loading
+    remaining_settle_frames: usize,
+    delay_start: Duration,
+    /// When the loading state was entered for the current run
+    entered: Option<Instant>,
+    wait_condition: Option<WaitCondition>,
+    handle_observers: Vec<HandleObserver>,
+    /// Predicates replacing the [`LoadState::Loaded`] check for the handles of single collections
+    completion_checks: HashMap<TypeId, CompletionCheck>,
+    expect_hot_reload: bool,
+    collect_errors: bool,
+    tracked_handles: Vec<HandleUntyped>,
+    target_world: Option<TargetWorld>,
+    async_collections: HashMap<TypeId, SpawnCreateTask>,
+    /// Collections that are recreated when their assets are modified
+    rebuild_on_modify: HashSet<TypeId>,
+    /// Tasks creating collections of the current run
+    creating_collections: Vec<CreatedCollection>,
+    /// Task hashing the file of the hash condition in the current run
+    #[cfg(feature = "content_hash")]
+    hashing_file: Option<FileHashSlot>,
+    asset_types: HashSet<&'static str>,
+    /// Type names of the collections in the order they were added
+    collections: Vec<&'static str>,
+    collection_meta: HashMap<&'static str, &'static [(&'static str, &'static str)]>,
+    /// Whether the tracked handles count as a loading collection of the current run
+    tracking_handles: bool,
     loading_failed: bool,
+    /// Whether the loading state was left before it finished the last time
+    cancelled: bool,
+    /// Cleanup of every collection of the loading state
+    cancel_loading: Vec<CancelLoading>,
+    /// Moves every collection of the loading state to the background when it is cancelled
+    ///
+    /// See [`LoadingState::finish_in_background`]
+    finish_loading: Vec<FinishInBackground>,
+    /// Update schedule of [`LoadingState::build_in`] for the systems of collections outside of the loading state
+    collection_schedule: Option<BoxedScheduleLabel>,
+    /// Whether the next state was checked on the first entry of the loading state
+    #[cfg(not(feature = "progress_tracking"))]
+    checked_next_state: bool,
+    /// Issued loads of asset types with a [concurrency limit](LoadingState::max_concurrent_loads_for)
+    loads_in_flight: HashMap<&'static str, Vec<HandleId>>,
     loading_collections: usize,
     loading_dynamic_collections: HashSet<TypeId>,
+    /// Progress weight of every dynamic asset collection file
+    dynamic_assets_file_weight: f32,
+    progress_event_threshold: f32,
+    /// Fraction of the last sent [`LoadingProgressChanged`] event in the current run
+    last_progress_event: f32,
+    /// Command line flags and the keys their values are registered for
+    cli_overrides: Vec<(String, String)>,
+    loading_screen_assets: Vec<(String, String)>,
+    /// Collections uploading data in the render world before the loading state finishes
+    #[cfg(any(feature = "2d", feature = "3d"))]
+    render_uploads: HashSet<TypeId>,
 }
 
 impl<State: States> Default for LoadingConfiguration<State> {
@@ -530,9 +3491,52 @@ impl<State: States> Default for LoadingConfiguration<State> {
         LoadingConfiguration {
             next: None,
             failure: None,
+            #[cfg(feature = "content_hash")]
+            hash_condition: None,
+            stay_in_state: false,
+            finish_in_background: false,
+            no_auto_transition: false,
+            verbose_logging: false,
+            batch_size: None,
+            max_concurrent_loads: default(),
+            non_blocking_types: default(),
+            settle_frames: 0,
+            remaining_settle_frames: 0,
+            delay_start: Duration::ZERO,
+            entered: None,
+            wait_condition: None,
+            handle_observers: vec![],
+            completion_checks: default(),
+            expect_hot_reload: false,
+            collect_errors: false,
+            tracked_handles: vec![],
+            target_world: None,
+            async_collections: default(),
+            rebuild_on_modify: default(),
+            creating_collections: vec![],
+            #[cfg(feature = "content_hash")]
+            hashing_file: None,
+            asset_types: default(),
+            collections: vec![],
+            collection_meta: default(),
+            tracking_handles: false,
             loading_failed: false,
+            cancelled: false,
+            cancel_loading: vec![],
+            finish_loading: vec![],
+            collection_schedule: None,
+            #[cfg(not(feature = "progress_tracking"))]
+            checked_next_state: false,
+            loads_in_flight: default(),
             loading_collections: 0,
             loading_dynamic_collections: default(),
+            dynamic_assets_file_weight: 1.,
+            progress_event_threshold: 0.01,
+            last_progress_event: 0.,
+            cli_overrides: vec![],
+            loading_screen_assets: vec![],
+            #[cfg(any(feature = "2d", feature = "3d"))]
+            render_uploads: default(),
         }
     }
 }
@@ -616,6 +3620,81 @@ pub trait LoadingStateAppExt {
         file: &str,
     ) -> &mut Self;
 
+    /// Register files to be loaded as a manifest of type `M` and expanded into dynamic assets
+    ///
+    /// This works like [`add_dynamic_collection_to_loading_state`](Self::add_dynamic_collection_to_loading_state),
+    /// but the manifest does not have to implement [`DynamicAssetCollection`]. Instead, the given
+    /// `expand` function registers dynamic assets based on the content of each loaded manifest.
+    /// All manifests of a loading state are loaded and expanded before any asset collection
+    /// of that state starts loading, so collections can use the keys registered by `expand`.
+    ///
+    /// You need to register a loader for your manifest type yourself. For every manifest type
+    /// and loading state, the `expand` function of the first call is used.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # use bevy::reflect::{TypePath, TypeUuid};
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///         )
+    ///         .add_manifest_to_loading_state::<_, Manifest>(
+    ///             GameState::Loading,
+    ///             "level.manifest",
+    ///             expand_manifest,
+    ///         )
+    ///         .add_collection_to_loading_state::<_, LevelAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// #[derive(TypeUuid, TypePath)]
+    /// #[uuid = "7b4b9ac0-a5b1-4a2c-8e6e-2b9bc8d3a6f4"]
+    /// struct Manifest {
+    ///     background: String,
+    /// }
+    ///
+    /// fn expand_manifest(manifest: &Manifest, dynamic_assets: &mut DynamicAssets) {
+    ///     dynamic_assets.register_asset(
+    ///         "background",
+    ///         Box::new(ImageFile(manifest.background.clone())),
+    ///     );
+    /// }
+    /// # #[derive(Debug)]
+    /// # struct ImageFile(String);
+    /// # impl DynamicAsset for ImageFile {
+    /// #     fn load(&self, asset_server: &AssetServer) -> Vec<HandleUntyped> {
+    /// #         vec![asset_server.load_untyped(&self.0)]
+    /// #     }
+    /// #     fn build(&self, world: &mut World) -> Result<DynamicAssetType, anyhow::Error> {
+    /// #         Ok(DynamicAssetType::Single(world.resource::<AssetServer>().get_handle_untyped(&self.0)))
+    /// #     }
+    /// # }
+    ///
+    /// #[derive(AssetCollection, Resource)]
+    /// struct LevelAssets {
+    ///     #[asset(key = "background")]
+    ///     background: Handle<Image>,
+    /// }
+    /// ```
+    fn add_manifest_to_loading_state<S: States, M: Asset>(
+        &mut self,
+        loading_state: S,
+        file: &str,
+        expand: fn(&M, &mut DynamicAssets),
+    ) -> &mut Self;
+
     /// Add any [`FromWorld`] resource to be initialized after all asset collections are loaded.
     /// ```edition2021
     /// # use bevy_asset_loader::prelude::*;
@@ -665,6 +3744,46 @@ pub trait LoadingStateAppExt {
         &mut self,
         loading_state: S,
     ) -> &mut Self;
+
+    /// Track the combined progress of loading states that run one after another
+    ///
+    /// The [`SequenceProgress`] resource reports the progress over all given loading states, so
+    /// a single progress bar can cover e.g. booting, the menu and the first level. A loading
+    /// state counts as finished once it sent [`LoadingComplete`] and stays finished afterwards.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .add_state::<GameState>()
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Boot).continue_to_state(GameState::Menu)
+    ///         )
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Menu).continue_to_state(GameState::Playing)
+    ///         )
+    ///         .add_collection_to_loading_state::<_, MenuAssets>(GameState::Menu)
+    ///         .add_loading_sequence(&[GameState::Boot, GameState::Menu])
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Boot,
+    /// #     Menu,
+    /// #     Playing
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct MenuAssets {
+    /// #     #[asset(path = "audio/background.ogg")]
+    /// #     pub background: Handle<AudioSource>,
+    /// # }
+    /// ```
+    fn add_loading_sequence<S: States>(&mut self, states: &[S]) -> &mut Self;
 }
 
 impl LoadingStateAppExt for App {
@@ -678,14 +3797,53 @@ impl LoadingStateAppExt for App {
         &mut self,
         loading_state: S,
     ) -> &mut Self {
+        self.init_resource::<AssetLoaderConfiguration<S>>();
+        register_collection_types::<A>(&self.world);
+        let first_registration = self
+            .world
+            .get_resource_or_insert_with(LoadingHandleRegistry::default)
+            .register::<A>(|world| dump_loading_handles::<A>(world, type_name::<A>()));
+        let mut collection_schedule = None;
+        {
+            let mut asset_loader_configuration =
+                self.world.resource_mut::<AssetLoaderConfiguration<S>>();
+            let config = asset_loader_configuration
+                .state_configurations
+                .entry(loading_state.clone())
+                .or_default();
+            if first_registration {
+                collection_schedule = Some(config.collection_schedule.clone());
+            }
+            config.asset_types.extend(A::asset_types());
+            if !config.collections.contains(&type_name::<A>()) {
+                config.collections.push(type_name::<A>());
+                config.cancel_loading.push(cancel_loading_collection::<A>);
+                config
+                    .finish_loading
+                    .push(move_collection_to_background::<A>);
+            }
+            config.collection_meta.insert(type_name::<A>(), A::meta());
+        }
         self.add_systems(
+            OnEnterInternalLoadingState(
+                loading_state.clone(),
+                InternalLoadingState::LoadingDynamicAssetCollections,
+            ),
+            count_pending_collection::<S, A>,
+        )
+        .add_systems(
             OnEnterInternalLoadingState(loading_state.clone(), InternalLoadingState::LoadingAssets),
-            start_loading_collection::<S, A>,
+            start_loading_collection::<S, A>.in_set(InternalLoadingStateSet::StartLoading),
         )
         .add_systems(
-            LoadingStateSchedule(loading_state),
+            LoadingStateSchedule(loading_state.clone()),
             check_loading_collection::<S, A>.in_set(InternalLoadingStateSet::CheckAssets),
         )
+        .add_systems(PreloadLoadingState(loading_state), preload_collection::<A>);
+        if let Some(collection_schedule) = collection_schedule {
+            add_collection_systems::<A>(self, collection_schedule);
+        }
+        self
     }
 
     fn add_dynamic_collection_to_loading_state<S: States, C: DynamicAssetCollection + Asset>(
@@ -699,6 +3857,19 @@ impl LoadingStateAppExt for App {
             .unwrap();
 
         if dynamic_asset_collections.register_file::<C>(loading_state.clone(), file) {
+            self.init_resource::<AssetLoaderConfiguration<S>>();
+            self.world
+                .get_resource_or_insert_with(LoadingHandleRegistry::default)
+                .register::<(S, C)>(|world| {
+                    dump_loading_handles::<(S, C)>(world, type_name::<C>())
+                });
+            self.world
+                .resource_mut::<AssetLoaderConfiguration<S>>()
+                .state_configurations
+                .entry(loading_state.clone())
+                .or_default()
+                .cancel_loading
+                .push(cancel_loading_dynamic_asset_collections::<S, C>);
             self.add_systems(
                 OnEnterInternalLoadingState(
                     loading_state.clone(),
@@ -716,6 +3887,51 @@ impl LoadingStateAppExt for App {
         self
     }
 
+    fn add_manifest_to_loading_state<S: States, M: Asset>(
+        &mut self,
+        loading_state: S,
+        file: &str,
+        expand: fn(&M, &mut DynamicAssets),
+    ) -> &mut Self {
+        let mut dynamic_asset_collections = self
+            .world
+            .get_resource_mut::<DynamicAssetCollections<S>>()
+            .unwrap();
+
+        if dynamic_asset_collections.register_file::<M>(loading_state.clone(), file) {
+            self.init_resource::<AssetLoaderConfiguration<S>>();
+            self.world
+                .get_resource_or_insert_with(LoadingHandleRegistry::default)
+                .register::<(S, M)>(|world| {
+                    dump_loading_handles::<(S, M)>(world, type_name::<M>())
+                });
+            self.world
+                .resource_mut::<AssetLoaderConfiguration<S>>()
+                .state_configurations
+                .entry(loading_state.clone())
+                .or_default()
+                .cancel_loading
+                .push(cancel_loading_dynamic_asset_collections::<S, M>);
+            self.add_systems(
+                OnEnterInternalLoadingState(
+                    loading_state.clone(),
+                    InternalLoadingState::LoadingDynamicAssetCollections,
+                ),
+                load_dynamic_asset_collections::<S, M>,
+            )
+            .add_systems(
+                LoadingStateSchedule(loading_state),
+                (move |world: &mut World,
+                       system_state: &mut CheckDynamicAssetCollectionsState<S, M>| {
+                    check_manifests(world, system_state, expand)
+                })
+                .in_set(InternalLoadingStateSet::CheckDynamicAssetCollections),
+            );
+        }
+
+        self
+    }
+
     fn init_resource_after_loading_state<S: States, A: Resource + FromWorld>(
         &mut self,
         loading_state: S,
@@ -725,6 +3941,20 @@ impl LoadingStateAppExt for App {
             init_resource::<A>,
         )
     }
+
+    fn add_loading_sequence<S: States>(&mut self, states: &[S]) -> &mut Self {
+        self.init_resource::<LoadingProgress<S>>()
+            .insert_resource(SequenceProgress::<S> {
+                states: states.len(),
+                ..default()
+            })
+            .add_event::<LoadingComplete<S>>()
+            .insert_resource(LoadingSequence {
+                states: states.to_vec(),
+                finished: vec![false; states.len()],
+            })
+            .add_systems(PostUpdate, update_sequence_progress::<S>)
+    }
 }
 
 struct InternalAssetLoaderPlugin<S> {
@@ -1,5 +1,8 @@
 use crate::dynamic_asset::{DynamicAssetCollection, DynamicAssetCollections, DynamicAssets};
-use crate::loading_state::{AssetLoaderConfiguration, InternalLoadingState, LoadingAssetHandles};
+use crate::loading_state::{
+    AssetLoaderConfiguration, CollectionProgress, InternalLoadingState, LoadingAssetHandles,
+    LoadingProgress,
+};
 use bevy::asset::{Asset, AssetServer, Assets, LoadState};
 use bevy::ecs::change_detection::ResMut;
 use bevy::ecs::schedule::{NextState, State, States};
@@ -8,8 +11,11 @@ use bevy::ecs::world::World;
 use bevy::log::debug;
 use std::any::TypeId;
 
+#[cfg(feature = "progress_tracking")]
+use iyes_progress::{Progress, ProgressCounter};
+
 #[allow(clippy::type_complexity)]
-pub(crate) fn load_dynamic_asset_collections<S: States, C: DynamicAssetCollection + Asset>(
+pub(crate) fn load_dynamic_asset_collections<S: States, C: Asset>(
     world: &mut World,
     system_state: &mut SystemState<(
         Res<DynamicAssetCollections<S>>,
@@ -38,42 +44,89 @@ pub(crate) fn load_dynamic_asset_collections<S: States, C: DynamicAssetCollectio
     world.insert_resource(loading_collections);
 }
 
-#[allow(clippy::type_complexity)]
+pub(crate) type CheckDynamicAssetCollectionsState<'w, S, C> = SystemState<(
+    Res<'w, AssetServer>,
+    Option<ResMut<'w, LoadingAssetHandles<(S, C)>>>,
+    Res<'w, State<S>>,
+    Res<'w, Assets<C>>,
+    ResMut<'w, DynamicAssets>,
+    ResMut<'w, AssetLoaderConfiguration<S>>,
+)>;
+
 pub(crate) fn check_dynamic_asset_collections<S: States, C: DynamicAssetCollection + Asset>(
     world: &mut World,
-    system_state: &mut SystemState<(
-        Res<AssetServer>,
-        Option<ResMut<LoadingAssetHandles<(S, C)>>>,
-        Res<State<S>>,
-        Res<Assets<C>>,
-        ResMut<DynamicAssets>,
-        ResMut<AssetLoaderConfiguration<S>>,
-    )>,
+    system_state: &mut CheckDynamicAssetCollectionsState<S, C>,
+) {
+    check_manifests(world, system_state, C::register);
+}
+
+/// Register the content of all loaded files with the given function once all of them finished loading
+///
+/// Until then, the files count towards the [`LoadingProgress`] of the loading state.
+pub(crate) fn check_manifests<S: States, C: Asset>(
+    world: &mut World,
+    system_state: &mut CheckDynamicAssetCollectionsState<S, C>,
+    register: fn(&C, &mut DynamicAssets),
 ) {
+    let (total, loaded, weight) = {
+        let (asset_server, loading_collections, state, _, _, asset_loader_config) =
+            system_state.get_mut(world);
+        let Some(loading_collections) = loading_collections else {
+            return;
+        };
+        let loaded = loading_collections
+            .handles
+            .iter()
+            .filter(|handle| asset_server.get_load_state(handle.id()) == LoadState::Loaded)
+            .count();
+        let weight = asset_loader_config
+            .state_configurations
+            .get(state.get())
+            .map_or(1., |config| config.dynamic_assets_file_weight);
+        (loading_collections.handles.len(), loaded, weight)
+    };
+    // The files are keyed by their loading state and type, so they cannot collide with collections
+    world
+        .resource_mut::<LoadingProgress<S>>()
+        .update::<(S, C)>(CollectionProgress {
+            total,
+            issued: total,
+            loaded,
+            total_weight: total as f32 * weight,
+            loaded_weight: loaded as f32 * weight,
+        });
+    if loaded < total {
+        #[cfg(feature = "progress_tracking")]
+        world
+            .resource::<ProgressCounter>()
+            .manually_track(Progress {
+                done: loaded as u32,
+                total: total as u32,
+            });
+        return;
+    }
+    #[cfg(feature = "progress_tracking")]
+    world
+        .resource_mut::<ProgressCounter>()
+        .persist_progress(Progress {
+            done: loaded as u32,
+            total: total as u32,
+        });
     {
         let (
-            asset_server,
+            _,
             mut loading_collections,
             state,
             dynamic_asset_collections,
             mut asset_keys,
             mut asset_loader_config,
         ) = system_state.get_mut(world);
-
-        if loading_collections.is_none() {
-            return;
-        }
         let loading_collections = loading_collections.as_mut().unwrap();
-        let collections_load_state = asset_server
-            .get_group_load_state(loading_collections.handles.iter().map(|handle| handle.id()));
-        if collections_load_state != LoadState::Loaded {
-            return;
-        }
         for collection in loading_collections.handles.drain(..) {
             let collection = dynamic_asset_collections
                 .get(&collection.typed_weak::<C>())
                 .unwrap();
-            collection.register(&mut asset_keys);
+            register(collection, &mut asset_keys);
         }
         let config = asset_loader_config
             .state_configurations
@@ -86,17 +139,29 @@ pub(crate) fn check_dynamic_asset_collections<S: States, C: DynamicAssetCollecti
     world.remove_resource::<LoadingAssetHandles<(S, C)>>();
 }
 
+/// Drops the handles of a dynamic asset collection file that did not finish loading
+pub(crate) fn cancel_loading_dynamic_asset_collections<S: States, C: Asset>(world: &mut World) {
+    world.remove_resource::<LoadingAssetHandles<(S, C)>>();
+}
+
 pub(crate) fn resume_to_loading_asset_collections<S: States>(
     state: Res<State<S>>,
     mut loading_state: ResMut<NextState<InternalLoadingState<S>>>,
     asset_loader_config: Res<AssetLoaderConfiguration<S>>,
+    mut dynamic_assets: ResMut<DynamicAssets>,
 ) {
     let config = asset_loader_config
         .state_configurations
         .get(state.get())
         .expect("No asset loader configuration for current state");
     if config.loading_dynamic_collections.is_empty() {
-        debug!("No dynamic asset collection file left loading. Resuming to 'LoadingAssets'");
+        debug!(
+            state = ?state.get(),
+            "No dynamic asset collection file left loading. Resuming to 'LoadingAssets'"
+        );
+        if !config.cli_overrides.is_empty() {
+            dynamic_assets.register_from_args(&config.cli_overrides);
+        }
         loading_state.set(InternalLoadingState::LoadingAssets);
     }
 }
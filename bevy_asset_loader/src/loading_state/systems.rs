@@ -1,20 +1,43 @@
-use bevy::asset::{AssetServer, LoadState};
+use bevy::asset::{Asset, AssetEvent, AssetServer, HandleId, HandleUntyped, LoadState};
+use bevy::ecs::change_detection::DetectChangesMut;
+use bevy::ecs::event::{Events, ManualEventReader};
 use bevy::ecs::schedule::{State, States};
 use bevy::ecs::system::SystemState;
-use bevy::ecs::world::{FromWorld, World, WorldCell};
-use bevy::log::{debug, info, trace, warn};
-use bevy::prelude::{NextState, Res, ResMut, Resource, Schedules};
+use bevy::ecs::world::{FromWorld, Mut, World, WorldCell};
+use bevy::log::{debug, debug_span, info, trace, warn};
+use bevy::prelude::{EventReader, EventWriter, Local, NextState, Res, ResMut, Resource, Schedules};
+use bevy::tasks::AsyncComputeTaskPool;
+use bevy::utils::{default, HashMap, HashSet, Instant};
 use std::any::{type_name, TypeId};
+use std::collections::VecDeque;
 use std::marker::PhantomData;
 
 #[cfg(feature = "progress_tracking")]
 use iyes_progress::{HiddenProgress, Progress, ProgressCounter};
 
-use crate::asset_collection::AssetCollection;
+#[cfg(feature = "load_replay")]
+use crate::load_replay::{LoadRecorder, LoadReplayer, RecordedLoad};
+#[cfg(feature = "load_replay")]
+use bevy::core::FrameCount;
+
+use crate::asset_collection::{
+    pin_handles, AssetCollection, AsyncAssetCollection, CollectionErrors, HandleWeights,
+    RecoverableHandles,
+};
+#[cfg(feature = "content_hash")]
+use crate::content_hash::{hex, spawn_file_hash};
+use crate::dynamic_asset::DynamicAssets;
 use crate::loading_state::{
-    AssetLoaderConfiguration, InternalLoadingState, LoadingAssetHandles, LoadingStateSchedule,
-    OnEnterInternalLoadingState,
+    all_loaded, count_loaded_by, AssetLoadedWithin, AssetLoaderConfiguration, BackgroundCollection,
+    CollectionProgress, CollectionTimings, CreatedCollection, FailedAssets, InsertCollection,
+    InternalLoadingState, LoadingAssetHandles, LoadingCancelled, LoadingComplete,
+    LoadingFieldsTarget, LoadingProgress, LoadingProgressChanged, LoadingScreenAssets,
+    LoadingSequence, LoadingStateSchedule, LoadingStateTime, OnEnterInternalLoadingState,
+    PreloadedAssetHandles, RebuildingAssetHandles, ReloadingAssetHandles, SequenceProgress,
+    TargetWorld,
 };
+#[cfg(any(feature = "2d", feature = "3d"))]
+use crate::render_upload::RenderUploads;
 
 pub(crate) fn init_resource<Asset: Resource + FromWorld>(world: &mut World) {
     let asset = Asset::from_world(world);
@@ -26,11 +49,12 @@ pub(crate) fn start_loading_collection<S: States, Assets: AssetCollection>(
     world: &mut World,
     system_state: &mut SystemState<(ResMut<AssetLoaderConfiguration<S>>, Res<State<S>>)>,
 ) {
+    let (mut asset_loader_configuration, state) = system_state.get_mut(world);
     debug!(
-        "Starting to load collection for type id {:?}",
-        TypeId::of::<Assets>()
+        state = ?state.get(),
+        collection = type_name::<Assets>(),
+        "Starting to load collection"
     );
-    let (mut asset_loader_configuration, state) = system_state.get_mut(world);
 
     let mut config = asset_loader_configuration
         .state_configurations
@@ -42,23 +66,383 @@ pub(crate) fn start_loading_collection<S: States, Assets: AssetCollection>(
             )
         });
     config.loading_collections += 1;
-    let handles = LoadingAssetHandles {
-        handles: Assets::load(world),
-        marker: PhantomData::<Assets>,
+    let verbose_logging = config.verbose_logging;
+    let target_world = config.target_world.clone();
+    let queue_loads = config.batch_size.is_some()
+        || !config.max_concurrent_loads.is_empty()
+        || !config.non_blocking_types.is_empty();
+    let state = state.get().clone();
+    world.remove_resource::<ReloadingAssetHandles<Assets>>();
+    world.remove_resource::<RebuildingAssetHandles<Assets>>();
+    world.remove_resource::<BackgroundCollection<Assets>>();
+    if queue_loads {
+        world.remove_resource::<RecoverableHandles<Assets>>();
+        world.remove_resource::<HandleWeights<Assets>>();
+        world.insert_resource(LoadingAssetHandles::<Assets> {
+            queued: Assets::typed_load_requests().into(),
+            ..default()
+        });
+        issue_queued_loads::<S, Assets>(world, &state);
+    } else {
+        let handles = Assets::load(world);
+        world.insert_resource(LoadingAssetHandles::<Assets>::default());
+        track_handles::<Assets>(world, handles, verbose_logging);
+    }
+    if Assets::has_loading_fields() {
+        let asset_collection = Assets::create(world);
+        insert_collection(world, target_world.as_ref(), asset_collection);
+        world.insert_resource(LoadingFieldsTarget::<Assets> {
+            target_world,
+            marker: PhantomData,
+        });
+    }
+}
+
+/// Mark the [`LoadingField`](crate::loading_field::LoadingField)s of a collection as ready once their assets are loaded
+///
+/// The collection is looked up in the world it was inserted into by its loading state.
+pub(crate) fn update_loading_fields<Assets: AssetCollection>(world: &mut World) {
+    let Some(asset_server) = world.get_resource::<AssetServer>().cloned() else {
+        return;
+    };
+    let target_world = world
+        .get_resource::<LoadingFieldsTarget<Assets>>()
+        .and_then(|target| target.target_world.clone());
+    let world = match target_world.as_ref() {
+        Some(target_world) => target_world(world),
+        None => world,
+    };
+    let Some(mut asset_collection) = world.get_resource_mut::<Assets>() else {
+        return;
+    };
+    if asset_collection
+        .bypass_change_detection()
+        .update_loading_fields(&asset_server)
+    {
+        asset_collection.set_changed();
+    }
+}
+
+/// Count a collection as a single asset in the [`LoadingProgress`] until it starts loading
+pub(crate) fn count_pending_collection<S: States, Assets: AssetCollection>(
+    mut loading_progress: ResMut<LoadingProgress<S>>,
+) {
+    loading_progress.update::<Assets>(CollectionProgress {
+        total: 1,
+        issued: 0,
+        loaded: 0,
+        total_weight: 1.,
+        loaded_weight: 0.,
+    });
+}
+
+pub(crate) fn preload_collection<Assets: AssetCollection>(world: &mut World) {
+    debug!(collection = type_name::<Assets>(), "Preloading collection");
+    let handles = Assets::load(world);
+    world.insert_resource(PreloadedAssetHandles::<Assets> {
+        handles,
+        marker: PhantomData,
+    });
+}
+
+/// Start loading a collection while the app is being built
+///
+/// See [`LoadingState::preload_now`](crate::loading_state::LoadingState::preload_now)
+pub(crate) fn preload_collection_now<Assets: AssetCollection>(world: &mut World) {
+    if !world.contains_resource::<AssetServer>() {
+        warn!(
+            collection = type_name::<Assets>(),
+            "Cannot preload collection without an AssetServer. Add the AssetPlugin before the loading state. The collection will load when the loading state is entered."
+        );
+        return;
+    }
+    world.init_resource::<DynamicAssets>();
+    let dynamic_assets = world.resource::<DynamicAssets>();
+    if let Some(key) = Assets::dynamic_asset_keys()
+        .into_iter()
+        .find(|key| dynamic_assets.get_asset(key).is_none())
+    {
+        warn!(
+            collection = type_name::<Assets>(),
+            key,
+            "Cannot preload collection before its dynamic asset key is registered. The collection will load when the loading state is entered."
+        );
+        return;
+    }
+    preload_collection::<Assets>(world);
+}
+
+/// Issue the queued load requests of a collection
+///
+/// At most `batch_size` requests are issued per call. Requests of an asset type with a
+/// concurrency limit stay queued while the limit is reached by earlier loads of that type.
+fn issue_queued_loads<S: States, Assets: AssetCollection>(world: &mut World, state: &S) {
+    let Some(loading_asset_handles) = world.get_resource::<LoadingAssetHandles<Assets>>() else {
+        return;
+    };
+    if loading_asset_handles.queued.is_empty() {
+        return;
+    }
+    let asset_server = world.resource::<AssetServer>().clone();
+    let mut asset_loader_configuration = world.resource_mut::<AssetLoaderConfiguration<S>>();
+    let Some(config) = asset_loader_configuration
+        .state_configurations
+        .get_mut(state)
+    else {
+        return;
+    };
+    let batch_size = config.batch_size.unwrap_or(usize::MAX);
+    let verbose_logging = config.verbose_logging;
+    let max_concurrent_loads = config.max_concurrent_loads.clone();
+    let non_blocking_types = config.non_blocking_types.clone();
+    let mut in_flight: HashMap<&'static str, usize> = HashMap::default();
+    for (asset_type, handles) in config.loads_in_flight.iter_mut() {
+        handles.retain(|handle_id| {
+            matches!(
+                asset_server.get_load_state(*handle_id),
+                LoadState::NotLoaded | LoadState::Loading
+            )
+        });
+        in_flight.insert(asset_type, handles.len());
+    }
+
+    let mut loading_asset_handles = world.resource_mut::<LoadingAssetHandles<Assets>>();
+    let mut requests = vec![];
+    let mut throttled = VecDeque::new();
+    while let Some((asset_type, request)) = loading_asset_handles.queued.pop_front() {
+        let limited_type =
+            asset_type.filter(|asset_type| max_concurrent_loads.contains_key(asset_type));
+        let limit_reached = limited_type.is_some_and(|asset_type| {
+            in_flight.get(asset_type).copied().unwrap_or(0) >= max_concurrent_loads[asset_type]
+        });
+        if requests.len() < batch_size && !limit_reached {
+            if let Some(asset_type) = limited_type {
+                *in_flight.entry(asset_type).or_default() += 1;
+            }
+            requests.push((asset_type, limited_type, request));
+        } else {
+            throttled.push_back((asset_type, request));
+        }
+    }
+    loading_asset_handles.queued = throttled;
+
+    let mut handles = vec![];
+    let mut limited_handles: Vec<(&'static str, Vec<HandleId>)> = vec![];
+    let mut non_blocking_handles = vec![];
+    for (asset_type, limited_type, request) in requests {
+        let request_handles = request(world);
+        if asset_type.is_some_and(|asset_type| non_blocking_types.contains(asset_type)) {
+            non_blocking_handles.extend(request_handles.iter().map(|handle| handle.id()));
+        }
+        if let Some(asset_type) = limited_type {
+            limited_handles.push((
+                asset_type,
+                request_handles.iter().map(|handle| handle.id()).collect(),
+            ));
+        }
+        handles.extend(request_handles);
+    }
+    if let Some(config) = world
+        .resource_mut::<AssetLoaderConfiguration<S>>()
+        .state_configurations
+        .get_mut(state)
+    {
+        for (asset_type, handle_ids) in limited_handles {
+            config
+                .loads_in_flight
+                .entry(asset_type)
+                .or_default()
+                .extend(handle_ids);
+        }
+    }
+    world
+        .resource_mut::<LoadingAssetHandles<Assets>>()
+        .non_blocking
+        .extend(non_blocking_handles);
+    track_handles::<Assets>(world, handles, verbose_logging);
+}
+
+/// Start loading the deferred assets of a collection once all its other assets are loaded
+fn issue_deferred_loads<Assets: AssetCollection>(world: &mut World, verbose_logging: bool) {
+    let loading_asset_handles = world.resource::<LoadingAssetHandles<Assets>>();
+    if loading_asset_handles.deferred_issued || !loading_asset_handles.queued.is_empty() {
+        return;
+    }
+    let asset_server = world.resource::<AssetServer>();
+    let recoverable = recoverable_handle_ids::<Assets>(world);
+    if loading_asset_handles.handles.iter().any(|handle| {
+        if loading_asset_handles.non_blocking.contains(&handle.id()) {
+            return false;
+        }
+        match asset_server.get_load_state(handle.id()) {
+            LoadState::Loaded => false,
+            LoadState::Failed => !recoverable.contains(&handle.id()),
+            _ => true,
+        }
+    }) {
+        return;
+    }
+    world
+        .resource_mut::<LoadingAssetHandles<Assets>>()
+        .deferred_issued = true;
+    let handles = Assets::load_deferred(world);
+    track_handles::<Assets>(world, handles, verbose_logging);
+}
+
+fn track_handles<Assets: AssetCollection>(
+    world: &mut World,
+    handles: Vec<HandleUntyped>,
+    verbose_logging: bool,
+) {
+    if verbose_logging {
+        let asset_server = world.resource::<AssetServer>();
+        for handle in &handles {
+            info!(
+                collection = type_name::<Assets>(),
+                path = %describe_handle(asset_server, handle.id()),
+                "Started loading asset"
+            );
+        }
+    }
+    let paths: Vec<_> = {
+        let asset_server = world.resource::<AssetServer>();
+        handles
+            .iter()
+            .map(|handle| (handle.id(), describe_handle(asset_server, handle.id())))
+            .collect()
     };
-    world.insert_resource(handles);
+    let now = Instant::now();
+    let mut loading_asset_handles = world.resource_mut::<LoadingAssetHandles<Assets>>();
+    loading_asset_handles
+        .pending
+        .extend(handles.iter().map(|handle| (handle.id(), now)));
+    loading_asset_handles.paths.extend(paths);
+    loading_asset_handles.handles.extend(handles);
 }
 
 pub(crate) fn check_loading_collection<S: States, Assets: AssetCollection>(world: &mut World) {
+    // A WorldCell keeps the resource marked as borrowed if it does not exist, so check up front
+    if !world.contains_resource::<LoadingAssetHandles<Assets>>() {
+        return;
+    }
+    let state = world.resource::<State<S>>().get();
     debug!(
-        "Check loading of collection for type id {:?}",
-        TypeId::of::<Assets>()
+        state = ?state,
+        collection = type_name::<Assets>(),
+        "Checking loading of collection"
     );
-    if let Some((done, total)) = count_loaded_handles::<S, Assets>(world.cell()) {
+    let mut expect_hot_reload = false;
+    let mut rebuild_on_modify = false;
+    let mut target_world = None;
+    let mut spawn_create_task = None;
+    let mut verbose_logging = false;
+    let mut completion_check = None;
+    if let Some(config) = world
+        .resource::<AssetLoaderConfiguration<S>>()
+        .state_configurations
+        .get(state)
+    {
+        expect_hot_reload = config.expect_hot_reload;
+        completion_check = config
+            .completion_checks
+            .get(&TypeId::of::<Assets>())
+            .cloned();
+        rebuild_on_modify = config.rebuild_on_modify.contains(&TypeId::of::<Assets>());
+        target_world = config.target_world.clone();
+        spawn_create_task = config
+            .async_collections
+            .get(&TypeId::of::<Assets>())
+            .copied();
+        verbose_logging = config.verbose_logging;
+    }
+    let state = state.clone();
+    issue_queued_loads::<S, Assets>(world, &state);
+    issue_deferred_loads::<Assets>(world, verbose_logging);
+    let recoverable = recoverable_handle_ids::<Assets>(world);
+    let weights = world
+        .get_resource::<HandleWeights<Assets>>()
+        .map(|handle_weights| handle_weights.weights().clone())
+        .unwrap_or_default();
+    let completed = completion_check.map(|check| {
+        world
+            .resource::<LoadingAssetHandles<Assets>>()
+            .handles
+            .iter()
+            .filter(|handle| check(world, handle))
+            .map(HandleUntyped::id)
+            .collect::<HashSet<_>>()
+    });
+    let ready = Assets::ready_to_create(world);
+    if let Some((done, total)) = count_loaded_handles::<S, Assets>(
+        world.cell(),
+        &recoverable,
+        &weights,
+        completed.as_ref(),
+        ready,
+    ) {
         if total == done {
-            let asset_collection = Assets::create(world);
-            world.insert_resource(asset_collection);
-            world.remove_resource::<LoadingAssetHandles<Assets>>();
+            let duration = world
+                .resource::<LoadingAssetHandles<Assets>>()
+                .started
+                .elapsed();
+            debug!(
+                state = ?world.resource::<State<S>>().get(),
+                collection = type_name::<Assets>(),
+                assets = total,
+                ?duration,
+                "Finished loading collection"
+            );
+            world
+                .resource_mut::<CollectionTimings>()
+                .insert::<Assets>(duration);
+            match spawn_create_task {
+                Some(spawn_create_task) => {
+                    let created_collection = spawn_create_task(world);
+                    let state = world.resource::<State<S>>().get().clone();
+                    if let Some(config) = world
+                        .resource_mut::<AssetLoaderConfiguration<S>>()
+                        .state_configurations
+                        .get_mut(&state)
+                    {
+                        // The collection keeps the loading state busy until its task completed
+                        config.loading_collections += 1;
+                        config.creating_collections.push(created_collection);
+                    }
+                }
+                None => {
+                    let asset_collection = Assets::create(world);
+                    insert_collection(world, target_world.as_ref(), asset_collection);
+                }
+            }
+            let loading_asset_handles = world
+                .remove_resource::<LoadingAssetHandles<Assets>>()
+                .unwrap();
+            world.remove_resource::<PreloadedAssetHandles<Assets>>();
+            pin_handles::<Assets>(world, &loading_asset_handles.handles);
+            if rebuild_on_modify {
+                world.insert_resource(RebuildingAssetHandles::<Assets> {
+                    handles: loading_asset_handles
+                        .handles
+                        .iter()
+                        .map(|handle| handle.id())
+                        .collect(),
+                    modified: false,
+                    // Ignore modifications caused by creating the collection
+                    generation: 1,
+                    frames_since_rebuild: 0,
+                    rapid_rebuilds: 0,
+                    target_world: target_world.clone(),
+                    marker: PhantomData,
+                });
+            }
+            if expect_hot_reload {
+                world.insert_resource(ReloadingAssetHandles::<Assets> {
+                    handles: loading_asset_handles.handles,
+                    reloading: default(),
+                    target_world,
+                    marker: PhantomData,
+                });
+            }
 
             #[cfg(feature = "progress_tracking")]
             world
@@ -73,32 +457,246 @@ pub(crate) fn check_loading_collection<S: States, Assets: AssetCollection>(world
     }
 }
 
-fn count_loaded_handles<S: States, Assets: AssetCollection>(cell: WorldCell) -> Option<(u32, u32)> {
-    let loading_asset_handles = cell.get_resource::<LoadingAssetHandles<Assets>>()?;
-    let total = loading_asset_handles.handles.len();
+/// Prepare a collection on the main thread and create it on the [`AsyncComputeTaskPool`]
+pub(crate) fn spawn_create_task<Assets: AsyncAssetCollection>(
+    world: &mut World,
+) -> CreatedCollection {
+    debug!(
+        collection = type_name::<Assets>(),
+        "Creating collection in a task"
+    );
+    let input = Assets::prepare(world);
+    let created_collection = CreatedCollection::default();
+    let slot = created_collection.clone();
+    // Single threaded task pools (e.g. on wasm) do not return the output of tasks
+    AsyncComputeTaskPool::get()
+        .spawn(async move {
+            let asset_collection = Assets::create_async(input);
+            let insert: InsertCollection = Box::new(
+                move |world: &mut World, target_world: Option<&TargetWorld>| {
+                    insert_collection(world, target_world, asset_collection)
+                },
+            );
+            *slot.lock().expect("Collection slot is poisoned") = Some(insert);
+        })
+        .detach();
+    created_collection
+}
+
+/// Insert collections whose creation tasks completed
+pub(crate) fn check_async_collections<S: States>(world: &mut World) {
+    let state = world.resource::<State<S>>().get().clone();
+    let mut asset_loader_configuration = world.resource_mut::<AssetLoaderConfiguration<S>>();
+    let Some(config) = asset_loader_configuration
+        .state_configurations
+        .get_mut(&state)
+    else {
+        return;
+    };
+    let mut inserts = vec![];
+    config.creating_collections.retain(|created_collection| {
+        match created_collection
+            .lock()
+            .expect("Collection slot is poisoned")
+            .take()
+        {
+            Some(insert) => {
+                inserts.push(insert);
+                false
+            }
+            None => true,
+        }
+    });
+    config.loading_collections -= inserts.len();
+    let target_world = config.target_world.clone();
+    for insert in inserts {
+        insert(world, target_world.as_ref());
+    }
+}
+
+/// Insert a collection into the main world or the configured target world
+///
+/// Mergeable collections are merged into an existing resource of the same type.
+fn insert_collection<Assets: AssetCollection>(
+    world: &mut World,
+    target_world: Option<&TargetWorld>,
+    asset_collection: Assets,
+) {
+    let world = match target_world {
+        Some(target_world) => target_world(world),
+        None => world,
+    };
+    match (Assets::merge_with(), world.get_resource_mut::<Assets>()) {
+        (Some(merge), Some(mut existing)) => {
+            debug!(
+                collection = type_name::<Assets>(),
+                "Merging collection into the existing resource"
+            );
+            merge(&mut existing, asset_collection)
+        }
+        _ => world.insert_resource(asset_collection),
+    }
+}
+
+/// Replace a collection in the main world or the configured target world
+fn replace_collection<Assets: AssetCollection>(
+    world: &mut World,
+    target_world: Option<&TargetWorld>,
+    asset_collection: Assets,
+) {
+    match target_world {
+        Some(target_world) => target_world(world).insert_resource(asset_collection),
+        None => world.insert_resource(asset_collection),
+    }
+}
+
+/// Ids of the handles that are replaced by an `on_fail` fallback if they fail to load
+fn recoverable_handle_ids<Assets: AssetCollection>(world: &World) -> HashSet<HandleId> {
+    world
+        .get_resource::<RecoverableHandles<Assets>>()
+        .map(RecoverableHandles::handle_ids)
+        .unwrap_or_default()
+}
+
+fn count_loaded_handles<S: States, Assets: AssetCollection>(
+    cell: WorldCell,
+    recoverable: &HashSet<HandleId>,
+    weights: &HashMap<HandleId, f32>,
+    completed: Option<&HashSet<HandleId>>,
+    ready: bool,
+) -> Option<(u32, u32)> {
+    let mut loading_asset_handles = cell.get_resource_mut::<LoadingAssetHandles<Assets>>()?;
+    // Queued requests count as at least one handle each, so the collection cannot finish before they were issued
+    // Work that has to finish before creating the collection, like content hashes, counts as one more handle
+    let total = loading_asset_handles.handles.len()
+        + loading_asset_handles.queued.len()
+        + usize::from(!ready);
 
     let asset_server = cell
         .get_resource::<AssetServer>()
         .expect("Cannot get AssetServer resource");
+    let state = cell
+        .get_resource::<State<S>>()
+        .expect("Cannot get State resource");
+    let (verbose_logging, handle_observers) = cell
+        .get_resource::<AssetLoaderConfiguration<S>>()
+        .expect("Cannot get AssetLoaderConfiguration resource")
+        .state_configurations
+        .get(state.get())
+        .map_or((false, vec![]), |config| {
+            (config.verbose_logging, config.handle_observers.clone())
+        });
+    let mut loaded_events = cell.get_resource_mut::<Events<AssetLoadedWithin>>();
+    #[cfg(feature = "load_replay")]
+    let (replayer, mut recorder, frame) = (
+        cell.get_resource::<LoadReplayer>(),
+        cell.get_resource_mut::<LoadRecorder>(),
+        cell.get_resource::<FrameCount>()
+            .map_or(0, |frame_count| frame_count.0),
+    );
+    let load_state = |handle_id: HandleId| {
+        #[cfg(feature = "load_replay")]
+        if let Some(load_state) = replayer
+            .as_ref()
+            .and_then(|replayer| replayer.load_state(&asset_server, handle_id, frame))
+        {
+            return load_state;
+        }
+        asset_server.get_load_state(handle_id)
+    };
+    // A completion check of the collection replaces the default check for loaded handles
+    let is_loaded = |handle_id: HandleId| match completed {
+        Some(completed) => completed.contains(&handle_id),
+        None => load_state(handle_id) == LoadState::Loaded,
+    };
+    let describe = |handle_id: HandleId, paths: &HashMap<HandleId, String>| {
+        paths
+            .get(&handle_id)
+            .cloned()
+            .unwrap_or_else(|| describe_handle(&asset_server, handle_id))
+    };
+    if !handle_observers.is_empty() {
+        for handle in &loading_asset_handles.handles {
+            let path = describe(handle.id(), &loading_asset_handles.paths);
+            let state = load_state(handle.id());
+            for observer in &handle_observers {
+                observer(&path, state);
+            }
+        }
+    }
+    let LoadingAssetHandles { pending, paths, .. } = &mut *loading_asset_handles;
+    pending.retain(|handle_id, started| {
+        if !is_loaded(*handle_id) {
+            return true;
+        }
+        let path = describe(*handle_id, paths);
+        #[cfg(feature = "load_replay")]
+        if let Some(recorder) = recorder.as_mut() {
+            recorder.record(RecordedLoad {
+                frame,
+                elapsed: started.elapsed(),
+                collection: type_name::<Assets>(),
+                path: path.clone(),
+            });
+        }
+        if verbose_logging {
+            info!(
+                collection = type_name::<Assets>(),
+                path = %path,
+                elapsed = ?started.elapsed(),
+                "Finished loading asset"
+            );
+        }
+        if let Some(events) = loaded_events.as_mut() {
+            events.send(AssetLoadedWithin {
+                collection: type_name::<Assets>(),
+                path,
+            });
+        }
+        false
+    });
+    // Failed handles with a fallback are resolved, since the fallback replaces them on creation
+    let failed = |handle_id: HandleId| {
+        load_state(handle_id) == LoadState::Failed
+            && !recoverable.contains(&handle_id)
+            && !loading_asset_handles.non_blocking.contains(&handle_id)
+    };
     let failure = loading_asset_handles
         .handles
         .iter()
-        .map(|handle| handle.id())
-        .any(|handle_id| asset_server.get_load_state(handle_id) == LoadState::Failed);
-    let done = loading_asset_handles
-        .handles
-        .iter()
-        .map(|handle| handle.id())
-        .map(|handle_id| asset_server.get_load_state(handle_id))
-        .filter(|state| state == &LoadState::Loaded)
-        .count();
+        .any(|handle| failed(handle.id()));
+    let resolved = |handle_id: HandleId| {
+        is_loaded(handle_id)
+            || (load_state(handle_id) == LoadState::Failed && recoverable.contains(&handle_id))
+    };
+    let (done, _) = count_loaded_by(&loading_asset_handles.handles, resolved);
+    let weight = |handle: &HandleUntyped| weights.get(&handle.id()).copied().unwrap_or(1.);
+    cell.resource_mut::<LoadingProgress<S>>()
+        .update::<Assets>(CollectionProgress {
+            total,
+            issued: loading_asset_handles.handles.len(),
+            loaded: done,
+            total_weight: loading_asset_handles
+                .handles
+                .iter()
+                .map(weight)
+                .sum::<f32>()
+                + loading_asset_handles.queued.len() as f32,
+            loaded_weight: loading_asset_handles
+                .handles
+                .iter()
+                .filter(|handle| resolved(handle.id()))
+                .map(weight)
+                .sum(),
+        });
+    // Handles of non-blocking asset types do not keep the collection from finishing
+    let (done, _) = count_loaded_by(&loading_asset_handles.handles, |handle_id| {
+        loading_asset_handles.non_blocking.contains(&handle_id) || resolved(handle_id)
+    });
     if done < total && !failure {
         return Some((done as u32, total as u32));
     }
 
-    let state = cell
-        .get_resource::<State<S>>()
-        .expect("Cannot get State resource");
     let mut asset_loader_configuration = cell
         .get_resource_mut::<AssetLoaderConfiguration<S>>()
         .expect("Cannot get AssetLoaderConfiguration resource");
@@ -108,67 +706,362 @@ fn count_loaded_handles<S: States, Assets: AssetCollection>(cell: WorldCell) ->
     {
         if failure {
             config.loading_failed = true;
+            let mut failed_assets = cell.resource_mut::<FailedAssets>();
+            for handle in &loading_asset_handles.handles {
+                if !failed(handle.id()) {
+                    continue;
+                }
+                let path = describe_handle(&asset_server, handle.id());
+                if !failed_assets.paths.contains(&path) {
+                    warn!(
+                        state = ?state.get(),
+                        collection = type_name::<Assets>(),
+                        path = %path,
+                        "Failed to load asset"
+                    );
+                    failed_assets.paths.push(path);
+                }
+            }
         } else {
             config.loading_collections -= 1;
         }
     } else {
-        warn!("Failed to read loading state configuration in count_loaded_handles")
+        warn!(
+            state = ?state.get(),
+            "Failed to read loading state configuration in count_loaded_handles"
+        )
     }
 
     Some((done as u32, total as u32))
 }
 
+pub(crate) fn start_tracking_handles<S: States>(
+    mut asset_loader_configuration: ResMut<AssetLoaderConfiguration<S>>,
+    state: Res<State<S>>,
+) {
+    if let Some(config) = asset_loader_configuration
+        .state_configurations
+        .get_mut(state.get())
+    {
+        if !config.tracked_handles.is_empty() {
+            config.loading_collections += 1;
+            config.tracking_handles = true;
+        }
+    }
+}
+
+/// Key of the handles tracked by a loading state in the [`LoadingProgress`]
+struct TrackedHandles<S>(PhantomData<S>);
+
+pub(crate) fn check_tracked_handles<S: States>(
+    mut asset_loader_configuration: ResMut<AssetLoaderConfiguration<S>>,
+    state: Res<State<S>>,
+    asset_server: Res<AssetServer>,
+    mut failed_assets: ResMut<FailedAssets>,
+    mut loading_progress: ResMut<LoadingProgress<S>>,
+    #[cfg(feature = "progress_tracking")] mut progress_counter: ResMut<ProgressCounter>,
+) {
+    let Some(config) = asset_loader_configuration
+        .state_configurations
+        .get_mut(state.get())
+    else {
+        return;
+    };
+    if !config.tracking_handles {
+        return;
+    }
+    let (done, total) = all_loaded(&asset_server, &config.tracked_handles);
+    let (done, total) = (done as u32, total as u32);
+    for handle in &config.tracked_handles {
+        if asset_server.get_load_state(handle.id()) != LoadState::Failed {
+            continue;
+        }
+        config.loading_failed = true;
+        let path = describe_handle(&asset_server, handle.id());
+        if !failed_assets.paths.contains(&path) {
+            warn!(
+                state = ?state.get(),
+                path = %path,
+                "Failed to load tracked asset"
+            );
+            failed_assets.paths.push(path);
+        }
+    }
+    loading_progress.update::<TrackedHandles<S>>(CollectionProgress {
+        total: total as usize,
+        issued: total as usize,
+        loaded: done as usize,
+        total_weight: total as f32,
+        loaded_weight: done as f32,
+    });
+    if done == total {
+        config.loading_collections -= 1;
+        config.tracking_handles = false;
+        #[cfg(feature = "progress_tracking")]
+        progress_counter.persist_progress(Progress { done, total });
+    } else {
+        #[cfg(feature = "progress_tracking")]
+        progress_counter.manually_track(Progress { done, total });
+    }
+}
+
+/// Recreate a collection after all of its reloaded assets finished loading again
+pub(crate) fn recreate_reloaded_collection<Assets: AssetCollection>(world: &mut World) {
+    if !world.contains_resource::<ReloadingAssetHandles<Assets>>() {
+        return;
+    }
+    let recreate = world.resource_scope(
+        |world, mut reloading_asset_handles: Mut<ReloadingAssetHandles<Assets>>| {
+            let asset_server = world.resource::<AssetServer>();
+            let reloading_asset_handles = &mut *reloading_asset_handles;
+            let mut reloaded = false;
+            for handle in &reloading_asset_handles.handles {
+                let handle_id = handle.id();
+                if asset_server.get_load_state(handle_id) != LoadState::Loaded {
+                    reloading_asset_handles.reloading.insert(handle_id);
+                } else if reloading_asset_handles.reloading.remove(&handle_id) {
+                    reloaded = true;
+                }
+            }
+            (reloaded && reloading_asset_handles.reloading.is_empty())
+                .then(|| reloading_asset_handles.target_world.clone())
+        },
+    );
+    if let Some(target_world) = recreate {
+        info!(
+            collection = type_name::<Assets>(),
+            "Recreating collection after its assets were reloaded"
+        );
+        let asset_collection = Assets::create(world);
+        // Merging would add the reloaded assets a second time
+        replace_collection(world, target_world.as_ref(), asset_collection);
+    }
+}
+
+/// Rebuilds within this many frames of the previous rebuild count as rapid
+const RAPID_REBUILD_FRAMES: usize = 3;
+/// Number of rapid rebuilds in a row after which a collection is considered to be in a rebuild loop
+const MAX_RAPID_REBUILDS: usize = 5;
+
+/// Recreate a collection configured with [`LoadingState::rebuild_on_modify`](crate::loading_state::LoadingState::rebuild_on_modify) after its assets were modified
+pub(crate) fn rebuild_modified_collection<Assets: AssetCollection>(world: &mut World) {
+    let Some(mut rebuilding_asset_handles) =
+        world.get_resource_mut::<RebuildingAssetHandles<Assets>>()
+    else {
+        return;
+    };
+    rebuilding_asset_handles.frames_since_rebuild += 1;
+    if !rebuilding_asset_handles.modified {
+        return;
+    }
+    rebuilding_asset_handles.modified = false;
+    if rebuilding_asset_handles.frames_since_rebuild <= RAPID_REBUILD_FRAMES {
+        rebuilding_asset_handles.rapid_rebuilds += 1;
+    } else {
+        rebuilding_asset_handles.rapid_rebuilds = 0;
+    }
+    if rebuilding_asset_handles.rapid_rebuilds >= MAX_RAPID_REBUILDS {
+        warn!(
+            collection = type_name::<Assets>(),
+            "The collection keeps getting rebuilt, probably because its assets are modified whenever it is created. It will not be rebuilt on modifications anymore"
+        );
+        world.remove_resource::<RebuildingAssetHandles<Assets>>();
+        return;
+    }
+    rebuilding_asset_handles.frames_since_rebuild = 0;
+    rebuilding_asset_handles.generation += 1;
+    let target_world = rebuilding_asset_handles.target_world.clone();
+    info!(
+        collection = type_name::<Assets>(),
+        "Rebuilding collection after its assets were modified"
+    );
+    let asset_collection = Assets::create(world);
+    replace_collection(world, target_world.as_ref(), asset_collection);
+}
+
+/// Mark a collection as modified if one of its assets of type `T` was modified
+pub(crate) fn watch_modified_handles<Assets: AssetCollection, T: Asset>(
+    events: Option<Res<Events<AssetEvent<T>>>>,
+    rebuilding_asset_handles: Option<ResMut<RebuildingAssetHandles<Assets>>>,
+    mut reader: Local<ManualEventReader<AssetEvent<T>>>,
+    mut seen_generation: Local<usize>,
+) {
+    let Some(events) = events else {
+        return;
+    };
+    let modified = reader.iter(&events).any(|event| match event {
+        AssetEvent::Modified { handle } => rebuilding_asset_handles
+            .as_ref()
+            .is_some_and(|rebuilding| rebuilding.handles.contains(&handle.id())),
+        _ => false,
+    });
+    let Some(mut rebuilding_asset_handles) = rebuilding_asset_handles else {
+        return;
+    };
+    // Modifications in the frame of a rebuild are usually caused by the rebuild itself
+    if *seen_generation != rebuilding_asset_handles.generation {
+        *seen_generation = rebuilding_asset_handles.generation;
+        return;
+    }
+    if modified {
+        rebuilding_asset_handles.modified = true;
+    }
+}
+
+/// Describe a handle by its asset path or, if it has none, by its id
+pub(crate) fn describe_handle(asset_server: &AssetServer, handle_id: HandleId) -> String {
+    match asset_server.get_handle_path(handle_id) {
+        Some(asset_path) => match asset_path.label() {
+            Some(label) => format!("{}#{}", asset_path.path().display(), label),
+            None => asset_path.path().display().to_string(),
+        },
+        None => format!("{handle_id:?}"),
+    }
+}
+
 pub(crate) fn resume_to_finalize<S: States>(
-    loader_configuration: Res<AssetLoaderConfiguration<S>>,
+    mut loader_configuration: ResMut<AssetLoaderConfiguration<S>>,
     mut internal_state: ResMut<NextState<InternalLoadingState<S>>>,
     user_state: Res<State<S>>,
     mut next_user_state: ResMut<NextState<S>>,
 ) {
     if let Some(configuration) = loader_configuration
         .state_configurations
-        .get(user_state.get())
+        .get_mut(user_state.get())
     {
         if configuration.loading_collections == 0 {
-            internal_state.set(InternalLoadingState::Finalize);
+            if configuration.remaining_settle_frames > 0 {
+                configuration.remaining_settle_frames -= 1;
+            } else {
+                internal_state.set(InternalLoadingState::Finalize);
+            }
         }
         if configuration.loading_failed && configuration.failure.is_some() {
             let failure = configuration.failure.clone().unwrap();
+            warn!(
+                state = ?user_state.get(),
+                failure_state = ?failure,
+                "Loading failed, continuing to the failure state"
+            );
             next_user_state.set(failure);
         }
     } else {
-        warn!("Failed to read loading state configuration in resume_to_finalize")
+        warn!(
+            state = ?user_state.get(),
+            "Failed to read loading state configuration in resume_to_finalize"
+        )
     }
 }
 
 pub(crate) fn initialize_loading_state<S: States>(
+    state: Res<State<S>>,
+    asset_loader_configuration: Res<AssetLoaderConfiguration<S>>,
     mut loading_state: ResMut<NextState<InternalLoadingState<S>>>,
+    asset_server: Res<AssetServer>,
+    loading_screen_assets: Option<Res<LoadingScreenAssets>>,
     #[cfg(feature = "progress_tracking")] mut progress_counter: ResMut<ProgressCounter>,
 ) {
     #[cfg(feature = "progress_tracking")]
     progress_counter.persist_progress_hidden(HiddenProgress(Progress { total: 1, done: 0 }));
+    if let Some(config) = asset_loader_configuration
+        .state_configurations
+        .get(state.get())
+    {
+        if config
+            .entered
+            .is_some_and(|entered| entered.elapsed() < config.delay_start)
+        {
+            return;
+        }
+        if !config.loading_screen_assets.is_empty()
+            && loading_screen_assets.is_some_and(|loading_screen_assets| {
+                loading_screen_assets.handles().any(|handle| {
+                    matches!(
+                        asset_server.get_load_state(handle),
+                        LoadState::NotLoaded | LoadState::Loading
+                    )
+                })
+            })
+        {
+            return;
+        }
+    }
     loading_state.set(InternalLoadingState::LoadingDynamicAssetCollections);
 }
 
+/// Whether the condition configured with [`LoadingState::wait_for_input_with`](crate::loading_state::LoadingState::wait_for_input_with) allows finishing the loading state
+pub(crate) fn wait_condition_met<S: States>(world: &World) -> bool {
+    let state = world.resource::<State<S>>();
+    let condition = world
+        .resource::<AssetLoaderConfiguration<S>>()
+        .state_configurations
+        .get(state.get())
+        .and_then(|config| config.wait_condition.as_ref());
+    match condition {
+        Some(condition) => condition(world),
+        None => true,
+    }
+}
+
 pub(crate) fn finish_loading_state<S: States>(
     state: Res<State<S>>,
     mut next_state: ResMut<NextState<S>>,
+    mut complete: EventWriter<LoadingComplete<S>>,
     #[cfg(feature = "progress_tracking")] mut progress_counter: ResMut<ProgressCounter>,
     mut loading_state: ResMut<NextState<InternalLoadingState<S>>>,
     asset_loader_configuration: Res<AssetLoaderConfiguration<S>>,
+    mut collection_errors: ResMut<CollectionErrors>,
 ) {
     #[cfg(feature = "progress_tracking")]
     progress_counter.persist_progress_hidden(HiddenProgress(Progress { total: 0, done: 1 }));
+    collection_errors.collecting = false;
     info!(
-        "Loading state '{}::{:?}' is done",
-        type_name::<S>(),
-        state.get()
+        state_type = type_name::<S>(),
+        state = ?state.get(),
+        "Loading state is done"
     );
-    if let Some(config) = asset_loader_configuration
+    let config = asset_loader_configuration
         .state_configurations
-        .get(state.get())
-    {
-        if let Some(next) = config.next.as_ref() {
-            next_state.set(next.clone());
+        .get(state.get());
+    #[allow(unused_mut)]
+    let mut next = config.and_then(|config| config.next.clone());
+    #[cfg(feature = "content_hash")]
+    if let Some(condition) = config.and_then(|config| config.hash_condition.as_ref()) {
+        let hash = config
+            .and_then(|config| config.hashing_file.as_ref())
+            .and_then(|slot| slot.lock().unwrap().clone())
+            .unwrap_or_else(|| Err("the file was not hashed".to_owned()));
+        match hash {
+            Ok(hash) if hash == condition.expected => {}
+            Ok(hash) => {
+                info!(
+                    state = ?state.get(),
+                    path = %condition.path,
+                    hash = %hex(&hash),
+                    expected = %hex(&condition.expected),
+                    else_state = ?condition.else_state,
+                    "Content hash does not match"
+                );
+                next = Some(condition.else_state.clone());
+            }
+            Err(error) => {
+                warn!(
+                    state = ?state.get(),
+                    path = %condition.path,
+                    else_state = ?condition.else_state,
+                    "Failed to hash file: {error}"
+                );
+                next = Some(condition.else_state.clone());
+            }
+        }
+    }
+    complete.send(LoadingComplete {
+        state: state.get().clone(),
+        next: next.clone(),
+    });
+    if let Some(next) = next {
+        if !config.is_some_and(|config| config.no_auto_transition) {
+            next_state.set(next);
             return;
         }
     }
@@ -179,10 +1072,240 @@ pub(crate) fn finish_loading_state<S: States>(
 pub(crate) fn reset_loading_state<S: States>(world: &mut World) {
     world.remove_resource::<State<InternalLoadingState<S>>>();
     world.init_resource::<State<InternalLoadingState<S>>>();
+    let state = world.resource::<State<S>>().get().clone();
+    debug!(
+        state_type = type_name::<S>(),
+        state = ?state,
+        "Entering loading state"
+    );
+    let entered = Instant::now();
+    world.insert_resource(LoadingStateTime { entered });
+    let mut collect_errors = false;
+    let mut loading_screen_assets = vec![];
+    if let Some(config) = world
+        .resource_mut::<AssetLoaderConfiguration<S>>()
+        .state_configurations
+        .get_mut(&state)
+    {
+        loading_screen_assets = config.loading_screen_assets.clone();
+        config.loading_failed = false;
+        config.loading_collections = 0;
+        config.tracking_handles = false;
+        config.remaining_settle_frames = config.settle_frames;
+        config.last_progress_event = 0.;
+        config.entered = Some(entered);
+        config.creating_collections.clear();
+        config.loads_in_flight.clear();
+        // A loading state can be configured by multiple builders, so wait for the complete configuration
+        // With progress tracking, iyes_progress changes the state
+        #[cfg(not(feature = "progress_tracking"))]
+        if !config.checked_next_state {
+            config.checked_next_state = true;
+            if config.next.is_none()
+                && config.failure.is_none()
+                && !config.stay_in_state
+                && !config.no_auto_transition
+            {
+                warn!(
+                    state = ?state,
+                    "The loading state has no next state and will not be left after loading. Configure one with 'continue_to_state' or call 'stay_in_state' to silence this warning"
+                );
+            }
+        }
+        #[cfg(feature = "content_hash")]
+        {
+            config.hashing_file = None;
+        }
+        collect_errors = config.collect_errors;
+    }
+    let mut collection_errors = world.resource_mut::<CollectionErrors>();
+    if collect_errors {
+        collection_errors.clear();
+    }
+    collection_errors.collecting = collect_errors;
+    world.resource_mut::<FailedAssets>().paths.clear();
+    world.resource_mut::<LoadingProgress<S>>().reset();
+    if !loading_screen_assets.is_empty() {
+        let asset_server = world.resource::<AssetServer>();
+        let handles = loading_screen_assets
+            .into_iter()
+            .map(|(key, path)| (key, asset_server.load_untyped(path)))
+            .collect();
+        world.insert_resource(LoadingScreenAssets { handles });
+    }
+}
+
+/// Runs when leaving a loading state, possibly before it finished
+///
+/// If the loading state did not finish, all partially loaded collections are dropped and a
+/// [`LoadingCancelled`] event is sent.
+pub(crate) fn exit_loading_state<S: States>(world: &mut World, loading_state: &S) {
+    debug!(state_type = type_name::<S>(), "Leaving loading state");
+    world.remove_resource::<LoadingStateTime>();
+    world.resource_mut::<CollectionErrors>().collecting = false;
+    if world
+        .resource::<AssetLoaderConfiguration<S>>()
+        .state_configurations
+        .get(loading_state)
+        .is_some_and(|config| !config.loading_screen_assets.is_empty())
+    {
+        world.remove_resource::<LoadingScreenAssets>();
+    }
+    // Uploads that did not complete until the loading state was left are not needed anymore
+    #[cfg(any(feature = "2d", feature = "3d"))]
+    if let (Some(config), Some(uploads)) = (
+        world
+            .resource::<AssetLoaderConfiguration<S>>()
+            .state_configurations
+            .get(loading_state),
+        world.get_resource::<RenderUploads>(),
+    ) {
+        uploads.withdraw(&config.render_uploads);
+    }
+    let finished = matches!(
+        world.resource::<State<InternalLoadingState<S>>>().get(),
+        InternalLoadingState::Finalize | InternalLoadingState::Done(_)
+    );
+    let mut asset_loader_configuration = world.resource_mut::<AssetLoaderConfiguration<S>>();
+    let Some(config) = asset_loader_configuration
+        .state_configurations
+        .get_mut(loading_state)
+    else {
+        return;
+    };
+    config.cancelled = !finished && !config.loading_failed;
+    if !config.cancelled {
+        return;
+    }
+    config.loading_collections = 0;
+    config.tracking_handles = false;
+    config.creating_collections.clear();
+    config.loading_dynamic_collections.clear();
+    config.loads_in_flight.clear();
+    let cancel_loading = config.cancel_loading.clone();
+    let finish_loading = if config.finish_in_background {
+        config.finish_loading.clone()
+    } else {
+        vec![]
+    };
+    let target_world = config.target_world.clone();
+    info!(state = ?loading_state, "Loading state was left before it finished");
+    for finish in finish_loading {
+        finish(world, target_world.clone());
+    }
+    for cancel in cancel_loading {
+        cancel(world);
+    }
+    world.remove_resource::<State<InternalLoadingState<S>>>();
+    world.init_resource::<State<InternalLoadingState<S>>>();
+    world.resource_mut::<NextState<InternalLoadingState<S>>>().0 = None;
+    world.resource_mut::<LoadingProgress<S>>().reset();
+    world.send_event(LoadingCancelled {
+        state: loading_state.clone(),
+    });
+}
+
+/// Drops the handles of a collection that did not finish loading
+pub(crate) fn cancel_loading_collection<Assets: AssetCollection>(world: &mut World) {
+    // Collections finishing in the background keep their handles
+    if world.contains_resource::<BackgroundCollection<Assets>>() {
+        return;
+    }
+    world.remove_resource::<LoadingAssetHandles<Assets>>();
+    world.remove_resource::<RecoverableHandles<Assets>>();
+    world.remove_resource::<HandleWeights<Assets>>();
+}
+
+/// Keep loading a collection after its loading state was cancelled, if it already started loading
+pub(crate) fn move_collection_to_background<Assets: AssetCollection>(
+    world: &mut World,
+    target_world: Option<TargetWorld>,
+) {
+    if !world.contains_resource::<LoadingAssetHandles<Assets>>() {
+        return;
+    }
+    debug!(
+        collection = type_name::<Assets>(),
+        "Finishing collection in the background"
+    );
+    world.insert_resource(BackgroundCollection::<Assets> {
+        target_world,
+        marker: PhantomData,
+    });
+}
+
+/// Create a collection that kept loading after its loading state was cancelled
+///
+/// Without the loading state, queued loads are issued all at once.
+pub(crate) fn finish_collection_in_background<Assets: AssetCollection>(world: &mut World) {
+    let Some(mut loading_asset_handles) = world.get_resource_mut::<LoadingAssetHandles<Assets>>()
+    else {
+        world.remove_resource::<BackgroundCollection<Assets>>();
+        return;
+    };
+    let requests: Vec<_> = loading_asset_handles
+        .queued
+        .drain(..)
+        .map(|(_, request)| request)
+        .collect();
+    let handles = requests
+        .into_iter()
+        .flat_map(|request| request(world))
+        .collect();
+    track_handles::<Assets>(world, handles, false);
+    issue_deferred_loads::<Assets>(world, false);
+
+    let recoverable = recoverable_handle_ids::<Assets>(world);
+    let asset_server = world.resource::<AssetServer>();
+    let loading_asset_handles = world.resource::<LoadingAssetHandles<Assets>>();
+    let mut done = loading_asset_handles.deferred_issued;
+    for handle in &loading_asset_handles.handles {
+        if loading_asset_handles.non_blocking.contains(&handle.id()) {
+            continue;
+        }
+        match asset_server.get_load_state(handle.id()) {
+            LoadState::Loaded => {}
+            LoadState::Failed if recoverable.contains(&handle.id()) => {}
+            LoadState::Failed => {
+                warn!(
+                    collection = type_name::<Assets>(),
+                    path = %describe_handle(asset_server, handle.id()),
+                    "Failed to load asset of a collection in the background, dropping the collection"
+                );
+                world.remove_resource::<BackgroundCollection<Assets>>();
+                cancel_loading_collection::<Assets>(world);
+                return;
+            }
+            _ => done = false,
+        }
+    }
+    if !done || !Assets::ready_to_create(world) {
+        return;
+    }
+    let duration = loading_asset_handles.started.elapsed();
+    info!(
+        collection = type_name::<Assets>(),
+        ?duration,
+        "Finished loading collection in the background"
+    );
+    world
+        .resource_mut::<CollectionTimings>()
+        .insert::<Assets>(duration);
+    let background = world
+        .remove_resource::<BackgroundCollection<Assets>>()
+        .unwrap();
+    let asset_collection = Assets::create(world);
+    insert_collection(world, background.target_world.as_ref(), asset_collection);
+    let loading_asset_handles = world
+        .remove_resource::<LoadingAssetHandles<Assets>>()
+        .unwrap();
+    world.remove_resource::<PreloadedAssetHandles<Assets>>();
+    pin_handles::<Assets>(world, &loading_asset_handles.handles);
 }
 
 pub(crate) fn run_loading_state<S: States>(world: &mut World) {
     let state = world.resource::<State<S>>().get().clone();
+    let _span = debug_span!("loading_state", state = ?state).entered();
     world.run_schedule(LoadingStateSchedule(state));
 }
 
@@ -204,7 +1327,10 @@ pub fn apply_internal_state_transition<S: States>(world: &mut World) {
             .unwrap_or_default();
         world.insert_resource(State::new(entered_state.clone()));
         trace!(
-            "Switching internal state of loading state from {exited_state:?} to {entered_state:?}"
+            state = ?state,
+            from = ?exited_state,
+            to = ?entered_state,
+            "Switching internal state of loading state"
         );
         if world
             .resource::<Schedules>()
@@ -217,3 +1343,141 @@ pub fn apply_internal_state_transition<S: States>(world: &mut World) {
         }
     }
 }
+
+pub(crate) fn send_progress_changed<S: States>(
+    state: Res<State<S>>,
+    mut asset_loader_configuration: ResMut<AssetLoaderConfiguration<S>>,
+    loading_progress: Res<LoadingProgress<S>>,
+    mut progress_changed: EventWriter<LoadingProgressChanged<S>>,
+) {
+    let Some(config) = asset_loader_configuration
+        .state_configurations
+        .get_mut(state.get())
+    else {
+        return;
+    };
+    let fraction = loading_progress.fraction();
+    let difference = (fraction - config.last_progress_event).abs();
+    let completed = fraction >= 1. && config.last_progress_event < 1.;
+    if difference > config.progress_event_threshold || completed {
+        config.last_progress_event = fraction;
+        progress_changed.send(LoadingProgressChanged {
+            state: state.get().clone(),
+            fraction,
+        });
+    }
+}
+
+pub(crate) fn update_sequence_progress<S: States>(
+    mut sequence: ResMut<LoadingSequence<S>>,
+    mut completed: EventReader<LoadingComplete<S>>,
+    state: Option<Res<State<S>>>,
+    asset_loader_configuration: Option<Res<AssetLoaderConfiguration<S>>>,
+    loading_progress: Res<LoadingProgress<S>>,
+    mut sequence_progress: ResMut<SequenceProgress<S>>,
+) {
+    for completed in completed.iter() {
+        if let Some(index) = sequence
+            .states
+            .iter()
+            .position(|state| state == &completed.state)
+        {
+            sequence.finished[index] = true;
+        }
+    }
+    let mut progress = SequenceProgress {
+        states: sequence.states.len(),
+        ..default()
+    };
+    for (loading_state, finished) in sequence.states.iter().zip(sequence.finished.iter()) {
+        let weight = asset_loader_configuration
+            .as_ref()
+            .map_or(0, |configuration| {
+                configuration.collections_for(loading_state).len()
+            })
+            .max(1) as f32;
+        progress.total_weight += weight;
+        if *finished {
+            progress.finished += 1;
+            progress.loaded_weight += weight;
+        } else if state
+            .as_ref()
+            .is_some_and(|state| state.get() == loading_state)
+        {
+            progress.loaded_weight += weight * loading_progress.fraction();
+        }
+    }
+    *sequence_progress = progress;
+}
+
+/// Request the render world uploads of a loading state once all its collections are inserted
+#[cfg(any(feature = "2d", feature = "3d"))]
+pub(crate) fn request_render_uploads<S: States>(
+    state: Res<State<S>>,
+    asset_loader_configuration: Res<AssetLoaderConfiguration<S>>,
+    uploads: Option<Res<RenderUploads>>,
+) {
+    let (Some(config), Some(uploads)) = (
+        asset_loader_configuration
+            .state_configurations
+            .get(state.get()),
+        uploads,
+    ) else {
+        return;
+    };
+    uploads.request(&config.render_uploads);
+}
+
+/// Start hashing the file of the hash condition once all collections are inserted
+#[cfg(feature = "content_hash")]
+pub(crate) fn request_condition_file_hash<S: States>(
+    state: Res<State<S>>,
+    mut asset_loader_configuration: ResMut<AssetLoaderConfiguration<S>>,
+    asset_server: Res<AssetServer>,
+) {
+    let Some(config) = asset_loader_configuration
+        .state_configurations
+        .get_mut(state.get())
+    else {
+        return;
+    };
+    config.hashing_file = config
+        .hash_condition
+        .as_ref()
+        .map(|condition| spawn_file_hash(&asset_server, &condition.path));
+}
+
+/// Whether the file of the hash condition of the current loading state is hashed
+#[cfg(feature = "content_hash")]
+pub(crate) fn file_hash_completed<S: States>(
+    state: Res<State<S>>,
+    asset_loader_configuration: Res<AssetLoaderConfiguration<S>>,
+) -> bool {
+    let Some(slot) = asset_loader_configuration
+        .state_configurations
+        .get(state.get())
+        .and_then(|config| config.hashing_file.as_ref())
+    else {
+        return true;
+    };
+    let completed = slot.lock().unwrap().is_some();
+    completed
+}
+
+/// Whether all render world uploads of the current loading state completed
+#[cfg(any(feature = "2d", feature = "3d"))]
+pub(crate) fn render_uploads_completed<S: States>(
+    state: Res<State<S>>,
+    asset_loader_configuration: Res<AssetLoaderConfiguration<S>>,
+    uploads: Option<Res<RenderUploads>>,
+) -> bool {
+    let (Some(config), Some(uploads)) = (
+        asset_loader_configuration
+            .state_configurations
+            .get(state.get()),
+        uploads,
+    ) else {
+        return true;
+    };
+    !uploads.any_pending(&config.render_uploads)
+}
@@ -0,0 +1,106 @@
+use bevy::asset::{Assets, Handle};
+use bevy::ecs::world::World;
+use bevy::log::warn;
+use bevy::render::render_resource::{TextureDimension, TextureFormat};
+use bevy::render::texture::Image;
+
+/// Generate the full mipmap chain of a loaded image
+///
+/// Each level halves the size of the previous one by averaging blocks of 2x2 pixels, until
+/// a single pixel is left. Color channels of sRGB images are averaged in linear space.
+/// The mip levels are appended to the image data and uploaded together with the image.
+///
+/// Only 2d images with 8 bit RGBA or BGRA formats and a single mip level are supported.
+/// For other images, a warning is logged and the image is left untouched.
+#[doc(hidden)]
+pub fn generate_mipmaps(world: &mut World, handle: &Handle<Image>) {
+    let mut images = world
+        .get_resource_mut::<Assets<Image>>()
+        .expect("Cannot get Assets<Image>");
+    // Without a loading state, the image might not be loaded yet
+    let Some((data, mip_level_count)) = images.get(handle).and_then(mip_chain) else {
+        return;
+    };
+    let image = images
+        .get_mut(handle)
+        .expect("The image was available a moment ago");
+    image.data = data;
+    image.texture_descriptor.mip_level_count = mip_level_count;
+}
+
+fn mip_chain(image: &Image) -> Option<(Vec<u8>, u32)> {
+    let descriptor = &image.texture_descriptor;
+    let srgb = match descriptor.format {
+        TextureFormat::Rgba8UnormSrgb | TextureFormat::Bgra8UnormSrgb => true,
+        TextureFormat::Rgba8Unorm | TextureFormat::Bgra8Unorm => false,
+        format => {
+            warn!(
+                ?format,
+                "Mipmaps can only be generated for images with 8 bit RGBA or BGRA formats"
+            );
+            return None;
+        }
+    };
+    if descriptor.dimension != TextureDimension::D2
+        || descriptor.size.depth_or_array_layers != 1
+        || descriptor.mip_level_count != 1
+    {
+        warn!("Mipmaps can only be generated for 2d images without existing mip levels");
+        return None;
+    }
+
+    let mut width = descriptor.size.width as usize;
+    let mut height = descriptor.size.height as usize;
+    let mut data = image.data.clone();
+    let mut level_start = 0;
+    let mut mip_level_count = 1;
+    while width > 1 || height > 1 {
+        let next_width = (width / 2).max(1);
+        let next_height = (height / 2).max(1);
+        let next_level_start = data.len();
+        for y in 0..next_height {
+            for x in 0..next_width {
+                let mut sum = [0.; 4];
+                for (dx, dy) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+                    let source_x = (x * 2 + dx).min(width - 1);
+                    let source_y = (y * 2 + dy).min(height - 1);
+                    let pixel = level_start + (source_y * width + source_x) * 4;
+                    for (channel, sum) in sum.iter_mut().enumerate() {
+                        *sum += decode(data[pixel + channel], srgb && channel < 3);
+                    }
+                }
+                for (channel, sum) in sum.iter().enumerate() {
+                    data.push(encode(sum / 4., srgb && channel < 3));
+                }
+            }
+        }
+        width = next_width;
+        height = next_height;
+        level_start = next_level_start;
+        mip_level_count += 1;
+    }
+
+    Some((data, mip_level_count))
+}
+
+fn decode(value: u8, srgb: bool) -> f32 {
+    let value = value as f32 / 255.;
+    if !srgb {
+        value
+    } else if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn encode(value: f32, srgb: bool) -> u8 {
+    let value = if !srgb {
+        value
+    } else if value <= 0.003_130_8 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1. / 2.4) - 0.055
+    };
+    (value * 255.).round().clamp(0., 255.) as u8
+}
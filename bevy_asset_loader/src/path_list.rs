@@ -0,0 +1,157 @@
+use bevy::app::{App, Plugin};
+use bevy::asset::{
+    AddAsset, Asset, AssetLoader, AssetServer, Assets, Handle, HandleUntyped, LoadContext,
+    LoadedAsset,
+};
+use bevy::ecs::world::World;
+use bevy::reflect::{TypePath, TypeUuid};
+use bevy::utils::BoxedFuture;
+use path_slash::PathExt;
+use std::path::Path;
+
+/// List of asset paths loaded from a text file
+///
+/// The file contains one path per line. Paths are relative to the directory of the list file.
+/// Empty lines and lines starting with `#` are ignored.
+/// ```text
+/// # Trees of the forest pack
+/// trees/oak.png
+/// trees/pine.png
+/// ```
+/// Path lists are loaded by the [`PathListPlugin`].
+#[derive(TypeUuid, TypePath, Debug, Clone, Default, PartialEq, Eq)]
+#[uuid = "4b0d7e3a-6f1c-4c1e-8a9e-2d5b7c3f9a10"]
+pub struct PathList(pub Vec<String>);
+
+impl PathList {
+    /// Parse the content of a path list file located in the given directory
+    pub fn parse(list: &str, directory: &Path) -> Self {
+        PathList(
+            list.lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .map(|line| {
+                    directory
+                        .join(line)
+                        .to_slash()
+                        .expect("Path should be valid UTF-8")
+                        .into_owned()
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Plugin loading [`PathList`]s for fields with the `path_list` attribute
+///
+/// By default, path lists are loaded from files ending on `.txt`. Use [`PathListPlugin::new`]
+/// to configure different file endings.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy::asset::AssetPlugin;
+/// # fn main() {
+///     App::new()
+///         .add_plugins((
+///             MinimalPlugins,
+///             AssetPlugin::default(),
+///             PathListPlugin::default(),
+///         ))
+/// #       .set_runner(|mut app| app.update())
+///         .run();
+/// # }
+/// ```
+pub struct PathListPlugin {
+    file_endings: Vec<&'static str>,
+}
+
+impl PathListPlugin {
+    /// Load path lists from files with the given endings
+    pub fn new(file_endings: &[&'static str]) -> Self {
+        PathListPlugin {
+            file_endings: file_endings.to_vec(),
+        }
+    }
+}
+
+impl Default for PathListPlugin {
+    fn default() -> Self {
+        PathListPlugin::new(&["txt"])
+    }
+}
+
+impl Plugin for PathListPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<PathList>()
+            .add_asset_loader(PathListLoader {
+                file_endings: self.file_endings.clone(),
+            });
+    }
+}
+
+struct PathListLoader {
+    file_endings: Vec<&'static str>,
+}
+
+impl AssetLoader for PathListLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+        Box::pin(async move {
+            let list = std::str::from_utf8(bytes)?;
+            let directory = load_context.path().parent().unwrap_or(Path::new(""));
+            let path_list = PathList::parse(list, directory);
+            load_context.set_default_asset(LoadedAsset::new(path_list));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &self.file_endings
+    }
+}
+
+/// Start loading all paths of a loaded path list
+///
+/// Returns no handles if the path list is not loaded.
+#[doc(hidden)]
+pub fn load_listed_paths(world: &World, list_path: &str) -> Vec<HandleUntyped> {
+    let asset_server = world
+        .get_resource::<AssetServer>()
+        .expect("Cannot get AssetServer");
+    let Some(path_list) = get_path_list(world, list_path) else {
+        return vec![];
+    };
+    path_list
+        .0
+        .iter()
+        .map(|path| asset_server.load_untyped(path.as_str()))
+        .collect()
+}
+
+/// Handles to all paths of a loaded path list
+#[doc(hidden)]
+pub fn listed_handles<T: Asset>(world: &World, list_path: &str) -> Vec<Handle<T>> {
+    let asset_server = world
+        .get_resource::<AssetServer>()
+        .expect("Cannot get AssetServer");
+    get_path_list(world, list_path)
+        .unwrap_or_else(|| panic!("The path list '{list_path}' is not loaded"))
+        .0
+        .iter()
+        .map(|path| asset_server.get_handle(path.as_str()))
+        .collect()
+}
+
+fn get_path_list<'w>(world: &'w World, list_path: &str) -> Option<&'w PathList> {
+    let asset_server = world
+        .get_resource::<AssetServer>()
+        .expect("Cannot get AssetServer");
+    let handle: Handle<PathList> = asset_server.get_handle(list_path);
+    world
+        .get_resource::<Assets<PathList>>()
+        .expect("Cannot get Assets<PathList>. Did you add the PathListPlugin?")
+        .get(&handle)
+}
@@ -0,0 +1,172 @@
+use crate::asset_collection::{pin_handles, AssetCollection, RecoverableHandles};
+use crate::loading_state::describe_handle;
+use bevy::app::{PreUpdate, Update};
+use bevy::asset::{AssetServer, HandleUntyped, LoadState};
+use bevy::ecs::schedule::{ScheduleLabel, Schedules};
+use bevy::ecs::system::{Command, Resource};
+use bevy::ecs::world::World;
+use bevy::log::{debug, warn};
+use std::any::type_name;
+use std::marker::PhantomData;
+
+/// Command to load an asset collection again and replace its resource once all assets are loaded
+///
+/// The command removes the resource of the collection and calls [`AssetCollection::load`].
+/// As soon as all assets are loaded, the collection is created and inserted again. This happens
+/// independent of any loading state, so the current state does not change. Systems using the
+/// collection while it reloads should access it as `Option<Res<A>>`.
+///
+/// Reloading picks up changed [`DynamicAssets`](crate::dynamic_asset::DynamicAssets), for example
+/// keys that point to different files now. Assets that are already loaded are not read from disk
+/// again; use the hot reloading of the `AssetServer` for that. The assets of the previous
+/// collection are kept alive until the new collection is inserted.
+///
+/// If an asset fails to load, a warning is logged and the collection is not inserted again.
+/// Fields with an `on_fail` fallback are filled by their fallback instead.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// fn reload_on_key_press(mut commands: Commands, keys: Res<Input<KeyCode>>) {
+///     if keys.just_pressed(KeyCode::F5) {
+///         commands.add(ReloadCollection::<LevelAssets>::new());
+///     }
+/// }
+///
+/// #[derive(AssetCollection, Resource)]
+/// struct LevelAssets {
+///     #[asset(key = "level.background")]
+///     background: Handle<Image>,
+/// }
+/// ```
+pub struct ReloadCollection<A: AssetCollection> {
+    marker: PhantomData<A>,
+}
+
+impl<A: AssetCollection> ReloadCollection<A> {
+    /// Create a command reloading the collection `A`
+    pub fn new() -> Self {
+        ReloadCollection {
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<A: AssetCollection> Default for ReloadCollection<A> {
+    fn default() -> Self {
+        ReloadCollection::new()
+    }
+}
+
+impl<A: AssetCollection> Command for ReloadCollection<A> {
+    fn apply(self, world: &mut World) {
+        if !world.contains_resource::<ReloadCollectionSystem<A>>() && !add_check_system::<A>(world)
+        {
+            warn!(
+                collection = type_name::<A>(),
+                "Cannot reload collection without the 'PreUpdate' or 'Update' schedule"
+            );
+            return;
+        }
+        debug!(collection = type_name::<A>(), "Reloading collection");
+        let previous = world.remove_resource::<A>();
+        let handles = A::load(world);
+        world.insert_resource(PendingCollectionReload::<A> {
+            handles,
+            deferred_issued: false,
+            previous,
+        });
+    }
+}
+
+/// Marks that the system checking reloads of the collection `A` was added
+#[derive(Resource)]
+struct ReloadCollectionSystem<A> {
+    marker: PhantomData<A>,
+}
+
+/// Handles of a collection that is reloading
+#[derive(Resource)]
+struct PendingCollectionReload<A> {
+    handles: Vec<HandleUntyped>,
+    /// Whether the [deferred assets](AssetCollection::load_deferred) started loading
+    deferred_issued: bool,
+    /// The collection before the reload, keeping its assets alive
+    #[allow(dead_code)]
+    previous: Option<A>,
+}
+
+/// Add the system checking reloads of `A` to the first main schedule that is not running
+fn add_check_system<A: AssetCollection>(world: &mut World) -> bool {
+    let mut schedules = world.resource_mut::<Schedules>();
+    let labels: [&dyn ScheduleLabel; 2] = [&PreUpdate, &Update];
+    let Some(schedule) = labels
+        .into_iter()
+        .find(|label| schedules.contains(*label))
+        .and_then(|label| schedules.get_mut(label))
+    else {
+        return false;
+    };
+    schedule.add_systems(check_reloading_collection::<A>);
+    world.insert_resource(ReloadCollectionSystem::<A> {
+        marker: PhantomData,
+    });
+    true
+}
+
+fn check_reloading_collection<A: AssetCollection>(world: &mut World) {
+    let Some(reload) = world.get_resource::<PendingCollectionReload<A>>() else {
+        return;
+    };
+    let asset_server = world.resource::<AssetServer>();
+    let recoverable = world
+        .get_resource::<RecoverableHandles<A>>()
+        .map(RecoverableHandles::handle_ids)
+        .unwrap_or_default();
+    let load_states: Vec<_> = reload
+        .handles
+        .iter()
+        .map(|handle| match asset_server.get_load_state(handle.id()) {
+            // Fields with a fallback are replaced on creation
+            LoadState::Failed if recoverable.contains(&handle.id()) => LoadState::Loaded,
+            load_state => load_state,
+        })
+        .collect();
+    if load_states.contains(&LoadState::Failed) {
+        for (handle, load_state) in reload.handles.iter().zip(load_states) {
+            if load_state == LoadState::Failed {
+                warn!(
+                    collection = type_name::<A>(),
+                    path = %describe_handle(asset_server, handle.id()),
+                    "Failed to load asset"
+                );
+            }
+        }
+        world.remove_resource::<PendingCollectionReload<A>>();
+        return;
+    }
+    if !load_states
+        .iter()
+        .all(|load_state| *load_state == LoadState::Loaded)
+    {
+        return;
+    }
+    if !reload.deferred_issued {
+        // Deferred assets only start loading once all other assets are loaded
+        let deferred = A::load_deferred(world);
+        let mut reload = world.resource_mut::<PendingCollectionReload<A>>();
+        reload.deferred_issued = true;
+        if !deferred.is_empty() {
+            reload.handles.extend(deferred);
+            return;
+        }
+    }
+    if !A::ready_to_create(world) {
+        return;
+    }
+    let collection = A::create(world);
+    world.insert_resource(collection);
+    if let Some(reload) = world.remove_resource::<PendingCollectionReload<A>>() {
+        pin_handles::<A>(world, &reload.handles);
+    }
+    debug!(collection = type_name::<A>(), "Reloaded collection");
+}
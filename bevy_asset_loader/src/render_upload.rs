@@ -0,0 +1,56 @@
+use crate::asset_collection::AssetCollection;
+use bevy::ecs::system::{Res, Resource};
+use bevy::utils::HashSet;
+use std::any::TypeId;
+use std::sync::{Arc, Mutex};
+
+/// Uploads of asset collections to the render world that a loading state waits for
+///
+/// The same resource is inserted into the main world and the render world, so systems in
+/// either world can see which uploads are pending. Systems added with
+/// [`LoadingState::upload_in_render_world`](crate::loading_state::LoadingState::upload_in_render_world)
+/// call [`complete`](Self::complete) once they uploaded the data of their collection.
+#[derive(Resource, Clone, Default)]
+pub struct RenderUploads {
+    pending: Arc<Mutex<HashSet<TypeId>>>,
+}
+
+impl RenderUploads {
+    /// Whether the upload of the collection `A` was requested and did not complete yet
+    pub fn is_pending<A: AssetCollection>(&self) -> bool {
+        self.pending.lock().unwrap().contains(&TypeId::of::<A>())
+    }
+
+    /// Mark the upload of the collection `A` as completed
+    ///
+    /// This allows the loading state to continue to its next state.
+    pub fn complete<A: AssetCollection>(&self) {
+        self.pending.lock().unwrap().remove(&TypeId::of::<A>());
+    }
+
+    pub(crate) fn request(&self, collections: &HashSet<TypeId>) {
+        self.pending
+            .lock()
+            .unwrap()
+            .extend(collections.iter().copied());
+    }
+
+    pub(crate) fn withdraw(&self, collections: &HashSet<TypeId>) {
+        self.pending
+            .lock()
+            .unwrap()
+            .retain(|collection| !collections.contains(collection));
+    }
+
+    pub(crate) fn any_pending(&self, collections: &HashSet<TypeId>) -> bool {
+        let pending = self.pending.lock().unwrap();
+        collections
+            .iter()
+            .any(|collection| pending.contains(collection))
+    }
+}
+
+/// Run condition for render world systems uploading the collection `A`
+pub(crate) fn upload_pending<A: AssetCollection>(uploads: Option<Res<RenderUploads>>) -> bool {
+    uploads.is_some_and(|uploads| uploads.is_pending::<A>())
+}
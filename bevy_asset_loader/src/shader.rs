@@ -0,0 +1,53 @@
+use bevy::asset::{AssetServer, Assets, Handle, HandleUntyped};
+use bevy::ecs::system::Resource;
+use bevy::ecs::world::World;
+use bevy::render::render_resource::{Shader, ShaderImport};
+use bevy::utils::HashMap;
+
+/// Handles to the imports of shaders in asset collections, by the path of the importing shader
+///
+/// Nothing else holds strong handles to the imports, so Bevy would unload them again.
+#[derive(Resource, Default)]
+struct ShaderImports {
+    handles: HashMap<String, Vec<HandleUntyped>>,
+}
+
+/// Start loading the shaders imported by asset path from a loaded shader
+///
+/// Bevy loads these imports as dependencies of the shader, but the load state of the shader
+/// does not include them. Only direct imports are returned; imports like
+/// `#import bevy_pbr::mesh_functions` that refer to a module name instead of a path are ignored.
+///
+/// Returns no handles if the shader is not loaded.
+#[doc(hidden)]
+pub fn load_shader_imports(world: &World, shader_path: &str) -> Vec<HandleUntyped> {
+    let asset_server = world
+        .get_resource::<AssetServer>()
+        .expect("Cannot get AssetServer");
+    let shaders = world
+        .get_resource::<Assets<Shader>>()
+        .expect("Cannot get Assets<Shader>");
+    let handle: Handle<Shader> = asset_server.get_handle(shader_path);
+    let Some(shader) = shaders.get(&handle) else {
+        return vec![];
+    };
+    shader
+        .imports()
+        .filter_map(|import| match import {
+            ShaderImport::AssetPath(path) => Some(asset_server.load_untyped(path.as_str())),
+            ShaderImport::Custom(_) => None,
+        })
+        .collect()
+}
+
+/// Keep the imports of a loaded shader loaded, as long as the app runs
+///
+/// Creating the collection again replaces the kept imports of the shader.
+#[doc(hidden)]
+pub fn keep_shader_imports(world: &mut World, shader_path: &str) {
+    let imports = load_shader_imports(world, shader_path);
+    world
+        .get_resource_or_insert_with(ShaderImports::default)
+        .handles
+        .insert(shader_path.to_owned(), imports);
+}
@@ -1,17 +1,22 @@
+use crate::asset_collection::{transform_asset_path, AssetCollection, AssetPathTransform};
 use crate::dynamic_asset::{DynamicAsset, DynamicAssetType};
-use bevy::asset::{AssetServer, HandleUntyped};
-use bevy::ecs::system::Command;
+use bevy::asset::{AssetServer, Assets, Handle, HandleUntyped};
+use bevy::ecs::schedule::States;
+use bevy::ecs::system::{Command, Resource};
 use bevy::ecs::world::World;
+use bevy::log::warn;
 #[cfg(feature = "2d")]
 use bevy::math::Vec2;
 
 use crate::dynamic_asset::{DynamicAssetCollection, DynamicAssets};
 use bevy::reflect::{TypePath, TypeUuid};
 use bevy::utils::HashMap;
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
 
 /// These asset variants can be loaded from configuration files. They will then replace
 /// a dynamic asset based on their keys.
-#[derive(Debug, Clone, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub enum StandardDynamicAsset {
     /// A dynamic asset directly loaded from a single file
     File {
@@ -38,6 +43,12 @@ pub enum StandardDynamicAsset {
         /// Asset file path
         path: String,
     },
+    /// A dynamic color material asset directly loaded from an image file
+    #[cfg(feature = "2d")]
+    ColorMaterial {
+        /// Asset file path
+        path: String,
+    },
     /// A dynamic texture atlas asset loaded from a sprite sheet
     #[cfg(feature = "2d")]
     TextureAtlas {
@@ -64,22 +75,37 @@ pub enum StandardDynamicAsset {
 
 impl DynamicAsset for StandardDynamicAsset {
     fn load(&self, asset_server: &AssetServer) -> Vec<HandleUntyped> {
+        self.load_with_transform(asset_server, None)
+    }
+
+    fn load_with_transform(
+        &self,
+        asset_server: &AssetServer,
+        transform: Option<&AssetPathTransform>,
+    ) -> Vec<HandleUntyped> {
+        let transformed = |path: &str| transform_asset_path(None, transform, path);
         match self {
-            StandardDynamicAsset::File { path } => vec![asset_server.load_untyped(path)],
+            StandardDynamicAsset::File { path } => {
+                vec![asset_server.load_untyped(transformed(path).as_str())]
+            }
             StandardDynamicAsset::Folder { path } => asset_server
-                .load_folder(path)
+                .load_folder(transformed(path).as_str())
                 .unwrap_or_else(|_| panic!("Failed to load '{path}' as a folder")),
             StandardDynamicAsset::Files { paths } => paths
                 .iter()
-                .map(|path| asset_server.load_untyped(path))
+                .map(|path| asset_server.load_untyped(transformed(path).as_str()))
                 .collect(),
             #[cfg(feature = "3d")]
             StandardDynamicAsset::StandardMaterial { path } => {
-                vec![asset_server.load_untyped(path)]
+                vec![asset_server.load_untyped(transformed(path).as_str())]
+            }
+            #[cfg(feature = "2d")]
+            StandardDynamicAsset::ColorMaterial { path } => {
+                vec![asset_server.load_untyped(transformed(path).as_str())]
             }
             #[cfg(feature = "2d")]
             StandardDynamicAsset::TextureAtlas { path, .. } => {
-                vec![asset_server.load_untyped(path)]
+                vec![asset_server.load_untyped(transformed(path).as_str())]
             }
         }
     }
@@ -89,9 +115,11 @@ impl DynamicAsset for StandardDynamicAsset {
         let asset_server = cell
             .get_resource::<AssetServer>()
             .expect("Cannot get AssetServer");
+        let transform = cell.get_resource::<AssetPathTransform>();
+        let transformed = |path: &str| transform_asset_path(None, transform.as_deref(), path);
         match self {
             StandardDynamicAsset::File { path } => Ok(DynamicAssetType::Single(
-                asset_server.get_handle_untyped(path),
+                asset_server.get_handle_untyped(transformed(path).as_str()),
             )),
             #[cfg(feature = "3d")]
             StandardDynamicAsset::StandardMaterial { path } => {
@@ -101,7 +129,26 @@ impl DynamicAsset for StandardDynamicAsset {
                 let handle = materials
                     .add(
                         asset_server
-                            .get_handle::<bevy::render::texture::Image, &String>(path)
+                            .get_handle::<bevy::render::texture::Image, _>(
+                                transformed(path).as_str(),
+                            )
+                            .into(),
+                    )
+                    .clone_untyped();
+
+                Ok(DynamicAssetType::Single(handle))
+            }
+            #[cfg(feature = "2d")]
+            StandardDynamicAsset::ColorMaterial { path } => {
+                let mut materials = cell
+                    .get_resource_mut::<bevy::asset::Assets<bevy::sprite::ColorMaterial>>()
+                    .expect("Cannot get resource Assets<ColorMaterial>");
+                let handle = materials
+                    .add(
+                        asset_server
+                            .get_handle::<bevy::render::texture::Image, _>(
+                                transformed(path).as_str(),
+                            )
                             .into(),
                     )
                     .clone_untyped();
@@ -125,7 +172,7 @@ impl DynamicAsset for StandardDynamicAsset {
                     .expect("Cannot get resource Assets<TextureAtlas>");
                 let handle = atlases
                     .add(bevy::sprite::TextureAtlas::from_grid(
-                        asset_server.get_handle(path),
+                        asset_server.get_handle(transformed(path).as_str()),
                         Vec2::new(*tile_size_x, *tile_size_y),
                         *columns,
                         *rows,
@@ -138,17 +185,21 @@ impl DynamicAsset for StandardDynamicAsset {
             }
             StandardDynamicAsset::Folder { path } => Ok(DynamicAssetType::Collection(
                 asset_server
-                    .load_folder(path)
+                    .load_folder(transformed(path).as_str())
                     .unwrap_or_else(|_| panic!("Failed to load '{path}' as a folder")),
             )),
             StandardDynamicAsset::Files { paths } => Ok(DynamicAssetType::Collection(
                 paths
                     .iter()
-                    .map(|path| asset_server.load_untyped(path))
+                    .map(|path| asset_server.load_untyped(transformed(path).as_str()))
                     .collect(),
             )),
         }
     }
+
+    fn as_standard(&self) -> Option<StandardDynamicAsset> {
+        Some(self.clone())
+    }
 }
 
 /// Command to register a standard dynamic asset under the given key
@@ -181,3 +232,274 @@ impl DynamicAssetCollection for StandardDynamicAssetCollection {
         }
     }
 }
+
+impl DynamicAssets {
+    /// Set the corresponding standard dynamic assets for all given keys.
+    ///
+    /// Takes e.g. a `HashMap<String, StandardDynamicAsset>` that was deserialized from your own
+    /// configuration format. In case a key is already known, its value will be overwritten.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::utils::HashMap;
+    /// fn register_config(mut dynamic_assets: ResMut<DynamicAssets>) {
+    ///     let mut config = HashMap::default();
+    ///     config.insert(
+    ///         "background".to_owned(),
+    ///         StandardDynamicAsset::File {
+    ///             path: "images/background.png".to_owned(),
+    ///         },
+    ///     );
+    ///     dynamic_assets.register_standard_assets(config);
+    /// }
+    /// ```
+    pub fn register_standard_assets<K: Into<String>>(
+        &mut self,
+        assets: impl IntoIterator<Item = (K, StandardDynamicAsset)>,
+    ) {
+        self.register_all(
+            assets
+                .into_iter()
+                .map(|(key, asset)| (key, Box::new(asset) as Box<dyn DynamicAsset>)),
+        );
+    }
+}
+
+/// Snapshot of the dynamic assets bound to the keys of asset collections
+///
+/// A manifest records which [`StandardDynamicAsset`] is registered for every key used by the
+/// given collections, but not the loaded handles. This allows saving the asset bindings of e.g.
+/// a level editor and restoring them on the next start.
+///
+/// Manifests serialize to the same format as [`StandardDynamicAssetCollection`] files, so a saved
+/// manifest can be loaded with [`add_dynamic_collection_to_loading_state`](crate::loading_state::LoadingStateAppExt::add_dynamic_collection_to_loading_state).
+/// Alternatively, read it with [`CollectionManifest::from_ron`] and register it in the
+/// [`DynamicAssets`] resource through [`DynamicAssetCollection::register`].
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// fn save_bindings(world: &mut World) {
+///     let manifest = CollectionManifest::snapshot::<LevelAssets>(world);
+///     let ron = manifest.to_ron().expect("Failed to serialize the manifest");
+///     // write `ron` to e.g. `assets/levels/custom.assets.ron`
+/// }
+///
+/// #[derive(AssetCollection, Resource)]
+/// struct LevelAssets {
+///     #[asset(key = "level.floor")]
+///     floor: Handle<Image>,
+/// }
+/// ```
+// Keys are kept sorted to produce stable files
+#[derive(Debug, Clone, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct CollectionManifest(pub BTreeMap<String, StandardDynamicAsset>);
+
+impl CollectionManifest {
+    /// Create a manifest of the dynamic assets currently registered for the collection `A`
+    ///
+    /// Keys without a registered asset and custom dynamic assets that cannot be described as
+    /// [`StandardDynamicAsset`] are left out.
+    pub fn snapshot<A: AssetCollection>(world: &World) -> Self {
+        let mut manifest = CollectionManifest::default();
+        manifest.add_collection::<A>(world);
+        manifest
+    }
+
+    /// Add the dynamic assets currently registered for the collection `A` to this manifest
+    pub fn add_collection<A: AssetCollection>(&mut self, world: &World) -> &mut Self {
+        let dynamic_assets = world
+            .get_resource::<DynamicAssets>()
+            .expect("Cannot get DynamicAssets");
+        for key in A::dynamic_asset_keys() {
+            if let Some(asset) = dynamic_assets
+                .get_asset(&key)
+                .and_then(|asset| asset.as_standard())
+            {
+                self.0.insert(key, asset);
+            }
+        }
+        self
+    }
+
+    /// Serialize the manifest to RON
+    pub fn to_ron(&self) -> Result<String, ron::Error> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+    }
+
+    /// Deserialize a manifest from RON
+    pub fn from_ron(manifest: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(manifest)
+    }
+}
+
+impl DynamicAssetCollection for CollectionManifest {
+    fn register(&self, dynamic_assets: &mut DynamicAssets) {
+        for (key, asset) in self.0.iter() {
+            dynamic_assets.register_asset(key, Box::new(asset.clone()));
+        }
+    }
+}
+
+/// The variants of [`StandardDynamicAsset`] without their content
+///
+/// All kinds are available independent of the enabled features, so a schema can be shared
+/// between builds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum StandardDynamicAssetKind {
+    /// See [`StandardDynamicAsset::File`]
+    File,
+    /// See [`StandardDynamicAsset::Folder`]
+    Folder,
+    /// See [`StandardDynamicAsset::Files`]
+    Files,
+    /// A standard material loaded from an image file (requires the `3d` feature)
+    StandardMaterial,
+    /// A color material loaded from an image file (requires the `2d` feature)
+    ColorMaterial,
+    /// A texture atlas loaded from a sprite sheet (requires the `2d` feature)
+    TextureAtlas,
+}
+
+impl StandardDynamicAsset {
+    /// The variant of this dynamic asset
+    pub fn kind(&self) -> StandardDynamicAssetKind {
+        match self {
+            StandardDynamicAsset::File { .. } => StandardDynamicAssetKind::File,
+            StandardDynamicAsset::Folder { .. } => StandardDynamicAssetKind::Folder,
+            StandardDynamicAsset::Files { .. } => StandardDynamicAssetKind::Files,
+            #[cfg(feature = "3d")]
+            StandardDynamicAsset::StandardMaterial { .. } => {
+                StandardDynamicAssetKind::StandardMaterial
+            }
+            #[cfg(feature = "2d")]
+            StandardDynamicAsset::ColorMaterial { .. } => StandardDynamicAssetKind::ColorMaterial,
+            #[cfg(feature = "2d")]
+            StandardDynamicAsset::TextureAtlas { .. } => StandardDynamicAssetKind::TextureAtlas,
+        }
+    }
+}
+
+/// Schema listing the keys a dynamic assets file has to provide
+///
+/// Every key is mapped to the expected [`StandardDynamicAssetKind`]. In RON, a schema looks like
+/// ```ron
+/// ({
+///     "images.player": File,
+///     "images.tiles": TextureAtlas,
+///     "sounds": Files,
+/// })
+/// ```
+/// Use [`LoadingState::validate_dynamic_assets_against`](crate::loading_state::LoadingState::validate_dynamic_assets_against)
+/// to check the dynamic assets of a loading state against a schema file or call
+/// [`DynamicAssetsSchema::validate`] yourself.
+#[derive(
+    Debug, Clone, Default, PartialEq, serde::Deserialize, serde::Serialize, TypeUuid, TypePath,
+)]
+#[uuid = "5e0c1a6b-7f0d-4f4e-9b3a-3c1b8e2d6a47"]
+pub struct DynamicAssetsSchema(pub BTreeMap<String, StandardDynamicAssetKind>);
+
+impl DynamicAssetsSchema {
+    /// Deserialize a schema from RON
+    pub fn from_ron(schema: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(schema)
+    }
+
+    /// Check the registered dynamic assets against this schema
+    ///
+    /// Returns one [`SchemaDiscrepancy`] for every key that is missing or bound to a dynamic asset
+    /// of another kind. Keys that are not part of the schema are ignored.
+    pub fn validate(&self, dynamic_assets: &DynamicAssets) -> Vec<SchemaDiscrepancy> {
+        self.0
+            .iter()
+            .filter_map(|(key, expected)| {
+                let Some(asset) = dynamic_assets.get_asset(key) else {
+                    return Some(SchemaDiscrepancy::MissingKey {
+                        key: key.clone(),
+                        expected: *expected,
+                    });
+                };
+                let found = asset.as_standard().map(|asset| asset.kind());
+                (found != Some(*expected)).then(|| SchemaDiscrepancy::WrongKind {
+                    key: key.clone(),
+                    expected: *expected,
+                    found,
+                })
+            })
+            .collect()
+    }
+}
+
+// Schemas are loaded like dynamic asset collection files, but do not register anything
+impl DynamicAssetCollection for DynamicAssetsSchema {
+    fn register(&self, _dynamic_assets: &mut DynamicAssets) {}
+}
+
+/// A difference between the registered dynamic assets and a [`DynamicAssetsSchema`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaDiscrepancy {
+    /// No dynamic asset is registered for the key
+    MissingKey {
+        /// The key required by the schema
+        key: String,
+        /// The kind of asset the schema expects
+        expected: StandardDynamicAssetKind,
+    },
+    /// The dynamic asset registered for the key is of another kind
+    WrongKind {
+        /// The key required by the schema
+        key: String,
+        /// The kind of asset the schema expects
+        expected: StandardDynamicAssetKind,
+        /// The kind of the registered asset or `None` for custom dynamic assets
+        found: Option<StandardDynamicAssetKind>,
+    },
+}
+
+/// Resource listing the differences between the dynamic assets of the last loading state
+/// and its schema files
+///
+/// Inserted when the loading state starts loading its collections if it was configured with
+/// [`LoadingState::validate_dynamic_assets_against`](crate::loading_state::LoadingState::validate_dynamic_assets_against).
+#[derive(Resource, Debug, Default)]
+pub struct SchemaDiscrepancies {
+    /// All discrepancies in the order of the schema files
+    pub discrepancies: Vec<SchemaDiscrepancy>,
+}
+
+/// Strong handles to the schema files of the current loading state
+///
+/// Keeps the schemas alive after the dynamic asset collection files got registered.
+#[derive(Resource)]
+pub(crate) struct LoadingSchemas<S: States> {
+    handles: Vec<Handle<DynamicAssetsSchema>>,
+    _marker: PhantomData<S>,
+}
+
+pub(crate) fn load_schemas<S: States>(world: &mut World, paths: &[String]) {
+    let asset_server = world.resource::<AssetServer>();
+    let handles = paths.iter().map(|path| asset_server.load(path)).collect();
+    world.insert_resource(LoadingSchemas::<S> {
+        handles,
+        _marker: PhantomData,
+    });
+}
+
+pub(crate) fn validate_dynamic_assets<S: States>(world: &mut World) {
+    let Some(schemas) = world.remove_resource::<LoadingSchemas<S>>() else {
+        return;
+    };
+    let mut discrepancies = vec![];
+    {
+        let schema_assets = world.resource::<Assets<DynamicAssetsSchema>>();
+        let dynamic_assets = world.resource::<DynamicAssets>();
+        for handle in &schemas.handles {
+            if let Some(schema) = schema_assets.get(handle) {
+                discrepancies.extend(schema.validate(dynamic_assets));
+            }
+        }
+    }
+    for discrepancy in &discrepancies {
+        warn!("Dynamic assets do not match the schema: {discrepancy:?}");
+    }
+    world.insert_resource(SchemaDiscrepancies { discrepancies });
+}
@@ -0,0 +1,82 @@
+use bevy::app::App;
+use bevy::ecs::schedule::{State, States};
+
+/// The app did not reach the target state of [`run_until_state`] in time
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateNotReached<S: States> {
+    /// The state the app was expected to reach
+    pub target: S,
+    /// The state the app was in after the last update
+    ///
+    /// This is `None` if the app has no [`State<S>`] resource.
+    pub current: Option<S>,
+    /// Number of times the app was updated
+    pub iterations: usize,
+}
+
+/// Update the app until it is in the given state
+///
+/// This is meant as a helper for tests of loading states. Instead of looping over `app.update()`
+/// an unknown number of times, the app is updated until the state `S` equals `target`. The
+/// returned number of updates is 0 if the app already is in the target state.
+///
+/// After `max_iterations` updates without reaching `target`, an error with the current state is
+/// returned. This keeps a misconfigured loading state from hanging the test.
+/// ```edition2021,no_run
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy_asset_loader::test_utils::run_until_state;
+/// # use bevy::prelude::*;
+/// # use bevy::asset::AssetPlugin;
+/// # use bevy::audio::AudioPlugin;
+/// # fn main() {
+///     let mut app = App::new();
+///     app.add_plugins((MinimalPlugins, AssetPlugin::default(), AudioPlugin::default()))
+///         .add_state::<GameState>()
+///         .add_loading_state(
+///             LoadingState::new(GameState::Loading).continue_to_state(GameState::Next),
+///         )
+///         .add_collection_to_loading_state::<_, AudioAssets>(GameState::Loading);
+///
+///     run_until_state(&mut app, GameState::Next, 1000).expect("Loading did not finish");
+///     assert!(app.world.contains_resource::<AudioAssets>());
+/// # }
+/// #[derive(AssetCollection, Resource)]
+/// struct AudioAssets {
+///     #[asset(path = "audio/background.ogg")]
+///     background: Handle<AudioSource>,
+/// }
+///
+/// #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+/// enum GameState {
+///     #[default]
+///     Loading,
+///     Next,
+/// }
+/// ```
+pub fn run_until_state<S: States>(
+    app: &mut App,
+    target: S,
+    max_iterations: usize,
+) -> Result<usize, StateNotReached<S>> {
+    let current_state = |app: &App| -> Option<S> {
+        app.world
+            .get_resource::<State<S>>()
+            .map(|state| state.get().clone())
+    };
+    let mut iterations = 0;
+    loop {
+        let current = current_state(app);
+        if current.as_ref() == Some(&target) {
+            return Ok(iterations);
+        }
+        if iterations >= max_iterations {
+            return Err(StateNotReached {
+                target,
+                current,
+                iterations,
+            });
+        }
+        app.update();
+        iterations += 1;
+    }
+}
@@ -0,0 +1,76 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn loads_assets_relative_to_the_executable() {
+    let executable_dir = std::env::current_exe()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .to_path_buf();
+    std::fs::copy(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/assets/audio/plop.ogg"),
+        executable_dir.join("absolute_path_plop.ogg"),
+    )
+    .expect("Failed to copy the test asset next to the executable");
+
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_failure_continue_to_state(MyStates::Error),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .add_systems(OnEnter(MyStates::Error), fail)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset collection was not loaded in 10 seconds");
+    }
+}
+
+fn fail() {
+    panic!("Failed to load the asset next to the executable");
+}
+
+fn expect(
+    collection: Res<MyAssets>,
+    audio: Res<Assets<AudioSource>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert!(audio.get(&collection.plop).is_some());
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "absolute_path_plop.ogg", absolute)]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Error,
+    Next,
+}
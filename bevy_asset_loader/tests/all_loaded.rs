@@ -0,0 +1,65 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::{AssetPlugin, LoadState};
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn counts_loaded_handles() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_systems(Startup, load_handles)
+        .add_systems(Update, (timeout, expect_loaded_handles))
+        .run();
+}
+
+#[derive(Resource)]
+struct MyHandles {
+    sounds: Vec<HandleUntyped>,
+    missing: HandleUntyped,
+}
+
+fn load_handles(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(MyHandles {
+        sounds: vec![
+            asset_server.load_untyped("audio/plop.ogg"),
+            asset_server.load_untyped("audio/yipee.ogg"),
+        ],
+        missing: asset_server.load_untyped("audio/missing.ogg"),
+    });
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The handles were not loaded in 10 seconds");
+    }
+}
+
+fn expect_loaded_handles(
+    asset_server: Res<AssetServer>,
+    handles: Res<MyHandles>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert_eq!(all_loaded(&asset_server, &[]), (0, 0));
+    let mut all_handles = handles.sounds.clone();
+    all_handles.push(handles.missing.clone());
+    if all_loaded(&asset_server, &handles.sounds) != (2, 2)
+        || asset_server.get_load_state(handles.missing.id()) != LoadState::Failed
+    {
+        return;
+    }
+    assert_eq!(all_loaded(&asset_server, &all_handles), (2, 3));
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
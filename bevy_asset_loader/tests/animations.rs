@@ -0,0 +1,75 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::animation::AnimationClip;
+use bevy::app::AppExit;
+use bevy::gltf::{Gltf, GltfPlugin};
+use bevy::prelude::*;
+use bevy::scene::Scene;
+use bevy::utils::HashMap;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(feature = "animation", not(feature = "progress_tracking")))]
+#[test]
+fn fills_named_animation_maps() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            GltfPlugin::default(),
+        ))
+        .add_asset::<Mesh>()
+        .add_asset::<Image>()
+        .add_asset::<StandardMaterial>()
+        .add_asset::<Scene>()
+        .add_asset::<AnimationClip>()
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset collection was not loaded in 10 seconds");
+    }
+}
+
+fn expect(
+    collection: Res<MyAssets>,
+    gltfs: Res<Assets<Gltf>>,
+    clips: Res<Assets<AnimationClip>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let gltf = gltfs.get(&collection.gltf).expect("glTF should be loaded");
+    assert_eq!(collection.animations, gltf.named_animations);
+    assert_eq!(
+        collection.animations.keys().collect::<Vec<_>>(),
+        vec!["Slide"]
+    );
+    assert_eq!(collection.animations["Slide"], gltf.animations[0]);
+    assert_eq!(collection.animations["Slide"], collection.first_animation);
+    let clip = clips
+        .get(&collection.animations["Slide"])
+        .expect("Animation clip should exist");
+    assert_eq!(clip.duration(), 1.);
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "models/triangle.gltf")]
+    gltf: Handle<Gltf>,
+    #[asset(path = "models/triangle.gltf", animations)]
+    animations: HashMap<String, Handle<AnimationClip>>,
+    #[asset(path = "models/triangle.gltf#Animation0")]
+    first_animation: Handle<AnimationClip>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
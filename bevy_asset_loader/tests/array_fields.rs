@@ -0,0 +1,77 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+const SOUND_COUNT: usize = 2;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn fills_arrays_in_order() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset collection was not loaded in 10 seconds");
+    }
+}
+
+fn expect(
+    collection: Res<MyAssets>,
+    asset_server: Res<AssetServer>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let paths: Vec<_> = collection
+        .sounds
+        .iter()
+        .map(|handle| asset_server.get_handle_path(handle))
+        .collect();
+    assert_eq!(
+        paths,
+        vec![
+            Some("audio/yipee.ogg".into()),
+            Some("audio/plop.ogg".into()),
+            Some("audio/background.ogg".into()),
+        ]
+    );
+    assert_eq!(
+        asset_server.get_handle_path(&collection.untyped[1]),
+        Some("audio/yipee.ogg".into())
+    );
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(paths("audio/yipee.ogg", "audio/plop.ogg", "audio/background.ogg"))]
+    sounds: [Handle<AudioSource>; 3],
+    #[asset(paths("audio/plop.ogg", "audio/yipee.ogg"))]
+    untyped: [HandleUntyped; SOUND_COUNT],
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,83 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::{
+    AssetCollection, AssetLoadedWithin, LoadingState, LoadingStateAppExt,
+};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn sends_an_event_per_loaded_asset() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .init_resource::<LoadedAssets>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .batch_size(1),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(PostUpdate, record_loaded_assets)
+        .add_systems(OnEnter(MyStates::Next), expect_all_assets_loaded)
+        .run();
+}
+
+#[derive(Resource, Default)]
+struct LoadedAssets(Vec<AssetLoadedWithin>);
+
+fn record_loaded_assets(
+    mut events: EventReader<AssetLoadedWithin>,
+    mut loaded: ResMut<LoadedAssets>,
+) {
+    loaded.0.extend(events.iter().cloned());
+}
+
+fn expect_all_assets_loaded(loaded: Res<LoadedAssets>, mut exit: EventWriter<AppExit>) {
+    let mut paths: Vec<_> = loaded.0.iter().map(|event| event.path.as_str()).collect();
+    paths.sort_unstable();
+    assert_eq!(
+        paths,
+        vec!["audio/background.ogg", "audio/plop.ogg", "audio/yipee.ogg"]
+    );
+    assert!(loaded
+        .0
+        .iter()
+        .all(|event| event.collection == std::any::type_name::<MyAssets>()));
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The app did not finish in 10 seconds");
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+    #[asset(path = "audio/yipee.ogg")]
+    yipee: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,43 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    feature = "asset_manifest",
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn lists_paths_of_all_collections() {
+    let manifest: Vec<_> = collect_asset_manifest()
+        .into_iter()
+        .filter(|asset| asset.collection.ends_with("::MyAssets"))
+        .map(|asset| (asset.field, asset.path))
+        .collect();
+    assert_eq!(
+        manifest,
+        vec![
+            ("plop", "audio/plop.ogg"),
+            ("sounds", "audio/background.ogg"),
+            ("sounds", "audio/yipee.ogg"),
+        ]
+    );
+    for (_, path) in manifest {
+        assert!(
+            std::path::Path::new("assets").join(path).exists(),
+            "The referenced asset '{path}' should exist"
+        );
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+    #[asset(paths("audio/yipee.ogg", "audio/background.ogg"), collection(typed))]
+    sounds: Vec<Handle<AudioSource>>,
+    #[asset(key = "dynamic")]
+    dynamic: Handle<AudioSource>,
+}
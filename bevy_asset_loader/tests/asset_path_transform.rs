@@ -0,0 +1,170 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+#[cfg(feature = "standard_dynamic_assets")]
+use bevy_asset_loader::prelude::StandardDynamicAsset;
+use bevy_asset_loader::prelude::{
+    AssetCollection, AssetPathTransform, DynamicAssets, FileAsset, LoadingState, LoadingStateAppExt,
+};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn transforms_asset_paths() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .insert_resource(AssetPathTransform::new(|path| {
+            path.replace("sounds/", "audio/")
+        }))
+        .add_state::<MyStates>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_enter(register_dynamic_assets),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect_transformed_paths)
+        .run();
+}
+
+fn register_dynamic_assets(mut dynamic_assets: ResMut<DynamicAssets>) {
+    dynamic_assets.register_asset(
+        "dynamic",
+        Box::new(FileAsset {
+            path: "sounds/yipee.ogg".to_owned(),
+        }),
+    );
+}
+
+#[cfg(all(
+    feature = "2d",
+    feature = "3d",
+    feature = "standard_dynamic_assets",
+    not(feature = "progress_tracking"),
+))]
+#[test]
+fn transforms_paths_of_standard_dynamic_assets() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .insert_resource(AssetPathTransform::new(|path| {
+            path.replace("sounds/", "audio/")
+        }))
+        .add_state::<MyStates>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_enter(register_standard_dynamic_assets),
+        )
+        .add_collection_to_loading_state::<_, DynamicCollection>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect_transformed_dynamic_paths)
+        .run();
+}
+
+#[cfg(feature = "standard_dynamic_assets")]
+fn register_standard_dynamic_assets(mut dynamic_assets: ResMut<DynamicAssets>) {
+    dynamic_assets.register_asset(
+        "file",
+        Box::new(StandardDynamicAsset::File {
+            path: "sounds/yipee.ogg".to_owned(),
+        }),
+    );
+    dynamic_assets.register_asset(
+        "files",
+        Box::new(StandardDynamicAsset::Files {
+            paths: vec!["sounds/plop.ogg".to_owned()],
+        }),
+    );
+}
+
+fn expect_transformed_dynamic_paths(
+    collection: Res<DynamicCollection>,
+    asset_server: Res<AssetServer>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert_eq!(
+        asset_server.get_handle_path(&collection.file),
+        Some("audio/yipee.ogg".into())
+    );
+    assert_eq!(collection.files.len(), 1);
+    assert_eq!(
+        asset_server.get_handle_path(&collection.files[0]),
+        Some("audio/plop.ogg".into())
+    );
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+fn expect_transformed_paths(
+    collection: Res<MyAssets>,
+    asset_server: Res<AssetServer>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert_eq!(
+        asset_server.get_handle_path(&collection.background),
+        Some("audio/background.ogg".into())
+    );
+    assert_eq!(
+        asset_server.get_handle_path(&collection.files["sounds/plop.ogg"]),
+        Some("audio/plop.ogg".into())
+    );
+    assert_eq!(
+        asset_server.get_handle_path(&collection.files["sounds/yipee.ogg"]),
+        Some("audio/yipee.ogg".into())
+    );
+    assert_eq!(
+        asset_server.get_handle_path(&collection.dynamic),
+        Some("audio/yipee.ogg".into())
+    );
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The app did not finish in 10 seconds");
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "sounds/background.ogg")]
+    background: Handle<AudioSource>,
+    #[asset(
+        paths("sounds/plop.ogg", "sounds/yipee.ogg"),
+        collection(typed, mapped)
+    )]
+    files: HashMap<String, Handle<AudioSource>>,
+    #[asset(key = "dynamic")]
+    dynamic: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct DynamicCollection {
+    #[asset(key = "file")]
+    file: Handle<AudioSource>,
+    #[asset(key = "files", collection)]
+    files: Vec<HandleUntyped>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,76 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::reflect::{TypePath, TypeUuid};
+use bevy::utils::HashMap;
+use bevy_asset_loader::loading_state::AssetLoaderConfiguration;
+use bevy_asset_loader::prelude::{AssetCollection, LoadingState, LoadingStateAppExt};
+use std::any::type_name;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn lists_asset_types_of_loading_state() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+    ))
+    .add_state::<MyStates>()
+    .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+    .add_collection_to_loading_state::<_, AudioAssets>(MyStates::Load)
+    .add_collection_to_loading_state::<_, LevelAssets>(MyStates::Load);
+
+    let mut expected = vec![type_name::<AudioSource>(), type_name::<Level>()];
+    expected.sort_unstable();
+    assert_eq!(AudioAssets::asset_types(), vec![type_name::<AudioSource>()]);
+    assert_eq!(
+        app.world
+            .resource::<AssetLoaderConfiguration<MyStates>>()
+            .asset_types_for(&MyStates::Load),
+        expected
+    );
+    assert!(app
+        .world
+        .resource::<AssetLoaderConfiguration<MyStates>>()
+        .asset_types_for(&MyStates::Next)
+        .is_empty());
+}
+
+#[derive(AssetCollection, Resource)]
+struct AudioAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+    #[asset(paths("audio/plop.ogg", "audio/yipee.ogg"), collection(typed))]
+    effects: Vec<Handle<AudioSource>>,
+    #[asset(path = "audio", collection)]
+    untyped: Vec<HandleUntyped>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct LevelAssets {
+    #[asset(path = "levels", collection(typed, mapped))]
+    levels: HashMap<String, Handle<Level>>,
+    #[asset(key = "optional_level", optional)]
+    optional: Option<Handle<Level>>,
+    counter: Counter,
+}
+
+#[derive(TypeUuid, TypePath)]
+#[uuid = "7c1e4b0a-3f2d-4a5e-8b9c-6d0f1e2a3b4c"]
+struct Level;
+
+#[derive(Default)]
+struct Counter(usize);
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,76 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::reflect::{TypePath, TypeUuid};
+use bevy::utils::Uuid;
+use bevy_asset_loader::asset_uuid::uuid_handle_id;
+use bevy_asset_loader::prelude::*;
+
+const LEVEL_UUID: Uuid = Uuid::from_u128(0x6c8e2f3a_9b1d_4e7f_8a2c_5d3b1f0e9a47);
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn resolves_assets_by_uuid() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_asset::<Level>()
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Startup, add_level)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect_level)
+        .run();
+}
+
+fn add_level(mut levels: ResMut<Assets<Level>>) {
+    levels.set_untracked(uuid_handle_id(LEVEL_UUID), Level(7));
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset collection was not loaded in 10 seconds");
+    }
+}
+
+fn expect_level(
+    collection: Res<MyAssets>,
+    levels: Res<Assets<Level>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert!(collection.level.is_strong());
+    assert_eq!(collection.level.id(), uuid_handle_id(LEVEL_UUID));
+    assert_eq!(levels.get(&collection.level).map(|level| level.0), Some(7));
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+#[derive(TypeUuid, TypePath)]
+#[uuid = "3d5a1c6e-8f2b-4a9d-b7e1-0c4f6a2d8b53"]
+struct Level(u32);
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(uuid = "6c8e2f3a-9b1d-4e7f-8a2c-5d3b1f0e9a47")]
+    level: Handle<Level>,
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
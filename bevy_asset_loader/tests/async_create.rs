@@ -0,0 +1,91 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+use std::thread;
+use std::time::Duration;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn creates_collection_in_task() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .async_create::<MyAssets>(),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect_collection)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The app did not finish in 10 seconds");
+    }
+}
+
+fn expect_collection(
+    collection: Res<MyAssets>,
+    asset_server: Res<AssetServer>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert_eq!(
+        asset_server.get_handle_path(&collection.plop),
+        Some("audio/plop.ogg".into())
+    );
+    assert_eq!(collection.path, "audio/plop.ogg");
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+    #[asset(skip)]
+    path: String,
+}
+
+impl AsyncAssetCollection for MyAssets {
+    type Input = (Self, String);
+
+    fn prepare(world: &mut World) -> Self::Input {
+        let collection = MyAssets::create(world);
+        let path = world
+            .resource::<AssetServer>()
+            .get_handle_path(&collection.plop)
+            .expect("Handle should have a path")
+            .path()
+            .display()
+            .to_string();
+        (collection, path)
+    }
+
+    fn create_async((mut collection, path): Self::Input) -> Self {
+        // Simulate expensive post-processing
+        thread::sleep(Duration::from_millis(100));
+        collection.path = path;
+        collection
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
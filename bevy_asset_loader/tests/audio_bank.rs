@@ -0,0 +1,62 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(feature = "audio_bank")]
+#[test]
+fn loads_audio_bank() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+            AudioBankPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, SoundEffects>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect_clips)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The app did not finish in 10 seconds");
+    }
+}
+
+#[cfg(feature = "audio_bank")]
+fn expect_clips(
+    collection: Res<SoundEffects>,
+    asset_server: Res<AssetServer>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert_eq!(
+        asset_server.get_handle_path(&collection.sfx.source),
+        Some("audio/plop.ogg".into())
+    );
+    assert_eq!(collection.sfx.clips.len(), 2);
+    assert_eq!(collection.sfx.clip("plop"), Some((0.0, 0.25)));
+    assert_eq!(collection.sfx.clip("double_plop"), Some((0.25, 0.8)));
+    assert_eq!(collection.sfx.clip("missing"), None);
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+#[cfg(feature = "audio_bank")]
+#[derive(AssetCollection, Resource)]
+struct SoundEffects {
+    #[asset(path = "audio/plop.ogg", audio_bank = "audio/sfx.bank.json")]
+    sfx: AudioBank,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,55 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+use std::time::Duration;
+
+#[cfg(all(feature = "audio_duration", not(feature = "progress_tracking")))]
+#[test]
+fn fills_duration_fields() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset collection was not loaded in 10 seconds");
+    }
+}
+
+fn expect(collection: Res<MyAssets>, mut exit: EventWriter<AppExit>) {
+    // 4000 samples at 8 kHz
+    assert_eq!(collection.silence_duration, Duration::from_millis(500));
+    // the Ogg Vorbis decoder does not know the duration of its stream
+    assert_eq!(collection.plop_duration, Duration::ZERO);
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/silence.wav")]
+    silence: Handle<AudioSource>,
+    #[asset(path = "audio/silence.wav", duration)]
+    silence_duration: Duration,
+    #[asset(path = "audio/plop.ogg", duration)]
+    plop_duration: Duration,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,85 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::{AssetCollection, LoadingState, LoadingStateAppExt};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn issues_loads_in_batches() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .init_resource::<LoadingFrames>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .batch_size(1),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(
+            Update,
+            (count_frames, timeout).run_if(in_state(MyStates::Load)),
+        )
+        .add_systems(OnEnter(MyStates::Next), expect_collection)
+        .run();
+}
+
+#[derive(Resource, Default)]
+struct LoadingFrames(usize);
+
+fn count_frames(mut frames: ResMut<LoadingFrames>) {
+    frames.0 += 1;
+}
+
+fn expect_collection(
+    collection: Res<MyAssets>,
+    frames: Res<LoadingFrames>,
+    asset_server: Res<AssetServer>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert_eq!(collection.files.len(), 3);
+    for handle in &collection.files {
+        assert_eq!(
+            asset_server.get_load_state(handle),
+            bevy::asset::LoadState::Loaded
+        );
+    }
+    assert!(
+        frames.0 >= 3,
+        "Loading three assets with a batch size of one should take at least three frames"
+    );
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The app did not finish in 10 seconds");
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(
+        paths("audio/background.ogg", "audio/plop.ogg", "audio/yipee.ogg"),
+        collection(typed)
+    )]
+    files: Vec<Handle<AudioSource>>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,128 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::asset::{AssetPlugin, LoadState};
+use bevy::audio::AudioPlugin;
+use bevy::ecs::schedule::ScheduleLabel;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+use std::time::{Duration, Instant};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn runs_loading_state_in_custom_schedules() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+    ))
+    .init_resource::<State<MyStates>>()
+    .init_resource::<NextState<MyStates>>();
+    LoadingState::new(MyStates::Load)
+        .continue_to_state(MyStates::Next)
+        .build_in(&mut app, BakeStart, BakeStep, BakeEnd);
+    app.add_collection_to_loading_state::<_, MyAssets>(MyStates::Load);
+
+    app.world.run_schedule(BakeStart);
+    let start = Instant::now();
+    while app.world.resource::<NextState<MyStates>>().0.is_none() {
+        if start.elapsed() > Duration::from_secs(10) {
+            panic!("The loading state did not finish in 10 seconds");
+        }
+        app.update();
+        app.world.run_schedule(BakeStep);
+    }
+    app.world.run_schedule(BakeEnd);
+
+    assert_eq!(
+        app.world.resource::<NextState<MyStates>>().0,
+        Some(MyStates::Next)
+    );
+    assert!(app.world.contains_resource::<MyAssets>());
+    assert_eq!(
+        app.world.resource::<State<MyStates>>().get(),
+        &MyStates::Load
+    );
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn finishes_collection_in_background_in_custom_schedule() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+    ))
+    .init_resource::<State<MyStates>>()
+    .init_resource::<NextState<MyStates>>();
+    LoadingState::new(MyStates::Load)
+        .continue_to_state(MyStates::Next)
+        .finish_in_background()
+        .build_in(&mut app, BakeStart, BakeStep, BakeEnd);
+    app.add_collection_to_loading_state::<_, MyAssets>(MyStates::Load);
+
+    app.world.run_schedule(BakeStart);
+    let start = Instant::now();
+    while app.world.debug_dump_loading().is_empty() {
+        if start.elapsed() > Duration::from_secs(10) {
+            panic!("The loading state did not start loading in 10 seconds");
+        }
+        app.world.run_schedule(BakeStep);
+    }
+    app.world.run_schedule(BakeEnd);
+
+    let start = Instant::now();
+    loop {
+        if start.elapsed() > Duration::from_secs(10) {
+            panic!("The assets did not load in 10 seconds");
+        }
+        app.update();
+        let asset_server = app.world.resource::<AssetServer>();
+        if asset_server.get_load_state("audio/background.ogg") == LoadState::Loaded
+            && asset_server.get_load_state("audio/plop.ogg") == LoadState::Loaded
+        {
+            break;
+        }
+    }
+    app.update();
+    assert!(
+        !app.world.contains_resource::<MyAssets>(),
+        "The collection should only be finished in the schedule of the loading state"
+    );
+
+    app.world.run_schedule(BakeStep);
+    assert!(app.world.contains_resource::<MyAssets>());
+}
+
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+struct BakeStart;
+
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+struct BakeStep;
+
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+struct BakeEnd;
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
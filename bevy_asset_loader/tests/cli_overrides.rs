@@ -0,0 +1,116 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn registers_flag_values_for_mapped_keys() {
+    let mut dynamic_assets = DynamicAssets::default();
+    dynamic_assets.register_asset(
+        "music",
+        Box::new(FileAsset {
+            path: "audio/background.ogg".to_owned(),
+        }),
+    );
+    dynamic_assets.register_from_arg_list(
+        &[
+            ("--level", "level"),
+            ("--sound", "sound"),
+            ("--music", "music"),
+        ],
+        [
+            "--level",
+            "levels/first.png",
+            "--verbose",
+            "--sound=audio/plop.ogg",
+        ],
+    );
+
+    assert_eq!(
+        format!("{:?}", dynamic_assets.get_asset("level").unwrap()),
+        r#"FileAsset { path: "levels/first.png" }"#
+    );
+    assert_eq!(
+        format!("{:?}", dynamic_assets.get_asset("sound").unwrap()),
+        r#"FileAsset { path: "audio/plop.ogg" }"#
+    );
+    assert_eq!(
+        format!("{:?}", dynamic_assets.get_asset("music").unwrap()),
+        r#"FileAsset { path: "audio/background.ogg" }"#,
+        "Keys of flags that were not passed should keep their asset"
+    );
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn missing_flags_fall_back_to_configured_keys() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_enter(register_music)
+                .with_cli_overrides(&[("--not-passed-to-tests", "music")]),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect_configured_music)
+        .run();
+}
+
+fn register_music(mut dynamic_assets: ResMut<DynamicAssets>) {
+    dynamic_assets.register_asset(
+        "music",
+        Box::new(FileAsset {
+            path: "audio/background.ogg".to_owned(),
+        }),
+    );
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The app did not finish in 10 seconds");
+    }
+}
+
+fn expect_configured_music(
+    collection: Res<MyAssets>,
+    asset_server: Res<AssetServer>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert_eq!(
+        collection.music,
+        asset_server.get_handle("audio/background.ogg")
+    );
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(key = "music")]
+    music: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,98 @@
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn collects_errors_of_missing_keys() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .collect_errors()
+                .on_enter(register_dynamic_assets),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect_errors)
+        .run();
+}
+
+#[derive(Debug)]
+struct AudioFile(String);
+
+impl DynamicAsset for AudioFile {
+    fn load(&self, asset_server: &AssetServer) -> Vec<HandleUntyped> {
+        vec![asset_server.load_untyped(&self.0)]
+    }
+
+    fn build(&self, world: &mut World) -> Result<DynamicAssetType, anyhow::Error> {
+        let asset_server = world.resource::<AssetServer>();
+        Ok(DynamicAssetType::Single(
+            asset_server.get_handle_untyped(&self.0),
+        ))
+    }
+}
+
+fn register_dynamic_assets(mut dynamic_assets: ResMut<DynamicAssets>) {
+    dynamic_assets.register_asset("plop", Box::new(AudioFile("audio/plop.ogg".to_owned())));
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The app did not finish in 10 seconds");
+    }
+}
+
+fn expect_errors(
+    collection: Res<MyAssets>,
+    errors: Res<CollectionErrors>,
+    asset_server: Res<AssetServer>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert_eq!(
+        asset_server.get_handle_path(&collection.plop),
+        Some("audio/plop.ogg".into())
+    );
+    assert_eq!(collection.background, Handle::default());
+    assert!(collection.music.is_empty());
+
+    let mut fields: Vec<_> = errors.errors().iter().map(|error| error.field).collect();
+    fields.sort();
+    assert_eq!(fields, vec!["background", "music"]);
+    assert!(errors
+        .errors()
+        .iter()
+        .all(|error| error.collection.ends_with("MyAssets")));
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(key = "plop")]
+    plop: Handle<AudioSource>,
+    #[asset(key = "background")]
+    background: Handle<AudioSource>,
+    #[asset(key = "music", collection(typed))]
+    music: Vec<Handle<AudioSource>>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
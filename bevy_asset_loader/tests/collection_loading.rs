@@ -0,0 +1,95 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::{
+    collection_loading, AssetCollection, LoadingState, LoadingStateAppExt,
+};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn run_condition_is_true_while_collection_loads() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .init_resource::<LoadingFrames>()
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(
+            Update,
+            (
+                count_loading_frames.run_if(collection_loading::<MyAssets>()),
+                timeout.run_if(in_state(MyStates::Load)),
+                expect_no_more_loading_frames.run_if(in_state(MyStates::Next)),
+            ),
+        )
+        .add_systems(OnEnter(MyStates::Next), remember_loading_frames)
+        .run();
+}
+
+#[derive(Resource, Default)]
+struct LoadingFrames {
+    count: usize,
+    on_enter_next: Option<usize>,
+    frames_in_next: usize,
+}
+
+fn count_loading_frames(mut frames: ResMut<LoadingFrames>, assets: Option<Res<MyAssets>>) {
+    assert!(
+        assets.is_none(),
+        "The run condition should be false once the collection is inserted"
+    );
+    frames.count += 1;
+}
+
+fn remember_loading_frames(mut frames: ResMut<LoadingFrames>) {
+    assert!(
+        frames.count > 0,
+        "The run condition should be true while the collection is loading"
+    );
+    frames.on_enter_next = Some(frames.count);
+}
+
+fn expect_no_more_loading_frames(
+    mut frames: ResMut<LoadingFrames>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert_eq!(
+        Some(frames.count),
+        frames.on_enter_next,
+        "The run condition should be false after the collection finished loading"
+    );
+    frames.frames_in_next += 1;
+    if frames.frames_in_next > 3 {
+        info!("Everything fine, quitting the app");
+        exit.send(AppExit);
+    }
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The app did not finish in 10 seconds");
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
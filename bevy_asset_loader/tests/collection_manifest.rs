@@ -0,0 +1,62 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(feature = "standard_dynamic_assets")]
+#[test]
+fn restores_dynamic_assets_from_manifest() {
+    let mut world = World::new();
+    let mut dynamic_assets = DynamicAssets::default();
+    dynamic_assets.register_asset(
+        "level.floor",
+        Box::new(StandardDynamicAsset::File {
+            path: "images/tree.png".to_owned(),
+        }),
+    );
+    dynamic_assets.register_asset(
+        "level.props",
+        Box::new(StandardDynamicAsset::Files {
+            paths: vec!["images/player.png".to_owned(), "images/tree.png".to_owned()],
+        }),
+    );
+    dynamic_assets.register_asset(
+        "menu.background",
+        Box::new(StandardDynamicAsset::File {
+            path: "images/background.png".to_owned(),
+        }),
+    );
+    world.insert_resource(dynamic_assets);
+
+    let manifest = CollectionManifest::snapshot::<LevelAssets>(&world);
+    assert_eq!(
+        manifest.0.keys().collect::<Vec<_>>(),
+        vec!["level.floor", "level.props"]
+    );
+
+    let ron = manifest.to_ron().expect("Failed to serialize the manifest");
+    let restored = CollectionManifest::from_ron(&ron).expect("Failed to deserialize the manifest");
+    assert_eq!(restored, manifest);
+
+    let mut dynamic_assets = DynamicAssets::default();
+    restored.register(&mut dynamic_assets);
+    assert_eq!(
+        dynamic_assets
+            .get_asset("level.floor")
+            .and_then(|asset| asset.as_standard()),
+        Some(StandardDynamicAsset::File {
+            path: "images/tree.png".to_owned()
+        })
+    );
+    assert!(dynamic_assets.get_asset("menu.background").is_none());
+}
+
+#[derive(AssetCollection, Resource)]
+struct LevelAssets {
+    #[asset(key = "level.floor")]
+    floor: Handle<Image>,
+    #[asset(key = "level.props", collection(typed))]
+    props: Vec<Handle<Image>>,
+    #[asset(key = "level.music", optional)]
+    music: Option<Handle<Image>>,
+}
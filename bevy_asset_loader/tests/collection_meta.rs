@@ -0,0 +1,78 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::loading_state::AssetLoaderConfiguration;
+use bevy_asset_loader::prelude::{AssetCollection, LoadingState, LoadingStateAppExt};
+use std::any::type_name;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn lists_meta_of_collections() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+    ))
+    .add_state::<MyStates>()
+    .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+    .add_collection_to_loading_state::<_, MusicAssets>(MyStates::Load)
+    .add_collection_to_loading_state::<_, DebugAssets>(MyStates::Load)
+    .add_collection_to_loading_state::<_, AudioAssets>(MyStates::Load);
+
+    assert_eq!(
+        AudioAssets::meta(),
+        &[("category", "critical"), ("owner", "audio")]
+    );
+    assert!(MusicAssets::meta().is_empty());
+
+    let mut expected = vec![
+        (type_name::<AudioAssets>(), AudioAssets::meta()),
+        (type_name::<DebugAssets>(), DebugAssets::meta()),
+        (type_name::<MusicAssets>(), MusicAssets::meta()),
+    ];
+    expected.sort_unstable_by_key(|(collection, _)| *collection);
+    assert_eq!(
+        app.world
+            .resource::<AssetLoaderConfiguration<MyStates>>()
+            .collection_meta_for(&MyStates::Load),
+        expected
+    );
+    assert!(app
+        .world
+        .resource::<AssetLoaderConfiguration<MyStates>>()
+        .collection_meta_for(&MyStates::Next)
+        .is_empty());
+}
+
+#[derive(AssetCollection, Resource)]
+#[asset_collection(meta(category = "critical", owner = "audio"))]
+struct AudioAssets {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+#[asset_collection(debug, meta(category = "debug"))]
+struct DebugAssets {
+    #[asset(path = "audio/yipee.ogg")]
+    yipee: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct MusicAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,53 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::loading_state::AssetLoaderConfiguration;
+use bevy_asset_loader::prelude::{AssetCollection, LoadingState, LoadingStateAppExt};
+use std::any::type_name;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn lists_collections_of_loading_state() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+    ))
+    .add_state::<MyStates>()
+    .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+    .add_collection_to_loading_state::<_, MusicAssets>(MyStates::Load)
+    .add_collection_to_loading_state::<_, EffectAssets>(MyStates::Load)
+    .add_collection_to_loading_state::<_, MusicAssets>(MyStates::Load);
+
+    let configuration = app.world.resource::<AssetLoaderConfiguration<MyStates>>();
+    assert_eq!(
+        configuration.collections_for(&MyStates::Load),
+        [type_name::<MusicAssets>(), type_name::<EffectAssets>()]
+    );
+    assert!(configuration.collections_for(&MyStates::Next).is_empty());
+}
+
+#[derive(AssetCollection, Resource)]
+struct MusicAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct EffectAssets {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
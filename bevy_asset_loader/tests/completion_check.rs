@@ -0,0 +1,78 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::LoadState;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn waits_for_completion_check() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .init_resource::<Processed>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .with_completion_check::<MyAssets>(|world, handle| {
+                    world.resource::<AssetServer>().get_load_state(handle) == LoadState::Loaded
+                        && world.resource::<Processed>().0
+                }),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, (timeout, process).run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+/// Marks the assets as processed some time after they finished loading
+#[derive(Resource, Default)]
+struct Processed(bool);
+
+fn process(
+    asset_server: Res<AssetServer>,
+    mut processed: ResMut<Processed>,
+    mut loaded_frames: Local<usize>,
+) {
+    // Creating and dropping a handle here could free the asset before the collection loads it
+    if asset_server.get_load_state("audio/background.ogg") == LoadState::Loaded {
+        *loaded_frames += 1;
+    }
+    if *loaded_frames > 5 {
+        processed.0 = true;
+    }
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset collection was not loaded in 10 seconds");
+    }
+}
+
+fn expect(processed: Res<Processed>, mut exit: EventWriter<AppExit>) {
+    assert!(processed.0);
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
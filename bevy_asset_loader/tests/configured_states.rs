@@ -0,0 +1,110 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::loading_state::AssetLoaderConfiguration;
+use bevy_asset_loader::prelude::{AssetCollection, LoadingState, LoadingStateAppExt};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn reads_configured_states_of_running_loading_states() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .init_resource::<ConfiguredStates>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::LoadLevel)
+                .on_failure_continue_to_state(MyStates::Error),
+        )
+        .add_loading_state(LoadingState::new(MyStates::LoadLevel).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_collection_to_loading_state::<_, LevelAssets>(MyStates::LoadLevel)
+        .add_systems(
+            Update,
+            (timeout, read_configured_states)
+                .run_if(in_state(MyStates::Load).or_else(in_state(MyStates::LoadLevel))),
+        )
+        .add_systems(OnEnter(MyStates::Error), fail)
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+#[derive(Resource, Default)]
+struct ConfiguredStates(Vec<(MyStates, Option<MyStates>, Option<MyStates>)>);
+
+fn read_configured_states(
+    state: Res<State<MyStates>>,
+    configuration: Res<AssetLoaderConfiguration<MyStates>>,
+    mut configured: ResMut<ConfiguredStates>,
+) {
+    let state = state.get();
+    if configured
+        .0
+        .iter()
+        .any(|(loading_state, ..)| loading_state == state)
+    {
+        return;
+    }
+    configured.0.push((
+        state.clone(),
+        configuration.next_state_for(state).cloned(),
+        configuration.failure_state_for(state).cloned(),
+    ));
+}
+
+fn fail() {
+    panic!("The collections should have loaded");
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+fn expect(configured: Res<ConfiguredStates>, mut exit: EventWriter<AppExit>) {
+    assert_eq!(
+        configured.0,
+        vec![
+            (
+                MyStates::Load,
+                Some(MyStates::LoadLevel),
+                Some(MyStates::Error)
+            ),
+            (MyStates::LoadLevel, Some(MyStates::Next), None),
+        ]
+    );
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct LevelAssets {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    LoadLevel,
+    Error,
+    Next,
+}
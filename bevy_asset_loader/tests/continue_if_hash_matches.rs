@@ -0,0 +1,83 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::content_hash::hash_bytes;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn continues_if_hash_matches() {
+    let plop = std::fs::read("assets/audio/plop.ogg").expect("Failed to read plop.ogg");
+    run(hash_bytes(&plop), MyStates::Next);
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn continues_to_else_state_on_mismatch() {
+    run([42; 32], MyStates::Update);
+}
+
+fn run(expected: [u8; 32], target: MyStates) {
+    let other = match target {
+        MyStates::Next => MyStates::Update,
+        _ => MyStates::Next,
+    };
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .continue_if_hash_matches("audio/plop.ogg", expected, MyStates::Update),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(target), expect)
+        .add_systems(OnEnter(other), unexpected)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset collection was not loaded in 10 seconds");
+    }
+}
+
+fn expect(collection: Option<Res<MyAssets>>, mut exit: EventWriter<AppExit>) {
+    assert!(collection.is_some());
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+fn unexpected(state: Res<State<MyStates>>) {
+    panic!("Continued to the wrong state {:?}", state.get());
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Update,
+    Next,
+}
@@ -0,0 +1,47 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::AssetCollection;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn debug_lists_configured_assets() {
+    let collection = MyAssets {
+        background: Handle::default(),
+        music: None,
+        images: vec![],
+        skipped: 0,
+        counter: Counter(0),
+    };
+
+    assert_eq!(
+        format!("{collection:?}"),
+        r#"MyAssets { background: path = "audio/background.ogg", music: key = "music", optional, images: paths("images/player.png", "images/tree.png"), collection(typed), skipped: skip, counter: FromWorld }"#
+    );
+}
+
+#[derive(AssetCollection, Resource)]
+#[asset_collection(debug)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+    #[asset(key = "music", optional)]
+    music: Option<Handle<AudioSource>>,
+    #[asset(paths("images/player.png", "images/tree.png"), collection(typed))]
+    images: Vec<Handle<Image>>,
+    #[asset(skip)]
+    skipped: usize,
+    counter: Counter,
+}
+
+struct Counter(usize);
+
+impl FromWorld for Counter {
+    fn from_world(_world: &mut World) -> Self {
+        Counter(42)
+    }
+}
@@ -0,0 +1,78 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::utils::Instant;
+use bevy_asset_loader::prelude::*;
+use std::time::Duration;
+
+const DELAY: Duration = Duration::from_millis(300);
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn loading_starts_after_delay() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Splash)
+                .continue_to_state(MyStates::Next)
+                .delay_start(DELAY)
+                .on_enter(remember_enter),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Splash)
+        .add_systems(
+            Update,
+            (timeout, expect_nothing_loading).run_if(in_state(MyStates::Splash)),
+        )
+        .add_systems(OnEnter(MyStates::Next), expect_delay_passed)
+        .run();
+}
+
+#[derive(Resource)]
+struct Entered(Instant);
+
+fn remember_enter(mut commands: Commands) {
+    commands.insert_resource(Entered(Instant::now()));
+}
+
+fn expect_nothing_loading(entered: Res<Entered>, progress: Res<LoadingProgress<MyStates>>) {
+    // The loading state might start within this frame, so leave some margin
+    if entered.0.elapsed() < DELAY - Duration::from_millis(50) {
+        assert_eq!(progress.total, 0, "No asset should load during the delay");
+    }
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The app did not finish in 10 seconds");
+    }
+}
+
+fn expect_delay_passed(entered: Res<Entered>, mut exit: EventWriter<AppExit>) {
+    assert!(entered.0.elapsed() >= DELAY);
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Splash,
+    Next,
+}
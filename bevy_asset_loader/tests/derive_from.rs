@@ -0,0 +1,40 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::asset::AssetPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(feature = "3d")]
+#[test]
+fn derives_material_from_image_field() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+        .add_asset::<Image>()
+        .add_asset::<StandardMaterial>()
+        .init_collection::<MyAssets>();
+
+    let collection = app.world.resource::<MyAssets>();
+    let asset_server = app.world.resource::<AssetServer>();
+    assert_eq!(
+        asset_server.get_handle_path(&collection.texture),
+        Some("images/tree.png".into())
+    );
+    let material = app
+        .world
+        .resource::<Assets<StandardMaterial>>()
+        .get(&collection.material)
+        .expect("The material should have been added");
+    assert_eq!(
+        material.base_color_texture,
+        Some(collection.texture.clone())
+    );
+}
+
+#[cfg(feature = "3d")]
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(standard_material, derive_from = "texture")]
+    material: Handle<StandardMaterial>,
+    #[asset(path = "images/tree.png")]
+    texture: Handle<Image>,
+}
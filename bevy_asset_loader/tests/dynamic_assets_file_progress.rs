@@ -0,0 +1,88 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    feature = "standard_dynamic_assets",
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn dynamic_assets_files_count_towards_progress() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .init_resource::<Observed>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .with_dynamic_assets_files(&["layered/base.assets.ron"])
+                .dynamic_assets_file_weight(4.)
+                .with_loading_system(observe_progress),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+#[derive(Resource, Default)]
+struct Observed {
+    file_loaded_collection_pending: bool,
+}
+
+fn observe_progress(
+    progress: Res<LoadingProgress<MyStates>>,
+    collection: Option<Res<MyAssets>>,
+    mut observed: ResMut<Observed>,
+) {
+    if collection.is_none() {
+        assert!(progress.fraction() < 1.);
+    }
+    // The loaded file and the collection that did not start loading yet
+    if progress.total == 2 && progress.loaded == 1 {
+        assert_eq!(progress.total_weight, 5.);
+        assert_eq!(progress.loaded_weight, 4.);
+        observed.file_loaded_collection_pending = true;
+    }
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset collection was not loaded in 10 seconds");
+    }
+}
+
+fn expect(
+    progress: Res<LoadingProgress<MyStates>>,
+    observed: Res<Observed>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert!(observed.file_loaded_collection_pending);
+    // One file and two assets
+    assert_eq!(progress.total, 3);
+    assert_eq!(progress.loaded, 3);
+    assert_eq!(progress.total_weight, 6.);
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(key = "sounds.background")]
+    background: Handle<AudioSource>,
+    #[asset(key = "sounds.plop")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
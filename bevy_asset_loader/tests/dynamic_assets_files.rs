@@ -0,0 +1,74 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    feature = "standard_dynamic_assets",
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn later_files_override_earlier_keys() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .with_dynamic_assets_files(&[
+                    "layered/base.assets.ron",
+                    "layered/mods/override.assets.ron",
+                ]),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset collection was not loaded in 10 seconds");
+    }
+}
+
+fn expect(
+    collection: Res<MyAssets>,
+    asset_server: Res<AssetServer>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let path = |handle: &Handle<AudioSource>| asset_server.get_handle_path(handle);
+    // overridden by the later file
+    assert_eq!(path(&collection.background), Some("audio/yipee.ogg".into()));
+    // only defined in the base file
+    assert_eq!(path(&collection.plop), Some("audio/plop.ogg".into()));
+    // added by the later file
+    assert_eq!(
+        path(&collection.celebration),
+        Some("audio/yipee.ogg".into())
+    );
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(key = "sounds.background")]
+    background: Handle<AudioSource>,
+    #[asset(key = "sounds.plop")]
+    plop: Handle<AudioSource>,
+    #[asset(key = "sounds.celebration")]
+    celebration: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
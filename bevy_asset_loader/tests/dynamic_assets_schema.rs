@@ -0,0 +1,68 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    feature = "standard_dynamic_assets",
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn reports_keys_not_matching_the_schema() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .with_dynamic_assets_files(&["layered/base.assets.ron"])
+                .validate_dynamic_assets_against("layered/base.schema.ron"),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset collection was not loaded in 10 seconds");
+    }
+}
+
+fn expect(discrepancies: Res<SchemaDiscrepancies>, mut exit: EventWriter<AppExit>) {
+    assert_eq!(
+        discrepancies.discrepancies,
+        vec![
+            SchemaDiscrepancy::MissingKey {
+                key: "sounds.celebration".to_owned(),
+                expected: StandardDynamicAssetKind::File,
+            },
+            SchemaDiscrepancy::WrongKind {
+                key: "sounds.plop".to_owned(),
+                expected: StandardDynamicAssetKind::Files,
+                found: Some(StandardDynamicAssetKind::File),
+            },
+        ]
+    );
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(key = "sounds.background")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,133 @@
+#![allow(dead_code, unused_imports)]
+
+use anyhow::anyhow;
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn builds_dynamic_fields() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_enter(register_dynamic_assets),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect_collection)
+        .run();
+}
+
+#[derive(Debug)]
+enum Sound {
+    Single(Handle<AudioSource>),
+    Playlist(Vec<Handle<AudioSource>>),
+}
+
+impl DynamicAssetField for Sound {
+    fn from_dynamic_asset(asset: DynamicAssetType, _world: &World) -> Result<Self, anyhow::Error> {
+        match asset {
+            DynamicAssetType::Single(handle) => Ok(Sound::Single(handle.typed())),
+            DynamicAssetType::Collection(handles) if !handles.is_empty() => Ok(Sound::Playlist(
+                handles.into_iter().map(|handle| handle.typed()).collect(),
+            )),
+            DynamicAssetType::Collection(_) => Err(anyhow!("The playlist is empty")),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct AudioFiles(Vec<String>);
+
+impl DynamicAsset for AudioFiles {
+    fn load(&self, asset_server: &AssetServer) -> Vec<HandleUntyped> {
+        self.0
+            .iter()
+            .map(|path| asset_server.load_untyped(path))
+            .collect()
+    }
+
+    fn build(&self, world: &mut World) -> Result<DynamicAssetType, anyhow::Error> {
+        let asset_server = world.resource::<AssetServer>();
+        let mut handles = self
+            .0
+            .iter()
+            .map(|path| asset_server.get_handle_untyped(path));
+        if self.0.len() == 1 {
+            Ok(DynamicAssetType::Single(handles.next().unwrap()))
+        } else {
+            Ok(DynamicAssetType::Collection(handles.collect()))
+        }
+    }
+}
+
+fn register_dynamic_assets(mut dynamic_assets: ResMut<DynamicAssets>) {
+    dynamic_assets.register_asset(
+        "click",
+        Box::new(AudioFiles(vec!["audio/plop.ogg".to_owned()])),
+    );
+    dynamic_assets.register_asset(
+        "music",
+        Box::new(AudioFiles(vec![
+            "audio/background.ogg".to_owned(),
+            "audio/yipee.ogg".to_owned(),
+        ])),
+    );
+}
+
+fn expect_collection(
+    collection: Res<MyAssets>,
+    asset_server: Res<AssetServer>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let Sound::Single(click) = &collection.click else {
+        panic!("Expected a single sound, got {:?}", collection.click);
+    };
+    assert_eq!(
+        asset_server.get_handle_path(click),
+        Some("audio/plop.ogg".into())
+    );
+    let Some(Sound::Playlist(music)) = &collection.music else {
+        panic!("Expected a playlist, got {:?}", collection.music);
+    };
+    assert_eq!(music.len(), 2);
+    assert!(collection.missing.is_none());
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The app did not finish in 10 seconds");
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(key = "click", dynamic_field)]
+    click: Sound,
+    #[asset(key = "music", dynamic_field, optional)]
+    music: Option<Sound>,
+    #[asset(key = "missing", dynamic_field, optional)]
+    missing: Option<Sound>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
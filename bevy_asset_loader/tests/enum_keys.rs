@@ -0,0 +1,105 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn loads_assets_with_enum_keys() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_enter(register_dynamic_assets),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect_collection)
+        .run();
+}
+
+enum MyKeys {
+    Background,
+    Plop,
+}
+
+impl AssetKey for MyKeys {
+    fn as_str(&self) -> &str {
+        match self {
+            MyKeys::Background => "background",
+            MyKeys::Plop => "plop",
+        }
+    }
+}
+
+#[derive(Debug)]
+struct AudioFile(String);
+
+impl DynamicAsset for AudioFile {
+    fn load(&self, asset_server: &AssetServer) -> Vec<HandleUntyped> {
+        vec![asset_server.load_untyped(&self.0)]
+    }
+
+    fn build(&self, world: &mut World) -> Result<DynamicAssetType, anyhow::Error> {
+        let asset_server = world.resource::<AssetServer>();
+        Ok(DynamicAssetType::Single(
+            asset_server.get_handle_untyped(&self.0),
+        ))
+    }
+}
+
+fn register_dynamic_assets(mut dynamic_assets: ResMut<DynamicAssets>) {
+    dynamic_assets.register(
+        MyKeys::Background,
+        Box::new(AudioFile("audio/background.ogg".to_owned())),
+    );
+    assert!(dynamic_assets.get(&MyKeys::Background).is_some());
+    assert!(dynamic_assets.get(&MyKeys::Plop).is_none());
+}
+
+fn expect_collection(
+    collection: Res<MyAssets>,
+    asset_server: Res<AssetServer>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert_eq!(
+        asset_server.get_handle_path(&collection.background),
+        Some("audio/background.ogg".into())
+    );
+    assert!(collection.plop.is_none());
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The app did not finish in 10 seconds");
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(key_enum(MyKeys::Background))]
+    background: Handle<AudioSource>,
+    #[asset(key_enum(MyKeys::Plop), optional)]
+    plop: Option<Handle<AudioSource>>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
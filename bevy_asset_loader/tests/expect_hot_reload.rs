@@ -0,0 +1,72 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::{AssetCollection, LoadingState, LoadingStateAppExt};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn recreates_collection_after_reload() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .init_resource::<Creations>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .expect_hot_reload(),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, (timeout, count_creations))
+        .add_systems(OnEnter(MyStates::Next), reload_audio)
+        .add_systems(Update, expect_recreation.run_if(in_state(MyStates::Next)))
+        .run();
+}
+
+#[derive(Resource, Default)]
+struct Creations(usize);
+
+fn count_creations(assets: Option<Res<MyAssets>>, mut creations: ResMut<Creations>) {
+    if assets.is_some_and(|assets| assets.is_changed()) {
+        creations.0 += 1;
+    }
+}
+
+fn reload_audio(asset_server: Res<AssetServer>) {
+    asset_server.reload_asset("audio/background.ogg");
+}
+
+fn expect_recreation(creations: Res<Creations>, mut exit: EventWriter<AppExit>) {
+    if creations.0 == 2 {
+        info!("Everything fine, quitting the app");
+        exit.send(AppExit);
+    }
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The collection was not recreated within 10 seconds");
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,82 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::loading_state::FailedAssets;
+use bevy_asset_loader::prelude::{AssetCollection, LoadingState, LoadingStateAppExt};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn lists_failed_assets_and_can_retry() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .init_resource::<Attempts>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_failure_continue_to_state(MyStates::Error),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout)
+        .add_systems(OnEnter(MyStates::Next), fail)
+        .add_systems(OnEnter(MyStates::Error), retry_once)
+        .run();
+}
+
+#[derive(Resource, Default)]
+struct Attempts(usize);
+
+fn retry_once(
+    failed_assets: Res<FailedAssets>,
+    mut attempts: ResMut<Attempts>,
+    mut state: ResMut<NextState<MyStates>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert_eq!(
+        failed_assets.paths,
+        vec!["non-existing-file.ogg".to_owned()]
+    );
+    attempts.0 += 1;
+    if attempts.0 < 2 {
+        state.set(MyStates::Load);
+    } else {
+        info!("Everything fine, quitting the app");
+        exit.send(AppExit);
+    }
+}
+
+fn fail() {
+    panic!("The library should have switched to the failure state");
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The app did not finish in 10 seconds");
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+    #[asset(path = "non-existing-file.ogg")]
+    non_existing_file: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Error,
+    Next,
+}
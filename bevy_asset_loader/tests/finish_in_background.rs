@@ -0,0 +1,91 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn finishes_collection_after_leaving_loading_state() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .finish_in_background()
+                .on_loads_issued(leave_loading_state),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout)
+        .add_systems(OnEnter(MyStates::Next), unexpected_transition)
+        .add_systems(OnEnter(MyStates::Menu), expect_cancelled)
+        .add_systems(
+            Update,
+            expect_collection
+                .run_if(in_state(MyStates::Menu).and_then(resource_exists::<MyAssets>())),
+        )
+        .run();
+}
+
+fn leave_loading_state(mut next_state: ResMut<NextState<MyStates>>) {
+    next_state.set(MyStates::Menu);
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset collection was not loaded in the background in 10 seconds");
+    }
+}
+
+fn unexpected_transition() {
+    panic!("The cancelled loading state should not continue to its next state");
+}
+
+fn expect_cancelled(
+    mut cancelled: EventReader<LoadingCancelled<MyStates>>,
+    assets: Option<Res<MyAssets>>,
+) {
+    assert_eq!(cancelled.iter().count(), 1);
+    assert!(assets.is_none());
+}
+
+fn expect_collection(
+    assets: Res<MyAssets>,
+    asset_server: Res<AssetServer>,
+    audio: Res<Assets<AudioSource>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert_eq!(
+        asset_server.get_handle_path(&assets.plop),
+        Some("audio/plop.ogg".into())
+    );
+    assert!(audio.contains(&assets.background));
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+    Menu,
+}
@@ -0,0 +1,92 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use bevy_asset_loader::prelude::{AssetCollection, LoadingState, LoadingStateAppExt};
+use std::str::FromStr;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn parses_file_names_into_map_keys() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect_typed_keys)
+        .run();
+}
+
+fn expect_typed_keys(
+    collection: Res<MyAssets>,
+    asset_server: Res<AssetServer>,
+    mut exit: EventWriter<AppExit>,
+) {
+    // Files that cannot be parsed into a `Sound`, like "background.ogg", are skipped
+    assert_eq!(collection.sounds.len(), 2);
+    assert_eq!(
+        asset_server.get_handle_path(&collection.sounds[&Sound::Plop]),
+        Some("audio/plop.ogg".into())
+    );
+    assert_eq!(
+        asset_server.get_handle_path(&collection.sounds[&Sound::Yipee]),
+        Some("audio/yipee.ogg".into())
+    );
+    assert_eq!(collection.untyped_sounds.len(), 2);
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The app did not finish in 10 seconds");
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Hash)]
+enum Sound {
+    Plop,
+    Yipee,
+}
+
+impl FromStr for Sound {
+    type Err = ();
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "plop" => Ok(Sound::Plop),
+            "yipee" => Ok(Sound::Yipee),
+            _ => Err(()),
+        }
+    }
+}
+
+fn parse_sound(name: &str) -> Option<Sound> {
+    Sound::from_str(name).ok()
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio", collection(typed, mapped), map_key = "Sound::from_str")]
+    sounds: HashMap<Sound, Handle<AudioSource>>,
+    #[asset(path = "audio", collection(mapped), map_key = "parse_sound")]
+    untyped_sounds: HashMap<Sound, HandleUntyped>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,88 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::animation::AnimationClip;
+use bevy::app::AppExit;
+use bevy::gltf::{Gltf, GltfPlugin};
+use bevy::prelude::*;
+use bevy::scene::Scene;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(feature = "gltf_materials", not(feature = "progress_tracking")))]
+#[test]
+fn fills_material_vectors_in_index_order() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            GltfPlugin::default(),
+        ))
+        .add_asset::<Mesh>()
+        .add_asset::<Image>()
+        .add_asset::<StandardMaterial>()
+        .add_asset::<Scene>()
+        .add_asset::<AnimationClip>()
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset collection was not loaded in 10 seconds");
+    }
+}
+
+fn expect(
+    collection: Res<MyAssets>,
+    gltfs: Res<Assets<Gltf>>,
+    materials: Res<Assets<StandardMaterial>>,
+    asset_server: Res<AssetServer>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let gltf = gltfs.get(&collection.gltf).expect("glTF should be loaded");
+    assert_eq!(collection.materials, gltf.materials);
+    assert_eq!(collection.materials.len(), 2);
+    assert_eq!(
+        asset_server
+            .get_handle_path(&collection.materials[0])
+            .and_then(|path| path.label().map(str::to_owned)),
+        Some("Material0".to_owned())
+    );
+    assert_eq!(collection.materials[1], gltf.named_materials["Red"]);
+    let colors: Vec<Color> = collection
+        .materials
+        .iter()
+        .map(|handle| {
+            materials
+                .get(handle)
+                .expect("Material should exist")
+                .base_color
+        })
+        .collect();
+    assert_eq!(
+        colors,
+        vec![
+            Color::rgba_linear(0., 0., 1., 1.),
+            Color::rgba_linear(1., 0., 0., 1.)
+        ]
+    );
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "models/triangle.gltf")]
+    gltf: Handle<Gltf>,
+    #[asset(path = "models/triangle.gltf", gltf_materials)]
+    materials: Vec<Handle<StandardMaterial>>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
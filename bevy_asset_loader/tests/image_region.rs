@@ -0,0 +1,78 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::asset::AssetPlugin;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy_asset_loader::prelude::*;
+
+#[cfg(feature = "3d")]
+#[test]
+fn copies_region_into_new_image() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+        .add_asset::<Image>();
+
+    // 4x2 image where the red channel is the pixel index
+    let image = Image::new(
+        Extent3d {
+            width: 4,
+            height: 2,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        (0..8).flat_map(|index| [index, 0, 0, 255]).collect(),
+        TextureFormat::Rgba8UnormSrgb,
+    );
+    let handle: Handle<Image> = app
+        .world
+        .resource::<AssetServer>()
+        .get_handle("images/female_adventurer_sheet.png");
+    app.world
+        .resource_mut::<Assets<Image>>()
+        .set_untracked(handle, image);
+
+    app.init_collection::<MyAssets>();
+
+    let collection = app.world.resource::<MyAssets>();
+    let icon = app
+        .world
+        .resource::<Assets<Image>>()
+        .get(&collection.icon)
+        .expect("The region should have been added as new image");
+    assert_eq!(icon.size(), Vec2::new(2., 2.));
+    assert_eq!(
+        icon.texture_descriptor.format,
+        TextureFormat::Rgba8UnormSrgb
+    );
+    let red: Vec<u8> = icon.data.chunks(4).map(|pixel| pixel[0]).collect();
+    assert_eq!(red, vec![1, 2, 5, 6]);
+}
+
+#[cfg(feature = "3d")]
+#[test]
+#[should_panic(expected = "is outside of the image")]
+fn panics_for_regions_outside_of_the_image() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+        .add_asset::<Image>();
+
+    let handle: Handle<Image> = app
+        .world
+        .resource::<AssetServer>()
+        .get_handle("images/female_adventurer_sheet.png");
+    app.world
+        .resource_mut::<Assets<Image>>()
+        .set_untracked(handle, Image::default());
+
+    app.init_collection::<MyAssets>();
+}
+
+#[cfg(feature = "3d")]
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(
+        path = "images/female_adventurer_sheet.png",
+        region(x = 1, y = 0, w = 2, h = 2)
+    )]
+    icon: Handle<Image>,
+}
@@ -0,0 +1,122 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::{AssetPlugin, LoadState};
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn loads_registered_bytes() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            InMemoryAssetsPlugin::default(),
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_failure_continue_to_state(MyStates::Error)
+                .on_enter(register_bytes),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect_loaded)
+        .add_systems(OnEnter(MyStates::Error), unexpected_failure)
+        .run();
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn fails_to_load_registered_bytes_if_plugin_is_added_after_asset_plugin() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            InMemoryAssetsPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_failure_continue_to_state(MyStates::Error)
+                .on_enter(register_bytes),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), unexpected_success)
+        .add_systems(OnEnter(MyStates::Error), expect_failed)
+        .run();
+}
+
+fn register_bytes(mut dynamic_assets: ResMut<DynamicAssets>) {
+    dynamic_assets.register_bytes(
+        "sound",
+        include_bytes!("../assets/audio/plop.ogg").to_vec(),
+        "ogg",
+    );
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+fn expect_loaded(
+    assets: Res<MyAssets>,
+    asset_server: Res<AssetServer>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert_eq!(
+        asset_server.get_load_state(&assets.sound),
+        LoadState::Loaded
+    );
+    exit.send(AppExit);
+}
+
+fn expect_failed(failed_assets: Res<FailedAssets>, mut exit: EventWriter<AppExit>) {
+    assert!(
+        failed_assets
+            .paths
+            .iter()
+            .any(|path| path.contains("bevy_asset_loader_in_memory/sound.ogg")),
+        "The registered bytes should not be found without the in memory asset io"
+    );
+    exit.send(AppExit);
+}
+
+fn unexpected_failure() {
+    panic!("The registered bytes should have loaded");
+}
+
+fn unexpected_success() {
+    panic!("The registered bytes should only load if the InMemoryAssetsPlugin is added before the AssetPlugin");
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(key = "sound")]
+    sound: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Error,
+    Next,
+}
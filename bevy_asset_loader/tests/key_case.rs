@@ -0,0 +1,102 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn derives_keys_from_field_names() {
+    let mut app = App::new();
+    app.add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, DotAssets>(MyStates::Load)
+        .add_collection_to_loading_state::<_, KebabAssets>(MyStates::Load)
+        .add_collection_to_loading_state::<_, SnakeAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect_keys);
+    let mut dynamic_assets = app.world.resource_mut::<DynamicAssets>();
+    dynamic_assets.register_asset("audio.plop", Box::new(AudioFile("audio/plop.ogg")));
+    dynamic_assets.register_asset("audio.loop", Box::new(AudioFile("audio/background.ogg")));
+    dynamic_assets.register_asset("audio-yipee", Box::new(AudioFile("audio/yipee.ogg")));
+    dynamic_assets.register_asset("background", Box::new(AudioFile("audio/background.ogg")));
+    app.run();
+}
+
+#[derive(Debug)]
+struct AudioFile(&'static str);
+
+impl DynamicAsset for AudioFile {
+    fn load(&self, asset_server: &AssetServer) -> Vec<HandleUntyped> {
+        vec![asset_server.load_untyped(self.0)]
+    }
+
+    fn build(&self, world: &mut World) -> Result<DynamicAssetType, anyhow::Error> {
+        let asset_server = world.resource::<AssetServer>();
+        Ok(DynamicAssetType::Single(
+            asset_server.get_handle_untyped(self.0),
+        ))
+    }
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset collections were not loaded in 10 seconds");
+    }
+}
+
+fn expect_keys(
+    dot: Res<DotAssets>,
+    kebab: Res<KebabAssets>,
+    snake: Res<SnakeAssets>,
+    asset_server: Res<AssetServer>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let path = |handle: &Handle<AudioSource>| asset_server.get_handle_path(handle).unwrap();
+    assert_eq!(path(&dot.audio_plop), "audio/plop.ogg".into());
+    assert_eq!(path(&dot.audio_loop), "audio/background.ogg".into());
+    assert_eq!(path(&kebab.audio_yipee), "audio/yipee.ogg".into());
+    assert_eq!(path(&snake.background), "audio/background.ogg".into());
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+#[asset_collection(key_case = "dot")]
+struct DotAssets {
+    #[asset(key)]
+    audio_plop: Handle<AudioSource>,
+    #[asset(key = "audio.loop")]
+    audio_loop: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+#[asset_collection(key_case = "kebab")]
+struct KebabAssets {
+    #[asset(key)]
+    audio_yipee: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct SnakeAssets {
+    #[asset(key)]
+    background: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
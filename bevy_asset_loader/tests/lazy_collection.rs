@@ -0,0 +1,115 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::asset::{AssetPlugin, LoadState};
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn loads_collection_on_first_access() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+    ))
+    .init_lazy_collection::<MyAssets>()
+    .add_systems(Update, timeout);
+
+    for _ in 0..10 {
+        app.update();
+    }
+    assert_eq!(
+        app.world
+            .resource::<AssetServer>()
+            .get_load_state("audio/plop.ogg"),
+        LoadState::NotLoaded,
+        "The collection should not load before it is accessed"
+    );
+    assert!(!app.world.resource::<LazyCollection<MyAssets>>().is_loaded());
+
+    app.add_systems(Update, access_collection);
+    while !app.world.resource::<LazyCollection<MyAssets>>().is_loaded() {
+        app.update();
+    }
+    let lazy_collection = app.world.resource::<LazyCollection<MyAssets>>();
+    let collection = lazy_collection
+        .get()
+        .expect("The collection should be loaded");
+    assert!(app
+        .world
+        .resource::<Assets<AudioSource>>()
+        .contains(&collection.plop));
+
+    app.world
+        .resource_mut::<LazyCollection<MyAssets>>()
+        .unload();
+    app.update();
+    assert!(!app.world.resource::<LazyCollection<MyAssets>>().is_loaded());
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn loads_listed_paths_of_lazy_collection() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+        PathListPlugin::default(),
+    ))
+    .init_lazy_collection::<ListedAssets>()
+    .add_systems(Update, (timeout, access_listed_collection));
+
+    while !app
+        .world
+        .resource::<LazyCollection<ListedAssets>>()
+        .is_loaded()
+    {
+        app.update();
+    }
+    let lazy_collection = app.world.resource::<LazyCollection<ListedAssets>>();
+    let collection = lazy_collection
+        .get()
+        .expect("The collection should be loaded");
+    assert_eq!(collection.sounds.len(), 2);
+    let audio = app.world.resource::<Assets<AudioSource>>();
+    for sound in &collection.sounds {
+        assert!(audio.contains(sound), "Listed asset is not loaded");
+    }
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The lazy collection was not loaded in 10 seconds");
+    }
+}
+
+fn access_collection(lazy_collection: Res<LazyCollection<MyAssets>>) {
+    lazy_collection.get();
+}
+
+fn access_listed_collection(lazy_collection: Res<LazyCollection<ListedAssets>>) {
+    lazy_collection.get();
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct ListedAssets {
+    #[asset(path_list = "audio/sounds.txt")]
+    sounds: Vec<Handle<AudioSource>>,
+}
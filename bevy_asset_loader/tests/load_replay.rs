@@ -0,0 +1,90 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::core::FrameCount;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    feature = "load_replay",
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn replays_recorded_loads() {
+    let mut app = loading_app();
+    app.init_resource::<LoadRecorder>();
+    let loaded_in_frame = run_until_loaded(&mut app);
+    let recorder = app.world.resource::<LoadRecorder>().clone();
+    assert_eq!(recorder.loads().len(), 2);
+    assert!(recorder
+        .loads()
+        .iter()
+        .all(|load| load.collection == std::any::type_name::<MyAssets>()));
+    let last_frame = recorder.loads().iter().map(|load| load.frame).max();
+    assert_eq!(Some(loaded_in_frame), last_frame);
+
+    let mut app = loading_app();
+    app.insert_resource(recorder.replayer());
+    assert_eq!(Some(run_until_loaded(&mut app)), last_frame);
+
+    // finish loading the sound much later than in the recording
+    let delayed_frame = last_frame.unwrap() + 20;
+    let mut app = loading_app();
+    app.insert_resource(LoadReplayer::new(recorder.loads().iter().map(|load| {
+        if load.path == "audio/plop.ogg" {
+            (load.path.clone(), delayed_frame)
+        } else {
+            (load.path.clone(), load.frame)
+        }
+    })));
+    assert_eq!(run_until_loaded(&mut app), delayed_frame);
+}
+
+fn loading_app() -> App {
+    let mut app = App::new();
+    app.add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout);
+    app
+}
+
+/// Update the app until the collection exists and return the frame it was inserted in
+fn run_until_loaded(app: &mut App) -> u32 {
+    loop {
+        let frame = app.world.resource::<FrameCount>().0;
+        app.update();
+        if app.world.contains_resource::<MyAssets>() {
+            return frame;
+        }
+    }
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset collection was not loaded in 10 seconds");
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+    #[asset(path = "audio/yipee.ogg")]
+    yipee: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
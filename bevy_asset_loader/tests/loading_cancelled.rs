@@ -0,0 +1,106 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn leaving_loading_state_cancels_loading() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .init_resource::<Cancellations>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_cancel(count_cancellation),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(
+            Update,
+            (timeout, leave_while_loading).run_if(in_state(MyStates::Load)),
+        )
+        .add_systems(OnEnter(MyStates::Menu), expect_cancelled)
+        .add_systems(OnEnter(MyStates::Next), expect_ready_to_go)
+        .run();
+}
+
+#[derive(Resource, Default)]
+struct Cancellations(usize);
+
+fn count_cancellation(mut cancellations: ResMut<Cancellations>) {
+    cancellations.0 += 1;
+}
+
+fn leave_while_loading(
+    progress: Res<LoadingProgress<MyStates>>,
+    cancellations: Res<Cancellations>,
+    mut next_state: ResMut<NextState<MyStates>>,
+) {
+    if cancellations.0 == 0 && progress.total > 0 {
+        next_state.set(MyStates::Menu);
+    }
+}
+
+fn expect_cancelled(
+    mut cancelled: EventReader<LoadingCancelled<MyStates>>,
+    cancellations: Res<Cancellations>,
+    progress: Res<LoadingProgress<MyStates>>,
+    assets: Option<Res<MyAssets>>,
+    mut next_state: ResMut<NextState<MyStates>>,
+) {
+    let events: Vec<_> = cancelled.iter().collect();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].state, MyStates::Load);
+    assert_eq!(cancellations.0, 1, "on_cancel systems should run once");
+    assert_eq!(progress.total, 0, "Progress should be reset");
+    assert!(assets.is_none(), "The collection should not be inserted");
+    next_state.set(MyStates::Load);
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The app did not finish in 10 seconds");
+    }
+}
+
+fn expect_ready_to_go(
+    collection: Option<Res<MyAssets>>,
+    cancellations: Res<Cancellations>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert!(
+        collection.is_some(),
+        "The collection should load after entering the loading state again"
+    );
+    assert_eq!(
+        cancellations.0, 1,
+        "Finishing should not count as cancelled"
+    );
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Menu,
+    Next,
+}
@@ -0,0 +1,79 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::LoadState;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::loading_dump::LoadingDump;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn dumps_tracked_handles() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .init_resource::<Dumps>()
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, (timeout, dump).run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+#[derive(Resource, Default)]
+struct Dumps(Vec<LoadingDump>);
+
+fn dump(world: &mut World) {
+    let dump = world.debug_dump_loading();
+    world.resource_mut::<Dumps>().0.push(dump);
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset collection was not loaded in 10 seconds");
+    }
+}
+
+fn expect(world: &mut World) {
+    let dumps = world.resource::<Dumps>();
+    let dump = dumps
+        .0
+        .iter()
+        .find(|dump| !dump.is_empty())
+        .expect("No dump contained the loading collection");
+    assert_eq!(dump.collections.len(), 1);
+    assert!(dump.collections[0].collection.ends_with("MyAssets"));
+    let mut paths: Vec<_> = dump.handles().map(|handle| handle.path.as_str()).collect();
+    paths.sort();
+    assert_eq!(paths, vec!["audio/background.ogg", "audio/plop.ogg"]);
+    assert!(dump
+        .handles()
+        .all(|handle| handle.load_state != LoadState::Failed));
+
+    assert!(world.debug_dump_loading().is_empty());
+    world.send_event(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
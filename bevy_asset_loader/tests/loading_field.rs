@@ -0,0 +1,173 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::{AssetLoader, AssetPlugin, LoadContext};
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::render::render_resource::Shader;
+use bevy::utils::BoxedFuture;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_field::LoadingField;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn inserts_collection_with_loading_fields_early() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .init_resource::<SeenWhileLoading>()
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, AudioCollection>(MyStates::Load)
+        .add_systems(
+            Update,
+            (timeout, observe_loading_fields).run_if(in_state(MyStates::Load)),
+        )
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn updates_loading_fields_in_target_world() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_asset::<Shader>()
+        .add_asset_loader(NeverLoader)
+        .init_resource::<SceneWorld>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .target_world(|world| &mut world.resource_mut::<SceneWorld>().into_inner().0),
+        )
+        .add_collection_to_loading_state::<_, UnfinishedCollection>(MyStates::Load)
+        .add_systems(
+            Update,
+            (timeout, expect_ready_in_target_world).run_if(in_state(MyStates::Load)),
+        )
+        .run();
+}
+
+#[derive(Resource, Default)]
+struct SceneWorld(World);
+
+/// Loader that never finishes loading shader files, so the collection stays in its loading state
+struct NeverLoader;
+
+impl AssetLoader for NeverLoader {
+    fn load<'a>(
+        &'a self,
+        _bytes: &'a [u8],
+        _load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+        Box::pin(std::future::pending())
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["wgsl"]
+    }
+}
+
+fn expect_ready_in_target_world(world: &mut World) {
+    assert!(!world.contains_resource::<UnfinishedCollection>());
+    let Some(collection) = world
+        .resource::<SceneWorld>()
+        .0
+        .get_resource::<UnfinishedCollection>()
+    else {
+        return;
+    };
+    assert!(!collection.shader.is_ready());
+    if collection.background.is_ready() {
+        world.send_event(AppExit);
+    }
+}
+
+#[derive(Resource, Default)]
+struct SeenWhileLoading {
+    inserted: bool,
+    pending: bool,
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 60. {
+        panic!("The asset loader did not change the state in 60 seconds");
+    }
+}
+
+fn observe_loading_fields(
+    collection: Option<Res<AudioCollection>>,
+    mut seen: ResMut<SeenWhileLoading>,
+) {
+    let Some(collection) = collection else {
+        return;
+    };
+    seen.inserted = true;
+    if !collection.background.is_ready() {
+        assert!(collection.background.get().is_none());
+        seen.pending = true;
+    }
+}
+
+fn expect(
+    collection: Res<AudioCollection>,
+    seen: Res<SeenWhileLoading>,
+    asset_server: Res<AssetServer>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert!(
+        seen.inserted,
+        "The collection should be inserted while it is loading"
+    );
+    assert!(
+        seen.pending,
+        "Fields should be pending before their assets are loaded"
+    );
+    assert!(collection.background.is_ready());
+    assert!(collection.plop.is_ready());
+    assert_eq!(
+        asset_server.get_load_state(collection.plop.handle()),
+        bevy::asset::LoadState::Loaded
+    );
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct AudioCollection {
+    #[asset(path = "audio/background.ogg")]
+    background: LoadingField<AudioSource>,
+    #[asset(path = "audio/plop.ogg")]
+    plop: LoadingField<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct UnfinishedCollection {
+    #[asset(path = "audio/background.ogg")]
+    background: LoadingField<AudioSource>,
+    #[asset(path = "shaders/common.wgsl")]
+    shader: LoadingField<Shader>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,87 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+use futures_lite::future::block_on;
+use std::task::Poll;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn future_resolves_once_collections_are_loaded() {
+    let mut app = App::new();
+    app.add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_collection_to_loading_state::<_, MoreAssets>(MyStates::Load)
+        .add_systems(Update, timeout);
+
+    block_on(
+        LoadingStateFuture::new(&mut app)
+            .with_collection::<MyAssets>()
+            .with_collection::<MoreAssets>(),
+    );
+
+    assert!(app.world.contains_resource::<MyAssets>());
+    assert!(app.world.contains_resource::<MoreAssets>());
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn poll_loaded_is_pending_while_loading() {
+    let mut app = App::new();
+    app.add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout);
+
+    let mut future = LoadingStateFuture::new(&mut app).with_collection::<MyAssets>();
+    assert_eq!(future.poll_loaded(), Poll::Pending);
+    while future.poll_loaded().is_pending() {}
+
+    assert!(app.world.contains_resource::<MyAssets>());
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset collections were not loaded in 10 seconds");
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct MoreAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
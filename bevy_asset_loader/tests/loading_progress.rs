@@ -0,0 +1,154 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::{
+    AssetCollection, LoadingProgress, LoadingState, LoadingStateAppExt,
+};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn progress_distinguishes_issued_and_loaded_assets() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .init_resource::<ObservedRequesting>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .batch_size(1),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(
+            Update,
+            (timeout, observe_progress).run_if(in_state(MyStates::Load)),
+        )
+        .add_systems(OnEnter(MyStates::Next), expect_completed_progress)
+        .run();
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn tracks_progress_per_state_type() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .add_state::<OverlayStates>()
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_loading_state(
+            LoadingState::new(OverlayStates::Load).continue_to_state(OverlayStates::Next),
+        )
+        .add_collection_to_loading_state::<_, OverlayAssets>(OverlayStates::Load)
+        .add_systems(Update, (timeout, observe_separate_progress))
+        .run();
+}
+
+#[derive(Resource, Default)]
+struct ObservedRequesting(bool);
+
+fn observe_separate_progress(
+    progress: Res<LoadingProgress<MyStates>>,
+    overlay_progress: Res<LoadingProgress<OverlayStates>>,
+    state: Res<State<MyStates>>,
+    overlay_state: Res<State<OverlayStates>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert!(
+        progress.total <= 3,
+        "Overlay assets leaked into the progress"
+    );
+    assert!(
+        overlay_progress.total <= 1,
+        "Assets of MyStates leaked into the overlay progress"
+    );
+    if state.get() == &MyStates::Next && overlay_state.get() == &OverlayStates::Next {
+        assert_eq!(progress.total, 3);
+        assert_eq!(progress.fraction(), 1.);
+        assert_eq!(overlay_progress.total, 1);
+        assert_eq!(overlay_progress.fraction(), 1.);
+        info!("Everything fine, quitting the app");
+        exit.send(AppExit);
+    }
+}
+
+fn observe_progress(
+    progress: Res<LoadingProgress<MyStates>>,
+    mut observed: ResMut<ObservedRequesting>,
+) {
+    assert!(progress.loaded <= progress.issued);
+    assert!(progress.issued <= progress.total);
+    if progress.issued < progress.total {
+        observed.0 = true;
+    }
+}
+
+fn expect_completed_progress(
+    progress: Res<LoadingProgress<MyStates>>,
+    observed: Res<ObservedRequesting>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert!(
+        observed.0,
+        "With a batch size of 1, not all assets should be issued in the first frame"
+    );
+    assert_eq!(progress.total, 3);
+    assert_eq!(progress.issued, 3);
+    assert_eq!(progress.loaded, 3);
+    assert_eq!(progress.fraction(), 1.);
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The app did not finish in 10 seconds");
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+    #[asset(path = "audio/yipee.ogg")]
+    yipee: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct OverlayAssets {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum OverlayStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,90 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::LoadState;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn loads_loading_screen_assets_first() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .with_loading_screen_assets(&[("jingle", "audio/plop.ogg")]),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(
+            Update,
+            (timeout, expect_loading_screen_assets).run_if(in_state(MyStates::Load)),
+        )
+        .add_systems(OnEnter(MyStates::Next), expect_resource_removed)
+        .run();
+}
+
+fn expect_loading_screen_assets(
+    loading_screen_assets: Option<Res<LoadingScreenAssets>>,
+    progress: Res<LoadingProgress<MyStates>>,
+    asset_server: Res<AssetServer>,
+) {
+    let loading_screen_assets = loading_screen_assets
+        .expect("Loading screen assets should be available during the loading state");
+    let jingle: Handle<AudioSource> = loading_screen_assets
+        .get("jingle")
+        .expect("The loading screen asset should be available under its key");
+    assert!(loading_screen_assets
+        .get::<AudioSource>("unknown")
+        .is_none());
+    if progress.total > 0 {
+        assert_eq!(
+            asset_server.get_load_state(&jingle),
+            LoadState::Loaded,
+            "Collections should only start loading after the loading screen assets"
+        );
+    }
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The app did not finish in 10 seconds");
+    }
+}
+
+fn expect_resource_removed(
+    loading_screen_assets: Option<Res<LoadingScreenAssets>>,
+    collection: Option<Res<MyAssets>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert!(collection.is_some());
+    assert!(
+        loading_screen_assets.is_none(),
+        "Loading screen assets should be removed when leaving the loading state"
+    );
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,94 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn wires_loading_state_from_macro() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_plugins(loading_state! {
+            MyStates::Load => MyStates::Next,
+            collections: [PlopAudio, BackgroundAudio],
+            init_resources: [PostProcessed],
+        })
+        .add_plugins(loading_state! {
+            MyStates::Next => MyStates::Done,
+            collections: [YipeeAudio],
+        })
+        .add_systems(Update, timeout.run_if(not(in_state(MyStates::Done))))
+        .add_systems(OnEnter(MyStates::Next), expect_first)
+        .add_systems(OnEnter(MyStates::Done), expect_second)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset collections were not loaded in 10 seconds");
+    }
+}
+
+fn expect_first(
+    plop: Option<Res<PlopAudio>>,
+    background: Option<Res<BackgroundAudio>>,
+    post_processed: Option<Res<PostProcessed>>,
+) {
+    assert!(plop.is_some());
+    assert!(background.is_some());
+    assert!(post_processed.is_some());
+}
+
+fn expect_second(yipee: Option<Res<YipeeAudio>>, mut exit: EventWriter<AppExit>) {
+    assert!(yipee.is_some());
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct PlopAudio {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct BackgroundAudio {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct YipeeAudio {
+    #[asset(path = "audio/yipee.ogg")]
+    yipee: Handle<AudioSource>,
+}
+
+#[derive(Resource)]
+struct PostProcessed;
+
+impl FromWorld for PostProcessed {
+    fn from_world(world: &mut World) -> Self {
+        assert!(world.contains_resource::<PlopAudio>());
+        PostProcessed
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+    Done,
+}
@@ -0,0 +1,95 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn orders_systems_of_different_loading_states() {
+    App::new()
+        .add_state::<BootState>()
+        .add_state::<MenuState>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(LoadingState::new(BootState::Booting).continue_to_state(BootState::Done))
+        .add_collection_to_loading_state::<_, BootAssets>(BootState::Booting)
+        .add_loading_state(
+            LoadingState::new(MenuState::Loading)
+                .stay_in_state()
+                .with_loading_system(expect_boot_assets_in_same_frame),
+        )
+        .configure_set(
+            Update,
+            LoadingSystemSet(MenuState::Loading).after(LoadingStateSet(BootState::Booting)),
+        )
+        .insert_resource(Frame(0))
+        .add_systems(First, count_frames)
+        .add_systems(Update, timeout)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The boot assets were not seen in the frame they were loaded in 10 seconds");
+    }
+}
+
+fn count_frames(mut frame: ResMut<Frame>) {
+    frame.0 += 1;
+}
+
+fn expect_boot_assets_in_same_frame(
+    boot_assets: Option<Res<BootAssets>>,
+    frame: Res<Frame>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let Some(boot_assets) = boot_assets else {
+        return;
+    };
+    assert_eq!(
+        boot_assets.created_in.0, frame.0,
+        "The menu loading system should see the boot assets in the frame they were created"
+    );
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct BootAssets {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+    created_in: Frame,
+}
+
+#[derive(Resource, Clone, Copy)]
+struct Frame(u32);
+
+impl FromWorld for Frame {
+    fn from_world(world: &mut World) -> Self {
+        *world.resource::<Frame>()
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum BootState {
+    #[default]
+    Booting,
+    Done,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MenuState {
+    #[default]
+    Loading,
+    Ready,
+}
@@ -0,0 +1,73 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+use std::time::Duration;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn tracks_time_in_loading_state() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .init_resource::<LongestElapsed>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .delay_start(Duration::from_millis(50)),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(
+            Update,
+            (timeout, record_elapsed).run_if(in_state(MyStates::Load)),
+        )
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+#[derive(Resource, Default)]
+struct LongestElapsed(Duration);
+
+fn record_elapsed(time: Res<LoadingStateTime>, mut longest: ResMut<LongestElapsed>) {
+    assert!(time.elapsed() >= longest.0);
+    longest.0 = time.elapsed();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset collection was not loaded in 10 seconds");
+    }
+}
+
+fn expect(
+    time: Option<Res<LoadingStateTime>>,
+    longest: Res<LongestElapsed>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert!(time.is_none());
+    assert!(longest.0 >= Duration::from_millis(50));
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
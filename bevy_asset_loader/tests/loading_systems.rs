@@ -0,0 +1,87 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::{AssetCollection, LoadingState, LoadingStateAppExt};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn runs_loading_systems_only_during_the_loading_state() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .init_resource::<FrameCount>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .with_loading_system(count_frames),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), remember_frames)
+        .add_systems(
+            Update,
+            expect_no_more_frames.run_if(in_state(MyStates::Next)),
+        )
+        .run();
+}
+
+#[derive(Resource, Default)]
+struct FrameCount {
+    loading: usize,
+    after_loading: Option<usize>,
+    frames_in_next: usize,
+}
+
+fn count_frames(mut count: ResMut<FrameCount>) {
+    count.loading += 1;
+}
+
+fn remember_frames(mut count: ResMut<FrameCount>) {
+    assert!(
+        count.loading > 0,
+        "The loading system should run while loading"
+    );
+    count.after_loading = Some(count.loading);
+}
+
+fn expect_no_more_frames(mut count: ResMut<FrameCount>, mut exit: EventWriter<AppExit>) {
+    assert_eq!(
+        Some(count.loading),
+        count.after_loading,
+        "The loading system should not run after the loading state"
+    );
+    count.frames_in_next += 1;
+    if count.frames_in_next > 3 {
+        info!("Everything fine, quitting the app");
+        exit.send(AppExit);
+    }
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The app did not finish in 10 seconds");
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
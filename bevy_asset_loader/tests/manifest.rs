@@ -0,0 +1,185 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::{AssetLoader, LoadContext, LoadedAsset};
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::reflect::{TypePath, TypeUuid};
+use bevy::utils::BoxedFuture;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn expands_manifest_before_loading_collections() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_asset::<Manifest>()
+        .init_asset_loader::<ManifestLoader>()
+        .add_state::<MyStates>()
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_manifest_to_loading_state::<_, Manifest>(
+            MyStates::Load,
+            "audio.manifest",
+            expand_manifest,
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect_collection)
+        .run();
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn leaving_loading_state_drops_manifest_handles() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_asset::<Manifest>()
+        .add_asset_loader(NeverLoader)
+        .add_state::<MyStates>()
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_manifest_to_loading_state::<_, Manifest>(
+            MyStates::Load,
+            "audio.manifest",
+            expand_manifest,
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(
+            Update,
+            (timeout, leave_while_loading_manifest).run_if(in_state(MyStates::Load)),
+        )
+        .add_systems(OnEnter(MyStates::Menu), expect_no_loading_handles)
+        .run();
+}
+
+#[derive(TypeUuid, TypePath)]
+#[uuid = "5f7d3a62-0c2e-4f0b-9d4e-3a1c6b8e2f90"]
+struct Manifest {
+    background: String,
+}
+
+#[derive(Default)]
+struct ManifestLoader;
+
+impl AssetLoader for ManifestLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+        Box::pin(async move {
+            let background = std::str::from_utf8(bytes)?.trim().to_owned();
+            load_context.set_default_asset(LoadedAsset::new(Manifest { background }));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["manifest"]
+    }
+}
+
+#[derive(Debug)]
+struct AudioFile(String);
+
+impl DynamicAsset for AudioFile {
+    fn load(&self, asset_server: &AssetServer) -> Vec<HandleUntyped> {
+        vec![asset_server.load_untyped(&self.0)]
+    }
+
+    fn build(&self, world: &mut World) -> Result<DynamicAssetType, anyhow::Error> {
+        let asset_server = world.resource::<AssetServer>();
+        Ok(DynamicAssetType::Single(
+            asset_server.get_handle_untyped(&self.0),
+        ))
+    }
+}
+
+/// Loader that never finishes loading manifests, so the loading state waits for them
+struct NeverLoader;
+
+impl AssetLoader for NeverLoader {
+    fn load<'a>(
+        &'a self,
+        _bytes: &'a [u8],
+        _load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+        Box::pin(std::future::pending())
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["manifest"]
+    }
+}
+
+fn expand_manifest(manifest: &Manifest, dynamic_assets: &mut DynamicAssets) {
+    dynamic_assets.register_asset(
+        "background",
+        Box::new(AudioFile(manifest.background.clone())),
+    );
+}
+
+fn expect_collection(
+    collection: Res<MyAssets>,
+    asset_server: Res<AssetServer>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert_eq!(
+        asset_server.get_handle_path(&collection.background),
+        Some("audio/background.ogg".into())
+    );
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+fn leave_while_loading_manifest(world: &mut World) {
+    if !world.debug_dump_loading().is_empty() {
+        world
+            .resource_mut::<NextState<MyStates>>()
+            .set(MyStates::Menu);
+    }
+}
+
+fn expect_no_loading_handles(world: &mut World) {
+    assert!(
+        world.debug_dump_loading().is_empty(),
+        "The manifest handles should be dropped"
+    );
+    info!("Everything fine, quitting the app");
+    world.send_event(AppExit);
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The app did not finish in 10 seconds");
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(key = "background")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Menu,
+    Next,
+}
@@ -0,0 +1,100 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn limits_concurrent_loads_per_asset_type() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .init_resource::<MostInFlight>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .max_concurrent_loads_for::<AudioSource>(1),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_collection_to_loading_state::<_, OtherAssets>(MyStates::Load)
+        .add_systems(
+            Update,
+            (
+                timeout,
+                count_in_flight.after(LoadingStateSet(MyStates::Load)),
+            )
+                .run_if(in_state(MyStates::Load)),
+        )
+        .add_systems(OnEnter(MyStates::Next), expect_collections)
+        .run();
+}
+
+#[derive(Resource, Default)]
+struct MostInFlight(usize);
+
+fn count_in_flight(
+    progress: Res<LoadingProgress<MyStates>>,
+    mut most_in_flight: ResMut<MostInFlight>,
+) {
+    most_in_flight.0 = most_in_flight.0.max(progress.issued - progress.loaded);
+}
+
+fn expect_collections(
+    collection: Res<MyAssets>,
+    other: Res<OtherAssets>,
+    most_in_flight: Res<MostInFlight>,
+    asset_server: Res<AssetServer>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert_eq!(collection.files.len(), 3);
+    for handle in collection.files.iter().chain([&other.plop]) {
+        assert_eq!(
+            asset_server.get_load_state(handle),
+            bevy::asset::LoadState::Loaded
+        );
+    }
+    assert_eq!(
+        most_in_flight.0, 1,
+        "Only one audio file should load at a time over all collections"
+    );
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The app did not finish in 10 seconds");
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(
+        paths("audio/background.ogg", "audio/plop.ogg", "audio/yipee.ogg"),
+        collection(typed)
+    )]
+    files: Vec<Handle<AudioSource>>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct OtherAssets {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
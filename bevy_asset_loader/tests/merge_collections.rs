@@ -0,0 +1,125 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn merges_collections_of_multiple_loading_states() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Base)
+                .continue_to_state(MyStates::Expansion)
+                .on_enter(register_base),
+        )
+        .add_loading_state(
+            LoadingState::new(MyStates::Expansion)
+                .continue_to_state(MyStates::Done)
+                .on_enter(register_expansion),
+        )
+        .add_collection_to_loading_state::<_, CardAssets>(MyStates::Base)
+        .add_collection_to_loading_state::<_, CardAssets>(MyStates::Expansion)
+        .add_systems(Update, timeout.run_if(not(in_state(MyStates::Done))))
+        .add_systems(OnEnter(MyStates::Done), expect_merged_collection)
+        .run();
+}
+
+#[derive(Debug)]
+struct AudioFiles(Vec<&'static str>);
+
+impl DynamicAsset for AudioFiles {
+    fn load(&self, asset_server: &AssetServer) -> Vec<HandleUntyped> {
+        self.0
+            .iter()
+            .map(|path| asset_server.load_untyped(*path))
+            .collect()
+    }
+
+    fn build(&self, world: &mut World) -> Result<DynamicAssetType, anyhow::Error> {
+        let asset_server = world.resource::<AssetServer>();
+        Ok(DynamicAssetType::Collection(
+            self.0
+                .iter()
+                .map(|path| asset_server.get_handle_untyped(*path))
+                .collect(),
+        ))
+    }
+}
+
+fn register_base(mut dynamic_assets: ResMut<DynamicAssets>) {
+    dynamic_assets.register_asset("cards", Box::new(AudioFiles(vec!["audio/plop.ogg"])));
+    dynamic_assets.register_asset(
+        "theme",
+        Box::new(FileAsset {
+            path: "audio/background.ogg".to_owned(),
+        }),
+    );
+}
+
+fn register_expansion(mut dynamic_assets: ResMut<DynamicAssets>) {
+    dynamic_assets.register_asset("cards", Box::new(AudioFiles(vec!["audio/yipee.ogg"])));
+    dynamic_assets.register_asset(
+        "theme",
+        Box::new(FileAsset {
+            path: "audio/plop.ogg".to_owned(),
+        }),
+    );
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The app did not finish in 10 seconds");
+    }
+}
+
+fn expect_merged_collection(
+    collection: Res<CardAssets>,
+    asset_server: Res<AssetServer>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert_eq!(
+        collection.cards,
+        vec![
+            asset_server.get_handle("audio/plop.ogg"),
+            asset_server.get_handle("audio/yipee.ogg")
+        ],
+        "The cards of both loading states should be merged"
+    );
+    assert_eq!(
+        collection.theme,
+        asset_server.get_handle("audio/plop.ogg"),
+        "Single handles should be replaced by the later collection"
+    );
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+#[asset_collection(merge)]
+struct CardAssets {
+    #[asset(key = "cards", collection(typed))]
+    cards: Vec<Handle<AudioSource>>,
+    #[asset(key = "theme")]
+    theme: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Base,
+    Expansion,
+    Done,
+}
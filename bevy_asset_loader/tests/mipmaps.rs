@@ -0,0 +1,52 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::asset::AssetPlugin;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy_asset_loader::prelude::*;
+
+#[cfg(feature = "3d")]
+#[test]
+fn generates_mipmaps_on_creation() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+        .add_asset::<Image>();
+
+    // Two black and two white pixels
+    let image = Image::new(
+        Extent3d {
+            width: 2,
+            height: 2,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        [[0, 0, 0, 255], [255, 255, 255, 255]].repeat(2).concat(),
+        TextureFormat::Rgba8Unorm,
+    );
+    let handle: Handle<Image> = app
+        .world
+        .resource::<AssetServer>()
+        .get_handle("images/tree.png");
+    app.world
+        .resource_mut::<Assets<Image>>()
+        .set_untracked(handle, image);
+
+    app.init_collection::<MyAssets>();
+
+    let collection = app.world.resource::<MyAssets>();
+    let image = app
+        .world
+        .resource::<Assets<Image>>()
+        .get(&collection.tree)
+        .expect("The image should still be available");
+    assert_eq!(image.texture_descriptor.mip_level_count, 2);
+    assert_eq!(image.data.len(), (4 + 1) * 4);
+    assert_eq!(image.data[16..], [128, 128, 128, 255]);
+}
+
+#[cfg(feature = "3d")]
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "images/tree.png", mipmaps)]
+    tree: Handle<Image>,
+}
@@ -0,0 +1,91 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn leaves_transition_to_user_systems() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .no_auto_transition(),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .init_resource::<FramesSinceComplete>()
+        .add_systems(
+            Update,
+            (timeout, orchestrate).run_if(in_state(MyStates::Load)),
+        )
+        .add_systems(OnEnter(MyStates::Next), quit)
+        .run();
+}
+
+#[derive(Resource, Default)]
+struct FramesSinceComplete(Option<usize>);
+
+fn orchestrate(
+    mut complete: EventReader<LoadingComplete<MyStates>>,
+    mut frames: ResMut<FramesSinceComplete>,
+    collection: Option<Res<MyAssets>>,
+    mut next_state: ResMut<NextState<MyStates>>,
+) {
+    for event in complete.iter() {
+        assert_eq!(
+            event,
+            &LoadingComplete {
+                state: MyStates::Load,
+                next: Some(MyStates::Next),
+            }
+        );
+        assert!(collection.is_some(), "The collection should be inserted");
+        frames.0 = Some(0);
+    }
+    let Some(frames) = frames.0.as_mut() else {
+        return;
+    };
+    // The loading state should not change the state on its own
+    *frames += 1;
+    if *frames > 5 {
+        next_state.set(MyStates::Next);
+    }
+}
+
+fn quit(frames: Res<FramesSinceComplete>, mut exit: EventWriter<AppExit>) {
+    assert_eq!(frames.0, Some(6));
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The app did not finish in 10 seconds");
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,77 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::LoadState;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+use std::sync::{Arc, Mutex};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn observes_every_handle() {
+    let observed = ObservedHandles::default();
+    let observer = observed.clone();
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .insert_resource(observed)
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_each_handle(move |path, state| {
+                    observer.0.lock().unwrap().push((path.to_owned(), state));
+                }),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+#[derive(Resource, Clone, Default)]
+struct ObservedHandles(Arc<Mutex<Vec<(String, LoadState)>>>);
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset collection was not loaded in 10 seconds");
+    }
+}
+
+fn expect(observed: Res<ObservedHandles>, mut exit: EventWriter<AppExit>) {
+    let observed = observed.0.lock().unwrap();
+    let count = |path: &str| observed.iter().filter(|(p, _)| p == path).count();
+    // Both handles are reported once per check
+    assert!(count("audio/background.ogg") > 0);
+    assert_eq!(count("audio/background.ogg"), count("audio/plop.ogg"));
+    assert_eq!(observed.len(), 2 * count("audio/plop.ogg"));
+    for path in ["audio/background.ogg", "audio/plop.ogg"] {
+        assert!(observed
+            .iter()
+            .any(|(p, state)| p == path && *state == LoadState::Loaded));
+    }
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,77 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::{
+    AssetCollection, LoadingState, LoadingStateAppExt, OnEnterLoadingStateSet,
+};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn runs_on_enter_systems() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .init_resource::<EnterCount>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_enter(count_enter),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(
+            OnEnter(MyStates::Load),
+            expect_counted.after(OnEnterLoadingStateSet(MyStates::Load)),
+        )
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect_single_enter)
+        .run();
+}
+
+#[derive(Resource, Default)]
+struct EnterCount(usize);
+
+fn count_enter(mut count: ResMut<EnterCount>) {
+    count.0 += 1;
+}
+
+fn expect_counted(count: Res<EnterCount>) {
+    assert_eq!(
+        count.0, 1,
+        "The on_enter system should run before systems ordered after its set"
+    );
+}
+
+fn expect_single_enter(count: Res<EnterCount>, mut exit: EventWriter<AppExit>) {
+    assert_eq!(count.0, 1, "The on_enter system should run exactly once");
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The app did not finish in 10 seconds");
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,110 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::loading_state::FailedAssets;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn replaces_failed_fields_with_fallback() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_failure_continue_to_state(MyStates::Error)
+                .on_enter(register_dynamic_assets),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect_fallback)
+        .add_systems(OnEnter(MyStates::Error), fail)
+        .run();
+}
+
+#[derive(Debug)]
+struct AudioFile(String);
+
+impl DynamicAsset for AudioFile {
+    fn load(&self, asset_server: &AssetServer) -> Vec<HandleUntyped> {
+        vec![asset_server.load_untyped(&self.0)]
+    }
+
+    fn build(&self, world: &mut World) -> Result<DynamicAssetType, anyhow::Error> {
+        let asset_server = world.resource::<AssetServer>();
+        Ok(DynamicAssetType::Single(
+            asset_server.get_handle_untyped(&self.0),
+        ))
+    }
+}
+
+fn register_dynamic_assets(mut dynamic_assets: ResMut<DynamicAssets>) {
+    dynamic_assets.register_asset(
+        "hero",
+        Box::new(AudioFile("non-existing-file.ogg".to_owned())),
+    );
+    dynamic_assets.register_asset("plop", Box::new(AudioFile("audio/plop.ogg".to_owned())));
+}
+
+fn silence(world: &mut World) -> Handle<AudioSource> {
+    world.resource::<AssetServer>().load("audio/yipee.ogg")
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The app did not finish in 10 seconds");
+    }
+}
+
+fn fail() {
+    panic!("The fallback should have kept the loading state from failing");
+}
+
+fn expect_fallback(
+    collection: Res<MyAssets>,
+    asset_server: Res<AssetServer>,
+    failed_assets: Res<FailedAssets>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert_eq!(
+        collection.hero,
+        asset_server.get_handle("audio/yipee.ogg"),
+        "The failed field should hold the handle returned by its fallback"
+    );
+    assert_eq!(
+        collection.plop,
+        asset_server.get_handle("audio/plop.ogg"),
+        "Loaded fields should not be replaced by their fallback"
+    );
+    assert!(failed_assets.paths.is_empty());
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(key = "hero", on_fail = "silence")]
+    hero: Handle<AudioSource>,
+    #[asset(key = "plop", on_fail = "silence")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Error,
+    Next,
+}
@@ -0,0 +1,78 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn aliases_redirect_configured_paths() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_enter(register_aliases),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect_aliased_paths)
+        .run();
+}
+
+fn register_aliases(mut dynamic_assets: ResMut<DynamicAssets>) {
+    dynamic_assets.register_alias("audio/renamed.ogg", "audio/plop.ogg");
+    dynamic_assets.register_alias("audio/background.ogg", "audio/yipee.ogg");
+    dynamic_assets.register_asset(
+        "music",
+        Box::new(FileAsset {
+            path: "audio/background.ogg".to_owned(),
+        }),
+    );
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The app did not finish in 10 seconds");
+    }
+}
+
+fn expect_aliased_paths(
+    collection: Res<MyAssets>,
+    asset_server: Res<AssetServer>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert_eq!(collection.plop, asset_server.get_handle("audio/plop.ogg"));
+    assert_eq!(
+        collection.music,
+        asset_server.get_handle("audio/background.ogg"),
+        "Dynamic keys should take precedence over aliases"
+    );
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/renamed.ogg")]
+    plop: Handle<AudioSource>,
+    #[asset(key = "music")]
+    music: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
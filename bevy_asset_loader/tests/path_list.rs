@@ -0,0 +1,91 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::validate_collection;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn loads_listed_paths() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+            PathListPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect_listed_sounds)
+        .run();
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn validates_listed_paths() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+        PathListPlugin::default(),
+    ));
+
+    assert_eq!(validate_collection::<MyAssets>(&mut app), Ok(()));
+}
+
+fn expect_listed_sounds(
+    collection: Res<MyAssets>,
+    asset_server: Res<AssetServer>,
+    audio: Res<Assets<AudioSource>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let paths: Vec<_> = collection
+        .sounds
+        .iter()
+        .map(|handle| {
+            assert!(audio.get(handle).is_some(), "Listed asset is not loaded");
+            asset_server
+                .get_handle_path(handle)
+                .expect("Handle should have a path")
+                .path()
+                .to_str()
+                .expect("Path should be valid UTF-8")
+                .to_owned()
+        })
+        .collect();
+    assert_eq!(paths, vec!["audio/plop.ogg", "audio/yipee.ogg"]);
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The app did not finish in 10 seconds");
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path_list = "audio/sounds.txt")]
+    sounds: Vec<Handle<AudioSource>>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
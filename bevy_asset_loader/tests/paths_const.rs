@@ -0,0 +1,76 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use bevy_asset_loader::prelude::{AssetCollection, LoadingState, LoadingStateAppExt};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn loads_paths_from_const() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect_paths_in_order)
+        .run();
+}
+
+const SOUNDS: &[&str] = &["audio/yipee.ogg", "audio/background.ogg", "audio/plop.ogg"];
+
+fn expect_paths_in_order(
+    collection: Res<MyAssets>,
+    asset_server: Res<AssetServer>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let paths: Vec<_> = collection
+        .sounds
+        .iter()
+        .map(|handle| asset_server.get_handle_path(handle).unwrap())
+        .collect();
+    assert_eq!(
+        paths,
+        SOUNDS.iter().map(|path| (*path).into()).collect::<Vec<_>>()
+    );
+    assert_eq!(collection.mapped_sounds.len(), SOUNDS.len());
+    for path in SOUNDS {
+        assert_eq!(
+            asset_server.get_handle_path(&collection.mapped_sounds[*path]),
+            Some((*path).into())
+        );
+    }
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The app did not finish in 10 seconds");
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(paths_const = "SOUNDS", collection(typed))]
+    sounds: Vec<Handle<AudioSource>>,
+    #[asset(paths_const = "SOUNDS", collection(mapped))]
+    mapped_sounds: HashMap<String, HandleUntyped>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,82 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::{AssetPlugin, LoadState};
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn pins_all_loaded_handles() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+            PathListPlugin::default(),
+        ))
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, PinnedAssets>(MyStates::Load)
+        .add_collection_to_loading_state::<_, UnpinnedAssets>(MyStates::Load)
+        .add_systems(Update, timeout)
+        .add_systems(
+            Update,
+            expect_pinned_list_to_stay_loaded.run_if(in_state(MyStates::Next)),
+        )
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The app did not finish in 10 seconds");
+    }
+}
+
+fn expect_pinned_list_to_stay_loaded(
+    pinned: Option<Res<PinnedHandles<PinnedAssets>>>,
+    unpinned: Option<Res<PinnedHandles<UnpinnedAssets>>>,
+    asset_server: Res<AssetServer>,
+    mut frames: Local<u32>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert!(unpinned.is_none(), "Only pin_strong collections are pinned");
+    let pinned = pinned.expect("The pin_strong collection should be pinned");
+    let list = asset_server.get_handle_untyped("audio/sounds.txt");
+    assert!(pinned.handles().contains(&list));
+    assert_eq!(
+        asset_server.get_load_state(list.id()),
+        LoadState::Loaded,
+        "The pinned list file should stay loaded"
+    );
+    *frames += 1;
+    if *frames == 10 {
+        info!("Everything fine, quitting the app");
+        exit.send(AppExit);
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+#[asset_collection(pin_strong)]
+struct PinnedAssets {
+    #[asset(path_list = "audio/sounds.txt")]
+    sounds: Vec<Handle<AudioSource>>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct UnpinnedAssets {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
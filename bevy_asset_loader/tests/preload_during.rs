@@ -0,0 +1,71 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::LoadState;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::{AssetCollection, LoadingState, LoadingStateAppExt};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn preloads_collection_in_earlier_state() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .preload_during(MyStates::Splash),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout)
+        .add_systems(
+            Update,
+            leave_splash_after_preloading.run_if(in_state(MyStates::Splash)),
+        )
+        .add_systems(OnEnter(MyStates::Next), expect_collection)
+        .run();
+}
+
+fn leave_splash_after_preloading(
+    asset_server: Res<AssetServer>,
+    mut next_state: ResMut<NextState<MyStates>>,
+) {
+    let handle: Handle<AudioSource> = asset_server.get_handle("audio/background.ogg");
+    if asset_server.get_load_state(&handle) == LoadState::Loaded {
+        next_state.set(MyStates::Load);
+    }
+}
+
+fn expect_collection(_collection: Res<MyAssets>, mut exit: EventWriter<AppExit>) {
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The app did not finish in 10 seconds");
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Splash,
+    Load,
+    Next,
+}
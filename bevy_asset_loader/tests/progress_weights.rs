@@ -0,0 +1,78 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::{
+    AssetCollection, LoadingProgress, LoadingState, LoadingStateAppExt,
+};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn progress_is_weighted_per_field() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(
+            Update,
+            (timeout, observe_progress).run_if(in_state(MyStates::Load)),
+        )
+        .add_systems(OnEnter(MyStates::Next), expect_weighted_progress)
+        .run();
+}
+
+fn observe_progress(progress: Res<LoadingProgress<MyStates>>) {
+    assert!(progress.loaded_weight <= progress.total_weight);
+    if progress.total > 0 && progress.issued == progress.total {
+        assert_eq!(progress.total_weight, 10.);
+    }
+}
+
+fn expect_weighted_progress(
+    progress: Res<LoadingProgress<MyStates>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert_eq!(progress.total, 3);
+    assert_eq!(progress.loaded, 3);
+    assert_eq!(
+        progress.total_weight, 10.,
+        "The weighted field should count 8 times, the others once"
+    );
+    assert_eq!(progress.loaded_weight, 10.);
+    assert_eq!(progress.fraction(), 1.);
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The app did not finish in 10 seconds");
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg", weight = 8.0)]
+    background: Handle<AudioSource>,
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+    #[asset(path = "audio/yipee.ogg")]
+    yipee: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,149 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn rebuilds_collection_on_modification() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .init_resource::<Creations>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .rebuild_on_modify::<MyAssets>(),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout)
+        .add_systems(
+            Update,
+            (count_creations, modify_once, expect_single_rebuild)
+                .chain()
+                .run_if(in_state(MyStates::Next)),
+        )
+        .run();
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn stops_rebuilding_in_a_loop() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .init_resource::<Creations>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .rebuild_on_modify::<MyAssets>(),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout)
+        .add_systems(
+            Update,
+            (count_creations, modify_every_frame, expect_rebuilds_to_stop)
+                .chain()
+                .run_if(in_state(MyStates::Next)),
+        )
+        .run();
+}
+
+#[derive(Resource, Default)]
+struct Creations(usize);
+
+fn count_creations(collection: Res<MyAssets>, mut creations: ResMut<Creations>) {
+    if collection.is_changed() {
+        creations.0 += 1;
+    }
+}
+
+fn modify_once(
+    collection: Res<MyAssets>,
+    mut audio: ResMut<Assets<AudioSource>>,
+    mut frames: Local<usize>,
+) {
+    *frames += 1;
+    if *frames == 5 {
+        audio.get_mut(&collection.plop);
+    }
+}
+
+fn modify_every_frame(collection: Res<MyAssets>, mut audio: ResMut<Assets<AudioSource>>) {
+    audio.get_mut(&collection.plop);
+}
+
+fn expect_single_rebuild(
+    creations: Res<Creations>,
+    mut frames: Local<usize>,
+    mut exit: EventWriter<AppExit>,
+) {
+    *frames += 1;
+    if *frames < 30 {
+        return;
+    }
+    assert_eq!(
+        creations.0, 2,
+        "The collection should be created once and rebuilt once after the modification"
+    );
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+fn expect_rebuilds_to_stop(
+    creations: Res<Creations>,
+    mut frames: Local<usize>,
+    mut exit: EventWriter<AppExit>,
+) {
+    *frames += 1;
+    if *frames < 100 {
+        return;
+    }
+    assert!(
+        creations.0 > 1,
+        "The collection should be rebuilt after modifications"
+    );
+    assert!(
+        creations.0 < 10,
+        "The collection should stop being rebuilt in a loop"
+    );
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The app did not finish in 10 seconds");
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
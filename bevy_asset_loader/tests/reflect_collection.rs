@@ -0,0 +1,92 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::reflect::ReflectRef;
+use bevy_asset_loader::prelude::*;
+use std::any::TypeId;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn registers_reflected_collection() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_collection_to_loading_state::<_, PlainAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect_reflected_resource)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The app did not finish in 10 seconds");
+    }
+}
+
+fn expect_reflected_resource(world: &mut World) {
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    assert!(
+        registry.get(TypeId::of::<PlainAssets>()).is_none(),
+        "Collections without the reflect attribute should not be registered"
+    );
+    let registration = registry
+        .get(TypeId::of::<MyAssets>())
+        .expect("The collection should be registered");
+    let reflect_resource = registration
+        .data::<ReflectResource>()
+        .expect("The collection should reflect as a resource");
+    let collection = reflect_resource
+        .reflect(world)
+        .expect("The collection should be inserted as resource");
+    let ReflectRef::Struct(collection) = collection.reflect_ref() else {
+        panic!("The collection should reflect as a struct");
+    };
+    let background = collection
+        .field("background")
+        .and_then(|field| field.downcast_ref::<Handle<AudioSource>>())
+        .expect("Reflectable handles should be visible to reflection");
+    assert_eq!(background, &world.resource::<MyAssets>().background);
+    assert!(
+        collection.field("untyped").is_none(),
+        "Ignored fields should not be visible to reflection"
+    );
+    info!("Everything fine, quitting the app");
+    world.send_event(AppExit);
+}
+
+#[derive(AssetCollection, Resource, Reflect)]
+#[asset_collection(reflect)]
+#[reflect(from_reflect = false)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+    #[asset(paths("audio/plop.ogg", "audio/yipee.ogg"), collection)]
+    #[reflect(ignore)]
+    untyped: Vec<HandleUntyped>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct PlainAssets {
+    #[asset(path = "audio/yipee.ogg")]
+    yipee: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,86 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    feature = "standard_dynamic_assets",
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn registers_deserialized_assets() {
+    let config: HashMap<String, StandardDynamicAsset> = ron::from_str(
+        r#"{
+            "plop": File(path: "audio/plop.ogg"),
+            "sounds": Files(paths: ["audio/plop.ogg", "audio/background.ogg"]),
+        }"#,
+    )
+    .expect("Failed to deserialize the config");
+    let mut dynamic_assets = DynamicAssets::default();
+    dynamic_assets.register_standard_assets(config);
+    dynamic_assets.register_all([(
+        "yipee",
+        Box::new(StandardDynamicAsset::File {
+            path: "audio/yipee.ogg".to_owned(),
+        }) as Box<dyn DynamicAsset>,
+    )]);
+
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .insert_resource(dynamic_assets)
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset collection was not loaded in 10 seconds");
+    }
+}
+
+fn expect(
+    collection: Res<MyAssets>,
+    asset_server: Res<AssetServer>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert_eq!(
+        asset_server.get_handle_path(&collection.plop),
+        Some("audio/plop.ogg".into())
+    );
+    assert_eq!(
+        asset_server.get_handle_path(&collection.yipee),
+        Some("audio/yipee.ogg".into())
+    );
+    assert_eq!(collection.sounds.len(), 2);
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(key = "plop")]
+    plop: Handle<AudioSource>,
+    #[asset(key = "yipee")]
+    yipee: Handle<AudioSource>,
+    #[asset(key = "sounds", collection(typed))]
+    sounds: Vec<Handle<AudioSource>>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
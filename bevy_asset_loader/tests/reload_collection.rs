@@ -0,0 +1,148 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn reloads_collection_with_changed_keys() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+    ))
+    .init_resource::<DynamicAssets>();
+    register_sound(&mut app.world, "audio/plop.ogg");
+    app.init_collection::<MyAssets>()
+        .add_systems(Update, (timeout, reload_once, expect_new_sound).chain())
+        .run();
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn reloads_listed_paths_and_fallbacks() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+            PathListPlugin::default(),
+        ))
+        .init_resource::<DynamicAssets>()
+        .add_systems(
+            Update,
+            (timeout, reload_listed_once, expect_listed_sounds).chain(),
+        )
+        .run();
+}
+
+#[derive(Debug)]
+struct AudioFile(String);
+
+impl DynamicAsset for AudioFile {
+    fn load(&self, asset_server: &AssetServer) -> Vec<HandleUntyped> {
+        vec![asset_server.load_untyped(&self.0)]
+    }
+
+    fn build(&self, world: &mut World) -> Result<DynamicAssetType, anyhow::Error> {
+        let asset_server = world.resource::<AssetServer>();
+        Ok(DynamicAssetType::Single(
+            asset_server.get_handle_untyped(&self.0),
+        ))
+    }
+}
+
+fn register_sound(world: &mut World, path: &str) {
+    world
+        .resource_mut::<DynamicAssets>()
+        .register_asset("sound", Box::new(AudioFile(path.to_owned())));
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The collection was not reloaded in 10 seconds");
+    }
+}
+
+fn reload_once(mut commands: Commands, mut reloaded: Local<bool>) {
+    if *reloaded {
+        return;
+    }
+    *reloaded = true;
+    commands.add(|world: &mut World| register_sound(world, "audio/background.ogg"));
+    commands.add(ReloadCollection::<MyAssets>::new());
+}
+
+fn reload_listed_once(mut commands: Commands, mut reloaded: Local<bool>) {
+    if *reloaded {
+        return;
+    }
+    *reloaded = true;
+    commands.add(ReloadCollection::<ListedAssets>::new());
+}
+
+fn expect_listed_sounds(
+    collection: Option<Res<ListedAssets>>,
+    asset_server: Res<AssetServer>,
+    audio: Res<Assets<AudioSource>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let Some(collection) = collection else {
+        return;
+    };
+    assert_eq!(collection.sounds.len(), 2);
+    for sound in &collection.sounds {
+        assert!(audio.contains(sound), "Listed asset is not loaded");
+    }
+    assert_eq!(
+        collection.missing,
+        asset_server.get_handle("audio/background.ogg"),
+        "The failed field should hold the handle returned by its fallback"
+    );
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+fn background(world: &mut World) -> Handle<AudioSource> {
+    world.resource::<AssetServer>().load("audio/background.ogg")
+}
+
+fn expect_new_sound(
+    collection: Option<Res<MyAssets>>,
+    asset_server: Res<AssetServer>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let Some(collection) = collection else {
+        return;
+    };
+    if asset_server.get_handle_path(&collection.sound) == Some("audio/background.ogg".into()) {
+        info!("Everything fine, quitting the app");
+        exit.send(AppExit);
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(key = "sound")]
+    sound: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct ListedAssets {
+    #[asset(path_list = "audio/sounds.txt")]
+    sounds: Vec<Handle<AudioSource>>,
+    #[asset(path = "non-existing-file.ogg", on_fail = "background")]
+    missing: Handle<AudioSource>,
+}
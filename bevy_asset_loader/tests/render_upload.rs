@@ -0,0 +1,90 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(feature = "3d")]
+#[test]
+fn waits_for_render_world_upload() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .init_resource::<PendingFrames>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .upload_in_render_world::<MyAssets, _>(upload),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(
+            Update,
+            (timeout, complete_upload_manually).run_if(in_state(MyStates::Load)),
+        )
+        .add_systems(OnEnter(MyStates::Next), expect_upload_completed)
+        .run();
+}
+
+#[derive(Resource, Default)]
+struct PendingFrames(usize);
+
+fn upload() {
+    panic!("Without a render app, the upload systems should not run");
+}
+
+fn complete_upload_manually(
+    uploads: Option<Res<RenderUploads>>,
+    collection: Option<Res<MyAssets>>,
+    mut pending_frames: ResMut<PendingFrames>,
+) {
+    let uploads = uploads.expect("The RenderUploads resource should be inserted");
+    if !uploads.is_pending::<MyAssets>() {
+        return;
+    }
+    assert!(
+        collection.is_some(),
+        "The upload should be requested after the collection was inserted"
+    );
+    pending_frames.0 += 1;
+    if pending_frames.0 == 3 {
+        uploads.complete::<MyAssets>();
+    }
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The app did not finish in 10 seconds");
+    }
+}
+
+fn expect_upload_completed(
+    uploads: Res<RenderUploads>,
+    pending_frames: Res<PendingFrames>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert_eq!(
+        pending_frames.0, 3,
+        "The loading state should wait until the upload completed"
+    );
+    assert!(!uploads.is_pending::<MyAssets>());
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
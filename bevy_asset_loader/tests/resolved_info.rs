@@ -0,0 +1,48 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    feature = "standard_dynamic_assets",
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn retains_registered_descriptions() {
+    let mut dynamic_assets = DynamicAssets::default();
+    dynamic_assets.register_asset(
+        "sounds",
+        Box::new(StandardDynamicAsset::Files {
+            paths: vec!["audio/plop.ogg".to_owned(), "audio/yipee.ogg".to_owned()],
+        }),
+    );
+    dynamic_assets
+        .register_from_arg_list(&[("--music", "music")], ["--music=audio/background.ogg"]);
+
+    assert_eq!(
+        dynamic_assets.resolved_info("sounds"),
+        Some(&StandardDynamicAsset::Files {
+            paths: vec!["audio/plop.ogg".to_owned(), "audio/yipee.ogg".to_owned()],
+        })
+    );
+    assert_eq!(
+        dynamic_assets.resolved_info("music"),
+        Some(&StandardDynamicAsset::File {
+            path: "audio/background.ogg".to_owned(),
+        })
+    );
+    assert_eq!(dynamic_assets.resolved_info("unknown"), None);
+
+    dynamic_assets.register_asset(
+        "sounds",
+        Box::new(StandardDynamicAsset::File {
+            path: "audio/plop.ogg".to_owned(),
+        }),
+    );
+    assert_eq!(
+        dynamic_assets.resolved_info("sounds"),
+        Some(&StandardDynamicAsset::File {
+            path: "audio/plop.ogg".to_owned(),
+        }),
+        "Registering a key again should replace its description"
+    );
+}
@@ -0,0 +1,70 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+use bevy_asset_loader::test_utils::run_until_state;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking"),
+    feature = "test-utils"
+))]
+#[test]
+fn runs_until_loading_finished() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+    ))
+    .add_state::<MyStates>()
+    .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+    .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load);
+
+    let iterations =
+        run_until_state(&mut app, MyStates::Next, 10_000).expect("Loading should finish");
+    assert!(iterations > 0);
+    assert!(app.world.contains_resource::<MyAssets>());
+    assert_eq!(run_until_state(&mut app, MyStates::Next, 0), Ok(0));
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking"),
+    feature = "test-utils"
+))]
+#[test]
+fn errors_after_max_iterations() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+    ))
+    .add_state::<MyStates>()
+    .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+    .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load);
+
+    let error = run_until_state(&mut app, MyStates::Unreachable, 5)
+        .expect_err("The app cannot reach a state without transition");
+    assert_eq!(error.target, MyStates::Unreachable);
+    assert_eq!(error.iterations, 5);
+    assert!(error.current.is_some());
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+    Unreachable,
+}
@@ -0,0 +1,122 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn restores_registrations_after_scope() {
+    let mut dynamic_assets = DynamicAssets::default();
+    register_file(&mut dynamic_assets, "music", "audio/background.ogg");
+    dynamic_assets.register_alias("audio/plop.ogg", "audio/yipee.ogg");
+
+    let result = dynamic_assets.scoped(|dynamic_assets| {
+        register_file(dynamic_assets, "music", "audio/yipee.ogg");
+        register_file(dynamic_assets, "preview", "audio/plop.ogg");
+        dynamic_assets.register_alias("audio/plop.ogg", "audio/background.ogg");
+        dynamic_assets.scoped(|dynamic_assets| {
+            register_file(dynamic_assets, "music", "audio/plop.ogg");
+            assert_eq!(registered(dynamic_assets, "music"), file("audio/plop.ogg"));
+        });
+        assert_eq!(registered(dynamic_assets, "music"), file("audio/yipee.ogg"));
+        assert_eq!(
+            dynamic_assets.alias("audio/plop.ogg"),
+            Some("audio/background.ogg")
+        );
+        42
+    });
+
+    assert_eq!(result, 42);
+    assert_eq!(
+        registered(&dynamic_assets, "music"),
+        file("audio/background.ogg")
+    );
+    assert!(dynamic_assets.get_asset("preview").is_none());
+    assert_eq!(
+        dynamic_assets.alias("audio/plop.ogg"),
+        Some("audio/yipee.ogg")
+    );
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn creates_collection_inside_world_scope() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+    ));
+    let mut dynamic_assets = DynamicAssets::default();
+    register_file(&mut dynamic_assets, "music", "audio/background.ogg");
+    app.insert_resource(dynamic_assets);
+
+    DynamicAssets::scoped_in_world(&mut app.world, |world| {
+        register_file(
+            &mut world.resource_mut::<DynamicAssets>(),
+            "music",
+            "audio/yipee.ogg",
+        );
+        world.init_collection::<PreviewAssets>();
+    });
+
+    let asset_server = app.world.resource::<AssetServer>();
+    let preview = app.world.resource::<PreviewAssets>();
+    assert_eq!(
+        asset_server
+            .get_handle_path(&preview.music)
+            .expect("Handle without a path")
+            .path()
+            .to_string_lossy()
+            .replace('\\', "/"),
+        "audio/yipee.ogg"
+    );
+    assert_eq!(
+        registered(app.world.resource::<DynamicAssets>(), "music"),
+        file("audio/background.ogg")
+    );
+}
+
+fn register_file(dynamic_assets: &mut DynamicAssets, key: &str, path: &str) {
+    dynamic_assets.register_asset(
+        key,
+        Box::new(FileAsset {
+            path: path.to_owned(),
+        }),
+    );
+}
+
+/// Debug output of the asset registered for the key, to compare it with a [`FileAsset`]
+fn registered(dynamic_assets: &DynamicAssets, key: &str) -> String {
+    format!(
+        "{:?}",
+        dynamic_assets
+            .get_asset(key)
+            .expect("The key is not registered")
+    )
+}
+
+fn file(path: &str) -> String {
+    format!(
+        "{:?}",
+        FileAsset {
+            path: path.to_owned()
+        }
+    )
+}
+
+#[derive(AssetCollection, Resource)]
+struct PreviewAssets {
+    #[asset(key = "music")]
+    music: Handle<AudioSource>,
+}
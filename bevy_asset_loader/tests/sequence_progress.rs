@@ -0,0 +1,103 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn tracks_progress_over_loading_states() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .init_resource::<LastFraction>()
+        .add_loading_state(LoadingState::new(MyStates::Boot).continue_to_state(MyStates::Menu))
+        .add_collection_to_loading_state::<_, BootAssets>(MyStates::Boot)
+        .add_loading_state(LoadingState::new(MyStates::Menu).continue_to_state(MyStates::Game))
+        .add_collection_to_loading_state::<_, MenuAssets>(MyStates::Menu)
+        .add_collection_to_loading_state::<_, MoreMenuAssets>(MyStates::Menu)
+        .add_loading_sequence(&[MyStates::Boot, MyStates::Menu])
+        .add_systems(Update, (timeout, expect_increasing_progress))
+        .add_systems(OnEnter(MyStates::Menu), expect_boot_finished)
+        .add_systems(OnEnter(MyStates::Game), expect_sequence_finished)
+        .run();
+}
+
+#[derive(Resource, Default)]
+struct LastFraction(f32);
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The app did not finish in 10 seconds");
+    }
+}
+
+fn expect_increasing_progress(
+    progress: Res<SequenceProgress<MyStates>>,
+    mut last: ResMut<LastFraction>,
+) {
+    assert_eq!(progress.states, 2);
+    if progress.total_weight == 0. {
+        // The progress is first updated in PostUpdate
+        return;
+    }
+    assert_eq!(
+        progress.total_weight, 3.,
+        "Loading states should be weighted by their number of collections"
+    );
+    assert!(
+        progress.fraction() >= last.0,
+        "The progress of the sequence should not go backwards"
+    );
+    last.0 = progress.fraction();
+}
+
+fn expect_boot_finished(progress: Res<SequenceProgress<MyStates>>) {
+    assert_eq!(progress.finished, 1);
+    assert_eq!(progress.loaded_weight, 1.);
+}
+
+fn expect_sequence_finished(
+    progress: Res<SequenceProgress<MyStates>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert_eq!(progress.finished, 2);
+    assert_eq!(progress.fraction(), 1.);
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct BootAssets {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct MenuAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct MoreMenuAssets {
+    #[asset(path = "audio/yipee.ogg")]
+    yipee: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Boot,
+    Menu,
+    Game,
+}
@@ -0,0 +1,57 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::prelude::*;
+use bevy::render::render_resource::ShaderLoader;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(feature = "3d", not(feature = "progress_tracking")))]
+#[test]
+fn waits_for_shader_imports() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((MinimalPlugins, AssetPlugin::default()))
+        .add_asset::<Shader>()
+        .init_asset_loader::<ShaderLoader>()
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect_imports)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset collection was not loaded in 10 seconds");
+    }
+}
+
+fn expect_imports(
+    collection: Res<MyAssets>,
+    asset_server: Res<AssetServer>,
+    shaders: Res<Assets<Shader>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert!(shaders.contains(&collection.effect));
+    let common: Handle<Shader> = asset_server.get_handle("shaders/common.wgsl");
+    assert!(
+        shaders.contains(&common),
+        "The imported shader should be loaded"
+    );
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "shaders/effect.wgsl", shader)]
+    effect: Handle<Shader>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
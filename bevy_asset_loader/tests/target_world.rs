@@ -0,0 +1,70 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::{AssetCollection, LoadingState, LoadingStateAppExt};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn inserts_collection_into_target_world() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .init_resource::<SceneWorld>()
+        .add_state::<MyStates>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .target_world(|world| &mut world.resource_mut::<SceneWorld>().into_inner().0),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect_collection_in_scene_world)
+        .run();
+}
+
+fn expect_collection_in_scene_world(world: &mut World) {
+    assert!(!world.contains_resource::<MyAssets>());
+    let scene_world = &world.resource::<SceneWorld>().0;
+    let collection = scene_world
+        .get_resource::<MyAssets>()
+        .expect("The collection should be inserted into the scene world");
+    assert_eq!(
+        world
+            .resource::<AssetServer>()
+            .get_handle_path(&collection.background),
+        Some("audio/background.ogg".into())
+    );
+    info!("Everything fine, quitting the app");
+    world.send_event(AppExit);
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The app did not finish in 10 seconds");
+    }
+}
+
+#[derive(Resource, Default)]
+struct SceneWorld(World);
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
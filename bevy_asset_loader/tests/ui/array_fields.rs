@@ -0,0 +1,16 @@
+use bevy_asset_loader::prelude::*;
+use bevy::prelude::*;
+
+fn main() {}
+
+#[derive(AssetCollection, Resource)]
+struct ArrayLengthMismatch {
+    #[asset(paths("red.png", "blue.png", "green.png"))]
+    colors: [Handle<Image>; 4],
+}
+
+#[derive(AssetCollection, Resource)]
+struct ArrayAndCollection {
+    #[asset(paths("red.png", "blue.png"), collection(typed))]
+    colors: [Handle<Image>; 2],
+}
@@ -0,0 +1,11 @@
+use bevy_asset_loader::prelude::*;
+use bevy::prelude::*;
+
+fn main() {}
+
+#[derive(AssetCollection, Resource)]
+#[asset_collection(key_case = "camel")]
+struct UnknownKeyCase {
+    #[asset(key)]
+    player_skin: Handle<Image>,
+}
@@ -1,5 +1,6 @@
 use bevy_asset_loader::prelude::*;
 use bevy::prelude::*;
+use bevy::utils::HashMap;
 
 fn main() {}
 
@@ -9,4 +10,10 @@ struct Test {
     atlas: Handle<TextureAtlas>,
     #[asset(standard_material)]
     material: Handle<StandardMaterial>,
+    #[asset(path = "image.png", color_material)]
+    color_material: Handle<ColorMaterial>,
+    #[asset(path = "model.gltf", animations)]
+    animations: HashMap<String, HandleUntyped>,
+    #[asset(path = "model.gltf", gltf_materials)]
+    materials: Vec<Handle<StandardMaterial>>,
 }
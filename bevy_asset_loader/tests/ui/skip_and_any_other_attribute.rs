@@ -0,0 +1,24 @@
+use bevy_asset_loader::prelude::*;
+use bevy::prelude::*;
+
+fn main() {}
+
+#[derive(AssetCollection, Resource)]
+struct PathAndSkip {
+    #[asset(path = "test.png", skip)]
+    test: Handle<Image>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct KeyAndSkip {
+    #[asset(skip)]
+    #[asset(key = "test")]
+    test: Handle<Image>,
+}
+
+// A skipped field is created from its Default implementation
+#[derive(AssetCollection, Resource)]
+struct Skip {
+    #[asset(skip)]
+    test: Handle<Image>,
+}
@@ -0,0 +1,112 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::render::render_resource::Shader;
+use bevy::utils::BoxedFuture;
+use bevy_asset_loader::asset_collection::{validate_collection, validate_collection_within};
+use bevy_asset_loader::prelude::AssetCollection;
+use std::time::Duration;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn valid_collection() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+    ));
+
+    assert_eq!(validate_collection::<ValidAssets>(&mut app), Ok(()));
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn collection_with_missing_files() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+    ));
+
+    assert_eq!(
+        validate_collection::<InvalidAssets>(&mut app),
+        Err(vec![
+            "audio/missing.ogg".to_owned(),
+            "audio/renamed.ogg".to_owned()
+        ])
+    );
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn collection_that_does_not_finish_loading() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+    ))
+    .add_asset_loader(NeverLoader);
+
+    assert_eq!(
+        validate_collection_within::<UnresolvedAssets>(&mut app, Duration::from_secs(1)),
+        Err(vec!["shaders/common.wgsl (timed out)".to_owned()])
+    );
+}
+
+/// Loader that never finishes loading shader files
+struct NeverLoader;
+
+impl AssetLoader for NeverLoader {
+    fn load<'a>(
+        &'a self,
+        _bytes: &'a [u8],
+        _load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+        Box::pin(std::future::pending())
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["wgsl"]
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+struct ValidAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+    #[asset(paths("audio/plop.ogg", "audio/yipee.ogg"), collection(typed))]
+    effects: Vec<Handle<AudioSource>>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct InvalidAssets {
+    #[asset(path = "audio/missing.ogg")]
+    missing: Handle<AudioSource>,
+    #[asset(paths("audio/plop.ogg", "audio/renamed.ogg"), collection(typed))]
+    effects: Vec<Handle<AudioSource>>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct UnresolvedAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+    #[asset(path = "shaders/common.wgsl")]
+    shader: Handle<Shader>,
+}
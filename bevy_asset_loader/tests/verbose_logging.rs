@@ -0,0 +1,82 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::{
+    AssetCollection, AssetLoadedWithin, LoadingDumpWorld, LoadingState, LoadingStateAppExt,
+};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn loads_collection_with_verbose_logging() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_state::<MyStates>()
+        .init_resource::<LoadedAssets>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .verbose_logging(true),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(PostUpdate, record_loaded_assets)
+        .add_systems(OnEnter(MyStates::Next), expect_no_pending_assets)
+        .run();
+}
+
+#[derive(Resource, Default)]
+struct LoadedAssets(Vec<AssetLoadedWithin>);
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset collection was not loaded in 10 seconds");
+    }
+}
+
+fn record_loaded_assets(
+    mut events: EventReader<AssetLoadedWithin>,
+    mut loaded: ResMut<LoadedAssets>,
+) {
+    loaded.0.extend(events.iter().cloned());
+}
+
+fn expect_no_pending_assets(world: &mut World) {
+    assert!(world.contains_resource::<MyAssets>());
+    // The event is sent when an asset stops being pending, so each asset shows up exactly once
+    let mut paths: Vec<_> = world
+        .resource::<LoadedAssets>()
+        .0
+        .iter()
+        .map(|event| event.path.clone())
+        .collect();
+    paths.sort_unstable();
+    assert_eq!(paths, vec!["audio/background.ogg", "audio/plop.ogg"]);
+    assert!(world.debug_dump_loading().is_empty());
+    info!("Everything fine, quitting the app");
+    world.send_event(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,83 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn waits_for_condition_after_loading() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .init_resource::<StartPressed>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .wait_for_input_with(|world| world.resource::<StartPressed>().0),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(
+            Update,
+            (timeout, press_start_after_waiting).run_if(in_state(MyStates::Load)),
+        )
+        .add_systems(OnEnter(MyStates::Next), expect_pressed)
+        .run();
+}
+
+#[derive(Resource, Default)]
+struct StartPressed(bool);
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The loading state did not continue in 10 seconds");
+    }
+}
+
+fn press_start_after_waiting(
+    collection: Option<Res<MyAssets>>,
+    loading_progress: Res<LoadingProgress<MyStates>>,
+    mut pressed: ResMut<StartPressed>,
+    mut waited_frames: Local<u32>,
+) {
+    if collection.is_none() {
+        return;
+    }
+    assert_eq!(loading_progress.fraction(), 1.);
+    *waited_frames += 1;
+    if *waited_frames == 5 {
+        pressed.0 = true;
+    }
+}
+
+fn expect_pressed(pressed: Res<StartPressed>, mut exit: EventWriter<AppExit>) {
+    assert!(
+        pressed.0,
+        "The loading state should only continue after start was pressed"
+    );
+    info!("Everything fine, quitting the app");
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -1,6 +1,9 @@
-use crate::{ParseFieldError, TextureAtlasAttribute, TEXTURE_ATLAS_ATTRIBUTE};
+use crate::{
+    ParseFieldError, RegionAttribute, TextureAtlasAttribute, CFG_DESKTOP_ATTRIBUTE,
+    CFG_MOBILE_ATTRIBUTE, REGION_ATTRIBUTE, TEXTURE_ATLAS_ATTRIBUTE,
+};
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
+use quote::{quote, ToTokens};
 
 #[derive(PartialEq, Debug)]
 pub(crate) struct TextureAtlasAssetField {
@@ -22,16 +25,223 @@ pub(crate) struct BasicAssetField {
     pub asset_path: String,
 }
 
+/// Field loading a folder; mapped folders can expect assets by their file name
+#[derive(Debug)]
+pub(crate) struct FolderAssetField {
+    pub field_ident: Ident,
+    pub asset_path: String,
+    pub expected_keys: Vec<String>,
+    /// Path to a function parsing the file stems of a mapped folder into the keys of the map
+    pub map_key: Option<TokenStream>,
+}
+
+impl PartialEq for FolderAssetField {
+    fn eq(&self, other: &Self) -> bool {
+        self.field_ident == other.field_ident
+            && self.asset_path == other.asset_path
+            && self.expected_keys == other.expected_keys
+            && self.map_key.as_ref().map(ToString::to_string)
+                == other.map_key.as_ref().map(ToString::to_string)
+    }
+}
+
+/// Field whose asset is created from the image handle of another field
+#[derive(PartialEq, Debug)]
+pub(crate) struct DerivedAssetField {
+    pub field_ident: Ident,
+    pub source: Ident,
+    pub asset: DerivedAsset,
+}
+
+/// The asset created by a derived field
+#[derive(PartialEq, Debug)]
+pub(crate) enum DerivedAsset {
+    StandardMaterial,
+    ColorMaterial,
+    TextureAtlas(TextureAtlasGrid),
+}
+
+/// Grid of a texture atlas derived from another field
+#[derive(PartialEq, Debug)]
+pub(crate) struct TextureAtlasGrid {
+    pub tile_size_x: f32,
+    pub tile_size_y: f32,
+    pub columns: usize,
+    pub rows: usize,
+    pub padding_x: f32,
+    pub padding_y: f32,
+    pub offset_x: f32,
+    pub offset_y: f32,
+}
+
+/// Field loading an audio file together with a manifest of its named clips
+#[derive(PartialEq, Debug)]
+pub(crate) struct AudioBankAssetField {
+    pub field_ident: Ident,
+    pub asset_path: String,
+    pub manifest_path: String,
+}
+
+/// Field holding a copy of a rectangular region of an image
+#[derive(PartialEq, Debug)]
+pub(crate) struct RegionAssetField {
+    pub field_ident: Ident,
+    pub asset_path: String,
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Platform a field is restricted to
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub(crate) enum Platform {
+    /// Every target that is not mobile, including the web
+    Desktop,
+    /// Android and iOS
+    Mobile,
+}
+
+impl Platform {
+    /// Configuration predicate matching the targets of the platform
+    fn predicate(&self) -> TokenStream {
+        match self {
+            Platform::Desktop => quote!(not(any(target_os = "android", target_os = "ios"))),
+            Platform::Mobile => quote!(any(target_os = "android", target_os = "ios")),
+        }
+    }
+
+    fn attribute(&self) -> &'static str {
+        match self {
+            Platform::Desktop => CFG_DESKTOP_ATTRIBUTE,
+            Platform::Mobile => CFG_MOBILE_ATTRIBUTE,
+        }
+    }
+}
+
+/// Field loading all paths listed in a text file
+#[derive(PartialEq, Debug)]
+pub(crate) struct PathListAssetField {
+    pub field_ident: Ident,
+    pub list_path: String,
+}
+
 #[derive(PartialEq, Debug)]
 pub(crate) struct MultipleFilesField {
     pub field_ident: Ident,
     pub asset_paths: Vec<String>,
 }
 
+/// Length and element type of a fixed size array field
+#[derive(PartialEq, Debug)]
+pub(crate) struct ArrayType {
+    /// `None` if the length is not an integer literal, e.g. a constant
+    pub length: Option<usize>,
+    pub typed: Typed,
+}
+
+/// Field cloning the handle of a field of another resource instead of loading anything
+#[derive(Debug)]
+pub(crate) struct ResourceAssetField {
+    pub field_ident: Ident,
+    pub resource: TokenStream,
+    pub source: Ident,
+}
+
+impl PartialEq for ResourceAssetField {
+    fn eq(&self, other: &Self) -> bool {
+        self.field_ident == other.field_ident
+            && self.resource.to_string() == other.resource.to_string()
+            && self.source == other.source
+    }
+}
+
+/// Handle of an asset that is addressed by UUID instead of a path
+#[derive(PartialEq, Debug)]
+pub(crate) struct UuidAssetField {
+    pub field_ident: Ident,
+    pub uuid: u128,
+}
+
+/// Field loading all paths of a `&[&str]` constant
+#[derive(Debug)]
+pub(crate) struct ConstFilesField {
+    pub field_ident: Ident,
+    pub const_path: TokenStream,
+}
+
+impl PartialEq for ConstFilesField {
+    fn eq(&self, other: &Self) -> bool {
+        self.field_ident == other.field_ident
+            && self.const_path.to_string() == other.const_path.to_string()
+    }
+}
+
+/// Path to the function creating the handle of a field whose asset failed to load
+#[derive(Debug)]
+pub(crate) struct Fallback(pub TokenStream);
+
+impl PartialEq for Fallback {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_string() == other.0.to_string()
+    }
+}
+
+/// Asset type of a field of type `LoadingField<T>`
+#[derive(Debug)]
+pub(crate) struct LoadingFieldType(pub TokenStream);
+
+impl PartialEq for LoadingFieldType {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_string() == other.0.to_string()
+    }
+}
+
 #[derive(PartialEq, Debug)]
 pub(crate) struct DynamicAssetField {
     pub field_ident: Ident,
-    pub key: String,
+    pub key: DynamicAssetKey,
+}
+
+/// Key of a dynamic asset field
+#[derive(Clone, Debug)]
+pub(crate) enum DynamicAssetKey {
+    /// Key given as string with the `key` attribute
+    Str(String),
+    /// Path to a value implementing `AssetKey` given with the `key_enum` attribute
+    Enum(TokenStream),
+}
+
+impl PartialEq for DynamicAssetKey {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (DynamicAssetKey::Str(key), DynamicAssetKey::Str(other_key)) => key == other_key,
+            (DynamicAssetKey::Enum(key), DynamicAssetKey::Enum(other_key)) => {
+                key.to_string() == other_key.to_string()
+            }
+            _ => false,
+        }
+    }
+}
+
+impl ToTokens for DynamicAssetKey {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            DynamicAssetKey::Str(key) => key.to_tokens(tokens),
+            DynamicAssetKey::Enum(key) => {
+                tokens.extend(quote!(::bevy_asset_loader::dynamic_asset::AssetKey::as_str(&#key)))
+            }
+        }
+    }
+}
+
+impl DynamicAssetKey {
+    fn describe(&self) -> String {
+        match self {
+            DynamicAssetKey::Str(key) => format!("key = {key:?}"),
+            DynamicAssetKey::Enum(key) => format!("key_enum({})", key.to_string().replace(' ', "")),
+        }
+    }
 }
 
 /// Enum describing an asset field at compile-time
@@ -40,14 +250,57 @@ pub(crate) struct DynamicAssetField {
 #[derive(PartialEq, Debug)]
 pub(crate) enum AssetField {
     Basic(BasicAssetField),
-    Folder(BasicAssetField, Typed, Mapped),
+    Folder(FolderAssetField, Typed, Mapped),
     Files(MultipleFilesField, Typed, Mapped),
+    /// Fixed size array of handles filled from a list of paths in order
+    Array(MultipleFilesField, Typed),
+    ConstFiles(ConstFilesField, Typed, Mapped),
     TextureAtlas(TextureAtlasAssetField),
     StandardMaterial(BasicAssetField),
+    ColorMaterial(BasicAssetField),
+    /// Image with a mipmap chain generated on creation
+    Mipmapped(BasicAssetField),
+    /// Shader whose imports by asset path are loaded before the collection is created
+    Shader(BasicAssetField),
+    /// Handle together with the hash of its file
+    Hashed(BasicAssetField),
+    /// Map of the named animations of a glTF file
+    Animations(BasicAssetField),
+    /// Materials of a glTF file in the order of their material index
+    GltfMaterials(BasicAssetField),
+    /// Duration of an audio file
+    AudioDuration(BasicAssetField),
+    /// Asset loaded from an absolute path or a path relative to the executable
+    Absolute(BasicAssetField),
+    /// New image copied from a region of the loaded image
+    Region(RegionAssetField),
+    AudioBank(AudioBankAssetField),
+    /// Handles to the paths of a list file, loaded after the list itself
+    PathList(PathListAssetField),
+    /// Material or texture atlas created from the image of another field after all other fields
+    Derived(DerivedAssetField),
     Dynamic(DynamicAssetField),
     OptionalDynamic(DynamicAssetField),
     DynamicFileCollection(DynamicAssetField, Typed, Mapped),
     OptionalDynamicFileCollection(DynamicAssetField, Typed, Mapped),
+    /// Dynamic asset built into a field type implementing `DynamicAssetField`
+    DynamicField(DynamicAssetField),
+    OptionalDynamicField(DynamicAssetField),
+    Skipped(Ident),
+    /// Handle cloned from a field of another resource on creation
+    FromResource(ResourceAssetField),
+    /// Handle of an asset that was added to its `Assets` under a UUID instead of being loaded
+    Uuid(UuidAssetField),
+    /// Field that is only loaded on the given platform and has its default value on all others
+    Platform(Platform, Box<AssetField>),
+    /// Field that is replaced by the return value of the given function if its asset fails to load
+    OnFail(Fallback, Box<AssetField>),
+    /// Field whose assets count with the given weight towards the loading progress
+    Weighted(f32, Box<AssetField>),
+    /// Collection of handles that gets sorted by the asset paths of its handles on creation
+    Sorted(Box<AssetField>),
+    /// Handle wrapped in a `LoadingField` that can be used before its asset finished loading
+    LoadingField(LoadingFieldType, Box<AssetField>),
 }
 
 #[derive(PartialEq, Debug)]
@@ -86,23 +339,110 @@ impl AssetField {
         token_stream: TokenStream,
     ) -> TokenStream {
         match self {
+            AssetField::Skipped(field_ident) => {
+                quote!(#token_stream #field_ident : ::std::default::Default::default(),)
+            }
+            AssetField::FromResource(from_resource) => {
+                let field_ident = from_resource.field_ident.clone();
+                let field_name = field_ident.to_string();
+                let resource = from_resource.resource.clone();
+                let source = from_resource.source.clone();
+                quote!(#token_stream #field_ident : {
+                    match world.get_resource::<#resource>() {
+                        Some(resource) => ::std::clone::Clone::clone(&resource.#source),
+                        None => ::bevy_asset_loader::asset_collection::collection_error::<Self, _>(&mut errors, #field_name, format!("The resource '{}' does not exist", ::std::any::type_name::<#resource>())),
+                    }
+                },)
+            }
+            AssetField::Uuid(asset) => {
+                let field_ident = asset.field_ident.clone();
+                let field_name = field_ident.to_string();
+                let uuid = asset.uuid;
+                quote!(#token_stream #field_ident : {
+                    let uuid = ::bevy::utils::Uuid::from_u128(#uuid);
+                    match ::bevy_asset_loader::asset_uuid::get_uuid_handle(world, uuid) {
+                        Some(handle) => handle,
+                        None => ::bevy_asset_loader::asset_collection::collection_error::<Self, _>(&mut errors, #field_name, format!("There is no asset with the UUID '{}'", uuid)),
+                    }
+                },)
+            }
+            AssetField::Platform(platform, asset) => {
+                let predicate = platform.predicate();
+                let field_ident = asset.field_ident();
+                let creation = asset.attach_token_stream_for_creation(quote!());
+                quote!(#token_stream
+                    #[cfg(#predicate)]
+                    #creation
+                    #[cfg(not(#predicate))]
+                    #field_ident : ::std::default::Default::default(),
+                )
+            }
+            AssetField::OnFail(_, asset)
+            | AssetField::Weighted(_, asset)
+            | AssetField::Sorted(asset) => asset.attach_token_stream_for_creation(token_stream),
+            AssetField::LoadingField(LoadingFieldType(asset_type), asset) => {
+                let field_ident = asset.field_ident();
+                let creation = asset.attach_token_stream_for_creation(quote!());
+                quote!(#token_stream #field_ident : {
+                    struct LoadingFieldHandle {
+                        #field_ident: ::bevy::asset::Handle<#asset_type>,
+                    }
+                    let handle = LoadingFieldHandle { #creation };
+                    ::bevy_asset_loader::loading_field::LoadingField::from_handle(handle.#field_ident, world)
+                },)
+            }
+            AssetField::Derived(derived) => {
+                let field_ident = derived.field_ident.clone();
+                quote!(#token_stream #field_ident : ::std::default::Default::default(),)
+            }
             AssetField::Basic(basic) => {
                 let field_ident = basic.field_ident.clone();
-                let asset_path = basic.asset_path.clone();
+                let creation_path = creation_path(&basic.asset_path);
                 quote!(#token_stream #field_ident : {
                     let asset_server = world.get_resource::<AssetServer>().expect("Cannot get AssetServer");
-                    asset_server.get_handle(#asset_path)
+                    asset_server.get_handle(#creation_path)
                 },)
             }
-            AssetField::Folder(basic, typed, mapped) => {
-                let field_ident = basic.field_ident.clone();
-                let asset_path = basic.asset_path.clone();
+            AssetField::Absolute(asset) => {
+                let field_ident = asset.field_ident.clone();
+                let asset_path = asset.asset_path.clone();
+                quote!(#token_stream #field_ident : {
+                    let asset_server = world.get_resource::<AssetServer>().expect("Cannot get AssetServer");
+                    asset_server.get_handle(::bevy_asset_loader::absolute_path::resolve_absolute_path(#asset_path).as_str())
+                },)
+            }
+            AssetField::Folder(folder, typed, mapped) => {
+                let field_ident = folder.field_ident.clone();
+                let creation_path = creation_path(&folder.asset_path);
+                let field_name = field_ident.to_string();
+                let folder_path = folder.asset_path.clone();
+                let expected_keys = folder.expected_keys.clone();
+                let check_expected_keys = quote!(#(
+                    if ::bevy_asset_loader::asset_collection::folder_map_entry(&folder_map, #expected_keys).is_none() {
+                        panic!("The folder '{}' of the asset collection field '{}' does not contain the expected asset '{}'", #folder_path, #field_name, #expected_keys);
+                    }
+                )*);
+                let insert = |handle: TokenStream| match &folder.map_key {
+                    Some(map_key) => quote!(
+                        if let Some(key) = ::bevy_asset_loader::asset_collection::parse_map_key::<Self, _, _>(#field_name, asset_path.path(), #map_key) {
+                            folder_map.insert(key, #handle);
+                        }
+                    ),
+                    None => quote!(
+                        let key: String = ::bevy_asset_loader::path_slash::PathExt::to_slash(asset_path.path())
+                            .expect("Path should be valid UTF-8")
+                            .into();
+                        folder_map.insert(key, #handle);
+                    ),
+                };
+                let insert_typed = insert(quote!(handle.typed()));
+                let insert_untyped = insert(quote!(handle));
                 match typed {
                     Typed::Yes => match mapped {
                         Mapped::No => {
                             quote!(#token_stream #field_ident : {
                                     let asset_server = world.get_resource::<AssetServer>().expect("Cannot get AssetServer");
-                                    asset_server.load_folder(#asset_path)
+                                    asset_server.load_folder(#creation_path)
                                         .unwrap()
                                         .drain(..)
                                         .map(|handle| handle.typed())
@@ -113,16 +453,14 @@ impl AssetField {
                             quote!(#token_stream #field_ident : {
                                     let asset_server = world.get_resource::<AssetServer>().expect("Cannot get AssetServer");
                                     let mut folder_map = ::bevy::utils::HashMap::default();
-                                    let handles = asset_server.load_folder(#asset_path).unwrap();
+                                    let handles = asset_server.load_folder(#creation_path).unwrap();
                                     for handle in handles {
                                         let asset_path = asset_server
                                             .get_handle_path(&handle)
                                             .expect("Handle should have a path");
-                                        let key: String = ::bevy_asset_loader::path_slash::PathExt::to_slash(asset_path.path())
-                                            .expect("Path should be valid UTF-8")
-                                            .into();
-                                        folder_map.insert(key, handle.typed());
+                                        #insert_typed
                                     }
+                                    #check_expected_keys
                                     folder_map
                                 },)
                         }
@@ -131,23 +469,21 @@ impl AssetField {
                         Mapped::No => {
                             quote!(#token_stream #field_ident : {
                                     let asset_server = world.get_resource::<AssetServer>().expect("Cannot get AssetServer");
-                                    asset_server.load_folder(#asset_path).unwrap()
+                                    asset_server.load_folder(#creation_path).unwrap()
                                 },)
                         }
                         Mapped::Yes => {
                             quote!(#token_stream #field_ident : {
                                     let asset_server = world.get_resource::<AssetServer>().expect("Cannot get AssetServer");
                                     let mut folder_map = ::bevy::utils::HashMap::default();
-                                    let handles = asset_server.load_folder(#asset_path).unwrap();
+                                    let handles = asset_server.load_folder(#creation_path).unwrap();
                                     for handle in handles {
                                         let asset_path = asset_server
                                             .get_handle_path(&handle)
                                             .expect("Handle should have a path");
-                                        let key: String = ::bevy_asset_loader::path_slash::PathExt::to_slash(asset_path.path())
-                                            .expect("Path should be valid UTF-8")
-                                            .into();
-                                        folder_map.insert(key, handle);
+                                        #insert_untyped
                                     }
+                                    #check_expected_keys
                                     folder_map
                                 },)
                         }
@@ -158,14 +494,115 @@ impl AssetField {
                 let field_ident = basic.field_ident.clone();
                 let asset_path = basic.asset_path.clone();
                 quote!(#token_stream #field_ident : {
+                    let asset_path = ::bevy_asset_loader::asset_collection::transform_asset_path(Some(&*asset_keys), world.get_resource(), #asset_path);
                     let cell = world.cell();
                     let asset_server = cell.get_resource::<AssetServer>().expect("Cannot get AssetServer");
                     let mut materials = cell
                         .get_resource_mut::<Assets<StandardMaterial>>()
                         .expect("Cannot get resource Assets<StandardMaterial>");
-                    materials.add(asset_server.get_handle(#asset_path).into())
+                    materials.add(asset_server.get_handle(asset_path.as_str()).into())
+                },)
+            }
+            AssetField::ColorMaterial(basic) => {
+                let field_ident = basic.field_ident.clone();
+                let asset_path = basic.asset_path.clone();
+                quote!(#token_stream #field_ident : {
+                    let asset_path = ::bevy_asset_loader::asset_collection::transform_asset_path(Some(&*asset_keys), world.get_resource(), #asset_path);
+                    let cell = world.cell();
+                    let asset_server = cell.get_resource::<AssetServer>().expect("Cannot get AssetServer");
+                    let mut materials = cell
+                        .get_resource_mut::<Assets<ColorMaterial>>()
+                        .expect("Cannot get resource Assets<ColorMaterial>");
+                    materials.add(asset_server.get_handle(asset_path.as_str()).into())
+                },)
+            }
+            AssetField::Hashed(asset) => {
+                let field_ident = asset.field_ident.clone();
+                let field_name = field_ident.to_string();
+                let creation_path = creation_path(&asset.asset_path);
+                quote!(#token_stream #field_ident : {
+                    let asset_path = #creation_path.to_owned();
+                    let asset_server = world.get_resource::<AssetServer>().expect("Cannot get AssetServer");
+                    ::bevy_asset_loader::content_hash::HashedHandle {
+                        handle: asset_server.get_handle(asset_path.as_str()),
+                        hash: match ::bevy_asset_loader::content_hash::file_hash(world, &asset_path) {
+                            Ok(hash) => hash,
+                            Err(error) => ::bevy_asset_loader::asset_collection::collection_error::<Self, _>(&mut errors, #field_name, format!("Failed to hash the file of '{}': {}", asset_path, error)),
+                        },
+                    }
+                },)
+            }
+            AssetField::Animations(gltf) => {
+                let field_ident = gltf.field_ident.clone();
+                let field_name = field_ident.to_string();
+                let creation_path = creation_path(&gltf.asset_path);
+                quote!(#token_stream #field_ident : {
+                    let gltf_path = #creation_path.to_owned();
+                    match ::bevy_asset_loader::animation::named_animations(world, &gltf_path) {
+                        Some(animations) => animations.into_iter().collect(),
+                        None => ::bevy_asset_loader::asset_collection::collection_error::<Self, _>(&mut errors, #field_name, format!("Failed to get the animations of '{}'", gltf_path)),
+                    }
+                },)
+            }
+            AssetField::GltfMaterials(gltf) => {
+                let field_ident = gltf.field_ident.clone();
+                let field_name = field_ident.to_string();
+                let creation_path = creation_path(&gltf.asset_path);
+                quote!(#token_stream #field_ident : {
+                    let gltf_path = #creation_path.to_owned();
+                    match ::bevy_asset_loader::gltf_materials::gltf_materials(world, &gltf_path) {
+                        Some(materials) => materials.into_iter().collect(),
+                        None => ::bevy_asset_loader::asset_collection::collection_error::<Self, _>(&mut errors, #field_name, format!("Failed to get the materials of '{}'", gltf_path)),
+                    }
+                },)
+            }
+            AssetField::AudioDuration(audio) => {
+                let field_ident = audio.field_ident.clone();
+                let creation_path = creation_path(&audio.asset_path);
+                quote!(#token_stream #field_ident : ::bevy_asset_loader::audio_duration::create_audio_duration(world, #creation_path),)
+            }
+            AssetField::Shader(shader) => {
+                let field_ident = shader.field_ident.clone();
+                let creation_path = creation_path(&shader.asset_path);
+                quote!(#token_stream #field_ident : {
+                    let shader_path = #creation_path.to_owned();
+                    let asset_server = world.get_resource::<AssetServer>().expect("Cannot get AssetServer");
+                    let handle: ::bevy::asset::Handle<::bevy::render::render_resource::Shader> = asset_server.get_handle(shader_path.as_str());
+                    ::bevy_asset_loader::shader::keep_shader_imports(world, &shader_path);
+                    handle
+                },)
+            }
+            AssetField::Mipmapped(image) => {
+                let field_ident = image.field_ident.clone();
+                let creation_path = creation_path(&image.asset_path);
+                quote!(#token_stream #field_ident : {
+                    let asset_server = world.get_resource::<AssetServer>().expect("Cannot get AssetServer");
+                    let handle: ::bevy::asset::Handle<::bevy::render::texture::Image> = asset_server.get_handle(#creation_path);
+                    ::bevy_asset_loader::mipmaps::generate_mipmaps(world, &handle);
+                    handle
+                },)
+            }
+            AssetField::Region(region) => {
+                let field_ident = region.field_ident.clone();
+                let creation_path = creation_path(&region.asset_path);
+                let (x, y, width, height) = (region.x, region.y, region.width, region.height);
+                quote!(#token_stream #field_ident : {
+                    let asset_server = world.get_resource::<AssetServer>().expect("Cannot get AssetServer");
+                    let source: ::bevy::asset::Handle<::bevy::render::texture::Image> = asset_server.get_handle(#creation_path);
+                    ::bevy_asset_loader::image_region::extract_region(world, &source, #x, #y, #width, #height)
                 },)
             }
+            AssetField::AudioBank(audio_bank) => {
+                let field_ident = audio_bank.field_ident.clone();
+                let path = creation_path(&audio_bank.asset_path);
+                let manifest_path = creation_path(&audio_bank.manifest_path);
+                quote!(#token_stream #field_ident : ::bevy_asset_loader::audio_bank::create_audio_bank(world, #path, #manifest_path),)
+            }
+            AssetField::PathList(path_list) => {
+                let field_ident = path_list.field_ident.clone();
+                let list_path = creation_path(&path_list.list_path);
+                quote!(#token_stream #field_ident : ::bevy_asset_loader::path_list::listed_handles(world, #list_path),)
+            }
             AssetField::TextureAtlas(texture_atlas) => {
                 let field_ident = texture_atlas.field_ident.clone();
                 let asset_path = texture_atlas.asset_path.clone();
@@ -178,6 +615,7 @@ impl AssetField {
                 let offset_x = texture_atlas.offset_x;
                 let offset_y = texture_atlas.offset_y;
                 quote!(#token_stream #field_ident : {
+                    let asset_path = ::bevy_asset_loader::asset_collection::transform_asset_path(Some(&*asset_keys), world.get_resource(), #asset_path);
                     let cell = world.cell();
                     let asset_server = cell
                         .get_resource::<AssetServer>()
@@ -186,7 +624,7 @@ impl AssetField {
                         .get_resource_mut::<Assets<TextureAtlas>>()
                         .expect("Cannot get resource Assets<TextureAtlas>");
                     atlases.add(TextureAtlas::from_grid(
-                        asset_server.get_handle(#asset_path),
+                        asset_server.get_handle(asset_path.as_str()),
                         Vec2::new(#tile_size_x, #tile_size_y),
                         #columns,
                         #rows,
@@ -195,71 +633,145 @@ impl AssetField {
                     ))
                 },)
             }
+            AssetField::Array(files, typed) => {
+                let field_ident = files.field_ident.clone();
+                let creation_paths = files.asset_paths.iter().map(|path| creation_path(path));
+                match typed {
+                    Typed::Yes => quote!(#token_stream #field_ident : {
+                            let asset_server = world.get_resource::<AssetServer>().expect("Cannot get AssetServer");
+                            [#(asset_server.load(#creation_paths)),*]
+                        },),
+                    Typed::No => quote!(#token_stream #field_ident : {
+                            let asset_server = world.get_resource::<AssetServer>().expect("Cannot get AssetServer");
+                            [#(asset_server.load_untyped(#creation_paths)),*]
+                        },),
+                }
+            }
             AssetField::Files(files, typed, mapped) => {
                 let field_ident = files.field_ident.clone();
                 let asset_paths = files.asset_paths.clone();
+                let creation_paths: Vec<_> =
+                    asset_paths.iter().map(|path| creation_path(path)).collect();
                 match typed {
                     Typed::Yes => match mapped {
                         Mapped::No => quote!(#token_stream #field_ident : {
                                 let asset_server = world.get_resource::<AssetServer>().expect("Cannot get AssetServer");
-                                vec![#(asset_server.load(#asset_paths)),*]
+                                vec![#(asset_server.load(#creation_paths)),*]
                             },),
                         Mapped::Yes => quote!(#token_stream #field_ident : {
                                 let asset_server = world.get_resource::<AssetServer>().expect("Cannot get AssetServer");
                                 let mut folder_map = ::bevy::utils::HashMap::default();
-                                #(folder_map.insert(#asset_paths.to_owned(), asset_server.load(#asset_paths)));*;
+                                #(folder_map.insert(#asset_paths.to_owned(), asset_server.load(#creation_paths)));*;
                                 folder_map
                             },),
                     },
                     Typed::No => match mapped {
                         Mapped::No => quote!(#token_stream #field_ident : {
                                 let asset_server = world.get_resource::<AssetServer>().expect("Cannot get AssetServer");
-                                vec![#(asset_server.load_untyped(#asset_paths)),*]
+                                vec![#(asset_server.load_untyped(#creation_paths)),*]
                             },),
                         Mapped::Yes => quote!(#token_stream #field_ident : {
                                 let asset_server = world.get_resource::<AssetServer>().expect("Cannot get AssetServer");
                                 let mut folder_map = ::bevy::utils::HashMap::default();
-                                #(folder_map.insert(#asset_paths.to_owned(), asset_server.load_untyped(#asset_paths)));*;
+                                #(folder_map.insert(#asset_paths.to_owned(), asset_server.load_untyped(#creation_paths)));*;
                                 folder_map
                             },),
                     },
                 }
             }
+            AssetField::ConstFiles(files, typed, mapped) => {
+                let field_ident = files.field_ident.clone();
+                let const_path = files.const_path.clone();
+                let path = quote!(::bevy_asset_loader::asset_collection::transform_asset_path(
+                    Some(&*asset_keys),
+                    world.get_resource(),
+                    path
+                )
+                .as_str());
+                let load = match typed {
+                    Typed::Yes => quote!(asset_server.load(#path)),
+                    Typed::No => quote!(asset_server.load_untyped(#path)),
+                };
+                let collect = match mapped {
+                    Mapped::Yes => quote!(map(|path| ((*path).to_owned(), #load))),
+                    Mapped::No => quote!(map(|path| #load)),
+                };
+                quote!(#token_stream #field_ident : {
+                    let asset_server = world.get_resource::<AssetServer>().expect("Cannot get AssetServer");
+                    let paths: &[&str] = #const_path;
+                    paths.iter().#collect.collect()
+                },)
+            }
             AssetField::Dynamic(dynamic) => {
                 let field_ident = dynamic.field_ident.clone();
+                let field_name = field_ident.to_string();
                 let asset_key = dynamic.key.clone();
                 quote!(#token_stream #field_ident : {
-                    let asset = asset_keys.get_asset(#asset_key.into()).unwrap_or_else(|| panic!("Failed to get asset for key '{}'", #asset_key));
-                    match asset.build(world).unwrap_or_else(|_| panic!("Error building the dynamic asset {:?} with the key {}", asset, #asset_key)) {
-                        ::bevy_asset_loader::prelude::DynamicAssetType::Single(handle) => handle.typed(),
-                        _ => panic!("The dynamic asset '{}' cannot be created (expected `File`, `StandardMaterial`, or `TextureAtlas`), got {:?}", #asset_key, asset)
+                    match asset_keys.get_asset(#asset_key.into()) {
+                        Some(asset) => match ::bevy_asset_loader::asset_collection::build_dynamic_asset::<Self>(asset, #field_name, #asset_key, world, &mut errors) {
+                            Some(::bevy_asset_loader::prelude::DynamicAssetType::Single(handle)) => ::bevy_asset_loader::asset_collection::typed_dynamic_handle::<Self, _>(&asset_keys, #asset_key, handle, #field_name, &mut errors),
+                            Some(_) => ::bevy_asset_loader::asset_collection::collection_error::<Self, _>(&mut errors, #field_name, format!("The dynamic asset '{}' cannot be created (expected `File`, `StandardMaterial`, `ColorMaterial`, or `TextureAtlas`), got {:?}", #asset_key, asset)),
+                            None => ::std::default::Default::default()
+                        },
+                        None => ::bevy_asset_loader::asset_collection::collection_error::<Self, _>(&mut errors, #field_name, format!("Failed to get asset for key '{}'", #asset_key)),
                     }
                 },)
             }
             AssetField::OptionalDynamic(dynamic) => {
                 let field_ident = dynamic.field_ident.clone();
+                let field_name = field_ident.to_string();
                 let asset_key = dynamic.key.clone();
                 quote!(#token_stream #field_ident : {
                     let asset = asset_keys.get_asset(#asset_key.into());
-                    asset.map(|asset| match asset.build(world).unwrap_or_else(|_| panic!("Error building the dynamic asset {:?} with the key {}", asset, #asset_key)) {
-                            ::bevy_asset_loader::prelude::DynamicAssetType::Single(handle) => handle.typed(),
-                            _ => panic!("The dynamic asset '{}' cannot be created (expected `File`, `StandardMaterial`, or `TextureAtlas`), got {:?}", #asset_key, asset)
+                    asset.map(|asset| match ::bevy_asset_loader::asset_collection::build_dynamic_asset::<Self>(asset, #field_name, #asset_key, world, &mut errors) {
+                            Some(::bevy_asset_loader::prelude::DynamicAssetType::Single(handle)) => ::bevy_asset_loader::asset_collection::typed_dynamic_handle::<Self, _>(&asset_keys, #asset_key, handle, #field_name, &mut errors),
+                            Some(_) => ::bevy_asset_loader::asset_collection::collection_error::<Self, _>(&mut errors, #field_name, format!("The dynamic asset '{}' cannot be created (expected `File`, `StandardMaterial`, `ColorMaterial`, or `TextureAtlas`), got {:?}", #asset_key, asset)),
+                            None => ::std::default::Default::default()
                         }
                     )
                 },)
             }
+            AssetField::DynamicField(dynamic) => {
+                let field_ident = dynamic.field_ident.clone();
+                let asset_key = dynamic.key.clone();
+                quote!(#token_stream #field_ident : {
+                    let asset = asset_keys.get_asset(#asset_key.into()).unwrap_or_else(|| panic!("Failed to get asset for key '{}'", #asset_key));
+                    let built = asset.build(world).unwrap_or_else(|_| panic!("Error building the dynamic asset {:?} with the key {}", asset, #asset_key));
+                    ::bevy_asset_loader::dynamic_asset::DynamicAssetField::from_dynamic_asset(built, world)
+                        .unwrap_or_else(|error| panic!("The dynamic asset '{}' cannot be created: {}", #asset_key, error))
+                },)
+            }
+            AssetField::OptionalDynamicField(dynamic) => {
+                let field_ident = dynamic.field_ident.clone();
+                let field_name = field_ident.to_string();
+                let asset_key = dynamic.key.clone();
+                quote!(#token_stream #field_ident : {
+                    let asset = asset_keys.get_asset(#asset_key.into());
+                    asset.and_then(|asset| {
+                        let built = ::bevy_asset_loader::asset_collection::build_dynamic_asset::<Self>(asset, #field_name, #asset_key, world, &mut errors)?;
+                        match ::bevy_asset_loader::dynamic_asset::DynamicAssetField::from_dynamic_asset(built, world) {
+                            Ok(field) => Some(field),
+                            Err(error) => ::bevy_asset_loader::asset_collection::collection_error::<Self, _>(&mut errors, #field_name, format!("The dynamic asset '{}' cannot be created: {}", #asset_key, error)),
+                        }
+                    })
+                },)
+            }
             AssetField::DynamicFileCollection(dynamic, typed, mapped) => {
                 let field_ident = dynamic.field_ident.clone();
+                let field_name = field_ident.to_string();
                 let asset_key = dynamic.key.clone();
                 let load = match typed {
-                    Typed::Yes => {
-                        match mapped {
-                            Mapped::No => quote!(match asset.build(world).unwrap_or_else(|_| panic!("Error building the dynamic asset {:?} with the key {}", asset, #asset_key)) {
-                                ::bevy_asset_loader::prelude::DynamicAssetType::Collection(mut handles) => handles.drain(..).map(|handle| handle.typed()).collect(),
-                                _ => panic!("The dynamic asset '{}' cannot be created (expected `Folder` or `Files`), got {:?}", #asset_key, asset),
-                            }),
-                            Mapped::Yes => quote!(match asset.build(world).unwrap_or_else(|_| panic!("Error building the dynamic asset {:?} with the key {}", asset, #asset_key)) {
-                                ::bevy_asset_loader::prelude::DynamicAssetType::Collection(mut handles) => {
+                    Typed::Yes => match mapped {
+                        Mapped::No => {
+                            quote!(match ::bevy_asset_loader::asset_collection::build_dynamic_asset::<Self>(asset, #field_name, #asset_key, world, &mut errors) {
+                                Some(::bevy_asset_loader::prelude::DynamicAssetType::Collection(mut handles)) => handles.drain(..).map(|handle| handle.typed()).collect(),
+                                Some(_) => ::bevy_asset_loader::asset_collection::collection_error::<Self, _>(&mut errors, #field_name, format!("The dynamic asset '{}' cannot be created (expected `Folder` or `Files`), got {:?}", #asset_key, asset)),
+                                None => ::std::default::Default::default(),
+                            })
+                        }
+                        Mapped::Yes => {
+                            quote!(match ::bevy_asset_loader::asset_collection::build_dynamic_asset::<Self>(asset, #field_name, #asset_key, world, &mut errors) {
+                                Some(::bevy_asset_loader::prelude::DynamicAssetType::Collection(mut handles)) => {
                                     let asset_server = world.get_resource::<AssetServer>().expect("Cannot get AssetServer");
                                     let mut folder_map = ::bevy::utils::HashMap::default();
                                     for handle in handles {
@@ -273,174 +785,806 @@ impl AssetField {
                                     }
                                     folder_map
                                 },
-                                _ => panic!("The dynamic asset '{}' cannot be created (expected `Folder` or `Files`), got {:?}", #asset_key, asset),
+                                Some(_) => ::bevy_asset_loader::asset_collection::collection_error::<Self, _>(&mut errors, #field_name, format!("The dynamic asset '{}' cannot be created (expected `Folder` or `Files`), got {:?}", #asset_key, asset)),
+                                None => ::std::default::Default::default(),
                             })
                         }
-                    }
-                    Typed::No => {
-                        match mapped {
-                            Mapped::No =>
-                                quote!(match asset.build(world).unwrap_or_else(|_| panic!("Error building the dynamic asset {:?} with the key {}", asset, #asset_key)) {
-                                    ::bevy_asset_loader::prelude::DynamicAssetType::Collection(handles) => handles,
-                                    _ =>
-                                        panic!("The dynamic asset '{}' cannot be created (expected `Folder` or `Files`), got {:?}", #asset_key, asset),
-                                }),
-                            Mapped::Yes =>
-                                quote!(match asset.build(world).unwrap_or_else(|_| panic!("Error building the dynamic asset {:?} with the key {}", asset, #asset_key)) {
-                                    ::bevy_asset_loader::prelude::DynamicAssetType::Collection(handles) => {
-                                        let asset_server = world.get_resource::<AssetServer>().expect("Cannot get AssetServer");
-                                        let mut folder_map = ::bevy::utils::HashMap::default();
-                                        for handle in handles {
-                                            let asset_path = asset_server
-                                                .get_handle_path(&handle)
-                                                .expect("Handle should have a path");
-                                            let key: String = ::bevy_asset_loader::path_slash::PathExt::to_slash(asset_path.path())
-                                                .expect("Path should be valid UTF-8")
-                                                .into();
-                                            folder_map.insert(key, handle);
-                                        }
-                                        folder_map
-                                    },
-                                    _ =>
-                                        panic!("The dynamic asset '{}' cannot be created (expected `Folder` or `Files`), got {:?}", #asset_key, asset),
-                                })
+                    },
+                    Typed::No => match mapped {
+                        Mapped::No => {
+                            quote!(match ::bevy_asset_loader::asset_collection::build_dynamic_asset::<Self>(asset, #field_name, #asset_key, world, &mut errors) {
+                                Some(::bevy_asset_loader::prelude::DynamicAssetType::Collection(handles)) => handles,
+                                Some(_) => ::bevy_asset_loader::asset_collection::collection_error::<Self, _>(&mut errors, #field_name, format!("The dynamic asset '{}' cannot be created (expected `Folder` or `Files`), got {:?}", #asset_key, asset)),
+                                None => ::std::default::Default::default(),
+                            })
                         }
-                    }
+                        Mapped::Yes => {
+                            quote!(match ::bevy_asset_loader::asset_collection::build_dynamic_asset::<Self>(asset, #field_name, #asset_key, world, &mut errors) {
+                                Some(::bevy_asset_loader::prelude::DynamicAssetType::Collection(handles)) => {
+                                    let asset_server = world.get_resource::<AssetServer>().expect("Cannot get AssetServer");
+                                    let mut folder_map = ::bevy::utils::HashMap::default();
+                                    for handle in handles {
+                                        let asset_path = asset_server
+                                            .get_handle_path(&handle)
+                                            .expect("Handle should have a path");
+                                        let key: String = ::bevy_asset_loader::path_slash::PathExt::to_slash(asset_path.path())
+                                            .expect("Path should be valid UTF-8")
+                                            .into();
+                                        folder_map.insert(key, handle);
+                                    }
+                                    folder_map
+                                },
+                                Some(_) => ::bevy_asset_loader::asset_collection::collection_error::<Self, _>(&mut errors, #field_name, format!("The dynamic asset '{}' cannot be created (expected `Folder` or `Files`), got {:?}", #asset_key, asset)),
+                                None => ::std::default::Default::default(),
+                            })
+                        }
+                    },
                 };
                 quote!(#token_stream #field_ident : {
-                    let asset = asset_keys.get_asset(#asset_key.into()).unwrap_or_else(|| panic!("Failed to get asset for key '{}'", #asset_key));
-                    #load
+                    match asset_keys.get_asset(#asset_key.into()) {
+                        Some(asset) => #load,
+                        None => ::bevy_asset_loader::asset_collection::collection_error::<Self, _>(&mut errors, #field_name, format!("Failed to get asset for key '{}'", #asset_key)),
+                    }
                 },)
             }
             AssetField::OptionalDynamicFileCollection(dynamic, typed, mapped) => {
                 let field_ident = dynamic.field_ident.clone();
+                let field_name = field_ident.to_string();
                 let asset_key = dynamic.key.clone();
                 let load = match typed {
-                    Typed::Yes => {
-                        match mapped {
-                            Mapped::No => quote!(
-                                asset.map(|asset| match asset.build(world).unwrap_or_else(|_| panic!("Error building the dynamic asset {:?} with the key {}", asset, #asset_key)) {
-                                    ::bevy_asset_loader::prelude::DynamicAssetType::Collection(mut handles) => handles.drain(..).map(|handle| handle.typed()).collect(),
-                                    _ => panic!("The dynamic asset '{}' cannot be created (expected `Folder` or `Files`), got {:?}", #asset_key, asset),
-                                })
-                            ),
-                            Mapped::Yes => quote!(
-                                asset.map(|asset| match asset.build(world).unwrap_or_else(|_| panic!("Error building the dynamic asset {:?} with the key {}", asset, #asset_key)) {
-                                    ::bevy_asset_loader::prelude::DynamicAssetType::Collection(mut handles) => {
-                                        let asset_server = world.get_resource::<AssetServer>().expect("Cannot get AssetServer");
-                                        let mut folder_map = ::bevy::utils::HashMap::default();
-                                        for handle in handles {
-                                            let asset_path = asset_server
-                                                .get_handle_path(&handle)
-                                                .expect("Handle should have a path");
-                                            let key: String = ::bevy_asset_loader::path_slash::PathExt::to_slash(asset_path.path())
-                                                .expect("Path should be valid UTF-8")
-                                                .into();
-                                            folder_map.insert(key, handle.typed());
-                                        }
-                                        folder_map
-                                    },
-                                    _ => panic!("The dynamic asset '{}' cannot be created (expected `Folder` or `Files`), got {:?}", #asset_key, asset),
-                                })
-                            )
-                        }
-                    }
-                    Typed::No => {
-                        match mapped {
-                            Mapped::No => quote!(
-                                asset.map(|asset| match asset.build(world).unwrap_or_else(|_| panic!("Error building the dynamic asset {:?} with the key {}", asset, #asset_key)) {
-                                    ::bevy_asset_loader::prelude::DynamicAssetType::Collection(handles) => handles,
-                                    _ => panic!("The dynamic asset '{}' cannot be created (expected `Folder` or `Files`), got {:?}", #asset_key, asset),
-                                })
-                            ),
-                            Mapped::Yes => quote!(
-                                asset.map(|asset| match asset.build(world).unwrap_or_else(|_| panic!("Error building the dynamic asset {:?} with the key {}", asset, #asset_key)) {
-                                    ::bevy_asset_loader::prelude::DynamicAssetType::Collection(handles) => {
-                                        let asset_server = world.get_resource::<AssetServer>().expect("Cannot get AssetServer");
-                                        let mut folder_map = ::bevy::utils::HashMap::default();
-                                        for handle in handles {
-                                            let asset_path = asset_server
-                                                .get_handle_path(&handle)
-                                                .expect("Handle should have a path");
-                                            let key: String = ::bevy_asset_loader::path_slash::PathExt::to_slash(asset_path.path())
-                                                .expect("Path should be valid UTF-8")
-                                                .into();
-                                            folder_map.insert(key, handle);
-                                        }
-                                        folder_map
-                                    },
-                                    _ => panic!("The dynamic asset '{}' cannot be created (expected `Folder` or `Files`), got {:?}", #asset_key, asset),
-                                })
-                            )
-                        }
-                    }
-                };
-                quote!(#token_stream #field_ident : {
-                    let asset = asset_keys.get_asset(#asset_key.into());
-                    #load
-                },)
-            }
-        }
-    }
-
-    pub(crate) fn attach_token_stream_for_loading(&self, token_stream: TokenStream) -> TokenStream {
-        match self {
-            AssetField::Basic(asset) => {
-                let asset_path = asset.asset_path.clone();
-                quote!(#token_stream handles.push(asset_server.load_untyped(#asset_path));)
-            }
-            AssetField::Folder(asset, _, _) => {
-                let asset_path = asset.asset_path.clone();
-                quote!(#token_stream asset_server.load_folder(#asset_path).unwrap().drain(..).for_each(|handle| handles.push(handle));)
-            }
-            AssetField::OptionalDynamic(dynamic)
-            | AssetField::OptionalDynamicFileCollection(dynamic, _, _) => {
-                let asset_key = dynamic.key.clone();
-                quote!(
-                    #token_stream {
-                        let dynamic_asset = asset_keys.get_asset(#asset_key.into());
-                        if let Some(dynamic_asset) = dynamic_asset {
-                            handles.extend(dynamic_asset.load(&asset_server));
-                        }
-                    }
-                )
-            }
-            AssetField::Dynamic(dynamic) | AssetField::DynamicFileCollection(dynamic, _, _) => {
+                    Typed::Yes => match mapped {
+                        Mapped::No => quote!(
+                            asset.map(|asset| match ::bevy_asset_loader::asset_collection::build_dynamic_asset::<Self>(asset, #field_name, #asset_key, world, &mut errors) {
+                                Some(::bevy_asset_loader::prelude::DynamicAssetType::Collection(mut handles)) => handles.drain(..).map(|handle| handle.typed()).collect(),
+                                Some(_) => ::bevy_asset_loader::asset_collection::collection_error::<Self, _>(&mut errors, #field_name, format!("The dynamic asset '{}' cannot be created (expected `Folder` or `Files`), got {:?}", #asset_key, asset)),
+                                None => ::std::default::Default::default(),
+                            })
+                        ),
+                        Mapped::Yes => quote!(
+                            asset.map(|asset| match ::bevy_asset_loader::asset_collection::build_dynamic_asset::<Self>(asset, #field_name, #asset_key, world, &mut errors) {
+                                Some(::bevy_asset_loader::prelude::DynamicAssetType::Collection(mut handles)) => {
+                                    let asset_server = world.get_resource::<AssetServer>().expect("Cannot get AssetServer");
+                                    let mut folder_map = ::bevy::utils::HashMap::default();
+                                    for handle in handles {
+                                        let asset_path = asset_server
+                                            .get_handle_path(&handle)
+                                            .expect("Handle should have a path");
+                                        let key: String = ::bevy_asset_loader::path_slash::PathExt::to_slash(asset_path.path())
+                                            .expect("Path should be valid UTF-8")
+                                            .into();
+                                        folder_map.insert(key, handle.typed());
+                                    }
+                                    folder_map
+                                },
+                                Some(_) => ::bevy_asset_loader::asset_collection::collection_error::<Self, _>(&mut errors, #field_name, format!("The dynamic asset '{}' cannot be created (expected `Folder` or `Files`), got {:?}", #asset_key, asset)),
+                                None => ::std::default::Default::default(),
+                            })
+                        ),
+                    },
+                    Typed::No => match mapped {
+                        Mapped::No => quote!(
+                            asset.map(|asset| match ::bevy_asset_loader::asset_collection::build_dynamic_asset::<Self>(asset, #field_name, #asset_key, world, &mut errors) {
+                                Some(::bevy_asset_loader::prelude::DynamicAssetType::Collection(handles)) => handles,
+                                Some(_) => ::bevy_asset_loader::asset_collection::collection_error::<Self, _>(&mut errors, #field_name, format!("The dynamic asset '{}' cannot be created (expected `Folder` or `Files`), got {:?}", #asset_key, asset)),
+                                None => ::std::default::Default::default(),
+                            })
+                        ),
+                        Mapped::Yes => quote!(
+                            asset.map(|asset| match ::bevy_asset_loader::asset_collection::build_dynamic_asset::<Self>(asset, #field_name, #asset_key, world, &mut errors) {
+                                Some(::bevy_asset_loader::prelude::DynamicAssetType::Collection(handles)) => {
+                                    let asset_server = world.get_resource::<AssetServer>().expect("Cannot get AssetServer");
+                                    let mut folder_map = ::bevy::utils::HashMap::default();
+                                    for handle in handles {
+                                        let asset_path = asset_server
+                                            .get_handle_path(&handle)
+                                            .expect("Handle should have a path");
+                                        let key: String = ::bevy_asset_loader::path_slash::PathExt::to_slash(asset_path.path())
+                                            .expect("Path should be valid UTF-8")
+                                            .into();
+                                        folder_map.insert(key, handle);
+                                    }
+                                    folder_map
+                                },
+                                Some(_) => ::bevy_asset_loader::asset_collection::collection_error::<Self, _>(&mut errors, #field_name, format!("The dynamic asset '{}' cannot be created (expected `Folder` or `Files`), got {:?}", #asset_key, asset)),
+                                None => ::std::default::Default::default(),
+                            })
+                        ),
+                    },
+                };
+                quote!(#token_stream #field_ident : {
+                    let asset = asset_keys.get_asset(#asset_key.into());
+                    #load
+                },)
+            }
+        }
+    }
+
+    /// Assign derived fields once all other fields of the collection were created
+    pub(crate) fn attach_token_stream_for_derived_creation(
+        &self,
+        token_stream: TokenStream,
+    ) -> TokenStream {
+        let derived = match self {
+            AssetField::Derived(derived) => derived,
+            AssetField::OnFail(Fallback(fallback), asset) => {
+                let field_ident = asset.field_ident();
+                let field_name = field_ident.to_string();
+                return quote!(#token_stream
+                    if ::bevy_asset_loader::asset_collection::field_failed::<Self>(world, #field_name) {
+                        collection.#field_ident = #fallback(world);
+                    }
+                );
+            }
+            AssetField::Platform(_, asset)
+            | AssetField::Weighted(_, asset)
+            | AssetField::LoadingField(_, asset) => {
+                return asset.attach_token_stream_for_derived_creation(token_stream)
+            }
+            AssetField::Sorted(asset) => {
+                let field_ident = asset.field_ident();
+                let token_stream = asset.attach_token_stream_for_derived_creation(token_stream);
+                return quote!(#token_stream
+                    ::bevy_asset_loader::asset_collection::SortByPath::sort_by_path(&mut collection.#field_ident, world);
+                );
+            }
+            _ => return token_stream,
+        };
+        let field_ident = derived.field_ident.clone();
+        let source = derived.source.clone();
+        match &derived.asset {
+            DerivedAsset::StandardMaterial => quote!(#token_stream
+                collection.#field_ident = world
+                    .get_resource_mut::<Assets<StandardMaterial>>()
+                    .expect("Cannot get resource Assets<StandardMaterial>")
+                    .add(collection.#source.clone().into());
+            ),
+            DerivedAsset::ColorMaterial => quote!(#token_stream
+                collection.#field_ident = world
+                    .get_resource_mut::<Assets<ColorMaterial>>()
+                    .expect("Cannot get resource Assets<ColorMaterial>")
+                    .add(collection.#source.clone().into());
+            ),
+            DerivedAsset::TextureAtlas(grid) => {
+                let tile_size_x = grid.tile_size_x;
+                let tile_size_y = grid.tile_size_y;
+                let columns = grid.columns;
+                let rows = grid.rows;
+                let padding_x = grid.padding_x;
+                let padding_y = grid.padding_y;
+                let offset_x = grid.offset_x;
+                let offset_y = grid.offset_y;
+                quote!(#token_stream
+                    collection.#field_ident = world
+                        .get_resource_mut::<Assets<TextureAtlas>>()
+                        .expect("Cannot get resource Assets<TextureAtlas>")
+                        .add(TextureAtlas::from_grid(
+                            collection.#source.clone(),
+                            Vec2::new(#tile_size_x, #tile_size_y),
+                            #columns,
+                            #rows,
+                            Some(Vec2::new(#padding_x, #padding_y)),
+                            Some(Vec2::new(#offset_x, #offset_y)),
+                        ));
+                )
+            }
+        }
+    }
+
+    pub(crate) fn attach_token_stream_for_loading(&self, token_stream: TokenStream) -> TokenStream {
+        match self {
+            AssetField::Platform(platform, asset) => {
+                let predicate = platform.predicate();
+                let loading = asset.attach_token_stream_for_loading(quote!());
+                quote!(#token_stream if cfg!(#predicate) { #loading })
+            }
+            AssetField::OnFail(_, asset) => {
+                let field_name = asset.field_ident().to_string();
+                let loading = asset.attach_token_stream_for_loading(quote!());
+                quote!(#token_stream {
+                    let first_handle = handles.len();
+                    #loading
+                    recoverable.push((#field_name, handles[first_handle..].iter().map(|handle| handle.id()).collect()));
+                })
+            }
+            AssetField::Weighted(weight, asset) => {
+                let loading = asset.attach_token_stream_for_loading(quote!());
+                quote!(#token_stream {
+                    let first_handle = handles.len();
+                    #loading
+                    weighted.push((#weight, handles[first_handle..].iter().map(|handle| handle.id()).collect()));
+                })
+            }
+            AssetField::Sorted(asset) | AssetField::LoadingField(_, asset) => {
+                asset.attach_token_stream_for_loading(token_stream)
+            }
+            AssetField::Basic(asset)
+            | AssetField::Mipmapped(asset)
+            | AssetField::Shader(asset)
+            | AssetField::Hashed(asset)
+            | AssetField::Animations(asset)
+            | AssetField::GltfMaterials(asset)
+            | AssetField::AudioDuration(asset) => {
+                let loading_path = loading_path(&asset.asset_path);
+                quote!(#token_stream handles.push(asset_server.load_untyped(#loading_path));)
+            }
+            AssetField::Region(region) => {
+                let loading_path = loading_path(&region.asset_path);
+                quote!(#token_stream handles.push(asset_server.load_untyped(#loading_path));)
+            }
+            AssetField::Absolute(asset) => {
+                let asset_path = asset.asset_path.clone();
+                quote!(#token_stream handles.push(asset_server.load_untyped(::bevy_asset_loader::absolute_path::resolve_absolute_path(#asset_path).as_str()));)
+            }
+            AssetField::Folder(asset, _, _) => {
+                let loading_path = loading_path(&asset.asset_path);
+                quote!(#token_stream asset_server.load_folder(#loading_path).unwrap().drain(..).for_each(|handle| handles.push(handle));)
+            }
+            AssetField::OptionalDynamic(dynamic)
+            | AssetField::OptionalDynamicFileCollection(dynamic, _, _)
+            | AssetField::OptionalDynamicField(dynamic) => {
                 let asset_key = dynamic.key.clone();
                 quote!(
                     #token_stream {
-                        let dynamic_asset = asset_keys.get_asset(#asset_key.into()).unwrap_or_else(|| panic!("Failed to get asset for key '{}'", #asset_key));
-                        handles.extend(dynamic_asset.load(&asset_server));
+                        let dynamic_asset = asset_keys.get_asset(#asset_key.into());
+                        if let Some(dynamic_asset) = dynamic_asset {
+                            handles.extend(dynamic_asset.load_with_transform(&asset_server, path_transform.as_deref()));
+                        }
                     }
                 )
             }
-            AssetField::StandardMaterial(asset) => {
-                let asset_path = asset.asset_path.clone();
-                quote!(#token_stream handles.push(asset_server.load_untyped(#asset_path));)
+            AssetField::Dynamic(dynamic)
+            | AssetField::DynamicFileCollection(dynamic, _, _)
+            | AssetField::DynamicField(dynamic) => {
+                let asset_key = dynamic.key.clone();
+                quote!(
+                    #token_stream {
+                        match asset_keys.get_asset(#asset_key.into()) {
+                            Some(dynamic_asset) => handles.extend(dynamic_asset.load_with_transform(&asset_server, path_transform.as_deref())),
+                            None if collecting_errors => {}
+                            None => panic!("Failed to get asset for key '{}'", #asset_key),
+                        }
+                    }
+                )
+            }
+            AssetField::StandardMaterial(asset) | AssetField::ColorMaterial(asset) => {
+                let loading_path = loading_path(&asset.asset_path);
+                quote!(#token_stream handles.push(asset_server.load_untyped(#loading_path));)
             }
             AssetField::TextureAtlas(asset) => {
-                let asset_path = asset.asset_path.clone();
-                quote!(#token_stream handles.push(asset_server.load_untyped(#asset_path));)
+                let loading_path = loading_path(&asset.asset_path);
+                quote!(#token_stream handles.push(asset_server.load_untyped(#loading_path));)
+            }
+            AssetField::AudioBank(audio_bank) => {
+                let path = loading_path(&audio_bank.asset_path);
+                let manifest_path = loading_path(&audio_bank.manifest_path);
+                quote!(#token_stream
+                    handles.push(asset_server.load_untyped(#path));
+                    handles.push(asset_server.load_untyped(#manifest_path));
+                )
+            }
+            AssetField::PathList(path_list) => {
+                let list_path = loading_path(&path_list.list_path);
+                quote!(#token_stream handles.push(asset_server.load_untyped(#list_path));)
+            }
+            AssetField::Files(assets, _, _) | AssetField::Array(assets, _) => {
+                let loading_paths = assets.asset_paths.iter().map(|path| loading_path(path));
+                quote!(#token_stream #(handles.push(asset_server.load_untyped(#loading_paths)));*;)
+            }
+            AssetField::ConstFiles(files, _, _) => {
+                let const_path = files.const_path.clone();
+                quote!(#token_stream {
+                    let paths: &[&str] = #const_path;
+                    handles.extend(paths.iter().map(|path| asset_server.load_untyped(::bevy_asset_loader::asset_collection::transform_asset_path(Some(&*asset_keys), path_transform.as_deref(), path).as_str())));
+                })
+            }
+            AssetField::Skipped(_)
+            | AssetField::FromResource(_)
+            | AssetField::Uuid(_)
+            | AssetField::Derived(_) => token_stream,
+        }
+    }
+    /// Loading of the assets that are only known after the rest of the collection loaded
+    pub(crate) fn attach_token_stream_for_deferred_loading(
+        &self,
+        token_stream: TokenStream,
+    ) -> TokenStream {
+        match self {
+            AssetField::PathList(path_list) => {
+                let list_path = deferred_loading_path(&path_list.list_path);
+                quote!(#token_stream handles.extend(::bevy_asset_loader::path_list::load_listed_paths(world, #list_path));)
+            }
+            AssetField::Shader(shader) => {
+                let shader_path = deferred_loading_path(&shader.asset_path);
+                quote!(#token_stream handles.extend(::bevy_asset_loader::shader::load_shader_imports(world, #shader_path));)
+            }
+            AssetField::Hashed(asset) => {
+                let asset_path = deferred_loading_path(&asset.asset_path);
+                quote!(#token_stream ::bevy_asset_loader::content_hash::request_file_hash(world, #asset_path);)
+            }
+            AssetField::Platform(platform, asset) => {
+                let deferred_loading = asset.attach_token_stream_for_deferred_loading(quote!());
+                if deferred_loading.is_empty() {
+                    return token_stream;
+                }
+                let predicate = platform.predicate();
+                quote!(#token_stream if cfg!(#predicate) { #deferred_loading })
+            }
+            AssetField::OnFail(_, asset)
+            | AssetField::Weighted(_, asset)
+            | AssetField::Sorted(asset)
+            | AssetField::LoadingField(_, asset) => {
+                asset.attach_token_stream_for_deferred_loading(token_stream)
+            }
+            _ => token_stream,
+        }
+    }
+
+    /// Checks for work that has to finish after loading, before the collection can be created
+    pub(crate) fn attach_token_stream_for_ready_check(
+        &self,
+        token_stream: TokenStream,
+    ) -> TokenStream {
+        match self {
+            AssetField::Hashed(asset) => {
+                let asset_path = deferred_loading_path(&asset.asset_path);
+                quote!(#token_stream ready &= !::bevy_asset_loader::content_hash::file_hash_pending(world, #asset_path);)
+            }
+            AssetField::Platform(platform, asset) => {
+                let ready_check = asset.attach_token_stream_for_ready_check(quote!());
+                if ready_check.is_empty() {
+                    return token_stream;
+                }
+                let predicate = platform.predicate();
+                quote!(#token_stream if cfg!(#predicate) { #ready_check })
+            }
+            AssetField::OnFail(_, asset)
+            | AssetField::Weighted(_, asset)
+            | AssetField::Sorted(asset)
+            | AssetField::LoadingField(_, asset) => {
+                asset.attach_token_stream_for_ready_check(token_stream)
+            }
+            _ => token_stream,
+        }
+    }
+
+    pub(crate) fn attach_token_stream_for_load_requests(
+        &self,
+        token_stream: TokenStream,
+        request_type: &TokenStream,
+    ) -> TokenStream {
+        match self {
+            AssetField::Files(assets, _, _) | AssetField::Array(assets, _) => assets
+                .asset_paths
+                .iter()
+                .fold(token_stream, |token_stream, asset_path| {
+                    let loading_path = loading_path(asset_path);
+                    let loading = quote!(handles.push(asset_server.load_untyped(#loading_path));
+                    );
+                    attach_load_request(token_stream, request_type, loading)
+                }),
+            AssetField::Skipped(_)
+            | AssetField::FromResource(_)
+            | AssetField::Uuid(_)
+            | AssetField::Derived(_) => token_stream,
+            AssetField::Platform(platform, asset) => {
+                let predicate = platform.predicate();
+                let requests = asset.attach_token_stream_for_load_requests(quote!(), request_type);
+                quote!(#token_stream if cfg!(#predicate) { #requests })
+            }
+            _ => attach_load_request(
+                token_stream,
+                request_type,
+                self.attach_token_stream_for_loading(quote!()),
+            ),
+        }
+    }
+}
+
+impl AssetField {
+    pub(crate) fn attach_token_stream_for_debug(&self, token_stream: TokenStream) -> TokenStream {
+        let field_name = self.field_ident().to_string();
+        let description = self.describe();
+        quote!(#token_stream .field(#field_name, &::std::format_args!("{}", #description)))
+    }
+
+    pub(crate) fn field_ident(&self) -> &Ident {
+        match self {
+            AssetField::Basic(asset)
+            | AssetField::StandardMaterial(asset)
+            | AssetField::ColorMaterial(asset)
+            | AssetField::Mipmapped(asset)
+            | AssetField::Shader(asset)
+            | AssetField::Hashed(asset)
+            | AssetField::Animations(asset)
+            | AssetField::GltfMaterials(asset)
+            | AssetField::AudioDuration(asset)
+            | AssetField::Absolute(asset) => &asset.field_ident,
+            AssetField::Folder(folder, _, _) => &folder.field_ident,
+            AssetField::Files(assets, _, _) | AssetField::Array(assets, _) => &assets.field_ident,
+            AssetField::ConstFiles(files, _, _) => &files.field_ident,
+            AssetField::TextureAtlas(asset) => &asset.field_ident,
+            AssetField::Region(region) => &region.field_ident,
+            AssetField::AudioBank(audio_bank) => &audio_bank.field_ident,
+            AssetField::PathList(path_list) => &path_list.field_ident,
+            AssetField::Derived(derived) => &derived.field_ident,
+            AssetField::Dynamic(dynamic)
+            | AssetField::OptionalDynamic(dynamic)
+            | AssetField::DynamicFileCollection(dynamic, _, _)
+            | AssetField::OptionalDynamicFileCollection(dynamic, _, _)
+            | AssetField::DynamicField(dynamic)
+            | AssetField::OptionalDynamicField(dynamic) => &dynamic.field_ident,
+            AssetField::Skipped(field_ident) => field_ident,
+            AssetField::FromResource(from_resource) => &from_resource.field_ident,
+            AssetField::Uuid(asset) => &asset.field_ident,
+            AssetField::Platform(_, asset)
+            | AssetField::OnFail(_, asset)
+            | AssetField::Weighted(_, asset)
+            | AssetField::Sorted(asset)
+            | AssetField::LoadingField(_, asset) => asset.field_ident(),
+        }
+    }
+
+    /// Key of the dynamic asset the field is created from
+    pub(crate) fn dynamic_key(&self) -> Option<&DynamicAssetKey> {
+        match self {
+            AssetField::Dynamic(dynamic)
+            | AssetField::OptionalDynamic(dynamic)
+            | AssetField::DynamicFileCollection(dynamic, _, _)
+            | AssetField::OptionalDynamicFileCollection(dynamic, _, _)
+            | AssetField::DynamicField(dynamic)
+            | AssetField::OptionalDynamicField(dynamic) => Some(&dynamic.key),
+            AssetField::Platform(_, asset)
+            | AssetField::OnFail(_, asset)
+            | AssetField::Weighted(_, asset)
+            | AssetField::Sorted(asset)
+            | AssetField::LoadingField(_, asset) => asset.dynamic_key(),
+            _ => None,
+        }
+    }
+
+    /// Whether the field loads an image in addition to the asset type of its handle
+    pub(crate) fn loads_image(&self) -> bool {
+        match self {
+            AssetField::Platform(_, asset)
+            | AssetField::OnFail(_, asset)
+            | AssetField::Weighted(_, asset)
+            | AssetField::Sorted(asset)
+            | AssetField::LoadingField(_, asset) => asset.loads_image(),
+            asset => matches!(
+                asset,
+                AssetField::TextureAtlas(_)
+                    | AssetField::StandardMaterial(_)
+                    | AssetField::ColorMaterial(_)
+            ),
+        }
+    }
+
+    /// Keys of a mapped folder that get accessor methods on the collection
+    pub(crate) fn expected_keys(&self) -> &[String] {
+        match self {
+            AssetField::Folder(folder, _, _) => &folder.expected_keys,
+            AssetField::Platform(_, asset)
+            | AssetField::OnFail(_, asset)
+            | AssetField::Weighted(_, asset)
+            | AssetField::Sorted(asset)
+            | AssetField::LoadingField(_, asset) => asset.expected_keys(),
+            _ => &[],
+        }
+    }
+
+    /// Asset paths given in the attribute of the field
+    ///
+    /// Paths of dynamic assets and `paths_const` fields are only known at run time.
+    /// Paths of `absolute` fields are outside of the assets folder and left out.
+    #[allow(dead_code)]
+    pub(crate) fn asset_paths(&self) -> Vec<&str> {
+        match self {
+            AssetField::Basic(asset)
+            | AssetField::StandardMaterial(asset)
+            | AssetField::ColorMaterial(asset)
+            | AssetField::Mipmapped(asset)
+            | AssetField::Shader(asset)
+            | AssetField::Hashed(asset)
+            | AssetField::Animations(asset)
+            | AssetField::GltfMaterials(asset)
+            | AssetField::AudioDuration(asset) => vec![&asset.asset_path],
+            AssetField::Folder(folder, _, _) => vec![&folder.asset_path],
+            AssetField::Files(assets, _, _) | AssetField::Array(assets, _) => {
+                assets.asset_paths.iter().map(String::as_str).collect()
+            }
+            AssetField::TextureAtlas(asset) => vec![&asset.asset_path],
+            AssetField::Region(region) => vec![&region.asset_path],
+            AssetField::AudioBank(audio_bank) => {
+                vec![&audio_bank.asset_path, &audio_bank.manifest_path]
+            }
+            AssetField::PathList(path_list) => vec![&path_list.list_path],
+            AssetField::Platform(_, asset)
+            | AssetField::OnFail(_, asset)
+            | AssetField::Weighted(_, asset)
+            | AssetField::Sorted(asset)
+            | AssetField::LoadingField(_, asset) => asset.asset_paths(),
+            AssetField::ConstFiles(..)
+            | AssetField::Derived(_)
+            | AssetField::Dynamic(_)
+            | AssetField::OptionalDynamic(_)
+            | AssetField::DynamicFileCollection(..)
+            | AssetField::OptionalDynamicFileCollection(..)
+            | AssetField::DynamicField(_)
+            | AssetField::OptionalDynamicField(_)
+            | AssetField::Skipped(_)
+            | AssetField::FromResource(_)
+            | AssetField::Uuid(_)
+            | AssetField::Absolute(_) => vec![],
+        }
+    }
+
+    /// Merge the field of `other` into the field of `self`
+    ///
+    /// Fields holding a collection of handles are extended, skipped fields keep their
+    /// current value and all other fields take the value of `other`.
+    pub(crate) fn attach_token_stream_for_merge(&self, token_stream: TokenStream) -> TokenStream {
+        let field_ident = self.field_ident();
+        match self {
+            AssetField::Folder(..)
+            | AssetField::Files(..)
+            | AssetField::ConstFiles(..)
+            | AssetField::PathList(_)
+            | AssetField::DynamicFileCollection(..) => {
+                quote!(#token_stream self.#field_ident.extend(other.#field_ident);)
+            }
+            AssetField::OptionalDynamicFileCollection(..) => quote!(#token_stream
+                if let Some(other) = other.#field_ident {
+                    match &mut self.#field_ident {
+                        Some(current) => current.extend(other),
+                        None => self.#field_ident = Some(other),
+                    }
+                }
+            ),
+            AssetField::Platform(_, asset)
+            | AssetField::OnFail(_, asset)
+            | AssetField::Weighted(_, asset)
+            | AssetField::Sorted(asset)
+            | AssetField::LoadingField(_, asset) => {
+                asset.attach_token_stream_for_merge(token_stream)
+            }
+            AssetField::Skipped(_) => token_stream,
+            _ => quote!(#token_stream self.#field_ident = other.#field_ident;),
+        }
+    }
+
+    /// Describe the field the way it is configured in its `asset` attribute
+    pub(crate) fn describe(&self) -> String {
+        match self {
+            AssetField::Basic(asset) => format!("path = {:?}", asset.asset_path),
+            AssetField::Folder(folder, typed, mapped) if !folder.expected_keys.is_empty() => {
+                format!(
+                    "path = {:?}, {}, expect({})",
+                    folder.asset_path,
+                    describe_collection(typed, mapped),
+                    folder
+                        .expected_keys
+                        .iter()
+                        .map(|key| format!("{key:?}"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            }
+            AssetField::Folder(folder, typed, mapped) => match &folder.map_key {
+                Some(map_key) => format!(
+                    "path = {:?}, {}, map_key = {:?}",
+                    folder.asset_path,
+                    describe_collection(typed, mapped),
+                    map_key.to_string().replace(' ', "")
+                ),
+                None => format!(
+                    "path = {:?}, {}",
+                    folder.asset_path,
+                    describe_collection(typed, mapped)
+                ),
+            },
+            AssetField::Files(assets, typed, mapped) => format!(
+                "paths({}), {}",
+                assets
+                    .asset_paths
+                    .iter()
+                    .map(|path| format!("{path:?}"))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                describe_collection(typed, mapped)
+            ),
+            AssetField::Array(assets, _) => format!(
+                "paths({})",
+                assets
+                    .asset_paths
+                    .iter()
+                    .map(|path| format!("{path:?}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            AssetField::ConstFiles(files, typed, mapped) => format!(
+                "paths_const = {:?}, {}",
+                files.const_path.to_string().replace(' ', ""),
+                describe_collection(typed, mapped)
+            ),
+            AssetField::TextureAtlas(asset) => format!(
+                "path = {:?}, texture_atlas(tile_size_x = {:?}, tile_size_y = {:?}, columns = {}, rows = {})",
+                asset.asset_path, asset.tile_size_x, asset.tile_size_y, asset.columns, asset.rows
+            ),
+            AssetField::StandardMaterial(asset) => {
+                format!("path = {:?}, standard_material", asset.asset_path)
+            }
+            AssetField::ColorMaterial(asset) => {
+                format!("path = {:?}, color_material", asset.asset_path)
+            }
+            AssetField::Mipmapped(asset) => format!("path = {:?}, mipmaps", asset.asset_path),
+            AssetField::Shader(asset) => format!("path = {:?}, shader", asset.asset_path),
+            AssetField::Hashed(asset) => format!("path = {:?}, hash", asset.asset_path),
+            AssetField::Animations(asset) => {
+                format!("path = {:?}, animations", asset.asset_path)
+            }
+            AssetField::GltfMaterials(asset) => {
+                format!("path = {:?}, gltf_materials", asset.asset_path)
+            }
+            AssetField::AudioDuration(asset) => {
+                format!("path = {:?}, duration", asset.asset_path)
+            }
+            AssetField::Absolute(asset) => format!("path = {:?}, absolute", asset.asset_path),
+            AssetField::Region(region) => format!(
+                "path = {:?}, region(x = {}, y = {}, w = {}, h = {})",
+                region.asset_path, region.x, region.y, region.width, region.height
+            ),
+            AssetField::AudioBank(audio_bank) => format!(
+                "path = {:?}, audio_bank = {:?}",
+                audio_bank.asset_path, audio_bank.manifest_path
+            ),
+            AssetField::PathList(path_list) => format!("path_list = {:?}", path_list.list_path),
+            AssetField::Derived(derived) => {
+                let asset = match &derived.asset {
+                    DerivedAsset::StandardMaterial => "standard_material".to_owned(),
+                    DerivedAsset::ColorMaterial => "color_material".to_owned(),
+                    DerivedAsset::TextureAtlas(grid) => format!(
+                        "texture_atlas(tile_size_x = {:?}, tile_size_y = {:?}, columns = {}, rows = {})",
+                        grid.tile_size_x, grid.tile_size_y, grid.columns, grid.rows
+                    ),
+                };
+                format!("{asset}, derive_from = {:?}", derived.source.to_string())
+            }
+            AssetField::Dynamic(dynamic) => dynamic.key.describe(),
+            AssetField::OptionalDynamic(dynamic) => format!("{}, optional", dynamic.key.describe()),
+            AssetField::DynamicFileCollection(dynamic, typed, mapped) => format!(
+                "{}, {}",
+                dynamic.key.describe(),
+                describe_collection(typed, mapped)
+            ),
+            AssetField::OptionalDynamicFileCollection(dynamic, typed, mapped) => format!(
+                "{}, {}, optional",
+                dynamic.key.describe(),
+                describe_collection(typed, mapped)
+            ),
+            AssetField::DynamicField(dynamic) => format!("{}, dynamic_field", dynamic.key.describe()),
+            AssetField::OptionalDynamicField(dynamic) => {
+                format!("{}, dynamic_field, optional", dynamic.key.describe())
+            }
+            AssetField::Skipped(_) => "skip".to_owned(),
+            AssetField::FromResource(from_resource) => format!(
+                "from_resource = \"{}::{}\"",
+                from_resource.resource.to_string().replace(' ', ""),
+                from_resource.source
+            ),
+            AssetField::Uuid(asset) => format!("uuid = \"{}\"", format_uuid(asset.uuid)),
+            AssetField::Platform(platform, asset) => {
+                format!("{}, {}", asset.describe(), platform.attribute())
             }
-            AssetField::Files(assets, _, _) => {
-                let asset_paths = assets.asset_paths.clone();
-                quote!(#token_stream #(handles.push(asset_server.load_untyped(#asset_paths)));*;)
+            AssetField::OnFail(fallback, asset) => format!(
+                "{}, on_fail = {:?}",
+                asset.describe(),
+                fallback.0.to_string().replace(' ', "")
+            ),
+            AssetField::Weighted(weight, asset) => {
+                format!("{}, weight = {weight:?}", asset.describe())
             }
+            AssetField::Sorted(asset) => format!("{}, sort = \"path\"", asset.describe()),
+            AssetField::LoadingField(_, asset) => asset.describe(),
         }
     }
 }
 
+/// Hyphenated, lowercase form of a UUID
+fn format_uuid(uuid: u128) -> String {
+    let hex = format!("{uuid:032x}");
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
+fn describe_collection(typed: &Typed, mapped: &Mapped) -> String {
+    match (typed, mapped) {
+        (Typed::No, Mapped::No) => "collection".to_owned(),
+        (Typed::Yes, Mapped::No) => "collection(typed)".to_owned(),
+        (Typed::No, Mapped::Yes) => "collection(mapped)".to_owned(),
+        (Typed::Yes, Mapped::Yes) => "collection(typed, mapped)".to_owned(),
+    }
+}
+
+/// Expression for the transformed path of an asset while creating a collection
+fn creation_path(path: &str) -> TokenStream {
+    quote!(::bevy_asset_loader::asset_collection::transform_asset_path(Some(&*asset_keys), world.get_resource(), #path).as_str())
+}
+
+/// Expression for the transformed path of an asset while loading deferred assets of a collection
+fn deferred_loading_path(path: &str) -> TokenStream {
+    quote!(::bevy_asset_loader::asset_collection::transform_asset_path(world.get_resource(), world.get_resource(), #path).as_str())
+}
+
+/// Expression for the transformed path of an asset while loading a collection
+fn loading_path(path: &str) -> TokenStream {
+    quote!(::bevy_asset_loader::asset_collection::transform_asset_path(Some(&*asset_keys), path_transform.as_deref(), #path).as_str())
+}
+
+fn attach_load_request(
+    token_stream: TokenStream,
+    request_type: &TokenStream,
+    loading: TokenStream,
+) -> TokenStream {
+    quote!(
+        #token_stream requests.push((#request_type, Box::new(|world: &mut ::bevy::ecs::world::World| {
+            let collecting_errors = ::bevy_asset_loader::asset_collection::collecting_errors(world).is_some();
+            #[allow(unused_mut)]
+            let mut recoverable: Vec<(&'static str, Vec<::bevy::asset::HandleId>)> = vec![];
+            #[allow(unused_mut)]
+            let mut weighted: Vec<(f32, Vec<::bevy::asset::HandleId>)> = vec![];
+            let handles = {
+                let cell = world.cell();
+                let asset_server = cell.get_resource::<::bevy::prelude::AssetServer>().expect("Cannot get AssetServer");
+                let asset_keys = cell.get_resource::<bevy_asset_loader::prelude::DynamicAssets>().expect("Cannot get bevy_asset_loader::prelude::DynamicAssets");
+                let path_transform = cell.get_resource::<::bevy_asset_loader::asset_collection::AssetPathTransform>();
+                let mut handles = vec![];
+                #loading
+                handles
+            };
+            ::bevy_asset_loader::asset_collection::add_recoverable_handles::<Self>(world, recoverable);
+            ::bevy_asset_loader::asset_collection::add_handle_weights::<Self>(world, weighted);
+            handles
+        })));
+    )
+}
+
 #[derive(Default, Debug)]
 pub(crate) struct AssetBuilder {
     pub field_ident: Option<Ident>,
     pub asset_path: Option<String>,
     pub asset_paths: Option<Vec<String>>,
+    pub asset_paths_const: Option<TokenStream>,
     pub is_standard_material: bool,
+    pub is_color_material: bool,
+    pub is_mipmaps: bool,
+    pub is_shader: bool,
+    pub is_hashed: bool,
+    pub is_animations: bool,
+    pub is_gltf_materials: bool,
+    pub is_duration: bool,
+    pub is_absolute: bool,
     pub is_optional: bool,
     pub is_collection: bool,
     pub is_typed: bool,
     pub is_mapped: bool,
+    pub is_skipped: bool,
+    pub is_dynamic_field: bool,
+    pub audio_bank_manifest: Option<String>,
+    pub path_list: Option<String>,
+    pub derive_from: Option<Ident>,
+    pub from_resource: Option<(TokenStream, Ident)>,
+    pub asset_uuid: Option<u128>,
+    pub array: Option<ArrayType>,
+    pub expected_keys: Option<Vec<String>>,
+    pub map_key: Option<TokenStream>,
     pub key: Option<String>,
+    pub key_enum: Option<TokenStream>,
     pub tile_size_x: Option<f32>,
     pub tile_size_y: Option<f32>,
     pub columns: Option<usize>,
@@ -449,10 +1593,149 @@ pub(crate) struct AssetBuilder {
     pub padding_y: Option<f32>,
     pub offset_x: Option<f32>,
     pub offset_y: Option<f32>,
+    pub region_x: Option<u32>,
+    pub region_y: Option<u32>,
+    pub region_width: Option<u32>,
+    pub region_height: Option<u32>,
+    pub platform: Option<Platform>,
+    pub on_fail: Option<TokenStream>,
+    pub weight: Option<f32>,
+    pub is_sorted: bool,
+    /// Asset type of a field of type `LoadingField<T>`
+    pub loading_field: Option<TokenStream>,
 }
 
 impl AssetBuilder {
     pub(crate) fn build(self) -> Result<AssetField, Vec<ParseFieldError>> {
+        if let Some(asset_type) = self.loading_field {
+            let asset = AssetBuilder {
+                loading_field: None,
+                ..self
+            }
+            .build()
+            .map_err(|errors| {
+                errors
+                    .into_iter()
+                    .map(|error| match error {
+                        ParseFieldError::NoAttributes => {
+                            ParseFieldError::LoadingFieldRequiresHandle
+                        }
+                        error => error,
+                    })
+                    .collect::<Vec<_>>()
+            })?;
+            return match asset {
+                AssetField::Basic(_) | AssetField::Dynamic(_) => Ok(AssetField::LoadingField(
+                    LoadingFieldType(asset_type),
+                    Box::new(asset),
+                )),
+                _ => Err(vec![ParseFieldError::LoadingFieldRequiresHandle]),
+            };
+        }
+        if let Some(platform) = self.platform {
+            let asset = AssetBuilder {
+                platform: None,
+                ..self
+            }
+            .build()
+            .map_err(|errors| {
+                errors
+                    .into_iter()
+                    .map(|error| match error {
+                        ParseFieldError::NoAttributes => ParseFieldError::PlatformRequiresAsset,
+                        error => error,
+                    })
+                    .collect::<Vec<_>>()
+            })?;
+            return match asset {
+                AssetField::Skipped(_)
+                | AssetField::FromResource(_)
+                | AssetField::Uuid(_)
+                | AssetField::Derived(_) => Err(vec![ParseFieldError::PlatformRequiresAsset]),
+                asset => Ok(AssetField::Platform(platform, Box::new(asset))),
+            };
+        }
+        if let Some(fallback) = self.on_fail {
+            let asset = AssetBuilder {
+                on_fail: None,
+                ..self
+            }
+            .build()
+            .map_err(|errors| {
+                errors
+                    .into_iter()
+                    .map(|error| match error {
+                        ParseFieldError::NoAttributes => ParseFieldError::OnFailRequiresAsset,
+                        error => error,
+                    })
+                    .collect::<Vec<_>>()
+            })?;
+            return match asset {
+                AssetField::Skipped(_)
+                | AssetField::FromResource(_)
+                | AssetField::Uuid(_)
+                | AssetField::Derived(_) => Err(vec![ParseFieldError::OnFailRequiresAsset]),
+                asset => Ok(AssetField::OnFail(Fallback(fallback), Box::new(asset))),
+            };
+        }
+        if let Some(weight) = self.weight {
+            let asset = AssetBuilder {
+                weight: None,
+                ..self
+            }
+            .build()
+            .map_err(|errors| {
+                errors
+                    .into_iter()
+                    .map(|error| match error {
+                        ParseFieldError::NoAttributes => ParseFieldError::WeightRequiresAsset,
+                        error => error,
+                    })
+                    .collect::<Vec<_>>()
+            })?;
+            return match asset {
+                AssetField::Skipped(_)
+                | AssetField::FromResource(_)
+                | AssetField::Uuid(_)
+                | AssetField::Derived(_) => Err(vec![ParseFieldError::WeightRequiresAsset]),
+                asset => Ok(AssetField::Weighted(weight, Box::new(asset))),
+            };
+        }
+        if self.is_sorted {
+            let asset = AssetBuilder {
+                is_sorted: false,
+                ..self
+            }
+            .build()
+            .map_err(|errors| {
+                errors
+                    .into_iter()
+                    .map(|error| match error {
+                        ParseFieldError::NoAttributes => ParseFieldError::SortRequiresCollection,
+                        error => error,
+                    })
+                    .collect::<Vec<_>>()
+            })?;
+            return match asset {
+                AssetField::Folder(_, _, Mapped::No)
+                | AssetField::DynamicFileCollection(_, _, Mapped::No)
+                | AssetField::OptionalDynamicFileCollection(_, _, Mapped::No) => {
+                    Ok(AssetField::Sorted(Box::new(asset)))
+                }
+                _ => Err(vec![ParseFieldError::SortRequiresCollection]),
+            };
+        }
+        if self.key.is_some() && self.key_enum.is_some() {
+            return Err(vec![ParseFieldError::KeyAttributeStandsAlone]);
+        }
+        let key = self
+            .key
+            .map(DynamicAssetKey::Str)
+            .or(self.key_enum.map(DynamicAssetKey::Enum));
+        let has_region = self.region_x.is_some()
+            || self.region_y.is_some()
+            || self.region_width.is_some()
+            || self.region_height.is_some();
         let mut missing_fields = vec![];
         if self.tile_size_x.is_none() {
             missing_fields.push(format!(
@@ -482,61 +1765,559 @@ impl AssetBuilder {
                 TextureAtlasAttribute::ROWS
             ));
         }
-        if self.asset_path.is_none() && self.asset_paths.is_none() && self.key.is_none() {
-            return Err(vec![ParseFieldError::NoAttributes]);
+        if self.is_skipped {
+            if self.asset_path.is_some()
+                || self.asset_paths.is_some()
+                || self.asset_paths_const.is_some()
+                || key.is_some()
+                || missing_fields.len() < 4
+                || self.padding_x.is_some()
+                || self.padding_y.is_some()
+                || self.offset_x.is_some()
+                || self.offset_y.is_some()
+                || self.is_standard_material
+                || self.is_color_material
+                || self.is_mipmaps
+                || self.is_shader
+                || self.is_hashed
+                || self.is_animations
+                || self.is_gltf_materials
+                || self.is_duration
+                || self.is_absolute
+                || has_region
+                || self.is_optional
+                || self.is_collection
+                || self.is_typed
+                || self.is_mapped
+                || self.expected_keys.is_some()
+                || self.map_key.is_some()
+                || self.is_dynamic_field
+                || self.audio_bank_manifest.is_some()
+                || self.path_list.is_some()
+                || self.derive_from.is_some()
+                || self.from_resource.is_some()
+                || self.asset_uuid.is_some()
+            {
+                return Err(vec![ParseFieldError::SkipAttributeStandsAlone]);
+            }
+            return Ok(AssetField::Skipped(self.field_ident.unwrap()));
         }
-        if self.key.is_some()
-            && (self.asset_path.is_some()
+        if let Some((resource, source)) = self.from_resource {
+            if self.asset_path.is_some()
                 || self.asset_paths.is_some()
+                || self.asset_paths_const.is_some()
+                || key.is_some()
                 || missing_fields.len() < 4
                 || self.padding_x.is_some()
                 || self.padding_y.is_some()
                 || self.offset_x.is_some()
                 || self.offset_y.is_some()
-                || self.is_standard_material)
-        {
-            return Err(vec![ParseFieldError::KeyAttributeStandsAlone]);
+                || self.is_standard_material
+                || self.is_color_material
+                || self.is_mipmaps
+                || self.is_shader
+                || self.is_hashed
+                || self.is_animations
+                || self.is_gltf_materials
+                || self.is_duration
+                || self.is_absolute
+                || has_region
+                || self.is_optional
+                || self.is_collection
+                || self.is_typed
+                || self.is_mapped
+                || self.expected_keys.is_some()
+                || self.map_key.is_some()
+                || self.is_dynamic_field
+                || self.audio_bank_manifest.is_some()
+                || self.path_list.is_some()
+                || self.derive_from.is_some()
+                || self.asset_uuid.is_some()
+            {
+                return Err(vec![ParseFieldError::FromResourceStandsAlone]);
+            }
+            return Ok(AssetField::FromResource(ResourceAssetField {
+                field_ident: self.field_ident.unwrap(),
+                resource,
+                source,
+            }));
         }
-        if self.is_optional && self.key.is_none() {
-            return Err(vec![ParseFieldError::OnlyDynamicCanBeOptional]);
+        if let Some(uuid) = self.asset_uuid {
+            if self.asset_path.is_some()
+                || self.asset_paths.is_some()
+                || self.asset_paths_const.is_some()
+                || key.is_some()
+                || missing_fields.len() < 4
+                || self.padding_x.is_some()
+                || self.padding_y.is_some()
+                || self.offset_x.is_some()
+                || self.offset_y.is_some()
+                || self.is_standard_material
+                || self.is_color_material
+                || self.is_mipmaps
+                || self.is_shader
+                || self.is_hashed
+                || self.is_animations
+                || self.is_gltf_materials
+                || self.is_duration
+                || self.is_absolute
+                || has_region
+                || self.is_optional
+                || self.is_collection
+                || self.is_typed
+                || self.is_mapped
+                || self.expected_keys.is_some()
+                || self.map_key.is_some()
+                || self.is_dynamic_field
+                || self.audio_bank_manifest.is_some()
+                || self.path_list.is_some()
+                || self.derive_from.is_some()
+            {
+                return Err(vec![ParseFieldError::UuidStandsAlone]);
+            }
+            return Ok(AssetField::Uuid(UuidAssetField {
+                field_ident: self.field_ident.unwrap(),
+                uuid,
+            }));
         }
-        if self.asset_path.is_some() && self.asset_paths.is_some() {
-            return Err(vec![ParseFieldError::PathAndPathsAreExclusive]);
+        if let Some(source) = self.derive_from {
+            if self.asset_path.is_some()
+                || self.asset_paths.is_some()
+                || self.asset_paths_const.is_some()
+                || key.is_some()
+                || self.is_mipmaps
+                || self.is_shader
+                || self.is_hashed
+                || self.is_animations
+                || self.is_gltf_materials
+                || self.is_duration
+                || self.is_absolute
+                || has_region
+                || self.is_optional
+                || self.is_collection
+                || self.is_typed
+                || self.is_mapped
+                || self.is_dynamic_field
+                || self.expected_keys.is_some()
+                || self.map_key.is_some()
+                || self.audio_bank_manifest.is_some()
+                || self.path_list.is_some()
+            {
+                return Err(vec![ParseFieldError::DeriveFromRequiresImageAsset]);
+            }
+            let asset = match (
+                self.is_standard_material,
+                self.is_color_material,
+                missing_fields.len(),
+            ) {
+                (true, false, 4) => DerivedAsset::StandardMaterial,
+                (false, true, 4) => DerivedAsset::ColorMaterial,
+                (false, false, 0) => DerivedAsset::TextureAtlas(TextureAtlasGrid {
+                    tile_size_x: self.tile_size_x.unwrap(),
+                    tile_size_y: self.tile_size_y.unwrap(),
+                    columns: self.columns.unwrap(),
+                    rows: self.rows.unwrap(),
+                    padding_x: self.padding_x.unwrap_or_default(),
+                    padding_y: self.padding_y.unwrap_or_default(),
+                    offset_x: self.offset_x.unwrap_or_default(),
+                    offset_y: self.offset_y.unwrap_or_default(),
+                }),
+                (false, false, 1..=3) => {
+                    return Err(vec![ParseFieldError::MissingAttributes(missing_fields)])
+                }
+                _ => return Err(vec![ParseFieldError::DeriveFromRequiresImageAsset]),
+            };
+            return Ok(AssetField::Derived(DerivedAssetField {
+                field_ident: self.field_ident.unwrap(),
+                source,
+                asset,
+            }));
         }
-        if missing_fields.len() == 4 {
-            if self.key.is_some() {
-                return if self.is_optional {
-                    if self.is_collection {
+        if let Some(list_path) = self.path_list {
+            if self.asset_path.is_some()
+                || self.asset_paths.is_some()
+                || self.asset_paths_const.is_some()
+                || key.is_some()
+                || missing_fields.len() < 4
+                || self.padding_x.is_some()
+                || self.padding_y.is_some()
+                || self.offset_x.is_some()
+                || self.offset_y.is_some()
+                || self.is_standard_material
+                || self.is_color_material
+                || self.is_mipmaps
+                || self.is_shader
+                || self.is_hashed
+                || self.is_animations
+                || self.is_gltf_materials
+                || self.is_duration
+                || self.is_absolute
+                || has_region
+                || self.is_optional
+                || self.is_collection
+                || self.is_typed
+                || self.is_mapped
+                || self.expected_keys.is_some()
+                || self.map_key.is_some()
+                || self.is_dynamic_field
+                || self.audio_bank_manifest.is_some()
+            {
+                return Err(vec![ParseFieldError::PathListStandsAlone]);
+            }
+            return Ok(AssetField::PathList(PathListAssetField {
+                field_ident: self.field_ident.unwrap(),
+                list_path,
+            }));
+        }
+        if self.asset_path.is_none()
+            && self.asset_paths.is_none()
+            && self.asset_paths_const.is_none()
+            && key.is_none()
+        {
+            return Err(vec![ParseFieldError::NoAttributes]);
+        }
+        if let Some(array) = self.array {
+            if self.asset_paths.is_none()
+                || self.asset_path.is_some()
+                || self.asset_paths_const.is_some()
+                || key.is_some()
+                || missing_fields.len() < 4
+                || self.padding_x.is_some()
+                || self.padding_y.is_some()
+                || self.offset_x.is_some()
+                || self.offset_y.is_some()
+                || self.is_standard_material
+                || self.is_color_material
+                || self.is_mipmaps
+                || self.is_shader
+                || self.is_hashed
+                || self.is_animations
+                || self.is_gltf_materials
+                || self.is_duration
+                || self.is_absolute
+                || has_region
+                || self.is_optional
+                || self.is_collection
+                || self.is_typed
+                || self.is_mapped
+                || self.expected_keys.is_some()
+                || self.map_key.is_some()
+                || self.is_dynamic_field
+                || self.audio_bank_manifest.is_some()
+            {
+                return Err(vec![ParseFieldError::ArrayRequiresPaths]);
+            }
+            let asset_paths = self.asset_paths.unwrap();
+            if let Some(length) = array.length {
+                if length != asset_paths.len() {
+                    return Err(vec![ParseFieldError::ArrayLengthMismatch(
+                        length,
+                        asset_paths.len(),
+                    )]);
+                }
+            }
+            return Ok(AssetField::Array(
+                MultipleFilesField {
+                    field_ident: self.field_ident.unwrap(),
+                    asset_paths,
+                },
+                array.typed,
+            ));
+        }
+        if key.is_some()
+            && (self.asset_path.is_some()
+                || self.asset_paths.is_some()
+                || self.asset_paths_const.is_some()
+                || missing_fields.len() < 4
+                || self.padding_x.is_some()
+                || self.padding_y.is_some()
+                || self.offset_x.is_some()
+                || self.offset_y.is_some()
+                || self.is_standard_material
+                || self.is_color_material)
+        {
+            return Err(vec![ParseFieldError::KeyAttributeStandsAlone]);
+        }
+        if self.is_optional && key.is_none() {
+            return Err(vec![ParseFieldError::OnlyDynamicCanBeOptional]);
+        }
+        if self.is_dynamic_field
+            && (key.is_none() || self.is_collection || self.is_typed || self.is_mapped)
+        {
+            return Err(vec![ParseFieldError::DynamicFieldRequiresKey]);
+        }
+        if self.asset_path.is_some() && self.asset_paths.is_some() {
+            return Err(vec![ParseFieldError::PathAndPathsAreExclusive]);
+        }
+        if self.asset_paths_const.is_some()
+            && (self.asset_path.is_some()
+                || self.asset_paths.is_some()
+                || missing_fields.len() < 4
+                || self.is_standard_material
+                || self.is_color_material)
+        {
+            return Err(vec![ParseFieldError::PathsConstIsExclusive]);
+        }
+        if self.is_standard_material && self.is_color_material {
+            return Err(vec![ParseFieldError::MaterialAttributesAreExclusive]);
+        }
+        if self.audio_bank_manifest.is_some()
+            && (self.asset_path.is_none()
+                || self.asset_paths.is_some()
+                || self.asset_paths_const.is_some()
+                || key.is_some()
+                || missing_fields.len() < 4
+                || self.is_standard_material
+                || self.is_color_material
+                || self.is_collection
+                || self.is_typed
+                || self.is_mapped)
+        {
+            return Err(vec![ParseFieldError::AudioBankRequiresPath]);
+        }
+        if self.expected_keys.is_some()
+            && (self.asset_path.is_none()
+                || self.asset_paths.is_some()
+                || self.asset_paths_const.is_some()
+                || key.is_some()
+                || missing_fields.len() < 4
+                || self.is_standard_material
+                || self.is_color_material
+                || !self.is_collection
+                || !self.is_mapped)
+        {
+            return Err(vec![ParseFieldError::ExpectRequiresMappedFolder]);
+        }
+        if self.map_key.is_some()
+            && (self.asset_path.is_none()
+                || self.asset_paths.is_some()
+                || self.asset_paths_const.is_some()
+                || key.is_some()
+                || missing_fields.len() < 4
+                || self.is_standard_material
+                || self.is_color_material
+                || !self.is_collection
+                || !self.is_mapped
+                || self.expected_keys.is_some())
+        {
+            return Err(vec![ParseFieldError::MapKeyRequiresMappedFolder]);
+        }
+        if self.is_mipmaps
+            && (self.asset_path.is_none()
+                || self.asset_paths.is_some()
+                || self.asset_paths_const.is_some()
+                || key.is_some()
+                || missing_fields.len() < 4
+                || self.is_standard_material
+                || self.is_color_material
+                || self.is_collection
+                || self.is_typed
+                || self.is_mapped
+                || self.audio_bank_manifest.is_some())
+        {
+            return Err(vec![ParseFieldError::MipmapsRequiresPath]);
+        }
+        if self.is_shader
+            && (self.asset_path.is_none()
+                || self.asset_paths.is_some()
+                || self.asset_paths_const.is_some()
+                || key.is_some()
+                || missing_fields.len() < 4
+                || self.is_standard_material
+                || self.is_color_material
+                || self.is_mipmaps
+                || self.is_collection
+                || self.is_typed
+                || self.is_mapped
+                || self.audio_bank_manifest.is_some())
+        {
+            return Err(vec![ParseFieldError::ShaderRequiresPath]);
+        }
+        if self.is_hashed
+            && (self.asset_path.is_none()
+                || self.asset_paths.is_some()
+                || self.asset_paths_const.is_some()
+                || key.is_some()
+                || missing_fields.len() < 4
+                || self.is_standard_material
+                || self.is_color_material
+                || self.is_mipmaps
+                || self.is_shader
+                || self.is_collection
+                || self.is_typed
+                || self.is_mapped
+                || self.audio_bank_manifest.is_some())
+        {
+            return Err(vec![ParseFieldError::HashRequiresPath]);
+        }
+        if self.is_animations
+            && (self.asset_path.is_none()
+                || self.asset_paths.is_some()
+                || self.asset_paths_const.is_some()
+                || key.is_some()
+                || missing_fields.len() < 4
+                || self.is_standard_material
+                || self.is_color_material
+                || self.is_mipmaps
+                || self.is_shader
+                || self.is_hashed
+                || self.is_gltf_materials
+                || self.is_collection
+                || self.is_typed
+                || self.is_mapped
+                || self.audio_bank_manifest.is_some())
+        {
+            return Err(vec![ParseFieldError::AnimationsRequirePath]);
+        }
+        if self.is_gltf_materials
+            && (self.asset_path.is_none()
+                || self.asset_paths.is_some()
+                || self.asset_paths_const.is_some()
+                || key.is_some()
+                || missing_fields.len() < 4
+                || self.is_standard_material
+                || self.is_color_material
+                || self.is_mipmaps
+                || self.is_shader
+                || self.is_hashed
+                || self.is_animations
+                || self.is_collection
+                || self.is_typed
+                || self.is_mapped
+                || self.audio_bank_manifest.is_some())
+        {
+            return Err(vec![ParseFieldError::GltfMaterialsRequirePath]);
+        }
+        if self.is_duration
+            && (self.asset_path.is_none()
+                || self.asset_paths.is_some()
+                || self.asset_paths_const.is_some()
+                || key.is_some()
+                || missing_fields.len() < 4
+                || self.is_standard_material
+                || self.is_color_material
+                || self.is_mipmaps
+                || self.is_shader
+                || self.is_hashed
+                || self.is_animations
+                || self.is_gltf_materials
+                || self.is_collection
+                || self.is_typed
+                || self.is_mapped
+                || self.audio_bank_manifest.is_some())
+        {
+            return Err(vec![ParseFieldError::DurationRequiresPath]);
+        }
+        if self.is_absolute
+            && (self.asset_path.is_none()
+                || self.asset_paths.is_some()
+                || self.asset_paths_const.is_some()
+                || key.is_some()
+                || missing_fields.len() < 4
+                || self.is_standard_material
+                || self.is_color_material
+                || self.is_mipmaps
+                || self.is_shader
+                || self.is_hashed
+                || self.is_animations
+                || self.is_gltf_materials
+                || self.is_duration
+                || self.is_collection
+                || self.is_typed
+                || self.is_mapped
+                || self.audio_bank_manifest.is_some())
+        {
+            return Err(vec![ParseFieldError::AbsoluteRequiresPath]);
+        }
+        if has_region {
+            if self.asset_path.is_none()
+                || self.asset_paths.is_some()
+                || self.asset_paths_const.is_some()
+                || key.is_some()
+                || missing_fields.len() < 4
+                || self.is_standard_material
+                || self.is_color_material
+                || self.is_mipmaps
+                || self.is_shader
+                || self.is_hashed
+                || self.is_animations
+                || self.is_gltf_materials
+                || self.is_duration
+                || self.is_absolute
+                || self.is_collection
+                || self.is_typed
+                || self.is_mapped
+                || self.audio_bank_manifest.is_some()
+            {
+                return Err(vec![ParseFieldError::RegionRequiresPath]);
+            }
+            let mut missing_region_fields = vec![];
+            for (value, attribute) in [
+                (self.region_x, RegionAttribute::X),
+                (self.region_y, RegionAttribute::Y),
+                (self.region_width, RegionAttribute::WIDTH),
+                (self.region_height, RegionAttribute::HEIGHT),
+            ] {
+                if value.is_none() {
+                    missing_region_fields.push(format!("{REGION_ATTRIBUTE}/{attribute}"));
+                }
+            }
+            if !missing_region_fields.is_empty() {
+                return Err(vec![ParseFieldError::MissingAttributes(
+                    missing_region_fields,
+                )]);
+            }
+            return Ok(AssetField::Region(RegionAssetField {
+                field_ident: self.field_ident.unwrap(),
+                asset_path: self.asset_path.unwrap(),
+                x: self.region_x.unwrap(),
+                y: self.region_y.unwrap(),
+                width: self.region_width.unwrap(),
+                height: self.region_height.unwrap(),
+            }));
+        }
+        if missing_fields.len() == 4 {
+            if let Some(key) = key {
+                let dynamic = DynamicAssetField {
+                    field_ident: self.field_ident.unwrap(),
+                    key,
+                };
+                return if self.is_dynamic_field {
+                    if self.is_optional {
+                        Ok(AssetField::OptionalDynamicField(dynamic))
+                    } else {
+                        Ok(AssetField::DynamicField(dynamic))
+                    }
+                } else if self.is_optional {
+                    if self.is_collection {
                         Ok(AssetField::OptionalDynamicFileCollection(
-                            DynamicAssetField {
-                                field_ident: self.field_ident.unwrap(),
-                                key: self.key.unwrap(),
-                            },
+                            dynamic,
                             self.is_typed.into(),
                             self.is_mapped.into(),
                         ))
                     } else {
-                        Ok(AssetField::OptionalDynamic(DynamicAssetField {
-                            field_ident: self.field_ident.unwrap(),
-                            key: self.key.unwrap(),
-                        }))
+                        Ok(AssetField::OptionalDynamic(dynamic))
                     }
                 } else if self.is_collection {
                     Ok(AssetField::DynamicFileCollection(
-                        DynamicAssetField {
-                            field_ident: self.field_ident.unwrap(),
-                            key: self.key.unwrap(),
-                        },
+                        dynamic,
                         self.is_typed.into(),
                         self.is_mapped.into(),
                     ))
                 } else {
-                    Ok(AssetField::Dynamic(DynamicAssetField {
-                        field_ident: self.field_ident.unwrap(),
-                        key: self.key.unwrap(),
-                    }))
+                    Ok(AssetField::Dynamic(dynamic))
                 };
             }
+            if let Some(const_path) = self.asset_paths_const {
+                return Ok(AssetField::ConstFiles(
+                    ConstFilesField {
+                        field_ident: self.field_ident.unwrap(),
+                        const_path,
+                    },
+                    self.is_typed.into(),
+                    self.is_mapped.into(),
+                ));
+            }
             if self.asset_paths.is_some() {
                 return Ok(AssetField::Files(
                     MultipleFilesField {
@@ -549,14 +2330,23 @@ impl AssetBuilder {
             }
             if self.is_collection {
                 return Ok(AssetField::Folder(
-                    BasicAssetField {
+                    FolderAssetField {
                         field_ident: self.field_ident.unwrap(),
                         asset_path: self.asset_path.unwrap(),
+                        expected_keys: self.expected_keys.unwrap_or_default(),
+                        map_key: self.map_key,
                     },
                     self.is_typed.into(),
                     self.is_mapped.into(),
                 ));
             }
+            if let Some(manifest_path) = self.audio_bank_manifest {
+                return Ok(AssetField::AudioBank(AudioBankAssetField {
+                    field_ident: self.field_ident.unwrap(),
+                    asset_path: self.asset_path.unwrap(),
+                    manifest_path,
+                }));
+            }
             let asset = BasicAssetField {
                 field_ident: self.field_ident.unwrap(),
                 asset_path: self.asset_path.unwrap(),
@@ -564,6 +2354,30 @@ impl AssetBuilder {
             if self.is_standard_material {
                 return Ok(AssetField::StandardMaterial(asset));
             }
+            if self.is_color_material {
+                return Ok(AssetField::ColorMaterial(asset));
+            }
+            if self.is_mipmaps {
+                return Ok(AssetField::Mipmapped(asset));
+            }
+            if self.is_shader {
+                return Ok(AssetField::Shader(asset));
+            }
+            if self.is_hashed {
+                return Ok(AssetField::Hashed(asset));
+            }
+            if self.is_animations {
+                return Ok(AssetField::Animations(asset));
+            }
+            if self.is_gltf_materials {
+                return Ok(AssetField::GltfMaterials(asset));
+            }
+            if self.is_duration {
+                return Ok(AssetField::AudioDuration(asset));
+            }
+            if self.is_absolute {
+                return Ok(AssetField::Absolute(asset));
+            }
             return Ok(AssetField::Basic(asset));
         }
         if missing_fields.is_empty() {
@@ -590,205 +2404,1281 @@ mod test {
     use proc_macro2::Span;
 
     #[test]
-    fn basic_asset() {
+    fn basic_asset() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/image.png".to_owned()),
+            ..Default::default()
+        };
+
+        let asset = builder.build().expect("This should be a valid BasicAsset");
+        assert_eq!(
+            asset,
+            AssetField::Basic(BasicAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                asset_path: "some/image.png".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn standard_material() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/image.png".to_owned()),
+            is_standard_material: true,
+            ..Default::default()
+        };
+
+        let asset = builder.build().expect("This should be a valid BasicAsset");
+        assert_eq!(
+            asset,
+            AssetField::StandardMaterial(BasicAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                asset_path: "some/image.png".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn color_material() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/image.png".to_owned()),
+            is_color_material: true,
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("This should be a valid ColorMaterial");
+        assert_eq!(
+            asset,
+            AssetField::ColorMaterial(BasicAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                asset_path: "some/image.png".to_owned()
+            })
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/image.png".to_owned()),
+            is_color_material: true,
+            is_standard_material: true,
+            ..Default::default()
+        };
+        assert!(builder.build().is_err());
+    }
+
+    #[test]
+    fn folder() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/folder".to_owned()),
+            is_collection: true,
+            ..Default::default()
+        };
+
+        let asset = builder.build().expect("This should be a valid BasicAsset");
+        assert_eq!(
+            asset,
+            AssetField::Folder(
+                FolderAssetField {
+                    field_ident: Ident::new("test", Span::call_site()),
+                    asset_path: "some/folder".to_owned(),
+                    expected_keys: vec![],
+                    map_key: None,
+                },
+                Typed::No,
+                Mapped::No
+            )
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/folder".to_owned()),
+            is_collection: true,
+            is_typed: true,
+            ..Default::default()
+        };
+
+        let asset = builder.build().expect("This should be a valid BasicAsset");
+        assert_eq!(
+            asset,
+            AssetField::Folder(
+                FolderAssetField {
+                    field_ident: Ident::new("test", Span::call_site()),
+                    asset_path: "some/folder".to_owned(),
+                    expected_keys: vec![],
+                    map_key: None,
+                },
+                Typed::Yes,
+                Mapped::No
+            )
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/folder".to_owned()),
+            is_collection: true,
+            is_mapped: true,
+            ..Default::default()
+        };
+
+        let asset = builder.build().expect("This should be a valid BasicAsset");
+        assert_eq!(
+            asset,
+            AssetField::Folder(
+                FolderAssetField {
+                    field_ident: Ident::new("test", Span::call_site()),
+                    asset_path: "some/folder".to_owned(),
+                    expected_keys: vec![],
+                    map_key: None,
+                },
+                Typed::No,
+                Mapped::Yes
+            )
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/folder".to_owned()),
+            is_collection: true,
+            is_typed: true,
+            is_mapped: true,
+            ..Default::default()
+        };
+
+        let asset = builder.build().expect("This should be a valid BasicAsset");
+        assert_eq!(
+            asset,
+            AssetField::Folder(
+                FolderAssetField {
+                    field_ident: Ident::new("test", Span::call_site()),
+                    asset_path: "some/folder".to_owned(),
+                    expected_keys: vec![],
+                    map_key: None,
+                },
+                Typed::Yes,
+                Mapped::Yes
+            )
+        );
+    }
+
+    #[test]
+    fn dynamic_asset() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            key: Some("some.asset.key".to_owned()),
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("This should be a valid DynamicAsset");
+        assert_eq!(
+            asset,
+            AssetField::Dynamic(DynamicAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                key: DynamicAssetKey::Str("some.asset.key".to_owned())
+            })
+        );
+    }
+
+    #[test]
+    fn paths_and_path_exclusive() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some.asset".to_owned()),
+            asset_paths: Some(vec!["some.asset".to_owned()]),
+            ..Default::default()
+        };
+
+        let asset = builder.build().expect_err("Should be pasing error");
+        assert!(variant_eq(
+            asset.get(0).unwrap(),
+            &ParseFieldError::PathAndPathsAreExclusive
+        ));
+    }
+
+    #[test]
+    fn multiple_files() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_paths: Some(vec!["some.asset".to_owned()]),
+            ..Default::default()
+        };
+
+        let asset = builder.build().expect("This should be a valid Files asset");
+        assert_eq!(
+            asset,
+            AssetField::Files(
+                MultipleFilesField {
+                    field_ident: Ident::new("test", Span::call_site()),
+                    asset_paths: vec!["some.asset".to_owned()]
+                },
+                Typed::No,
+                Mapped::No
+            )
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_paths: Some(vec!["some.asset".to_owned()]),
+            is_typed: true,
+            ..Default::default()
+        };
+
+        let asset = builder.build().expect("This should be a valid Files asset");
+        assert_eq!(
+            asset,
+            AssetField::Files(
+                MultipleFilesField {
+                    field_ident: Ident::new("test", Span::call_site()),
+                    asset_paths: vec!["some.asset".to_owned()]
+                },
+                Typed::Yes,
+                Mapped::No
+            )
+        );
+    }
+
+    #[test]
+    fn const_files() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_paths_const: Some(quote!(ICONS)),
+            is_collection: true,
+            is_typed: true,
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("This should be a valid ConstFiles field");
+        assert_eq!(
+            asset,
+            AssetField::ConstFiles(
+                ConstFilesField {
+                    field_ident: Ident::new("test", Span::call_site()),
+                    const_path: quote!(ICONS),
+                },
+                Typed::Yes,
+                Mapped::No
+            )
+        );
+        assert_eq!(
+            asset.describe(),
+            r#"paths_const = "ICONS", collection(typed)"#
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_paths_const: Some(quote!(ICONS)),
+            asset_path: Some("some/image.png".to_owned()),
+            ..Default::default()
+        };
+        assert!(builder.build().is_err());
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_paths_const: Some(quote!(ICONS)),
+            key: Some("icons".to_owned()),
+            ..Default::default()
+        };
+        assert!(builder.build().is_err());
+    }
+
+    #[test]
+    fn folder_with_expected_keys() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("ui".to_owned()),
+            is_collection: true,
+            is_typed: true,
+            is_mapped: true,
+            expected_keys: Some(vec!["button".to_owned(), "panel".to_owned()]),
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("This should be a valid Folder field");
+        assert_eq!(
+            asset,
+            AssetField::Folder(
+                FolderAssetField {
+                    field_ident: Ident::new("test", Span::call_site()),
+                    asset_path: "ui".to_owned(),
+                    expected_keys: vec!["button".to_owned(), "panel".to_owned()],
+                    map_key: None,
+                },
+                Typed::Yes,
+                Mapped::Yes
+            )
+        );
+        assert_eq!(
+            asset.describe(),
+            r#"path = "ui", collection(typed, mapped), expect("button", "panel")"#
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("ui".to_owned()),
+            is_collection: true,
+            expected_keys: Some(vec!["button".to_owned()]),
+            ..Default::default()
+        };
+        assert!(builder.build().is_err());
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_paths: Some(vec!["ui/button.png".to_owned()]),
+            is_collection: true,
+            is_mapped: true,
+            expected_keys: Some(vec!["button".to_owned()]),
+            ..Default::default()
+        };
+        assert!(builder.build().is_err());
+    }
+
+    #[test]
+    fn merge_extends_collections() {
+        let folder = AssetBuilder {
+            field_ident: Some(Ident::new("cards", Span::call_site())),
+            asset_path: Some("cards".to_owned()),
+            is_collection: true,
+            is_typed: true,
+            ..Default::default()
+        }
+        .build()
+        .expect("This should be a valid Folder field");
+        assert_eq!(
+            folder.attach_token_stream_for_merge(quote!()).to_string(),
+            quote!(self.cards.extend(other.cards);).to_string()
+        );
+
+        let single = AssetBuilder {
+            field_ident: Some(Ident::new("theme", Span::call_site())),
+            asset_path: Some("theme.ogg".to_owned()),
+            ..Default::default()
+        }
+        .build()
+        .expect("This should be a valid Basic field");
+        assert_eq!(
+            single.attach_token_stream_for_merge(quote!()).to_string(),
+            quote!(self.theme = other.theme;).to_string()
+        );
+
+        let skipped = AssetBuilder {
+            field_ident: Some(Ident::new("score", Span::call_site())),
+            is_skipped: true,
+            ..Default::default()
+        }
+        .build()
+        .expect("This should be a valid skipped field");
+        assert!(skipped.attach_token_stream_for_merge(quote!()).is_empty());
+    }
+
+    #[test]
+    fn folder_with_map_key() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("cards", Span::call_site())),
+            asset_path: Some("cards".to_owned()),
+            is_collection: true,
+            is_typed: true,
+            is_mapped: true,
+            map_key: Some(quote!(CardId::from_str)),
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("This should be a valid Folder field");
+        assert_eq!(
+            asset,
+            AssetField::Folder(
+                FolderAssetField {
+                    field_ident: Ident::new("cards", Span::call_site()),
+                    asset_path: "cards".to_owned(),
+                    expected_keys: vec![],
+                    map_key: Some(quote!(CardId::from_str)),
+                },
+                Typed::Yes,
+                Mapped::Yes
+            )
+        );
+        assert_eq!(
+            asset.describe(),
+            r#"path = "cards", collection(typed, mapped), map_key = "CardId::from_str""#
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("cards", Span::call_site())),
+            asset_path: Some("cards".to_owned()),
+            is_collection: true,
+            map_key: Some(quote!(CardId::from_str)),
+            ..Default::default()
+        };
+        assert!(matches!(
+            builder.build().unwrap_err().as_slice(),
+            [ParseFieldError::MapKeyRequiresMappedFolder]
+        ));
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("cards", Span::call_site())),
+            asset_path: Some("cards".to_owned()),
+            is_collection: true,
+            is_mapped: true,
+            expected_keys: Some(vec!["ace".to_owned()]),
+            map_key: Some(quote!(CardId::from_str)),
+            ..Default::default()
+        };
+        assert!(builder.build().is_err());
+    }
+
+    #[test]
+    fn asset_paths() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("sounds", Span::call_site())),
+            asset_paths: Some(vec![
+                "audio/plop.ogg".to_owned(),
+                "audio/yipee.ogg".to_owned(),
+            ]),
+            is_collection: true,
+            platform: Some(Platform::Desktop),
+            ..Default::default()
+        };
+        let asset = builder
+            .build()
+            .expect("This should be a valid platform field");
+        assert_eq!(asset.asset_paths(), ["audio/plop.ogg", "audio/yipee.ogg"]);
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("hero", Span::call_site())),
+            key: Some("hero".to_owned()),
+            ..Default::default()
+        };
+        let asset = builder
+            .build()
+            .expect("This should be a valid dynamic field");
+        assert!(asset.asset_paths().is_empty());
+    }
+
+    #[test]
+    fn dynamic_field() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            key: Some("sprite".to_owned()),
+            is_dynamic_field: true,
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("This should be a valid DynamicField");
+        assert_eq!(
+            asset,
+            AssetField::DynamicField(DynamicAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                key: DynamicAssetKey::Str("sprite".to_owned())
+            })
+        );
+        assert_eq!(asset.describe(), r#"key = "sprite", dynamic_field"#);
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            key: Some("sprite".to_owned()),
+            is_dynamic_field: true,
+            is_optional: true,
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("This should be a valid OptionalDynamicField");
+        assert_eq!(
+            asset,
+            AssetField::OptionalDynamicField(DynamicAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                key: DynamicAssetKey::Str("sprite".to_owned())
+            })
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/image.png".to_owned()),
+            is_dynamic_field: true,
+            ..Default::default()
+        };
+        assert!(builder.build().is_err());
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            key: Some("sprites".to_owned()),
+            is_dynamic_field: true,
+            is_collection: true,
+            ..Default::default()
+        };
+        assert!(builder.build().is_err());
+    }
+
+    #[test]
+    fn path_list() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("sounds", Span::call_site())),
+            path_list: Some("audio/sounds.txt".to_owned()),
+            ..Default::default()
+        };
+
+        let asset = builder.build().expect("This should be a valid PathList");
+        assert_eq!(
+            asset,
+            AssetField::PathList(PathListAssetField {
+                field_ident: Ident::new("sounds", Span::call_site()),
+                list_path: "audio/sounds.txt".to_owned(),
+            })
+        );
+        assert_eq!(asset.describe(), r#"path_list = "audio/sounds.txt""#);
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("sounds", Span::call_site())),
+            path_list: Some("audio/sounds.txt".to_owned()),
+            is_collection: true,
+            is_typed: true,
+            ..Default::default()
+        };
+        assert!(builder.build().is_err());
+    }
+
+    #[test]
+    fn mipmaps() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("tree", Span::call_site())),
+            asset_path: Some("images/tree.png".to_owned()),
+            is_mipmaps: true,
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("This should be a valid Mipmapped asset");
+        assert_eq!(
+            asset,
+            AssetField::Mipmapped(BasicAssetField {
+                field_ident: Ident::new("tree", Span::call_site()),
+                asset_path: "images/tree.png".to_owned(),
+            })
+        );
+        assert_eq!(asset.describe(), r#"path = "images/tree.png", mipmaps"#);
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("tree", Span::call_site())),
+            asset_path: Some("images/tree.png".to_owned()),
+            is_mipmaps: true,
+            is_standard_material: true,
+            ..Default::default()
+        };
+        assert!(builder.build().is_err());
+    }
+
+    #[test]
+    fn shader() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("effect", Span::call_site())),
+            asset_path: Some("shaders/effect.wgsl".to_owned()),
+            is_shader: true,
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("This should be a valid Shader asset");
+        assert_eq!(
+            asset,
+            AssetField::Shader(BasicAssetField {
+                field_ident: Ident::new("effect", Span::call_site()),
+                asset_path: "shaders/effect.wgsl".to_owned(),
+            })
+        );
+        assert_eq!(asset.describe(), r#"path = "shaders/effect.wgsl", shader"#);
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("effect", Span::call_site())),
+            key: Some("effect".to_owned()),
+            is_shader: true,
+            ..Default::default()
+        };
+        assert!(matches!(
+            builder.build(),
+            Err(errors) if matches!(errors[..], [ParseFieldError::ShaderRequiresPath])
+        ));
+    }
+
+    #[test]
+    fn array() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("colors", Span::call_site())),
+            asset_paths: Some(vec!["red.png".to_owned(), "blue.png".to_owned()]),
+            array: Some(ArrayType {
+                length: Some(2),
+                typed: Typed::Yes,
+            }),
+            ..Default::default()
+        };
+
+        let asset = builder.build().expect("This should be a valid array field");
+        assert_eq!(
+            asset,
+            AssetField::Array(
+                MultipleFilesField {
+                    field_ident: Ident::new("colors", Span::call_site()),
+                    asset_paths: vec!["red.png".to_owned(), "blue.png".to_owned()],
+                },
+                Typed::Yes
+            )
+        );
+        assert_eq!(asset.describe(), r#"paths("red.png", "blue.png")"#);
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("colors", Span::call_site())),
+            asset_paths: Some(vec!["red.png".to_owned(), "blue.png".to_owned()]),
+            array: Some(ArrayType {
+                length: Some(4),
+                typed: Typed::Yes,
+            }),
+            ..Default::default()
+        };
+        assert!(matches!(
+            builder.build(),
+            Err(errors) if matches!(errors[..], [ParseFieldError::ArrayLengthMismatch(4, 2)])
+        ));
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("colors", Span::call_site())),
+            asset_paths: Some(vec!["red.png".to_owned(), "blue.png".to_owned()]),
+            is_collection: true,
+            array: Some(ArrayType {
+                length: None,
+                typed: Typed::Yes,
+            }),
+            ..Default::default()
+        };
+        assert!(matches!(
+            builder.build(),
+            Err(errors) if matches!(errors[..], [ParseFieldError::ArrayRequiresPaths])
+        ));
+    }
+
+    #[test]
+    fn uuid() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("noise", Span::call_site())),
+            asset_uuid: Some(0x6c8e2f3a_9b1d_4e7f_8a2c_5d3b1f0e9a47),
+            ..Default::default()
+        };
+
+        let asset = builder.build().expect("This should be a valid uuid field");
+        assert_eq!(
+            asset,
+            AssetField::Uuid(UuidAssetField {
+                field_ident: Ident::new("noise", Span::call_site()),
+                uuid: 0x6c8e2f3a_9b1d_4e7f_8a2c_5d3b1f0e9a47,
+            })
+        );
+        assert_eq!(
+            asset.describe(),
+            r#"uuid = "6c8e2f3a-9b1d-4e7f-8a2c-5d3b1f0e9a47""#
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("noise", Span::call_site())),
+            asset_uuid: Some(0x6c8e2f3a_9b1d_4e7f_8a2c_5d3b1f0e9a47),
+            asset_path: Some("images/noise.png".to_owned()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            builder.build(),
+            Err(errors) if matches!(errors[..], [ParseFieldError::UuidStandsAlone])
+        ));
+    }
+
+    #[test]
+    fn hash() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("level", Span::call_site())),
+            asset_path: Some("levels/one.bin".to_owned()),
+            is_hashed: true,
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("This should be a valid Hashed asset");
+        assert_eq!(
+            asset,
+            AssetField::Hashed(BasicAssetField {
+                field_ident: Ident::new("level", Span::call_site()),
+                asset_path: "levels/one.bin".to_owned(),
+            })
+        );
+        assert_eq!(asset.describe(), r#"path = "levels/one.bin", hash"#);
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("levels", Span::call_site())),
+            asset_path: Some("levels".to_owned()),
+            is_collection: true,
+            is_hashed: true,
+            ..Default::default()
+        };
+        assert!(matches!(
+            builder.build(),
+            Err(errors) if matches!(errors[..], [ParseFieldError::HashRequiresPath])
+        ));
+    }
+
+    #[test]
+    fn animations() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("animations", Span::call_site())),
+            asset_path: Some("models/fox.glb".to_owned()),
+            is_animations: true,
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("This should be a valid Animations asset");
+        assert_eq!(
+            asset,
+            AssetField::Animations(BasicAssetField {
+                field_ident: Ident::new("animations", Span::call_site()),
+                asset_path: "models/fox.glb".to_owned(),
+            })
+        );
+        assert_eq!(asset.describe(), r#"path = "models/fox.glb", animations"#);
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("animations", Span::call_site())),
+            asset_path: Some("models/fox.glb".to_owned()),
+            is_animations: true,
+            is_hashed: true,
+            ..Default::default()
+        };
+        assert!(matches!(
+            builder.build(),
+            Err(errors) if matches!(errors[..], [ParseFieldError::AnimationsRequirePath])
+        ));
+    }
+
+    #[test]
+    fn gltf_materials() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("materials", Span::call_site())),
+            asset_path: Some("models/fox.glb".to_owned()),
+            is_gltf_materials: true,
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("This should be a valid GltfMaterials asset");
+        assert_eq!(
+            asset,
+            AssetField::GltfMaterials(BasicAssetField {
+                field_ident: Ident::new("materials", Span::call_site()),
+                asset_path: "models/fox.glb".to_owned(),
+            })
+        );
+        assert_eq!(
+            asset.describe(),
+            r#"path = "models/fox.glb", gltf_materials"#
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("materials", Span::call_site())),
+            asset_path: Some("models/fox.glb".to_owned()),
+            is_gltf_materials: true,
+            is_animations: true,
+            ..Default::default()
+        };
+        assert!(builder.build().is_err());
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("materials", Span::call_site())),
+            asset_path: Some("models/fox.glb".to_owned()),
+            is_gltf_materials: true,
+            is_hashed: true,
+            ..Default::default()
+        };
+        assert!(matches!(
+            builder.build(),
+            Err(errors) if matches!(errors[..], [ParseFieldError::GltfMaterialsRequirePath])
+        ));
+    }
+
+    #[test]
+    fn duration() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("song_duration", Span::call_site())),
+            asset_path: Some("music/song.wav".to_owned()),
+            is_duration: true,
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("This should be a valid AudioDuration asset");
+        assert_eq!(
+            asset,
+            AssetField::AudioDuration(BasicAssetField {
+                field_ident: Ident::new("song_duration", Span::call_site()),
+                asset_path: "music/song.wav".to_owned(),
+            })
+        );
+        assert_eq!(asset.describe(), r#"path = "music/song.wav", duration"#);
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("song_duration", Span::call_site())),
+            key: Some("music.song".to_owned()),
+            is_duration: true,
+            ..Default::default()
+        };
+        assert!(matches!(
+            builder.build(),
+            Err(errors) if matches!(errors[..], [ParseFieldError::DurationRequiresPath])
+        ));
+    }
+
+    #[test]
+    fn absolute() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("config", Span::call_site())),
+            asset_path: Some("/etc/tool/config.ron".to_owned()),
+            is_absolute: true,
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("This should be a valid Absolute asset");
+        assert_eq!(
+            asset,
+            AssetField::Absolute(BasicAssetField {
+                field_ident: Ident::new("config", Span::call_site()),
+                asset_path: "/etc/tool/config.ron".to_owned(),
+            })
+        );
+        assert_eq!(
+            asset.describe(),
+            r#"path = "/etc/tool/config.ron", absolute"#
+        );
+        assert!(asset.asset_paths().is_empty());
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("config", Span::call_site())),
+            asset_paths: Some(vec!["a.ron".to_owned(), "b.ron".to_owned()]),
+            is_absolute: true,
+            ..Default::default()
+        };
+        assert!(matches!(
+            builder.build(),
+            Err(errors) if matches!(errors[..], [ParseFieldError::AbsoluteRequiresPath])
+        ));
+    }
+
+    #[test]
+    fn region() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("icon", Span::call_site())),
+            asset_path: Some("images/atlas.png".to_owned()),
+            region_x: Some(16),
+            region_y: Some(0),
+            region_width: Some(16),
+            region_height: Some(32),
+            ..Default::default()
+        };
+
+        let asset = builder.build().expect("This should be a valid Region");
+        assert_eq!(
+            asset,
+            AssetField::Region(RegionAssetField {
+                field_ident: Ident::new("icon", Span::call_site()),
+                asset_path: "images/atlas.png".to_owned(),
+                x: 16,
+                y: 0,
+                width: 16,
+                height: 32,
+            })
+        );
+        assert_eq!(
+            asset.describe(),
+            r#"path = "images/atlas.png", region(x = 16, y = 0, w = 16, h = 32)"#
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("icon", Span::call_site())),
+            asset_path: Some("images/atlas.png".to_owned()),
+            region_x: Some(16),
+            region_y: Some(0),
+            ..Default::default()
+        };
+        assert!(matches!(
+            builder.build().unwrap_err().as_slice(),
+            [ParseFieldError::MissingAttributes(missing)] if missing == &["region/w", "region/h"]
+        ));
+    }
+
+    #[test]
+    fn platform() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("texture", Span::call_site())),
+            asset_path: Some("images/tree_low.png".to_owned()),
+            platform: Some(Platform::Mobile),
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("This should be a valid platform field");
+        assert_eq!(
+            asset,
+            AssetField::Platform(
+                Platform::Mobile,
+                Box::new(AssetField::Basic(BasicAssetField {
+                    field_ident: Ident::new("texture", Span::call_site()),
+                    asset_path: "images/tree_low.png".to_owned(),
+                }))
+            )
+        );
+        assert_eq!(
+            asset.describe(),
+            r#"path = "images/tree_low.png", cfg_mobile"#
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("texture", Span::call_site())),
+            platform: Some(Platform::Desktop),
+            ..Default::default()
+        };
+        assert!(matches!(
+            builder.build().unwrap_err().as_slice(),
+            [ParseFieldError::PlatformRequiresAsset]
+        ));
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("texture", Span::call_site())),
+            platform: Some(Platform::Desktop),
+            is_skipped: true,
+            ..Default::default()
+        };
+        assert!(builder.build().is_err());
+    }
+
+    #[test]
+    fn on_fail() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("hero", Span::call_site())),
+            key: Some("hero".to_owned()),
+            on_fail: Some(quote!(placeholders::hero)),
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("This should be a valid field with a fallback");
+        assert_eq!(
+            asset,
+            AssetField::OnFail(
+                Fallback(quote!(placeholders::hero)),
+                Box::new(AssetField::Dynamic(DynamicAssetField {
+                    field_ident: Ident::new("hero", Span::call_site()),
+                    key: DynamicAssetKey::Str("hero".to_owned()),
+                }))
+            )
+        );
+        assert_eq!(
+            asset.describe(),
+            r#"key = "hero", on_fail = "placeholders::hero""#
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("hero", Span::call_site())),
+            on_fail: Some(quote!(placeholders::hero)),
+            ..Default::default()
+        };
+        assert!(matches!(
+            builder.build().unwrap_err().as_slice(),
+            [ParseFieldError::OnFailRequiresAsset]
+        ));
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("hero", Span::call_site())),
+            on_fail: Some(quote!(placeholders::hero)),
+            is_skipped: true,
+            ..Default::default()
+        };
+        assert!(builder.build().is_err());
+    }
+
+    #[test]
+    fn weight() {
         let builder = AssetBuilder {
-            field_ident: Some(Ident::new("test", Span::call_site())),
-            asset_path: Some("some/image.png".to_owned()),
+            field_ident: Some(Ident::new("level", Span::call_site())),
+            asset_path: Some("levels/first.png".to_owned()),
+            weight: Some(5.),
+            on_fail: Some(quote!(placeholder_level)),
             ..Default::default()
         };
 
-        let asset = builder.build().expect("This should be a valid BasicAsset");
+        let asset = builder
+            .build()
+            .expect("This should be a valid weighted field");
         assert_eq!(
             asset,
-            AssetField::Basic(BasicAssetField {
-                field_ident: Ident::new("test", Span::call_site()),
-                asset_path: "some/image.png".to_owned()
-            })
+            AssetField::OnFail(
+                Fallback(quote!(placeholder_level)),
+                Box::new(AssetField::Weighted(
+                    5.,
+                    Box::new(AssetField::Basic(BasicAssetField {
+                        field_ident: Ident::new("level", Span::call_site()),
+                        asset_path: "levels/first.png".to_owned(),
+                    }))
+                ))
+            )
+        );
+        assert_eq!(
+            asset.describe(),
+            r#"path = "levels/first.png", weight = 5.0, on_fail = "placeholder_level""#
         );
-    }
 
-    #[test]
-    fn standard_material() {
         let builder = AssetBuilder {
-            field_ident: Some(Ident::new("test", Span::call_site())),
-            asset_path: Some("some/image.png".to_owned()),
-            is_standard_material: true,
+            field_ident: Some(Ident::new("level", Span::call_site())),
+            weight: Some(5.),
             ..Default::default()
         };
+        assert!(matches!(
+            builder.build().unwrap_err().as_slice(),
+            [ParseFieldError::WeightRequiresAsset]
+        ));
 
-        let asset = builder.build().expect("This should be a valid BasicAsset");
-        assert_eq!(
-            asset,
-            AssetField::StandardMaterial(BasicAssetField {
-                field_ident: Ident::new("test", Span::call_site()),
-                asset_path: "some/image.png".to_owned()
-            })
-        );
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("level", Span::call_site())),
+            weight: Some(5.),
+            is_skipped: true,
+            ..Default::default()
+        };
+        assert!(builder.build().is_err());
     }
 
     #[test]
-    fn folder() {
+    fn sort() {
         let builder = AssetBuilder {
-            field_ident: Some(Ident::new("test", Span::call_site())),
-            asset_path: Some("some/folder".to_owned()),
+            field_ident: Some(Ident::new("levels", Span::call_site())),
+            asset_path: Some("levels".to_owned()),
             is_collection: true,
+            is_typed: true,
+            is_sorted: true,
             ..Default::default()
         };
 
-        let asset = builder.build().expect("This should be a valid BasicAsset");
+        let asset = builder
+            .build()
+            .expect("This should be a valid sorted folder");
         assert_eq!(
             asset,
-            AssetField::Folder(
-                BasicAssetField {
-                    field_ident: Ident::new("test", Span::call_site()),
-                    asset_path: "some/folder".to_owned()
+            AssetField::Sorted(Box::new(AssetField::Folder(
+                FolderAssetField {
+                    field_ident: Ident::new("levels", Span::call_site()),
+                    asset_path: "levels".to_owned(),
+                    expected_keys: vec![],
+                    map_key: None,
                 },
-                Typed::No,
+                Typed::Yes,
                 Mapped::No
-            )
+            )))
+        );
+        assert_eq!(
+            asset.describe(),
+            r#"path = "levels", collection(typed), sort = "path""#
         );
 
         let builder = AssetBuilder {
-            field_ident: Some(Ident::new("test", Span::call_site())),
-            asset_path: Some("some/folder".to_owned()),
+            field_ident: Some(Ident::new("levels", Span::call_site())),
+            asset_path: Some("levels".to_owned()),
             is_collection: true,
-            is_typed: true,
+            is_mapped: true,
+            is_sorted: true,
             ..Default::default()
         };
+        assert!(matches!(
+            builder.build().unwrap_err().as_slice(),
+            [ParseFieldError::SortRequiresCollection]
+        ));
 
-        let asset = builder.build().expect("This should be a valid BasicAsset");
-        assert_eq!(
-            asset,
-            AssetField::Folder(
-                BasicAssetField {
-                    field_ident: Ident::new("test", Span::call_site()),
-                    asset_path: "some/folder".to_owned()
-                },
-                Typed::Yes,
-                Mapped::No
-            )
-        );
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("level", Span::call_site())),
+            asset_path: Some("levels/first.png".to_owned()),
+            is_sorted: true,
+            ..Default::default()
+        };
+        assert!(matches!(
+            builder.build().unwrap_err().as_slice(),
+            [ParseFieldError::SortRequiresCollection]
+        ));
+    }
 
+    #[test]
+    fn loading_field() {
         let builder = AssetBuilder {
-            field_ident: Some(Ident::new("test", Span::call_site())),
-            asset_path: Some("some/folder".to_owned()),
-            is_collection: true,
-            is_mapped: true,
+            field_ident: Some(Ident::new("background", Span::call_site())),
+            asset_path: Some("images/background.png".to_owned()),
+            loading_field: Some(quote!(Image)),
             ..Default::default()
         };
 
-        let asset = builder.build().expect("This should be a valid BasicAsset");
+        let asset = builder
+            .build()
+            .expect("This should be a valid loading field");
         assert_eq!(
             asset,
-            AssetField::Folder(
-                BasicAssetField {
-                    field_ident: Ident::new("test", Span::call_site()),
-                    asset_path: "some/folder".to_owned()
-                },
-                Typed::No,
-                Mapped::Yes
+            AssetField::LoadingField(
+                LoadingFieldType(quote!(Image)),
+                Box::new(AssetField::Basic(BasicAssetField {
+                    field_ident: Ident::new("background", Span::call_site()),
+                    asset_path: "images/background.png".to_owned(),
+                }))
             )
         );
+        assert_eq!(asset.describe(), r#"path = "images/background.png""#);
 
         let builder = AssetBuilder {
-            field_ident: Some(Ident::new("test", Span::call_site())),
-            asset_path: Some("some/folder".to_owned()),
-            is_collection: true,
-            is_typed: true,
-            is_mapped: true,
+            field_ident: Some(Ident::new("background", Span::call_site())),
+            asset_path: Some("images/background.png".to_owned()),
+            is_mipmaps: true,
+            loading_field: Some(quote!(Image)),
             ..Default::default()
         };
+        assert!(matches!(
+            builder.build().unwrap_err().as_slice(),
+            [ParseFieldError::LoadingFieldRequiresHandle]
+        ));
 
-        let asset = builder.build().expect("This should be a valid BasicAsset");
-        assert_eq!(
-            asset,
-            AssetField::Folder(
-                BasicAssetField {
-                    field_ident: Ident::new("test", Span::call_site()),
-                    asset_path: "some/folder".to_owned()
-                },
-                Typed::Yes,
-                Mapped::Yes
-            )
-        );
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("background", Span::call_site())),
+            loading_field: Some(quote!(Image)),
+            ..Default::default()
+        };
+        assert!(matches!(
+            builder.build().unwrap_err().as_slice(),
+            [ParseFieldError::LoadingFieldRequiresHandle]
+        ));
     }
 
     #[test]
-    fn dynamic_asset() {
+    fn audio_bank() {
         let builder = AssetBuilder {
             field_ident: Some(Ident::new("test", Span::call_site())),
-            key: Some("some.asset.key".to_owned()),
+            asset_path: Some("audio/sfx.ogg".to_owned()),
+            audio_bank_manifest: Some("audio/sfx.bank.json".to_owned()),
             ..Default::default()
         };
 
-        let asset = builder
-            .build()
-            .expect("This should be a valid DynamicAsset");
+        let asset = builder.build().expect("This should be a valid AudioBank");
         assert_eq!(
             asset,
-            AssetField::Dynamic(DynamicAssetField {
+            AssetField::AudioBank(AudioBankAssetField {
                 field_ident: Ident::new("test", Span::call_site()),
-                key: "some.asset.key".to_owned()
+                asset_path: "audio/sfx.ogg".to_owned(),
+                manifest_path: "audio/sfx.bank.json".to_owned()
             })
         );
-    }
+        assert_eq!(
+            asset.describe(),
+            r#"path = "audio/sfx.ogg", audio_bank = "audio/sfx.bank.json""#
+        );
 
-    #[test]
-    fn paths_and_path_exclusive() {
         let builder = AssetBuilder {
             field_ident: Some(Ident::new("test", Span::call_site())),
-            asset_path: Some("some.asset".to_owned()),
-            asset_paths: Some(vec!["some.asset".to_owned()]),
+            asset_path: Some("audio".to_owned()),
+            audio_bank_manifest: Some("audio/sfx.bank.json".to_owned()),
+            is_collection: true,
             ..Default::default()
         };
-
-        let asset = builder.build().expect_err("Should be pasing error");
-        assert!(variant_eq(
-            asset.get(0).unwrap(),
-            &ParseFieldError::PathAndPathsAreExclusive
-        ));
+        assert!(builder.build().is_err());
     }
 
     #[test]
-    fn multiple_files() {
+    fn derive_from() {
         let builder = AssetBuilder {
-            field_ident: Some(Ident::new("test", Span::call_site())),
-            asset_paths: Some(vec!["some.asset".to_owned()]),
+            field_ident: Some(Ident::new("material", Span::call_site())),
+            derive_from: Some(Ident::new("texture", Span::call_site())),
+            is_standard_material: true,
             ..Default::default()
         };
 
-        let asset = builder.build().expect("This should be a valid Files asset");
+        let asset = builder
+            .build()
+            .expect("This should be a valid derived asset");
         assert_eq!(
             asset,
-            AssetField::Files(
-                MultipleFilesField {
-                    field_ident: Ident::new("test", Span::call_site()),
-                    asset_paths: vec!["some.asset".to_owned()]
-                },
-                Typed::No,
-                Mapped::No
-            )
+            AssetField::Derived(DerivedAssetField {
+                field_ident: Ident::new("material", Span::call_site()),
+                source: Ident::new("texture", Span::call_site()),
+                asset: DerivedAsset::StandardMaterial
+            })
+        );
+        assert_eq!(
+            asset.describe(),
+            r#"standard_material, derive_from = "texture""#
         );
 
         let builder = AssetBuilder {
-            field_ident: Some(Ident::new("test", Span::call_site())),
-            asset_paths: Some(vec!["some.asset".to_owned()]),
-            is_typed: true,
+            field_ident: Some(Ident::new("atlas", Span::call_site())),
+            derive_from: Some(Ident::new("texture", Span::call_site())),
+            tile_size_x: Some(64.),
+            tile_size_y: Some(32.),
+            columns: Some(4),
+            rows: Some(2),
             ..Default::default()
         };
 
-        let asset = builder.build().expect("This should be a valid Files asset");
+        let asset = builder
+            .build()
+            .expect("This should be a valid derived asset");
         assert_eq!(
             asset,
-            AssetField::Files(
-                MultipleFilesField {
-                    field_ident: Ident::new("test", Span::call_site()),
-                    asset_paths: vec!["some.asset".to_owned()]
-                },
-                Typed::Yes,
-                Mapped::No
-            )
+            AssetField::Derived(DerivedAssetField {
+                field_ident: Ident::new("atlas", Span::call_site()),
+                source: Ident::new("texture", Span::call_site()),
+                asset: DerivedAsset::TextureAtlas(TextureAtlasGrid {
+                    tile_size_x: 64.,
+                    tile_size_y: 32.,
+                    columns: 4,
+                    rows: 2,
+                    padding_x: 0.,
+                    padding_y: 0.,
+                    offset_x: 0.,
+                    offset_y: 0.,
+                })
+            })
         );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("material", Span::call_site())),
+            derive_from: Some(Ident::new("texture", Span::call_site())),
+            asset_path: Some("images/tree.png".to_owned()),
+            is_standard_material: true,
+            ..Default::default()
+        };
+        assert!(builder.build().is_err());
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("material", Span::call_site())),
+            derive_from: Some(Ident::new("texture", Span::call_site())),
+            ..Default::default()
+        };
+        assert!(builder.build().is_err());
     }
 
     #[test]
@@ -825,6 +3715,119 @@ mod test {
         );
     }
 
+    #[test]
+    fn skipped_field() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            is_skipped: true,
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("This should be a valid skipped field");
+        assert_eq!(
+            asset,
+            AssetField::Skipped(Ident::new("test", Span::call_site()))
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            is_skipped: true,
+            asset_path: Some("some/image.png".to_owned()),
+            ..Default::default()
+        };
+        assert!(builder.build().is_err());
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            is_skipped: true,
+            is_collection: true,
+            ..Default::default()
+        };
+        assert!(builder.build().is_err());
+    }
+
+    #[test]
+    fn from_resource() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            from_resource: Some((quote!(SharedAssets), Ident::new("font", Span::call_site()))),
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("This should be a valid from_resource field");
+        assert_eq!(
+            asset,
+            AssetField::FromResource(ResourceAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                resource: quote!(SharedAssets),
+                source: Ident::new("font", Span::call_site()),
+            })
+        );
+        assert_eq!(asset.describe(), r#"from_resource = "SharedAssets::font""#);
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            from_resource: Some((quote!(SharedAssets), Ident::new("font", Span::call_site()))),
+            asset_path: Some("fonts/FiraSans-Bold.ttf".to_owned()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            builder.build(),
+            Err(errors) if matches!(errors[..], [ParseFieldError::FromResourceStandsAlone])
+        ));
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            from_resource: Some((quote!(SharedAssets), Ident::new("font", Span::call_site()))),
+            platform: Some(Platform::Desktop),
+            ..Default::default()
+        };
+        assert!(builder.build().is_err());
+    }
+
+    #[test]
+    fn describe_fields() {
+        let describe = |builder: AssetBuilder| builder.build().unwrap().describe();
+        assert_eq!(
+            describe(AssetBuilder {
+                field_ident: Some(Ident::new("test", Span::call_site())),
+                asset_path: Some("some/image.png".to_owned()),
+                ..Default::default()
+            }),
+            r#"path = "some/image.png""#
+        );
+        assert_eq!(
+            describe(AssetBuilder {
+                field_ident: Some(Ident::new("test", Span::call_site())),
+                asset_paths: Some(vec!["a.png".to_owned(), "b.png".to_owned()]),
+                is_typed: true,
+                ..Default::default()
+            }),
+            r#"paths("a.png", "b.png"), collection(typed)"#
+        );
+        assert_eq!(
+            describe(AssetBuilder {
+                field_ident: Some(Ident::new("test", Span::call_site())),
+                key: Some("image".to_owned()),
+                is_optional: true,
+                ..Default::default()
+            }),
+            r#"key = "image", optional"#
+        );
+        assert_eq!(
+            describe(AssetBuilder {
+                field_ident: Some(Ident::new("test", Span::call_site())),
+                is_skipped: true,
+                ..Default::default()
+            }),
+            "skip"
+        );
+    }
+
     #[test]
     fn dynamic_asset_does_only_accept_some_attributes() {
         let mut builder = asset_builder_dynamic();
@@ -856,7 +3859,7 @@ mod test {
             asset,
             AssetField::OptionalDynamic(DynamicAssetField {
                 field_ident: Ident::new("test", Span::call_site()),
-                key: "some.asset.key".to_owned(),
+                key: DynamicAssetKey::Str("some.asset.key".to_owned()),
             }),
             "Dynamic asset with 'optional' attribute should yield 'AssetField::OptionalDynamic'"
         );
@@ -869,7 +3872,7 @@ mod test {
             AssetField::DynamicFileCollection(
                 DynamicAssetField {
                     field_ident: Ident::new("test", Span::call_site()),
-                    key: "some.asset.key".to_owned(),
+                    key: DynamicAssetKey::Str("some.asset.key".to_owned()),
                 },
                 Typed::No,
                 Mapped::No
@@ -886,7 +3889,7 @@ mod test {
             AssetField::DynamicFileCollection(
                 DynamicAssetField {
                     field_ident: Ident::new("test", Span::call_site()),
-                    key: "some.asset.key".to_owned(),
+                    key: DynamicAssetKey::Str("some.asset.key".to_owned()),
                 },
                 Typed::Yes,
                 Mapped::No
@@ -895,6 +3898,36 @@ mod test {
         );
     }
 
+    #[test]
+    fn enum_key() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            key_enum: Some(quote!(MyKeys::Character)),
+            is_optional: true,
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("This should be a valid dynamic asset");
+        assert_eq!(
+            asset,
+            AssetField::OptionalDynamic(DynamicAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                key: DynamicAssetKey::Enum(quote!(MyKeys::Character)),
+            })
+        );
+        assert_eq!(asset.describe(), "key_enum(MyKeys::Character), optional");
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            key: Some("character".to_owned()),
+            key_enum: Some(quote!(MyKeys::Character)),
+            ..Default::default()
+        };
+        assert!(builder.build().is_err());
+    }
+
     fn asset_builder_dynamic() -> AssetBuilder {
         AssetBuilder {
             field_ident: Some(Ident::new("test", Span::call_site())),
@@ -9,21 +9,25 @@
 extern crate proc_macro;
 
 mod assets;
+mod loading_state;
 
 use proc_macro::TokenStream;
 use std::option::Option::Some;
 use std::result::Result::{Err, Ok};
 
 use crate::assets::*;
+use crate::loading_state::{impl_loading_state, LoadingStateInput};
 use proc_macro2::Ident;
 use quote::{quote, quote_spanned, ToTokens, TokenStreamExt};
-use syn::{Data, Field, Fields, Index, Lit, Meta, NestedMeta};
+use syn::ext::IdentExt;
+use syn::{Data, Field, Fields, Index, Lit, Meta, MetaNameValue, NestedMeta};
 
 /// Derive macro for [`AssetCollection`]
 ///
 /// The helper attribute ``asset`` can be used to define the path to the asset file
-/// and other asset options.
-#[proc_macro_derive(AssetCollection, attributes(asset))]
+/// and other asset options. The struct level attribute ``asset_collection(debug)`` additionally
+/// derives a ``Debug`` implementation listing the configured asset of every field.
+#[proc_macro_derive(AssetCollection, attributes(asset, asset_collection))]
 pub fn asset_collection_derive(input: TokenStream) -> TokenStream {
     let ast = syn::parse(input).unwrap();
     impl_asset_collection(ast)
@@ -31,9 +35,35 @@ pub fn asset_collection_derive(input: TokenStream) -> TokenStream {
         .into()
 }
 
+/// Define a loading state with its collections in one place
+///
+/// Expands to a plugin that adds the loading state, its collections and the resources to
+/// initialize after it finished. The next state and both lists are optional.
+/// ```ignore
+/// app.add_plugins(loading_state! {
+///     MyStates::AssetLoading => MyStates::Next,
+///     collections: [ImageAssets, AudioAssets],
+///     init_resources: [AtlasResource],
+/// });
+/// ```
+#[proc_macro]
+pub fn loading_state(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as LoadingStateInput);
+    impl_loading_state(input).into()
+}
+
+pub(crate) const ASSET_COLLECTION_ATTRIBUTE: &str = "asset_collection";
+pub(crate) const DEBUG_ATTRIBUTE: &str = "debug";
+pub(crate) const META_ATTRIBUTE: &str = "meta";
+pub(crate) const PIN_STRONG_ATTRIBUTE: &str = "pin_strong";
+pub(crate) const MERGE_ATTRIBUTE: &str = "merge";
+pub(crate) const REFLECT_ATTRIBUTE: &str = "reflect";
+pub(crate) const KEY_CASE_ATTRIBUTE: &str = "key_case";
+
 pub(crate) const ASSET_ATTRIBUTE: &str = "asset";
 pub(crate) const PATH_ATTRIBUTE: &str = "path";
 pub(crate) const KEY_ATTRIBUTE: &str = "key";
+pub(crate) const KEY_ENUM_ATTRIBUTE: &str = "key_enum";
 pub(crate) const OPTIONAL_ATTRIBUTE: &str = "optional";
 
 pub(crate) const TEXTURE_ATLAS_ATTRIBUTE: &str = "texture_atlas";
@@ -55,28 +85,96 @@ impl TextureAtlasAttribute {
 
 pub(crate) const COLLECTION_ATTRIBUTE: &str = "collection";
 pub(crate) const PATHS_ATTRIBUTE: &str = "paths";
+pub(crate) const PATHS_CONST_ATTRIBUTE: &str = "paths_const";
 pub(crate) const TYPED_ATTRIBUTE: &str = "typed";
 pub(crate) const MAPPED_ATTRIBUTE: &str = "mapped";
 pub(crate) const STANDARD_MATERIAL_ATTRIBUTE: &str = "standard_material";
+pub(crate) const COLOR_MATERIAL_ATTRIBUTE: &str = "color_material";
+pub(crate) const SKIP_ATTRIBUTE: &str = "skip";
+pub(crate) const EXPECT_ATTRIBUTE: &str = "expect";
+pub(crate) const MAP_KEY_ATTRIBUTE: &str = "map_key";
+pub(crate) const DYNAMIC_FIELD_ATTRIBUTE: &str = "dynamic_field";
+pub(crate) const AUDIO_BANK_ATTRIBUTE: &str = "audio_bank";
+pub(crate) const DERIVE_FROM_ATTRIBUTE: &str = "derive_from";
+pub(crate) const ON_FAIL_ATTRIBUTE: &str = "on_fail";
+pub(crate) const WEIGHT_ATTRIBUTE: &str = "weight";
+pub(crate) const SORT_ATTRIBUTE: &str = "sort";
+pub(crate) const PATH_LIST_ATTRIBUTE: &str = "path_list";
+pub(crate) const FROM_RESOURCE_ATTRIBUTE: &str = "from_resource";
+pub(crate) const UUID_ATTRIBUTE: &str = "uuid";
+pub(crate) const MIPMAPS_ATTRIBUTE: &str = "mipmaps";
+pub(crate) const SHADER_ATTRIBUTE: &str = "shader";
+pub(crate) const HASH_ATTRIBUTE: &str = "hash";
+pub(crate) const ANIMATIONS_ATTRIBUTE: &str = "animations";
+pub(crate) const GLTF_MATERIALS_ATTRIBUTE: &str = "gltf_materials";
+pub(crate) const DURATION_ATTRIBUTE: &str = "duration";
+pub(crate) const ABSOLUTE_ATTRIBUTE: &str = "absolute";
+pub(crate) const CFG_DESKTOP_ATTRIBUTE: &str = "cfg_desktop";
+pub(crate) const CFG_MOBILE_ATTRIBUTE: &str = "cfg_mobile";
+
+pub(crate) const REGION_ATTRIBUTE: &str = "region";
+pub(crate) struct RegionAttribute;
+impl RegionAttribute {
+    pub const X: &'static str = "x";
+    pub const Y: &'static str = "y";
+    pub const WIDTH: &'static str = "w";
+    pub const HEIGHT: &'static str = "h";
+}
 
 fn impl_asset_collection(
     ast: syn::DeriveInput,
 ) -> Result<proc_macro2::TokenStream, Vec<syn::Error>> {
     let name = &ast.ident;
+    let CollectionAttributes {
+        derive_debug,
+        pin_strong,
+        merge,
+        reflect,
+        meta,
+        key_case,
+    } = parse_collection_attributes(&ast)?;
 
     let mut from_world_fields: Vec<Ident> = vec![];
     let mut assets: Vec<AssetField> = vec![];
+    let mut debug_fields = quote!();
+    let mut merge_fields = quote!();
+    let mut accessors = quote!();
+    let mut asset_types = quote!();
+    let mut request_types: Vec<proc_macro2::TokenStream> = vec![];
+    let mut watched_types: Vec<proc_macro2::TokenStream> = vec![];
     if let Data::Struct(ref data_struct) = ast.data {
         if let Fields::Named(ref named_fields) = data_struct.fields {
             let mut compile_errors = vec![];
             for field in named_fields.named.iter() {
-                match parse_field(field) {
-                    Ok(asset) => assets.push(asset),
+                match parse_field(field, key_case) {
+                    Ok(asset) => {
+                        debug_fields = asset.attach_token_stream_for_debug(debug_fields);
+                        merge_fields = asset.attach_token_stream_for_merge(merge_fields);
+                        for asset_type in field_asset_types(field, &asset) {
+                            asset_types =
+                                quote!(#asset_types ::std::any::type_name::<#asset_type>(),);
+                            if !watched_types
+                                .iter()
+                                .any(|watched| watched.to_string() == asset_type.to_string())
+                            {
+                                watched_types.push(asset_type);
+                            }
+                        }
+                        request_types.push(load_request_type(field, &asset));
+                        match expected_key_accessors(field, &asset) {
+                            Ok(field_accessors) => accessors = quote!(#accessors #field_accessors),
+                            Err(error) => compile_errors.push(error),
+                        }
+                        assets.push(asset)
+                    }
                     Err(errors) => {
                         for error in errors {
                             match error {
                                 ParseFieldError::NoAttributes => {
-                                    from_world_fields.push(field.clone().ident.unwrap())
+                                    let field_ident = field.clone().ident.unwrap();
+                                    let field_name = field_ident.to_string();
+                                    debug_fields = quote!(#debug_fields .field(#field_name, &::std::format_args!("FromWorld")));
+                                    from_world_fields.push(field_ident)
                                 }
                                 ParseFieldError::KeyAttributeStandsAlone => {
                                     compile_errors.push(syn::Error::new_spanned(
@@ -84,6 +182,12 @@ fn impl_asset_collection(
                                         "The 'key' attribute cannot be combined with any other asset defining attributes",
                                     ));
                                 }
+                                ParseFieldError::SkipAttributeStandsAlone => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        field.into_token_stream(),
+                                        "The 'skip' attribute cannot be combined with any other asset attributes",
+                                    ));
+                                }
                                 ParseFieldError::OnlyDynamicCanBeOptional => {
                                     compile_errors.push(syn::Error::new_spanned(
                                         field.into_token_stream(),
@@ -129,6 +233,192 @@ fn impl_asset_collection(
                                         "This attribute requires the '3d' feature",
                                     ));
                                 }
+                                ParseFieldError::MissingAudioBankFeature(token_stream) => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        token_stream,
+                                        "This attribute requires the 'audio_bank' feature",
+                                    ));
+                                }
+                                ParseFieldError::DeriveFromRequiresImageAsset => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        field.into_token_stream(),
+                                        "The 'derive_from' attribute requires one of 'standard_material', 'color_material' or 'texture_atlas' and cannot be combined with a path or key",
+                                    ));
+                                }
+                                ParseFieldError::AudioBankRequiresPath => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        field.into_token_stream(),
+                                        "The 'audio_bank' attribute requires a 'path' and cannot be combined with other asset attributes",
+                                    ));
+                                }
+                                ParseFieldError::MissingRenderFeature(token_stream) => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        token_stream,
+                                        "This attribute requires the '2d' or '3d' feature",
+                                    ));
+                                }
+                                ParseFieldError::MipmapsRequiresPath => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        field.into_token_stream(),
+                                        "The 'mipmaps' attribute requires a 'path' to an image and cannot be combined with other asset attributes",
+                                    ));
+                                }
+                                ParseFieldError::HashRequiresPath => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        field.into_token_stream(),
+                                        "The 'hash' attribute requires a 'path' to a single file and cannot be combined with other asset attributes",
+                                    ));
+                                }
+                                ParseFieldError::AnimationsRequirePath => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        field.into_token_stream(),
+                                        "The 'animations' attribute requires a 'path' to a glTF file and cannot be combined with other asset attributes",
+                                    ));
+                                }
+                                ParseFieldError::MissingAnimationFeature(token_stream) => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        token_stream,
+                                        "This attribute requires the 'animation' feature",
+                                    ));
+                                }
+                                ParseFieldError::GltfMaterialsRequirePath => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        field.into_token_stream(),
+                                        "The 'gltf_materials' attribute requires a 'path' to a glTF file and cannot be combined with other asset attributes",
+                                    ));
+                                }
+                                ParseFieldError::MissingGltfMaterialsFeature(token_stream) => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        token_stream,
+                                        "This attribute requires the 'gltf_materials' feature",
+                                    ));
+                                }
+                                ParseFieldError::DurationRequiresPath => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        field.into_token_stream(),
+                                        "The 'duration' attribute requires a 'path' to an audio file and cannot be combined with other asset attributes",
+                                    ));
+                                }
+                                ParseFieldError::AbsoluteRequiresPath => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        field.into_token_stream(),
+                                        "The 'absolute' attribute requires a single 'path' and cannot be combined with other asset attributes",
+                                    ));
+                                }
+                                ParseFieldError::MissingAudioDurationFeature(token_stream) => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        token_stream,
+                                        "This attribute requires the 'audio_duration' feature",
+                                    ));
+                                }
+                                ParseFieldError::MissingContentHashFeature(token_stream) => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        token_stream,
+                                        "This attribute requires the 'content_hash' feature",
+                                    ));
+                                }
+                                ParseFieldError::ShaderRequiresPath => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        field.into_token_stream(),
+                                        "The 'shader' attribute requires a 'path' to a shader and cannot be combined with other asset attributes",
+                                    ));
+                                }
+                                ParseFieldError::PlatformRequiresAsset => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        field.into_token_stream(),
+                                        "Only one of 'cfg_desktop' and 'cfg_mobile' can be used on a field. It requires an asset to load and cannot be combined with 'skip', 'from_resource', 'uuid' or 'derive_from'",
+                                    ));
+                                }
+                                ParseFieldError::OnFailRequiresAsset => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        field.into_token_stream(),
+                                        "The 'on_fail' attribute requires an asset to load and cannot be combined with 'skip', 'from_resource', 'uuid' or 'derive_from'",
+                                    ));
+                                }
+                                ParseFieldError::WeightRequiresAsset => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        field.into_token_stream(),
+                                        "The 'weight' attribute requires an asset to load and cannot be combined with 'skip', 'from_resource', 'uuid' or 'derive_from'",
+                                    ));
+                                }
+                                ParseFieldError::SortRequiresCollection => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        field.into_token_stream(),
+                                        "The 'sort' attribute requires a folder or dynamic collection loaded into a vector and cannot be combined with 'mapped'",
+                                    ));
+                                }
+                                ParseFieldError::LoadingFieldRequiresHandle => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        field.into_token_stream(),
+                                        "Fields of type 'LoadingField' require a single 'path' or 'key' and cannot be combined with other attributes",
+                                    ));
+                                }
+                                ParseFieldError::RegionRequiresPath => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        field.into_token_stream(),
+                                        "The 'region' attribute requires a 'path' to an image and cannot be combined with other asset attributes",
+                                    ));
+                                }
+                                ParseFieldError::PathListStandsAlone => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        field.into_token_stream(),
+                                        "The 'path_list' attribute cannot be combined with other asset attributes",
+                                    ));
+                                }
+                                ParseFieldError::FromResourceStandsAlone => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        field.into_token_stream(),
+                                        "The 'from_resource' attribute cannot be combined with other asset attributes",
+                                    ));
+                                }
+                                ParseFieldError::UuidStandsAlone => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        field.into_token_stream(),
+                                        "The 'uuid' attribute cannot be combined with other asset attributes",
+                                    ));
+                                }
+                                ParseFieldError::ArrayRequiresPaths => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        field.into_token_stream(),
+                                        "Array fields require 'paths' and cannot be combined with other asset attributes",
+                                    ));
+                                }
+                                ParseFieldError::ArrayLengthMismatch(length, paths) => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        field.ty.to_token_stream(),
+                                        format!("The array has a length of {length}, but {paths} paths are given"),
+                                    ));
+                                }
+                                ParseFieldError::MaterialAttributesAreExclusive => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        field.into_token_stream(),
+                                        "Either specify 'standard_material' OR 'color_material'",
+                                    ));
+                                }
+                                ParseFieldError::PathsConstIsExclusive => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        field.into_token_stream(),
+                                        "The 'paths_const' attribute cannot be combined with 'path', 'paths' or asset type attributes",
+                                    ));
+                                }
+                                ParseFieldError::ExpectRequiresMappedFolder => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        field.into_token_stream(),
+                                        "The 'expect' attribute requires a folder loaded as 'collection(mapped)'",
+                                    ));
+                                }
+                                ParseFieldError::MapKeyRequiresMappedFolder => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        field.into_token_stream(),
+                                        "The 'map_key' attribute requires a folder loaded as 'collection(mapped)' and cannot be combined with 'expect'",
+                                    ));
+                                }
+                                ParseFieldError::DynamicFieldRequiresKey => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        field.into_token_stream(),
+                                        "The 'dynamic_field' attribute requires a 'key' and cannot be combined with 'collection'",
+                                    ));
+                                }
                                 ParseFieldError::PathAndPathsAreExclusive => {
                                     compile_errors.push(syn::Error::new_spanned(
                                         field.into_token_stream(),
@@ -140,6 +430,12 @@ fn impl_asset_collection(
                     }
                 }
             }
+            for asset in assets.iter() {
+                if let Some(error) = check_derive_from_source(asset, &assets) {
+                    compile_errors.push(error);
+                }
+            }
+            compile_errors.extend(check_loading_fields(&assets));
             if !compile_errors.is_empty() {
                 return Err(compile_errors);
             }
@@ -161,13 +457,72 @@ fn impl_asset_collection(
     });
     let load_function = quote! {
             fn load(world: &mut ::bevy::ecs::world::World) -> Vec<::bevy::prelude::HandleUntyped> {
-                let cell = world.cell();
-                let asset_server = cell.get_resource::<::bevy::prelude::AssetServer>().expect("Cannot get AssetServer");
-                let asset_keys = cell.get_resource::<bevy_asset_loader::prelude::DynamicAssets>().expect("Cannot get bevy_asset_loader::prelude::DynamicAssets");
+                let collecting_errors = ::bevy_asset_loader::asset_collection::collecting_errors(world).is_some();
+                #[allow(unused_mut)]
+                let mut recoverable: Vec<(&'static str, Vec<::bevy::asset::HandleId>)> = vec![];
+                #[allow(unused_mut)]
+                let mut weighted: Vec<(f32, Vec<::bevy::asset::HandleId>)> = vec![];
+                let handles = {
+                    let cell = world.cell();
+                    let asset_server = cell.get_resource::<::bevy::prelude::AssetServer>().expect("Cannot get AssetServer");
+                    let asset_keys = cell.get_resource::<bevy_asset_loader::prelude::DynamicAssets>().expect("Cannot get bevy_asset_loader::prelude::DynamicAssets");
+                    let path_transform = cell.get_resource::<::bevy_asset_loader::asset_collection::AssetPathTransform>();
+                    let mut handles = vec![];
+                    #asset_loading
+                    handles
+                };
+                ::bevy_asset_loader::asset_collection::insert_recoverable_handles::<Self>(world, recoverable);
+                ::bevy_asset_loader::asset_collection::insert_handle_weights::<Self>(world, weighted);
+                handles
+            }
+    };
+
+    let asset_load_requests = assets.iter().zip(request_types.iter()).fold(
+        quote!(),
+        |token_stream, (asset, request_type)| {
+            asset.attach_token_stream_for_load_requests(token_stream, request_type)
+        },
+    );
+    let load_requests_function = quote! {
+            fn load_requests() -> Vec<::bevy_asset_loader::asset_collection::AssetLoadRequest> {
+                Self::typed_load_requests().into_iter().map(|(_, request)| request).collect()
+            }
+
+            fn typed_load_requests() -> Vec<(Option<&'static str>, ::bevy_asset_loader::asset_collection::AssetLoadRequest)> {
+                let mut requests: Vec<(Option<&'static str>, ::bevy_asset_loader::asset_collection::AssetLoadRequest)> = vec![];
+                #asset_load_requests
+                requests
+            }
+    };
+
+    let deferred_loading = assets.iter().fold(quote!(), |token_stream, asset| {
+        asset.attach_token_stream_for_deferred_loading(token_stream)
+    });
+    let load_deferred_function = if deferred_loading.is_empty() {
+        quote!()
+    } else {
+        quote! {
+            fn load_deferred(world: &mut ::bevy::ecs::world::World) -> Vec<::bevy::prelude::HandleUntyped> {
                 let mut handles = vec![];
-                #asset_loading
+                #deferred_loading
                 handles
             }
+        }
+    };
+
+    let ready_check = assets.iter().fold(quote!(), |token_stream, asset| {
+        asset.attach_token_stream_for_ready_check(token_stream)
+    });
+    let ready_to_create_function = if ready_check.is_empty() {
+        quote!()
+    } else {
+        quote! {
+            fn ready_to_create(world: &::bevy::ecs::world::World) -> bool {
+                let mut ready = true;
+                #ready_check
+                ready
+            }
+        }
     };
 
     let mut prepare_from_world = quote! {};
@@ -179,6 +534,9 @@ fn impl_asset_collection(
     let mut asset_creation = assets.iter().fold(quote!(), |token_stream, asset| {
         asset.attach_token_stream_for_creation(token_stream)
     });
+    let derived_asset_creation = assets.iter().fold(quote!(), |token_stream, asset| {
+        asset.attach_token_stream_for_derived_creation(token_stream)
+    });
     let mut index = 0;
     asset_creation.append_all(from_world_fields.iter().fold(quote!(), |es, ident| {
         let index_ident = Index::from(index);
@@ -189,16 +547,183 @@ fn impl_asset_collection(
     let create_function = quote! {
         fn create(world: &mut ::bevy::ecs::world::World) -> Self {
             let from_world_fields = (#prepare_from_world);
-            world.resource_scope(
+            #[allow(unused_mut)]
+            let mut errors = ::bevy_asset_loader::asset_collection::collecting_errors(world);
+            let collection = world.resource_scope(
                 |world, asset_keys: ::bevy::prelude::Mut<::bevy_asset_loader::dynamic_asset::DynamicAssets>| {
-                    #name {
+                    #[allow(unused_mut)]
+                    let mut collection = #name {
                         #asset_creation
-                    }
+                    };
+                    #derived_asset_creation
+                    collection
                 },
-            )
+            );
+            ::bevy_asset_loader::asset_collection::report_collection_errors(world, errors);
+            collection
         }
     };
 
+    let asset_types_function = quote! {
+        fn asset_types() -> Vec<&'static str> {
+            let mut types: Vec<&'static str> = vec![#asset_types];
+            types.sort_unstable();
+            types.dedup();
+            types
+        }
+    };
+    let modification_watchers_function = if watched_types.is_empty() {
+        quote!()
+    } else {
+        quote! {
+            fn add_modification_watchers(app: &mut ::bevy::app::App) {
+                #(::bevy_asset_loader::loading_state::watch_modified_assets::<Self, #watched_types>(app);)*
+            }
+        }
+    };
+
+    let dynamic_asset_keys = assets.iter().filter_map(|asset| asset.dynamic_key()).fold(
+        quote!(),
+        |token_stream, key| quote!(#token_stream ::std::string::String::from(#key),),
+    );
+    let dynamic_asset_keys_function = quote! {
+        fn dynamic_asset_keys() -> Vec<::std::string::String> {
+            vec![#dynamic_asset_keys]
+        }
+    };
+
+    let meta = meta.iter().fold(
+        quote!(),
+        |token_stream, (key, value)| quote!(#token_stream (#key, #value),),
+    );
+    let meta_function = quote! {
+        fn meta() -> &'static [(&'static str, &'static str)] {
+            &[#meta]
+        }
+    };
+
+    let pin_strong_function = if pin_strong {
+        quote! {
+            fn pin_strong() -> bool {
+                true
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    let loading_fields: Vec<_> = assets
+        .iter()
+        .filter(|asset| matches!(asset, AssetField::LoadingField(..)))
+        .map(AssetField::field_ident)
+        .collect();
+    let loading_fields_functions = if loading_fields.is_empty() {
+        quote!()
+    } else {
+        quote! {
+            fn has_loading_fields() -> bool {
+                true
+            }
+
+            fn update_loading_fields(&mut self, asset_server: &::bevy::asset::AssetServer) -> bool {
+                let mut changed = false;
+                #(changed |= self.#loading_fields.update(asset_server);)*
+                changed
+            }
+        }
+    };
+
+    let (merge_function, merge_impl) = if merge {
+        (
+            quote! {
+                fn merge_with() -> Option<fn(&mut Self, Self)> {
+                    Some(<Self as ::bevy_asset_loader::asset_collection::MergeableCollection>::merge)
+                }
+            },
+            quote! {
+                #[automatically_derived]
+                impl ::bevy_asset_loader::asset_collection::MergeableCollection for #name {
+                    fn merge(&mut self, other: Self) {
+                        #merge_fields
+                    }
+                }
+            },
+        )
+    } else {
+        (quote!(), quote!())
+    };
+
+    let register_types_function = if reflect {
+        quote! {
+            fn register_types(registry: &mut ::bevy::reflect::TypeRegistryInternal) {
+                ::bevy_asset_loader::asset_collection::register_reflect_collection::<Self>(registry);
+            }
+        }
+    } else {
+        quote!()
+    };
+    let from_world_impl = if reflect {
+        quote! {
+            impl ::bevy::ecs::world::FromWorld for #name {
+                fn from_world(world: &mut ::bevy::ecs::world::World) -> Self {
+                    world.init_resource::<::bevy_asset_loader::dynamic_asset::DynamicAssets>();
+                    <Self as AssetCollection>::create(world)
+                }
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    let debug_impl = if derive_debug {
+        let name_string = name.to_string();
+        quote! {
+            #[automatically_derived]
+            impl ::std::fmt::Debug for #name {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    f.debug_struct(#name_string)
+                        #debug_fields
+                        .finish()
+                }
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    let accessor_impl = if accessors.is_empty() {
+        quote!()
+    } else {
+        quote! {
+            #[automatically_derived]
+            #[allow(dead_code)]
+            impl #name {
+                #accessors
+            }
+        }
+    };
+
+    #[cfg(feature = "asset_manifest")]
+    let asset_manifest = {
+        let name_string = name.to_string();
+        assets.iter().fold(quote!(), |token_stream, asset| {
+            let field = asset.field_ident().to_string();
+            asset.asset_paths().into_iter().fold(token_stream, |token_stream, path| {
+                quote!(#token_stream
+                    ::bevy_asset_loader::asset_manifest::inventory::submit! {
+                        ::bevy_asset_loader::asset_manifest::AssetRef {
+                            collection: ::std::concat!(::std::module_path!(), "::", #name_string),
+                            field: #field,
+                            path: #path,
+                        }
+                    }
+                )
+            })
+        })
+    };
+    #[cfg(not(feature = "asset_manifest"))]
+    let asset_manifest = quote!();
+
     let impl_asset_collection = quote! {
         #[automatically_derived]
         #[allow(unused_variables)]
@@ -206,17 +731,415 @@ fn impl_asset_collection(
             #create_function
 
             #load_function
+
+            #load_requests_function
+
+            #load_deferred_function
+            #ready_to_create_function
+
+            #asset_types_function
+            #modification_watchers_function
+            #register_types_function
+
+            #dynamic_asset_keys_function
+
+            #meta_function
+
+            #pin_strong_function
+
+            #merge_function
+
+            #loading_fields_functions
         }
+
+        #merge_impl
+
+        #debug_impl
+
+        #from_world_impl
+
+        #accessor_impl
+
+        #asset_manifest
     };
     Ok(impl_asset_collection)
 }
 
+/// The asset types a field loads
+fn field_asset_types(field: &Field, asset: &AssetField) -> Vec<proc_macro2::TokenStream> {
+    let mut asset_types = vec![];
+    if let Some(asset_type) = handle_asset_type(&field.ty) {
+        asset_types.push(quote!(#asset_type));
+    }
+    if asset.loads_image() {
+        asset_types.push(quote!(::bevy::prelude::Image));
+    }
+    if let AssetField::PathList(_) = asset {
+        asset_types.push(quote!(::bevy_asset_loader::path_list::PathList));
+    }
+    if let AssetField::Animations(_) | AssetField::GltfMaterials(_) = asset {
+        asset_types.push(quote!(::bevy::gltf::Gltf));
+    }
+    if let AssetField::AudioDuration(_) = asset {
+        asset_types.push(quote!(::bevy::audio::AudioSource));
+    }
+    if let AssetField::AudioBank(_) = asset {
+        asset_types.push(quote!(::bevy::audio::AudioSource));
+        asset_types.push(quote!(::bevy_asset_loader::audio_bank::AudioBankManifest));
+    }
+    asset_types
+}
+
+/// The name of the asset type a field's load requests are throttled as
+///
+/// Fields loading images count as images, other fields use the type of their handles.
+fn load_request_type(field: &Field, asset: &AssetField) -> proc_macro2::TokenStream {
+    if asset.loads_image() {
+        return quote!(Some(::std::any::type_name::<::bevy::prelude::Image>()));
+    }
+    match handle_asset_type(&field.ty) {
+        Some(asset_type) => quote!(Some(::std::any::type_name::<#asset_type>())),
+        None => quote!(None),
+    }
+}
+
+/// The asset type of the first typed ``Handle<T>``, ``HashedHandle<T>`` or ``LoadingField<T>`` in the given type
+///
+/// Untyped handles do not have a known asset type.
+fn handle_asset_type(ty: &syn::Type) -> Option<&syn::Type> {
+    if let syn::Type::Array(array) = ty {
+        return handle_asset_type(&array.elem);
+    }
+    if let syn::Type::Path(type_path) = ty {
+        let segment = type_path.path.segments.last()?;
+        if let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments {
+            for argument in arguments.args.iter() {
+                if let syn::GenericArgument::Type(argument) = argument {
+                    if segment.ident == "Handle"
+                        || segment.ident == "HashedHandle"
+                        || segment.ident == "LoadingField"
+                    {
+                        return Some(argument);
+                    }
+                    if let Some(asset_type) = handle_asset_type(argument) {
+                        return Some(asset_type);
+                    }
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Generate accessor methods for the expected keys of a mapped folder
+fn expected_key_accessors(
+    field: &Field,
+    asset: &AssetField,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let expected_keys = asset.expected_keys();
+    if expected_keys.is_empty() {
+        return Ok(quote!());
+    }
+    let value_type = map_value_type(&field.ty).ok_or_else(|| {
+        syn::Error::new_spanned(
+            &field.ty,
+            "Fields with expected keys need to be maps like 'HashMap<String, Handle<T>>'",
+        )
+    })?;
+    let field_ident = field.ident.clone().unwrap();
+    let mut methods = quote!();
+    for key in expected_keys {
+        let method = syn::parse_str::<Ident>(key).map_err(|_| {
+            syn::Error::new_spanned(
+                field.into_token_stream(),
+                format!("The expected key '{key}' is not a valid method name"),
+            )
+        })?;
+        let doc = format!("The asset with the file name '{key}' in the folder map `{field_ident}`");
+        methods = quote! {
+            #methods
+            #[doc = #doc]
+            pub fn #method(&self) -> &#value_type {
+                ::bevy_asset_loader::asset_collection::folder_map_entry(&self.#field_ident, #key)
+                    .unwrap_or_else(|| panic!("The folder map '{}' does not contain the expected asset '{}'", ::std::stringify!(#field_ident), #key))
+            }
+        };
+    }
+    Ok(methods)
+}
+
+/// Make sure that a field with ``derive_from`` names another field that is not derived itself
+fn check_derive_from_source(asset: &AssetField, assets: &[AssetField]) -> Option<syn::Error> {
+    let AssetField::Derived(derived) = asset else {
+        return None;
+    };
+    let source = assets
+        .iter()
+        .find(|other| other.field_ident() == &derived.source);
+    match source {
+        Some(AssetField::Derived(_)) => Some(syn::Error::new_spanned(
+            &derived.source,
+            "Cannot derive from a field that is derived itself",
+        )),
+        Some(_) => None,
+        None => Some(syn::Error::new_spanned(
+            &derived.source,
+            format!(
+                "No asset field named '{}' in this collection",
+                derived.source
+            ),
+        )),
+    }
+}
+
+/// Collections with ``LoadingField``s are inserted before their assets finished loading
+///
+/// All other fields need to be available at that point already.
+fn check_loading_fields(assets: &[AssetField]) -> Vec<syn::Error> {
+    if !assets
+        .iter()
+        .any(|asset| matches!(asset, AssetField::LoadingField(..)))
+    {
+        return vec![];
+    }
+    assets
+        .iter()
+        .filter(|asset| {
+            !matches!(
+                asset,
+                AssetField::LoadingField(..)
+                    | AssetField::Skipped(_)
+                    | AssetField::FromResource(_)
+                    | AssetField::Uuid(_)
+            )
+        })
+        .map(|asset| {
+            syn::Error::new_spanned(
+                asset.field_ident(),
+                "Collections with 'LoadingField's are inserted before their assets are loaded. All other fields need to be of type 'LoadingField' or use 'skip', 'from_resource' or 'uuid'",
+            )
+        })
+        .collect()
+}
+
+/// The asset type of a field of type ``LoadingField<T>``
+fn loading_field_asset_type(ty: &syn::Type) -> Option<proc_macro2::TokenStream> {
+    let syn::Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "LoadingField" {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    arguments.args.iter().find_map(|argument| match argument {
+        syn::GenericArgument::Type(asset_type) => Some(asset_type.to_token_stream()),
+        _ => None,
+    })
+}
+
+/// The value type of a map type like ``HashMap<String, Handle<T>>``
+fn map_value_type(ty: &syn::Type) -> Option<&syn::Type> {
+    if let syn::Type::Path(type_path) = ty {
+        if let syn::PathArguments::AngleBracketed(arguments) =
+            &type_path.path.segments.last()?.arguments
+        {
+            if let Some(syn::GenericArgument::Type(value_type)) = arguments.args.last() {
+                if arguments.args.len() == 2 {
+                    return Some(value_type);
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Struct level attributes of an asset collection
+struct CollectionAttributes {
+    derive_debug: bool,
+    /// Keep strong handles to everything loaded for the collection in ``PinnedHandles``
+    pin_strong: bool,
+    /// Merge newly loaded collections into an existing resource instead of replacing it
+    merge: bool,
+    /// Register the collection in the ``AppTypeRegistry`` when it is added to an app
+    reflect: bool,
+    /// Key value pairs of the `meta` attribute in their declared order
+    meta: Vec<(String, String)>,
+    /// How a bare ``key`` attribute derives the key from the field name
+    key_case: KeyCase,
+}
+
+/// Transformation of field names into dynamic asset keys
+#[derive(Clone, Copy, Default)]
+enum KeyCase {
+    /// Use the field name as it is
+    #[default]
+    Snake,
+    /// Replace underscores with dots, like `player_skin` to `player.skin`
+    Dot,
+    /// Replace underscores with dashes, like `player_skin` to `player-skin`
+    Kebab,
+}
+
+impl KeyCase {
+    fn parse(case: &str) -> Option<KeyCase> {
+        match case {
+            "snake" => Some(KeyCase::Snake),
+            "dot" => Some(KeyCase::Dot),
+            "kebab" => Some(KeyCase::Kebab),
+            _ => None,
+        }
+    }
+
+    fn key_for(self, field_ident: &Ident) -> String {
+        let field_name = field_ident.unraw().to_string();
+        match self {
+            KeyCase::Snake => field_name,
+            KeyCase::Dot => field_name.replace('_', "."),
+            KeyCase::Kebab => field_name.replace('_', "-"),
+        }
+    }
+}
+
+/// Parse the struct level ``asset_collection`` attributes
+fn parse_collection_attributes(
+    ast: &syn::DeriveInput,
+) -> Result<CollectionAttributes, Vec<syn::Error>> {
+    let mut derive_debug = false;
+    let mut pin_strong = false;
+    let mut merge = false;
+    let mut reflect = false;
+    let mut meta: Vec<(String, String)> = vec![];
+    let mut key_case = None;
+    let mut errors = vec![];
+    for attr in ast.attrs.iter() {
+        if !attr.path.is_ident(ASSET_COLLECTION_ATTRIBUTE) {
+            continue;
+        }
+        match attr.parse_meta() {
+            Ok(Meta::List(meta_list)) => {
+                for nested in meta_list.nested.iter() {
+                    match nested {
+                        NestedMeta::Meta(Meta::Path(path)) if path.is_ident(DEBUG_ATTRIBUTE) => {
+                            derive_debug = true
+                        }
+                        NestedMeta::Meta(Meta::Path(path))
+                            if path.is_ident(PIN_STRONG_ATTRIBUTE) =>
+                        {
+                            pin_strong = true
+                        }
+                        NestedMeta::Meta(Meta::Path(path)) if path.is_ident(MERGE_ATTRIBUTE) => {
+                            merge = true
+                        }
+                        NestedMeta::Meta(Meta::Path(path)) if path.is_ident(REFLECT_ATTRIBUTE) => {
+                            reflect = true
+                        }
+                        NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                            path,
+                            lit: Lit::Str(case),
+                            ..
+                        })) if path.is_ident(KEY_CASE_ATTRIBUTE) => {
+                            match KeyCase::parse(&case.value()) {
+                                Some(_) if key_case.is_some() => {
+                                    errors.push(syn::Error::new_spanned(
+                                        nested.into_token_stream(),
+                                        "The key case is defined twice",
+                                    ))
+                                }
+                                Some(case) => key_case = Some(case),
+                                None => errors.push(syn::Error::new_spanned(
+                                    case.into_token_stream(),
+                                    "Unknown key case, expected one of \"snake\", \"dot\" or \"kebab\"",
+                                )),
+                            }
+                        }
+                        NestedMeta::Meta(Meta::List(meta_list))
+                            if meta_list.path.is_ident(META_ATTRIBUTE) =>
+                        {
+                            for entry in meta_list.nested.iter() {
+                                match entry {
+                                    NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                                        path,
+                                        lit: Lit::Str(value),
+                                        ..
+                                    })) if path.get_ident().is_some() => {
+                                        let key = path.get_ident().unwrap().to_string();
+                                        if meta.iter().any(|(existing, _)| *existing == key) {
+                                            errors.push(syn::Error::new_spanned(
+                                                entry.into_token_stream(),
+                                                format!("The meta key '{key}' is defined twice"),
+                                            ));
+                                        } else {
+                                            meta.push((key, value.value()));
+                                        }
+                                    }
+                                    _ => errors.push(syn::Error::new_spanned(
+                                        entry.into_token_stream(),
+                                        "Expected meta data like 'category = \"critical\"'",
+                                    )),
+                                }
+                            }
+                        }
+                        _ => errors.push(syn::Error::new_spanned(
+                            nested.into_token_stream(),
+                            "Unknown attribute",
+                        )),
+                    }
+                }
+            }
+            _ => errors.push(syn::Error::new_spanned(
+                attr.into_token_stream(),
+                "Expected a list of attributes like 'asset_collection(debug)'",
+            )),
+        }
+    }
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    Ok(CollectionAttributes {
+        derive_debug,
+        pin_strong,
+        merge,
+        reflect,
+        meta,
+        key_case: key_case.unwrap_or_default(),
+    })
+}
+
 #[derive(Debug)]
 enum ParseFieldError {
     NoAttributes,
     KeyAttributeStandsAlone,
+    SkipAttributeStandsAlone,
     OnlyDynamicCanBeOptional,
     PathAndPathsAreExclusive,
+    PathsConstIsExclusive,
+    ExpectRequiresMappedFolder,
+    MapKeyRequiresMappedFolder,
+    DynamicFieldRequiresKey,
+    AudioBankRequiresPath,
+    PathListStandsAlone,
+    FromResourceStandsAlone,
+    ArrayRequiresPaths,
+    ArrayLengthMismatch(usize, usize),
+    UuidStandsAlone,
+    MipmapsRequiresPath,
+    ShaderRequiresPath,
+    HashRequiresPath,
+    AnimationsRequirePath,
+    GltfMaterialsRequirePath,
+    DurationRequiresPath,
+    AbsoluteRequiresPath,
+    RegionRequiresPath,
+    PlatformRequiresAsset,
+    OnFailRequiresAsset,
+    WeightRequiresAsset,
+    SortRequiresCollection,
+    LoadingFieldRequiresHandle,
+    DeriveFromRequiresImageAsset,
+    MaterialAttributesAreExclusive,
     WrongAttributeType(proc_macro2::TokenStream, &'static str),
     UnknownAttributeType(proc_macro2::TokenStream),
     UnknownAttribute(proc_macro2::TokenStream),
@@ -225,9 +1148,67 @@ enum ParseFieldError {
     Missing2dFeature(proc_macro2::TokenStream),
     #[allow(dead_code)]
     Missing3dFeature(proc_macro2::TokenStream),
+    #[allow(dead_code)]
+    MissingAudioBankFeature(proc_macro2::TokenStream),
+    #[allow(dead_code)]
+    MissingAnimationFeature(proc_macro2::TokenStream),
+    #[allow(dead_code)]
+    MissingGltfMaterialsFeature(proc_macro2::TokenStream),
+    #[allow(dead_code)]
+    MissingAudioDurationFeature(proc_macro2::TokenStream),
+    #[allow(dead_code)]
+    MissingContentHashFeature(proc_macro2::TokenStream),
+    #[allow(dead_code)]
+    MissingRenderFeature(proc_macro2::TokenStream),
+}
+
+/// Split a path like `SharedAssets::font` into the resource type and its field
+fn parse_resource_field(
+    resource_field: &str,
+) -> Option<(proc_macro2::TokenStream, proc_macro2::Ident)> {
+    let (resource, field) = resource_field.rsplit_once("::")?;
+    let resource = syn::parse_str::<syn::Path>(resource).ok()?;
+    let field = syn::parse_str::<proc_macro2::Ident>(field).ok()?;
+    Some((resource.into_token_stream(), field))
+}
+
+/// Parse a hyphenated UUID like `d8a7d6e1-5a1c-4c1e-9d1b-3f1e2a7b9c00`
+fn parse_uuid(uuid: &str) -> Option<u128> {
+    let groups: Vec<&str> = uuid.split('-').collect();
+    if groups.iter().map(|group| group.len()).ne([8, 4, 4, 4, 12])
+        || !groups
+            .iter()
+            .all(|group| group.chars().all(|char| char.is_ascii_hexdigit()))
+    {
+        return None;
+    }
+    u128::from_str_radix(&groups.concat(), 16).ok()
+}
+
+/// Length and element type of an array field like `[Handle<Image>; 4]`
+fn parse_array_type(array: &syn::TypeArray) -> ArrayType {
+    let length = match &array.len {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: Lit::Int(length),
+            ..
+        }) => length.base10_parse().ok(),
+        _ => None,
+    };
+    let untyped = match array.elem.as_ref() {
+        syn::Type::Path(element) => element
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "HandleUntyped"),
+        _ => false,
+    };
+    ArrayType {
+        length,
+        typed: (!untyped).into(),
+    }
 }
 
-fn parse_field(field: &Field) -> Result<AssetField, Vec<ParseFieldError>> {
+fn parse_field(field: &Field, key_case: KeyCase) -> Result<AssetField, Vec<ParseFieldError>> {
     let mut builder = AssetBuilder::default();
     let mut errors = vec![];
     for attr in field.attrs.iter() {
@@ -250,6 +1231,22 @@ fn parse_field(field: &Field) -> Result<AssetField, Vec<ParseFieldError>> {
                                 "str",
                             ));
                         }
+                    } else if path == PATHS_CONST_ATTRIBUTE {
+                        match &named_value.lit {
+                            Lit::Str(const_literal) => match const_literal.parse::<syn::Path>() {
+                                Ok(const_path) => {
+                                    builder.asset_paths_const = Some(const_path.into_token_stream())
+                                }
+                                Err(_) => errors.push(ParseFieldError::WrongAttributeType(
+                                    named_value.into_token_stream(),
+                                    "path to a const",
+                                )),
+                            },
+                            _ => errors.push(ParseFieldError::WrongAttributeType(
+                                named_value.into_token_stream(),
+                                "str",
+                            )),
+                        }
                     } else if path == KEY_ATTRIBUTE {
                         if let Lit::Str(path_literal) = &named_value.lit {
                             builder.key = Some(path_literal.value());
@@ -259,6 +1256,130 @@ fn parse_field(field: &Field) -> Result<AssetField, Vec<ParseFieldError>> {
                                 "str",
                             ));
                         }
+                    } else if path == DERIVE_FROM_ATTRIBUTE {
+                        match &named_value.lit {
+                            Lit::Str(source_literal) => match source_literal.parse::<Ident>() {
+                                Ok(source) => builder.derive_from = Some(source),
+                                Err(_) => errors.push(ParseFieldError::WrongAttributeType(
+                                    named_value.into_token_stream(),
+                                    "field name",
+                                )),
+                            },
+                            _ => errors.push(ParseFieldError::WrongAttributeType(
+                                named_value.into_token_stream(),
+                                "str",
+                            )),
+                        }
+                    } else if path == MAP_KEY_ATTRIBUTE {
+                        match &named_value.lit {
+                            Lit::Str(parse_literal) => match parse_literal.parse::<syn::Path>() {
+                                Ok(parse) => builder.map_key = Some(parse.into_token_stream()),
+                                Err(_) => errors.push(ParseFieldError::WrongAttributeType(
+                                    named_value.into_token_stream(),
+                                    "path to a function",
+                                )),
+                            },
+                            _ => errors.push(ParseFieldError::WrongAttributeType(
+                                named_value.into_token_stream(),
+                                "str",
+                            )),
+                        }
+                    } else if path == WEIGHT_ATTRIBUTE {
+                        let weight = match &named_value.lit {
+                            Lit::Float(weight) => weight.base10_parse::<f32>().ok(),
+                            Lit::Int(weight) => weight.base10_parse::<f32>().ok(),
+                            _ => None,
+                        };
+                        match weight {
+                            Some(weight) if weight.is_finite() && weight >= 0. => {
+                                builder.weight = Some(weight)
+                            }
+                            _ => errors.push(ParseFieldError::WrongAttributeType(
+                                named_value.into_token_stream(),
+                                "non-negative number",
+                            )),
+                        }
+                    } else if path == SORT_ATTRIBUTE {
+                        match &named_value.lit {
+                            Lit::Str(sort) if sort.value() == "path" => builder.is_sorted = true,
+                            _ => errors.push(ParseFieldError::WrongAttributeType(
+                                named_value.into_token_stream(),
+                                "\"path\"",
+                            )),
+                        }
+                    } else if path == ON_FAIL_ATTRIBUTE {
+                        match &named_value.lit {
+                            Lit::Str(fallback_literal) => {
+                                match fallback_literal.parse::<syn::Path>() {
+                                    Ok(fallback) => {
+                                        builder.on_fail = Some(fallback.into_token_stream())
+                                    }
+                                    Err(_) => errors.push(ParseFieldError::WrongAttributeType(
+                                        named_value.into_token_stream(),
+                                        "path to a function",
+                                    )),
+                                }
+                            }
+                            _ => errors.push(ParseFieldError::WrongAttributeType(
+                                named_value.into_token_stream(),
+                                "str",
+                            )),
+                        }
+                    } else if path == FROM_RESOURCE_ATTRIBUTE {
+                        match &named_value.lit {
+                            Lit::Str(resource_literal) => {
+                                match parse_resource_field(&resource_literal.value()) {
+                                    Some(from_resource) => {
+                                        builder.from_resource = Some(from_resource)
+                                    }
+                                    None => errors.push(ParseFieldError::WrongAttributeType(
+                                        named_value.into_token_stream(),
+                                        "path to a resource field, like \"SharedAssets::font\"",
+                                    )),
+                                }
+                            }
+                            _ => errors.push(ParseFieldError::WrongAttributeType(
+                                named_value.into_token_stream(),
+                                "str",
+                            )),
+                        }
+                    } else if path == UUID_ATTRIBUTE {
+                        match &named_value.lit {
+                            Lit::Str(uuid_literal) => match parse_uuid(&uuid_literal.value()) {
+                                Some(uuid) => builder.asset_uuid = Some(uuid),
+                                None => errors.push(ParseFieldError::WrongAttributeType(
+                                    named_value.into_token_stream(),
+                                    "hyphenated UUID",
+                                )),
+                            },
+                            _ => errors.push(ParseFieldError::WrongAttributeType(
+                                named_value.into_token_stream(),
+                                "str",
+                            )),
+                        }
+                    } else if path == PATH_LIST_ATTRIBUTE {
+                        if let Lit::Str(list_literal) = &named_value.lit {
+                            builder.path_list = Some(list_literal.value());
+                        } else {
+                            errors.push(ParseFieldError::WrongAttributeType(
+                                named_value.into_token_stream(),
+                                "str",
+                            ));
+                        }
+                    } else if path == AUDIO_BANK_ATTRIBUTE {
+                        #[cfg(not(feature = "audio_bank"))]
+                        errors.push(ParseFieldError::MissingAudioBankFeature(
+                            named_value.into_token_stream(),
+                        ));
+                        #[cfg(feature = "audio_bank")]
+                        if let Lit::Str(manifest_literal) = &named_value.lit {
+                            builder.audio_bank_manifest = Some(manifest_literal.value());
+                        } else {
+                            errors.push(ParseFieldError::WrongAttributeType(
+                                named_value.into_token_stream(),
+                                "str",
+                            ));
+                        }
                     } else {
                         errors.push(ParseFieldError::UnknownAttribute(
                             named_value.into_token_stream(),
@@ -266,7 +1387,75 @@ fn parse_field(field: &Field) -> Result<AssetField, Vec<ParseFieldError>> {
                     }
                 } else if let NestedMeta::Meta(Meta::Path(ref meta_path)) = attribute {
                     let path = meta_path.get_ident().unwrap().clone();
-                    if path == STANDARD_MATERIAL_ATTRIBUTE {
+                    if path == CFG_DESKTOP_ATTRIBUTE || path == CFG_MOBILE_ATTRIBUTE {
+                        let platform = if path == CFG_DESKTOP_ATTRIBUTE {
+                            Platform::Desktop
+                        } else {
+                            Platform::Mobile
+                        };
+                        if builder.platform.is_some() {
+                            errors.push(ParseFieldError::PlatformRequiresAsset);
+                        }
+                        builder.platform = Some(platform);
+                    } else if path == MIPMAPS_ATTRIBUTE {
+                        #[cfg(not(any(feature = "2d", feature = "3d")))]
+                        errors.push(ParseFieldError::MissingRenderFeature(
+                            meta_path.into_token_stream(),
+                        ));
+                        #[cfg(any(feature = "2d", feature = "3d"))]
+                        {
+                            builder.is_mipmaps = true;
+                        }
+                    } else if path == KEY_ATTRIBUTE {
+                        builder.key = Some(key_case.key_for(field.ident.as_ref().unwrap()));
+                    } else if path == HASH_ATTRIBUTE {
+                        #[cfg(not(feature = "content_hash"))]
+                        errors.push(ParseFieldError::MissingContentHashFeature(
+                            meta_path.into_token_stream(),
+                        ));
+                        #[cfg(feature = "content_hash")]
+                        {
+                            builder.is_hashed = true;
+                        }
+                    } else if path == ANIMATIONS_ATTRIBUTE {
+                        #[cfg(not(feature = "animation"))]
+                        errors.push(ParseFieldError::MissingAnimationFeature(
+                            meta_path.into_token_stream(),
+                        ));
+                        #[cfg(feature = "animation")]
+                        {
+                            builder.is_animations = true;
+                        }
+                    } else if path == GLTF_MATERIALS_ATTRIBUTE {
+                        #[cfg(not(feature = "gltf_materials"))]
+                        errors.push(ParseFieldError::MissingGltfMaterialsFeature(
+                            meta_path.into_token_stream(),
+                        ));
+                        #[cfg(feature = "gltf_materials")]
+                        {
+                            builder.is_gltf_materials = true;
+                        }
+                    } else if path == ABSOLUTE_ATTRIBUTE {
+                        builder.is_absolute = true;
+                    } else if path == DURATION_ATTRIBUTE {
+                        #[cfg(not(feature = "audio_duration"))]
+                        errors.push(ParseFieldError::MissingAudioDurationFeature(
+                            meta_path.into_token_stream(),
+                        ));
+                        #[cfg(feature = "audio_duration")]
+                        {
+                            builder.is_duration = true;
+                        }
+                    } else if path == SHADER_ATTRIBUTE {
+                        #[cfg(not(any(feature = "2d", feature = "3d")))]
+                        errors.push(ParseFieldError::MissingRenderFeature(
+                            meta_path.into_token_stream(),
+                        ));
+                        #[cfg(any(feature = "2d", feature = "3d"))]
+                        {
+                            builder.is_shader = true;
+                        }
+                    } else if path == STANDARD_MATERIAL_ATTRIBUTE {
                         #[cfg(not(feature = "3d"))]
                         errors.push(ParseFieldError::Missing3dFeature(
                             meta_path.into_token_stream(),
@@ -275,12 +1464,25 @@ fn parse_field(field: &Field) -> Result<AssetField, Vec<ParseFieldError>> {
                         {
                             builder.is_standard_material = true;
                         }
+                    } else if path == COLOR_MATERIAL_ATTRIBUTE {
+                        #[cfg(not(feature = "2d"))]
+                        errors.push(ParseFieldError::Missing2dFeature(
+                            meta_path.into_token_stream(),
+                        ));
+                        #[cfg(feature = "2d")]
+                        {
+                            builder.is_color_material = true;
+                        }
                     } else if path == OPTIONAL_ATTRIBUTE {
                         builder.is_optional = true;
                     } else if path == COLLECTION_ATTRIBUTE {
                         builder.is_collection = true;
                     } else if path == TYPED_ATTRIBUTE {
                         builder.is_typed = true;
+                    } else if path == SKIP_ATTRIBUTE {
+                        builder.is_skipped = true;
+                    } else if path == DYNAMIC_FIELD_ATTRIBUTE {
+                        builder.is_dynamic_field = true;
                     } else {
                         errors.push(ParseFieldError::UnknownAttribute(
                             meta_path.into_token_stream(),
@@ -387,6 +1589,46 @@ fn parse_field(field: &Field) -> Result<AssetField, Vec<ParseFieldError>> {
                                 ));
                             }
                         }
+                    } else if path == REGION_ATTRIBUTE {
+                        #[cfg(not(any(feature = "2d", feature = "3d")))]
+                        errors.push(ParseFieldError::MissingRenderFeature(
+                            meta_list.into_token_stream(),
+                        ));
+                        #[cfg(any(feature = "2d", feature = "3d"))]
+                        for attribute in meta_list.nested.iter() {
+                            if let NestedMeta::Meta(Meta::NameValue(ref named_value)) = attribute {
+                                let path = named_value.path.get_ident().unwrap().clone();
+                                let value = if let Lit::Int(value) = &named_value.lit {
+                                    value.base10_parse::<u32>().ok()
+                                } else {
+                                    None
+                                };
+                                let Some(value) = value else {
+                                    errors.push(ParseFieldError::WrongAttributeType(
+                                        named_value.into_token_stream(),
+                                        "integer",
+                                    ));
+                                    continue;
+                                };
+                                if path == RegionAttribute::X {
+                                    builder.region_x = Some(value);
+                                } else if path == RegionAttribute::Y {
+                                    builder.region_y = Some(value);
+                                } else if path == RegionAttribute::WIDTH {
+                                    builder.region_width = Some(value);
+                                } else if path == RegionAttribute::HEIGHT {
+                                    builder.region_height = Some(value);
+                                } else {
+                                    errors.push(ParseFieldError::UnknownAttribute(
+                                        named_value.into_token_stream(),
+                                    ));
+                                }
+                            } else {
+                                errors.push(ParseFieldError::UnknownAttributeType(
+                                    attribute.into_token_stream(),
+                                ));
+                            }
+                        }
                     } else if path == COLLECTION_ATTRIBUTE {
                         builder.is_collection = true;
                         for attribute in meta_list.nested.iter() {
@@ -407,6 +1649,30 @@ fn parse_field(field: &Field) -> Result<AssetField, Vec<ParseFieldError>> {
                                 ));
                             }
                         }
+                    } else if path == KEY_ENUM_ATTRIBUTE {
+                        match meta_list.nested.first() {
+                            Some(NestedMeta::Meta(Meta::Path(key_path)))
+                                if meta_list.nested.len() == 1 =>
+                            {
+                                builder.key_enum = Some(key_path.into_token_stream());
+                            }
+                            _ => errors.push(ParseFieldError::WrongAttributeType(
+                                meta_list.into_token_stream(),
+                                "path",
+                            )),
+                        }
+                    } else if path == EXPECT_ATTRIBUTE {
+                        let mut keys = vec![];
+                        for attribute in meta_list.nested.iter() {
+                            if let NestedMeta::Lit(Lit::Str(key)) = attribute {
+                                keys.push(key.value());
+                            } else {
+                                errors.push(ParseFieldError::UnknownAttributeType(
+                                    attribute.into_token_stream(),
+                                ));
+                            }
+                        }
+                        builder.expected_keys = Some(keys);
                     } else if path == PATHS_ATTRIBUTE {
                         let mut paths = vec![];
                         for attribute in meta_list.nested.iter() {
@@ -435,6 +1701,10 @@ fn parse_field(field: &Field) -> Result<AssetField, Vec<ParseFieldError>> {
     if !errors.is_empty() {
         return Err(errors);
     }
+    if let syn::Type::Array(array) = &field.ty {
+        builder.array = Some(parse_array_type(array));
+    }
+    builder.loading_field = loading_field_asset_type(&field.ty);
     builder.build()
 }
 
@@ -0,0 +1,101 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{bracketed, Expr, Ident, Token, Type};
+
+const COLLECTIONS_KEY: &str = "collections";
+const INIT_RESOURCES_KEY: &str = "init_resources";
+
+/// Input of the `loading_state!` macro
+///
+/// `LoadingState => NextState, collections: [..], init_resources: [..]` where the next state and
+/// both lists are optional.
+pub(crate) struct LoadingStateInput {
+    loading_state: Expr,
+    next_state: Option<Expr>,
+    collections: Vec<Type>,
+    init_resources: Vec<Type>,
+}
+
+impl Parse for LoadingStateInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let loading_state = input.parse()?;
+        let next_state = if input.peek(Token![=>]) {
+            input.parse::<Token![=>]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        let mut collections = None;
+        let mut init_resources = None;
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            let key: Ident = input.parse()?;
+            input.parse::<Token![:]>()?;
+            let content;
+            bracketed!(content in input);
+            let types: Vec<Type> = Punctuated::<Type, Token![,]>::parse_terminated(&content)?
+                .into_iter()
+                .collect();
+            let list = if key == COLLECTIONS_KEY {
+                &mut collections
+            } else if key == INIT_RESOURCES_KEY {
+                &mut init_resources
+            } else {
+                return Err(syn::Error::new_spanned(
+                    key,
+                    format!("Unknown key; expected `{COLLECTIONS_KEY}` or `{INIT_RESOURCES_KEY}`"),
+                ));
+            };
+            if list.replace(types).is_some() {
+                return Err(syn::Error::new_spanned(key, "Duplicate key"));
+            }
+        }
+
+        Ok(LoadingStateInput {
+            loading_state,
+            next_state,
+            collections: collections.unwrap_or_default(),
+            init_resources: init_resources.unwrap_or_default(),
+        })
+    }
+}
+
+pub(crate) fn impl_loading_state(input: LoadingStateInput) -> TokenStream {
+    let LoadingStateInput {
+        loading_state,
+        next_state,
+        collections,
+        init_resources,
+    } = input;
+    let continue_to_state = next_state.map(|next| quote!(.continue_to_state(#next)));
+
+    quote! {
+        {
+            struct LoadingStatePlugin;
+
+            impl ::bevy::app::Plugin for LoadingStatePlugin {
+                fn build(&self, app: &mut ::bevy::app::App) {
+                    use ::bevy_asset_loader::loading_state::LoadingStateAppExt;
+                    app.add_loading_state(
+                        ::bevy_asset_loader::loading_state::LoadingState::new(#loading_state)
+                            #continue_to_state
+                    );
+                    #(app.add_collection_to_loading_state::<_, #collections>(#loading_state);)*
+                    #(app.init_resource_after_loading_state::<_, #init_resources>(#loading_state);)*
+                }
+
+                // Every invocation of the macro defines its own plugin with the same type name
+                fn is_unique(&self) -> bool {
+                    false
+                }
+            }
+
+            LoadingStatePlugin
+        }
+    }
+}